@@ -0,0 +1,57 @@
+//! Compares setting five components with [`EntityView::set_all`] against chained `set` calls,
+//! which move the entity to a new table for every component.
+//!
+//! Run with `cargo bench -p flecs_ecs --bench set_all`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use flecs_ecs::prelude::*;
+
+#[allow(dead_code)]
+#[derive(Component)]
+struct A(f32);
+
+#[allow(dead_code)]
+#[derive(Component)]
+struct B(f32);
+
+#[allow(dead_code)]
+#[derive(Component)]
+struct C(f32);
+
+#[allow(dead_code)]
+#[derive(Component)]
+struct D(f32);
+
+#[allow(dead_code)]
+#[derive(Component)]
+struct E(f32);
+
+fn set_five(c: &mut Criterion) {
+    let world = World::new();
+
+    let mut group = c.benchmark_group("set_five");
+    group.bench_function("set_all", |b| {
+        b.iter(|| {
+            world
+                .entity()
+                .set_all((A(1.0), B(2.0), C(3.0), D(4.0), E(5.0)))
+                .destruct();
+        });
+    });
+    group.bench_function("chained_set", |b| {
+        b.iter(|| {
+            world
+                .entity()
+                .set(A(1.0))
+                .set(B(2.0))
+                .set(C(3.0))
+                .set(D(4.0))
+                .set(E(5.0))
+                .destruct();
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, set_five);
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+//! Compares converting names to nul terminated strings with [`SmallCStr`] against allocating a
+//! `CString`, on its own and as part of a name lookup.
+//!
+//! Run with `cargo bench -p flecs_ecs --bench small_cstr`.
+
+use std::ffi::CString;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use flecs_ecs::prelude::*;
+use flecs_ecs::sys;
+
+const NAMES: [&str; 3] = [
+    "Bob",
+    "SpaceShip::Engine::Thruster",
+    "a_rather_long_entity_name_that_is_still_shorter_than_the_inline_capacity_of_small_c_str",
+];
+
+fn convert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convert");
+    for name in NAMES {
+        group.bench_with_input(
+            BenchmarkId::new("small_cstr", name.len()),
+            name,
+            |b, name| {
+                b.iter(|| SmallCStr::<128>::new(black_box(name)).unwrap());
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("cstring", name.len()), name, |b, name| {
+            b.iter(|| CString::new(black_box(name)).unwrap());
+        });
+    }
+    group.finish();
+}
+
+fn lookup(c: &mut Criterion) {
+    let world = World::new();
+    for name in NAMES {
+        world.entity_named(name);
+    }
+
+    let mut group = c.benchmark_group("lookup");
+    for name in NAMES {
+        group.bench_with_input(
+            BenchmarkId::new("small_cstr", name.len()),
+            name,
+            |b, name| {
+                b.iter(|| world.lookup(black_box(name)));
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("cstring", name.len()), name, |b, name| {
+            b.iter(|| {
+                let name = CString::new(black_box(name)).unwrap();
+                unsafe {
+                    sys::ecs_lookup_path_w_sep(
+                        world.ptr_mut(),
+                        0,
+                        name.as_ptr(),
+                        c"::".as_ptr(),
+                        c"::".as_ptr(),
+                        false,
+                    )
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, convert, lookup);
+criterion_main!(benches);
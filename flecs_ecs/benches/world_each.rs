@@ -0,0 +1,76 @@
+//! Compares iterating with [`World::each`], which creates an uncached query for every call,
+//! against iterating a cached query that was built once.
+//!
+//! Run with `cargo bench -p flecs_ecs --bench world_each`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+struct Velocity {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+struct TagA;
+
+#[derive(Component)]
+struct TagB;
+
+#[derive(Component)]
+struct TagC;
+
+fn populate(world: &World) {
+    for i in 0..1000 {
+        let e = world
+            .entity()
+            .set(Position { x: 0.0, y: 0.0 })
+            .set(Velocity { x: 1.0, y: 1.0 });
+        // spread the entities over a few tables
+        match i % 4 {
+            0 => e.add::<TagA>(),
+            1 => e.add::<TagB>(),
+            2 => e.add::<TagC>(),
+            _ => e,
+        };
+    }
+}
+
+fn world_each(c: &mut Criterion) {
+    let world = World::new();
+    populate(&world);
+
+    let query = world
+        .query::<(&mut Position, &Velocity)>()
+        .set_cached()
+        .build();
+
+    let mut group = c.benchmark_group("iterate_1000");
+    group.bench_function("world_each", |b| {
+        b.iter(|| {
+            world.each::<(&mut Position, &Velocity)>(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        });
+    });
+    group.bench_function("cached_query", |b| {
+        b.iter(|| {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, world_each);
+criterion_main!(benches);
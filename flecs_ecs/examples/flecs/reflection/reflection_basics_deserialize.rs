@@ -25,11 +25,11 @@ fn main() {
 
     e.get::<&mut Position>(|pos| {
         let mut cur = world.cursor::<Position>(pos);
-        cur.push(); // {
-        cur.set_float(10.0); //   10
-        cur.next(); //   ,
-        cur.set_float(20.0); //   20
-        cur.pop(); // }
+        cur.push().unwrap(); // {
+        cur.set_float(10.0).unwrap(); //   10
+        cur.next().unwrap(); //   ,
+        cur.set_float(20.0).unwrap(); //   20
+        cur.pop().unwrap(); // }
 
         println!("{}", world.to_expr(pos));
     });
@@ -37,12 +37,12 @@ fn main() {
     // Use member names before assigning values
     e.get::<&mut Position>(|pos| {
         let mut cur = world.cursor::<Position>(pos);
-        cur.push(); // {
-        cur.member("y"); //   y:
-        cur.set_float(10.0); //   10
-        cur.member("x"); //   x:
-        cur.set_float(20.0); //   20
-        cur.pop(); // }
+        cur.push().unwrap(); // {
+        cur.member("y").unwrap(); //   y:
+        cur.set_float(10.0).unwrap(); //   10
+        cur.member("x").unwrap(); //   x:
+        cur.set_float(20.0).unwrap(); //   20
+        cur.pop().unwrap(); // }
 
         println!("{}", world.to_expr(pos));
     });
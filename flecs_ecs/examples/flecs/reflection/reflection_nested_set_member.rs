@@ -29,22 +29,22 @@ fn main() {
     e.get::<&mut Line>(|line| {
         let mut cur = world.cursor(line);
 
-        cur.push(); // {
-        cur.member("start"); //   start:
-        cur.push(); //   {
-        cur.member("x"); //     x:
-        cur.set_float(10.0); //     10
-        cur.member("y"); //     y:
-        cur.set_float(20.0); //     20
-        cur.pop(); //   }
-        cur.member("stop"); //   stop:
-        cur.push(); //   {
-        cur.member("x"); //     x:
-        cur.set_float(30.0); //     30
-        cur.member("y"); //     y:
-        cur.set_float(40.0); //     40
-        cur.pop(); //   }
-        cur.pop(); // }
+        cur.push().unwrap(); // {
+        cur.member("start").unwrap(); //   start:
+        cur.push().unwrap(); //   {
+        cur.member("x").unwrap(); //     x:
+        cur.set_float(10.0).unwrap(); //     10
+        cur.member("y").unwrap(); //     y:
+        cur.set_float(20.0).unwrap(); //     20
+        cur.pop().unwrap(); //   }
+        cur.member("stop").unwrap(); //   stop:
+        cur.push().unwrap(); //   {
+        cur.member("x").unwrap(); //     x:
+        cur.set_float(30.0).unwrap(); //     30
+        cur.member("y").unwrap(); //     y:
+        cur.set_float(40.0).unwrap(); //     40
+        cur.pop().unwrap(); //   }
+        cur.pop().unwrap(); // }
 
         // Convert component to string
         println!("{}", world.to_expr(line));
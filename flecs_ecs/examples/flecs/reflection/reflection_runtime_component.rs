@@ -16,11 +16,11 @@ fn main() {
     let ptr = e.get_untyped_mut(position);
 
     let mut cur = world.cursor_id(position, ptr);
-    cur.push();
-    cur.set_float(10.0);
-    cur.next();
-    cur.set_float(20.0);
-    cur.pop();
+    cur.push().unwrap();
+    cur.set_float(10.0).unwrap();
+    cur.next().unwrap();
+    cur.set_float(20.0).unwrap();
+    cur.pop().unwrap();
 
     // Convert component to string
     println!("{:?}", world.to_expr_id(position, ptr));
@@ -23,25 +23,26 @@ fn main() {
     let mut cur = world.cursor_id(line, ptr);
 
     #[rustfmt::skip]
-    fn cursor(cur: &mut Cursor) {
-        cur.push();            // {
-        cur.push();            //   {
-        cur.set_float(10.0);   //     10
-        cur.next();            //     ,
-        cur.set_float(20.0);   //     20
-        cur.pop();             //   }
-        cur.next();            //   ,
-        cur.push();            //   {
-        cur.set_float(30.0);   //     30
-        cur.next();            //     ,
-        cur.set_float(40.0);   //     40
-        cur.pop();             //   }
-        cur.pop();             // }
+    fn cursor(cur: &mut Cursor) -> Result<(), CursorError> {
+        cur.push()?;          // {
+        cur.push()?;          //   {
+        cur.set_float(10.0)?; //     10
+        cur.next()?;          //     ,
+        cur.set_float(20.0)?; //     20
+        cur.pop()?;           //   }
+        cur.next()?;          //   ,
+        cur.push()?;          //   {
+        cur.set_float(30.0)?; //     30
+        cur.next()?;          //     ,
+        cur.set_float(40.0)?; //     40
+        cur.pop()?;           //   }
+        cur.pop()?;           // }
+        Ok(())
     }
 
     // we use a function to format skip the comments for better understanding.
     // in normal cases, you can just write the code directly.
-    cursor(&mut cur);
+    cursor(&mut cur).unwrap();
 
     // Convert component to string
     println!("{:?}", world.to_expr_id(line, ptr));
@@ -86,7 +86,7 @@ where
 
     /// Create a new `AlertBuilder` with a name
     pub(crate) fn new_named(world: &'a World, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let mut obj = Self {
             desc: Default::default(),
@@ -165,7 +165,7 @@ where
     ///
     /// # Arguments
     ///
-    /// * `severity` - The severity entity.
+    /// * `severity` - The severity entity, or an [`AlertSeverity`](super::AlertSeverity).
     ///
     /// # See also
     ///
@@ -374,7 +374,7 @@ where
     where
         With: ComponentId,
     {
-        let member_name = compact_str::format_compact!("{}\0", member_name);
+        let member_name = SmallCStr::<128>::from_arg(member_name);
         let world = self.world();
         let id = With::id(world);
         let member_id = unsafe {
@@ -77,6 +77,114 @@ impl<'a> Alert<'a> {
             entity: alert_entity,
         }
     }
+
+    /// Iterate the active instances of the alert.
+    ///
+    /// An instance is created for each entity matched by the alert query, as a child of the
+    /// alert. Instances are created and removed when the alerts are monitored, which happens
+    /// every 0.5 seconds of world time.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked with the instance, the entity that raised the alert
+    ///   and the alert message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::addons::alerts::*;
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.import::<AlertsModule>();
+    ///
+    /// let alert = world
+    ///     .alert::<&Position>()
+    ///     .without::<Velocity>()
+    ///     .message("$this has Position but not Velocity")
+    ///     .build();
+    ///
+    /// let e = world.entity_named("e").set(Position { x: 10.0, y: 20.0 });
+    ///
+    /// world.progress_time(1.0);
+    ///
+    /// alert.each_instance(|_, source, message| {
+    ///     assert_eq!(source, e);
+    ///     assert_eq!(message, "e has Position but not Velocity");
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Alert::instance_count()`]
+    /// * [`EntityView::alert_count()`]
+    pub fn each_instance(&self, mut func: impl FnMut(EntityView<'a>, EntityView<'a>, &str)) {
+        let world = self.entity.world;
+        let world_ptr = world.world_ptr();
+        let mut it =
+            unsafe { sys::ecs_each_id(world_ptr, ecs_pair(flecs::ChildOf::ID, *self.entity.id())) };
+
+        while unsafe { sys::ecs_each_next(&mut it) } {
+            for i in 0..it.count as usize {
+                let id = unsafe { *it.entities.add(i) };
+                let instance = unsafe {
+                    sys::ecs_get_id(world_ptr, id, *AlertInstance.deref())
+                        as *const sys::EcsAlertInstance
+                };
+                if instance.is_null() {
+                    continue;
+                }
+
+                let source = unsafe {
+                    sys::ecs_get_id(world_ptr, id, sys::FLECS_IDEcsMetricSourceID_)
+                        as *const sys::EcsMetricSource
+                };
+                let source = if source.is_null() {
+                    0
+                } else {
+                    unsafe { (*source).entity }
+                };
+
+                let message = unsafe { (*instance).message };
+                let message = if message.is_null() {
+                    ""
+                } else {
+                    unsafe { std::ffi::CStr::from_ptr(message) }
+                        .to_str()
+                        .unwrap_or("")
+                };
+
+                func(
+                    EntityView::new_from(world, id),
+                    EntityView::new_from(world, source),
+                    message,
+                );
+            }
+        }
+    }
+
+    /// Returns the number of active instances of the alert.
+    ///
+    /// # See also
+    ///
+    /// * [`Alert::each_instance()`]
+    pub fn instance_count(&self) -> usize {
+        let mut count = 0;
+        self.each_instance(|_, _, _| count += 1);
+        count
+    }
 }
 
 impl World {
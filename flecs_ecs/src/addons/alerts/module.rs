@@ -10,7 +10,11 @@ impl Module for AlertsModule {
 
         world.module::<AlertsModule>("::flecs::alerts");
 
-        // Import C module
-        unsafe { flecs_ecs_sys::FlecsAlertsImport(world.ptr_mut()) };
+        unsafe {
+            world.import_c_module(
+                std::ptr::addr_of_mut!(flecs_ecs_sys::FLECS_IDFlecsAlertsID_),
+                flecs_ecs_sys::FlecsAlertsImport,
+            );
+        }
     }
 }
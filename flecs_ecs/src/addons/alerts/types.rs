@@ -39,3 +39,27 @@ create_pre_registered_extern_component!(
     FLECS_IDEcsAlertTimeoutID_,
     "Component added to alert which tracks how long an alert has been inactive."
 );
+
+/// The severity of an alert, to pass to [`AlertBuilder::severity_id()`](super::AlertBuilder::severity_id)
+/// when the severity isn't known at compile time.
+///
+/// The severities are only valid after the [`AlertsModule`](super::AlertsModule) is imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Error,
+    Critical,
+}
+
+impl From<AlertSeverity> for Entity {
+    #[inline]
+    fn from(severity: AlertSeverity) -> Self {
+        match severity {
+            AlertSeverity::Info => Info.into(),
+            AlertSeverity::Warning => Warning.into(),
+            AlertSeverity::Error => Error.into(),
+            AlertSeverity::Critical => Critical.into(),
+        }
+    }
+}
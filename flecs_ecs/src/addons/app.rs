@@ -166,32 +166,49 @@ impl<'a> App<'a> {
     }
 
     /// Run application. This will run the application with the parameters specified in desc.
-    /// After the application quits ([`World::quit()`] is called) this will return.
+    /// After the application quits ([`World::quit()`] is called) or the number of frames set with
+    /// [`App::set_frames()`] ran, this will return.
     /// If a custom run action is set, it will be invoked by this operation.
     /// The default run action calls the frame action in a loop until it returns a non-zero value.
     ///
+    /// Unlike the C++ API, the world is not deleted when the application quits. It is deleted
+    /// when the [`World`] is dropped, so it can still be inspected after this returns. The quit
+    /// flag stays set, see [`World::should_quit()`].
+    ///
     /// # Returns
     ///
     /// The exit code of the application.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Frames(u32);
+    ///
+    /// let world = World::new();
+    /// world.set(Frames(0));
+    ///
+    /// world
+    ///     .system::<&mut Frames>()
+    ///     .term_at(0)
+    ///     .singleton()
+    ///     .each(|frames| frames.0 += 1);
+    ///
+    /// let result = world.app().set_target_fps(0.0).set_frames(3).run();
+    ///
+    /// assert_eq!(result, 0);
+    /// assert!(world.should_quit());
+    /// world.get::<&Frames>(|frames| assert_eq!(frames.0, 3));
+    /// ```
+    ///
     /// # See also
     ///
     /// * C++ API: `app_builder::run`
     #[doc(alias = "app_builder::run")]
     pub fn run(&mut self) -> i32 {
-        let world_ptr = self.world.ptr_mut();
-        let result = unsafe { sys::ecs_app_run(world_ptr, &mut self.desc) };
-        unsafe {
-            if sys::ecs_should_quit(world_ptr) {
-                // Only free world if quit flag is set. This ensures that we won't
-                // try to cleanup the world if the app is used in an environment
-                // that takes over the main loop, like with emscripten.
-                if sys::flecs_poly_release_(world_ptr as *mut c_void) == 0 {
-                    sys::ecs_fini(world_ptr);
-                }
-            }
-        }
-        result
+        unsafe { sys::ecs_app_run(self.world.ptr_mut(), &mut self.desc) }
     }
 }
 
@@ -191,7 +191,6 @@ pub trait Doc<'a>: WorldProvider<'a> + Into<Entity> + Clone {
     ///
     /// # Arguments
     ///
-    /// * `world` - The world.
     /// * `color` - The color to add.
     ///
     /// # See also
@@ -547,7 +546,7 @@ impl World {
     #[doc(alias = "world::set_doc_name")]
     #[inline(always)]
     pub fn set_doc_name_id(&self, entity: impl Into<Entity>, name: &str) {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
         unsafe { sys::ecs_doc_set_name(self.ptr_mut(), *entity.into(), name.as_ptr() as *const _) };
     }
 
@@ -587,7 +586,7 @@ impl World {
     #[doc(alias = "world::set_doc_brief")]
     #[inline(always)]
     pub fn set_doc_brief_id(&self, entity: impl Into<Entity>, brief: &str) {
-        let brief = compact_str::format_compact!("{}\0", brief);
+        let brief = SmallCStr::<128>::from_arg(brief);
         unsafe {
             sys::ecs_doc_set_brief(self.ptr_mut(), *entity.into(), brief.as_ptr() as *const _);
         };
@@ -629,7 +628,7 @@ impl World {
     #[doc(alias = "world::set_doc_detail")]
     #[inline(always)]
     pub fn set_doc_detail_id(&self, entity: impl Into<Entity>, detail: &str) {
-        let detail = compact_str::format_compact!("{}\0", detail);
+        let detail = SmallCStr::<128>::from_arg(detail);
         unsafe {
             sys::ecs_doc_set_detail(self.ptr_mut(), *entity.into(), detail.as_ptr() as *const _);
         };
@@ -671,7 +670,7 @@ impl World {
     #[doc(alias = "world::set_doc_link")]
     #[inline(always)]
     pub fn set_doc_link_id(&self, entity: impl Into<Entity>, link: &str) {
-        let link = compact_str::format_compact!("{}\0", link);
+        let link = SmallCStr::<128>::from_arg(link);
         unsafe { sys::ecs_doc_set_link(self.ptr_mut(), *entity.into(), link.as_ptr() as *const _) };
     }
 
@@ -715,7 +714,7 @@ impl World {
     #[doc(alias = "world::set_doc_color")]
     #[inline(always)]
     pub fn set_doc_color_id(&self, entity: impl Into<Entity>, color: &str) {
-        let color = compact_str::format_compact!("{}\0", color);
+        let color = SmallCStr::<128>::from_arg(color);
         unsafe {
             sys::ecs_doc_set_color(self.ptr_mut(), *entity.into(), color.as_ptr() as *const _);
         };
@@ -739,7 +738,7 @@ impl World {
     /// * C++ API: `entity_builder::set_doc_uuid`
     #[doc(alias = "entity_builder::set_doc_uuid")]
     pub fn set_doc_uuid_id(&self, entity: impl Into<Entity>, uuid: &str) {
-        let uuid = compact_str::format_compact!("{}\0", uuid);
+        let uuid = SmallCStr::<128>::from_arg(uuid);
         unsafe {
             sys::ecs_doc_set_uuid(self.ptr_mut(), *entity.into(), uuid.as_ptr() as *const _);
         };
@@ -40,7 +40,7 @@ impl EntityView<'_> {
                 FlecsErrorCode::InternalError,
                 "could not add comp to entity"
             );
-            let json = compact_str::format_compact!("{}\0", json);
+            let json = SmallCStr::<128>::from_arg(json);
             if let Some(desc) = desc {
                 sys::ecs_ptr_from_json(world, type_, ptr, json.as_ptr() as *const _, desc);
             } else {
@@ -126,7 +126,7 @@ impl EntityView<'_> {
         let world = self.world_ptr_mut();
         let id = *self.id;
         //TODO we should have an Json Type so we don't need to make these conversions multiple times.
-        let json = compact_str::format_compact!("{}\0", json);
+        let json = SmallCStr::<128>::from_arg(json);
         unsafe {
             sys::ecs_entity_from_json(world, id, json.as_ptr() as *const _, std::ptr::null());
         }
@@ -221,7 +221,7 @@ impl World {
             .map(|d| d as *const FromJsonDesc)
             .unwrap_or(std::ptr::null());
         //TODO json object to prevent multiple conversions
-        let json = compact_str::format_compact!("{}\0", json);
+        let json = SmallCStr::<128>::from_arg(json);
 
         unsafe {
             sys::ecs_ptr_from_json(world, tid, value, json.as_ptr() as *const _, desc_ptr);
@@ -257,7 +257,7 @@ impl World {
     pub fn from_json_world(&self, json: &str, desc: Option<&FromJsonDesc>) -> &Self {
         let world = self.ptr_mut();
         //TODO json object to prevent multiple conversions
-        let json = compact_str::format_compact!("{}\0", json);
+        let json = SmallCStr::<128>::from_arg(json);
         let desc_ptr = desc
             .map(|d| d as *const FromJsonDesc)
             .unwrap_or(std::ptr::null());
@@ -282,7 +282,7 @@ impl World {
     ) -> &mut Self {
         let world = self.ptr_mut();
         //TODO json object to prevent multiple conversions
-        let json_file = compact_str::format_compact!("{}\0", json_file);
+        let json_file = SmallCStr::<128>::from_arg(json_file);
         let desc_ptr = desc
             .map(|d| d as *const FromJsonDesc)
             .unwrap_or(std::ptr::null());
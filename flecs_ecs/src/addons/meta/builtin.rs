@@ -1,4 +1,5 @@
 use core::ffi::CStr;
+use core::str::FromStr;
 use flecs_ecs::prelude::*;
 use flecs_ecs::sys;
 
@@ -9,7 +10,7 @@ macro_rules! generate_vec_meta_registration {
                 let id = id!($world, Vec<$t>);
                 $world
                     .component_named_ext::<Vec<$t>>(id, format!("vector::{}", stringify!($t)).as_str())
-                    .opaque_func_id::<_, $t>(id, meta_register_vector_default::<$t>);
+                    .opaque_func_id::<_, $t>(id, std_vec_support::<$t>);
             }
         )*
     };
@@ -73,10 +74,10 @@ fn std_string_support(world: WorldRef) -> Opaque<String> {
 
     // Forward std::string value to (JSON/...) serializer
     ts.serialize(|s: &Serializer, data: &String| {
-        let data = compact_str::format_compact!("{}\0", data);
+        let data = SmallCStr::<128>::new_truncated(data);
         s.value_id(
             flecs::meta::String,
-            &data.as_ptr() as *const *const u8 as *const std::ffi::c_void,
+            &data.as_ptr() as *const *const std::ffi::c_char as *const std::ffi::c_void,
         )
     });
 
@@ -96,11 +97,10 @@ pub fn meta_ser_stringify_type_debug<T: core::fmt::Debug>(world: WorldRef) -> Op
 
     // Forward std::string value to (JSON/...) serializer
     ts.serialize(|s: &Serializer, data: &T| {
-        let data = format!("{:?}", data);
-        let data = compact_str::format_compact!("{}\0", data);
+        let data = SmallCStr::<128>::new_truncated(&format!("{:?}", data));
         s.value_id(
             flecs::meta::String,
-            &data.as_ptr() as *const *const u8 as *const std::ffi::c_void,
+            &data.as_ptr() as *const *const std::ffi::c_char as *const std::ffi::c_void,
         )
     });
 
@@ -115,22 +115,52 @@ pub fn meta_ser_stringify_type_display<T: core::fmt::Display>(world: WorldRef) -
 
     // Forward std::string value to (JSON/...) serializer
     ts.serialize(|s: &Serializer, data: &T| {
-        let data = format!("{}", data);
-        let data = compact_str::format_compact!("{}\0", data);
+        let data = SmallCStr::<128>::new_truncated(&data.to_string());
         s.value_id(
             flecs::meta::String,
-            &data.as_ptr() as *const *const u8 as *const std::ffi::c_void,
+            &data.as_ptr() as *const *const std::ffi::c_char as *const std::ffi::c_void,
         )
     });
 
     ts
 }
 
-pub fn meta_register_vector_default<T: Default>(world: WorldRef) -> Opaque<Vec<T>, T> {
+/// Reflection support for `Vec<T>`, which is described as a `flecs::meta::Vector` of `T`.
+///
+/// The elements are serialized with the type of `T`, which can be a primitive, a struct with
+/// reflection data or another opaque type such as `String`. Deserializing resizes the vector
+/// to the number of elements, new elements start as [`Default`].
+///
+/// `T` must be registered with reflection data before the vector is registered.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// #[meta]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Point>().meta();
+///
+/// let id = id!(&world, Vec<Point>);
+/// world
+///     .component_ext::<Vec<Point>>(id)
+///     .opaque_func_id::<_, Point>(id, std_vec_support::<Point>);
+///
+/// let points = vec![Point { x: 1, y: 2 }];
+/// assert_eq!(world.to_json_dyn(id, &points), "[{\"x\":1, \"y\":2}]");
+/// ```
+pub fn std_vec_support<T: Default>(world: WorldRef) -> Opaque<Vec<T>, T> {
     let mut ts = Opaque::<Vec<T>, T>::new(world);
 
     // Let reflection framework know what kind of type this is
-    ts.as_type(world.vector::<T>());
+    ts.as_type(world.vector_id(id!(world, T)));
 
     // Forward std::vector value to (JSON/...) serializer
     ts.serialize(|s: &Serializer, data: &Vec<T>| {
@@ -152,8 +182,8 @@ pub fn meta_register_vector_default<T: Default>(world: WorldRef) -> Opaque<Vec<T
         &mut data[elem]
     }
 
-    fn resize_generic_vec<T: Default>(data: &mut Vec<T>, elem: usize) {
-        data.resize_with(elem + 1, || T::default());
+    fn resize_generic_vec<T: Default>(data: &mut Vec<T>, count: usize) {
+        data.resize_with(count, || T::default());
     }
 
     // Ensure element exists, return
@@ -164,3 +194,97 @@ pub fn meta_register_vector_default<T: Default>(world: WorldRef) -> Opaque<Vec<T
 
     ts
 }
+
+/// Reflection support for `Vec<T>`, see [`std_vec_support()`].
+pub fn meta_register_vector_default<T: Default>(world: WorldRef) -> Opaque<Vec<T>, T> {
+    std_vec_support::<T>(world)
+}
+
+/// Register a type that converts to and from a string, such as an enum of another crate, as
+/// an opaque type that is serialized as a string.
+///
+/// The value is serialized with [`ToString`] and assigned with [`FromStr`]. When the string
+/// can't be parsed, the value is left unchanged.
+///
+/// Unlike the reflection of enums that derive `Component`, no constant entities are created
+/// for the variants. The type is a string to the reflection framework, so its values can't
+/// be matched or iterated as constants, and any string that parses is accepted.
+///
+/// # Returns
+///
+/// The id of the type, to register members of this type with `member_id`.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// // an enum of another crate, which can't derive `Component`
+/// #[derive(Debug, PartialEq)]
+/// enum Weather {
+///     Sunny,
+///     Rainy,
+/// }
+///
+/// impl std::fmt::Display for Weather {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// impl std::str::FromStr for Weather {
+///     type Err = ();
+///
+///     fn from_str(s: &str) -> Result<Self, ()> {
+///         match s {
+///             "Sunny" => Ok(Weather::Sunny),
+///             "Rainy" => Ok(Weather::Rainy),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// let world = World::new();
+///
+/// let id = register_enum_string::<Weather>(&world);
+///
+/// let json = world.to_json_dyn(id, &Weather::Rainy);
+/// assert_eq!(json, "\"Rainy\"");
+/// ```
+///
+/// # See also
+///
+/// * [`meta_ser_stringify_type_display()`]
+pub fn register_enum_string<'a, T: ToString + FromStr + 'static>(
+    world: impl WorldProvider<'a>,
+) -> FetchedId<T> {
+    let world = world.world();
+    let id = id!(world, T);
+    world
+        .component_ext::<T>(id)
+        .opaque_func_id::<_, ()>(id, meta_ser_enum_string::<T>);
+    id
+}
+
+fn meta_ser_enum_string<T: ToString + FromStr>(world: WorldRef) -> Opaque<T> {
+    let mut ts = Opaque::<T>::new(world);
+
+    ts.as_type(flecs::meta::String);
+
+    ts.serialize(|s: &Serializer, data: &T| {
+        let data = SmallCStr::<128>::new_truncated(&data.to_string());
+        s.value_id(
+            flecs::meta::String,
+            &data.as_ptr() as *const *const std::ffi::c_char as *const std::ffi::c_void,
+        )
+    });
+
+    ts.assign_string(|data: &mut T, value: *const std::ffi::c_char| {
+        let value = unsafe { CStr::from_ptr(value) }.to_string_lossy();
+        if let Ok(parsed) = value.parse::<T>() {
+            *data = parsed;
+        }
+    });
+
+    ts
+}
@@ -1,7 +1,81 @@
 use crate::core::*;
 use flecs_ecs::sys;
 
+/// Errors returned by [`Cursor`] operations
+///
+/// A failed operation leaves the cursor where it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CursorError {
+    /// Moved past the last member or element of the scope
+    OutOfBounds,
+    /// The struct of the current scope has no member with this name
+    UnknownMember(String),
+    /// The current value can't be pushed, or the scope doesn't support the operation
+    InvalidScope,
+    /// The value can't be converted from or to the type of the current value
+    TypeMismatch,
+}
+
+impl core::fmt::Display for CursorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CursorError::OutOfBounds => write!(f, "cursor moved out of bounds"),
+            CursorError::UnknownMember(name) => write!(f, "unknown member '{name}'"),
+            CursorError::InvalidScope => {
+                write!(f, "operation is not supported for the current scope")
+            }
+            CursorError::TypeMismatch => {
+                write!(f, "value does not match the type of the current value")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CursorError {}
+
+/// Kind of the value the cursor points to, used to validate getters.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Bool,
+    Char,
+    Number,
+    String,
+    Entity,
+    Id,
+    Enum,
+    Bitmask,
+    StringOpaque,
+    Other,
+}
+
 /// Class for reading/writing dynamic values
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// #[meta]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Position>().meta();
+///
+/// let mut pos = Position::default();
+/// let mut cur = world.cursor(&mut pos);
+/// cur.push().unwrap();
+/// cur.member("y").unwrap();
+/// cur.set_float(2.0).unwrap();
+/// assert_eq!(cur.get_float(), Ok(2.0));
+/// assert!(cur.member("z").is_err());
+/// cur.pop().unwrap();
+///
+/// assert_eq!(pos.y, 2.0);
+/// ```
 pub struct Cursor<'a> {
     cursor: sys::ecs_meta_cursor_t,
     phantom: std::marker::PhantomData<&'a ()>,
@@ -24,31 +98,132 @@ impl<'a> Cursor<'a> {
         }
     }
 
+    /// Run a cursor operation, restoring the cursor when it fails.
+    fn try_move(
+        &mut self,
+        op: impl FnOnce(&mut sys::ecs_meta_cursor_t) -> i32,
+        error: CursorError,
+    ) -> Result<(), CursorError> {
+        let prev = self.cursor;
+        if op(&mut self.cursor) == 0 {
+            Ok(())
+        } else {
+            self.cursor = prev;
+            Err(error)
+        }
+    }
+
+    fn check(result: i32) -> Result<(), CursorError> {
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(CursorError::TypeMismatch)
+        }
+    }
+
+    fn value_kind(&self) -> ValueKind {
+        let world = self.cursor.world;
+        let type_id = unsafe { sys::ecs_meta_get_type(&self.cursor) };
+        if type_id == 0 {
+            return ValueKind::Other;
+        }
+
+        let primitive = unsafe { sys::ecs_get_id(world, type_id, flecs::meta::Primitive::ID) }
+            as *const sys::EcsPrimitive;
+        if !primitive.is_null() {
+            return match unsafe { (*primitive).kind } {
+                sys::ecs_primitive_kind_t_EcsBool => ValueKind::Bool,
+                sys::ecs_primitive_kind_t_EcsChar => ValueKind::Char,
+                sys::ecs_primitive_kind_t_EcsString => ValueKind::String,
+                sys::ecs_primitive_kind_t_EcsEntity => ValueKind::Entity,
+                sys::ecs_primitive_kind_t_EcsId => ValueKind::Id,
+                _ => ValueKind::Number,
+            };
+        }
+
+        if unsafe { sys::ecs_has_id(world, type_id, flecs::meta::EcsEnum::ID) } {
+            return ValueKind::Enum;
+        }
+        if unsafe { sys::ecs_has_id(world, type_id, flecs::meta::Bitmask::ID) } {
+            return ValueKind::Bitmask;
+        }
+
+        let opaque = unsafe { sys::ecs_get_id(world, type_id, flecs::meta::EcsOpaque::ID) }
+            as *const sys::EcsOpaque;
+        if !opaque.is_null() && unsafe { (*opaque).as_type } == flecs::meta::String::ID {
+            return ValueKind::StringOpaque;
+        }
+
+        ValueKind::Other
+    }
+
+    fn expect_kind(&self, accepted: &[ValueKind]) -> Result<(), CursorError> {
+        if accepted.contains(&self.value_kind()) {
+            Ok(())
+        } else {
+            Err(CursorError::TypeMismatch)
+        }
+    }
+
     /// Push value scope (such as a nested struct)
-    pub fn push(&mut self) -> i32 {
-        unsafe { sys::ecs_meta_push(&mut self.cursor) }
+    pub fn push(&mut self) -> Result<(), CursorError> {
+        self.try_move(
+            |cursor| unsafe { sys::ecs_meta_push(cursor) },
+            CursorError::InvalidScope,
+        )
     }
 
     /// Pop value scope
-    pub fn pop(&mut self) -> i32 {
-        unsafe { sys::ecs_meta_pop(&mut self.cursor) }
+    pub fn pop(&mut self) -> Result<(), CursorError> {
+        self.try_move(
+            |cursor| unsafe { sys::ecs_meta_pop(cursor) },
+            CursorError::InvalidScope,
+        )
     }
 
     /// Move to next member/element
     #[allow(clippy::should_implement_trait)]
-    pub fn next(&mut self) -> i32 {
-        unsafe { sys::ecs_meta_next(&mut self.cursor) }
+    pub fn next(&mut self) -> Result<(), CursorError> {
+        // flecs allows moving to the end of a struct scope, which isn't a value
+        self.try_move(
+            |cursor| unsafe {
+                if sys::ecs_meta_next(cursor) != 0 {
+                    return -1;
+                }
+                let scope = &cursor.scope[cursor.depth as usize];
+                let at_end = !scope.is_collection
+                    && (*scope.ops.add(scope.op_cur as usize)).kind
+                        == sys::ecs_meta_type_op_kind_t_EcsOpPop;
+                -(at_end as i32)
+            },
+            CursorError::OutOfBounds,
+        )
     }
 
     /// Move to member by name
-    pub fn member(&mut self, name: &str) -> i32 {
-        let name = compact_str::format_compact!("{}\0", name);
-        unsafe { sys::ecs_meta_member(&mut self.cursor, name.as_ptr() as *const _) }
+    pub fn member(&mut self, name: &str) -> Result<(), CursorError> {
+        let scope = &self.cursor.scope[self.cursor.depth as usize];
+        if self.cursor.depth == 0 || scope.members.is_null() {
+            return Err(CursorError::InvalidScope);
+        }
+
+        let cname = SmallCStr::<128>::from_arg(name);
+        self.try_move(
+            |cursor| unsafe { sys::ecs_meta_member(cursor, cname.as_ptr() as *const _) },
+            CursorError::UnknownMember(name.to_string()),
+        )
     }
 
     /// Move to element by index
-    pub fn elem(&mut self, elem: i32) -> i32 {
-        unsafe { sys::ecs_meta_elem(&mut self.cursor, elem) }
+    pub fn elem(&mut self, elem: i32) -> Result<(), CursorError> {
+        if !self.is_collection() {
+            return Err(CursorError::InvalidScope);
+        }
+
+        self.try_move(
+            |cursor| unsafe { sys::ecs_meta_elem(cursor, elem) },
+            CursorError::OutOfBounds,
+        )
     }
 
     /// Test if current scope is a collection type
@@ -91,95 +266,177 @@ impl<'a> Cursor<'a> {
     }
 
     /// Set boolean value
-    pub fn set_bool(&mut self, value: bool) -> i32 {
-        unsafe { sys::ecs_meta_set_bool(&mut self.cursor, value) }
+    pub fn set_bool(&mut self, value: bool) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_bool(&mut self.cursor, value) })
     }
 
     /// Set char value
-    pub fn set_char(&mut self, value: char) -> i32 {
-        unsafe { sys::ecs_meta_set_char(&mut self.cursor, value as std::ffi::c_char) }
+    pub fn set_char(&mut self, value: char) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_char(&mut self.cursor, value as std::ffi::c_char) })
     }
 
     /// Set signed int value
-    pub fn set_int(&mut self, value: i64) -> i32 {
-        unsafe { sys::ecs_meta_set_int(&mut self.cursor, value) }
+    pub fn set_int(&mut self, value: i64) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_int(&mut self.cursor, value) })
     }
 
     /// Set unsigned int value
-    pub fn set_uint(&mut self, value: u64) -> i32 {
-        unsafe { sys::ecs_meta_set_uint(&mut self.cursor, value) }
+    pub fn set_uint(&mut self, value: u64) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_uint(&mut self.cursor, value) })
     }
 
     /// Set float value
-    pub fn set_float(&mut self, value: f64) -> i32 {
-        unsafe { sys::ecs_meta_set_float(&mut self.cursor, value) }
+    pub fn set_float(&mut self, value: f64) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_float(&mut self.cursor, value) })
     }
 
     /// Set string value
-    pub fn set_string(&mut self, value: &str) -> i32 {
-        let value = compact_str::format_compact!("{}\0", value);
-        unsafe { sys::ecs_meta_set_string(&mut self.cursor, value.as_ptr() as *const _) }
+    pub fn set_string(&mut self, value: &str) -> Result<(), CursorError> {
+        let value = SmallCStr::<128>::from_arg(value);
+        Self::check(unsafe {
+            sys::ecs_meta_set_string(&mut self.cursor, value.as_ptr() as *const _)
+        })
     }
 
     /// Set string literal value
-    pub fn set_string_literal(&mut self, value: &str) -> i32 {
-        let value = compact_str::format_compact!("{}\0", value);
-        unsafe { sys::ecs_meta_set_string_literal(&mut self.cursor, value.as_ptr() as *const _) }
+    pub fn set_string_literal(&mut self, value: &str) -> Result<(), CursorError> {
+        let value = SmallCStr::<128>::from_arg(value);
+        Self::check(unsafe {
+            sys::ecs_meta_set_string_literal(&mut self.cursor, value.as_ptr() as *const _)
+        })
     }
 
     /// Set entity value
-    pub fn set_entity(&mut self, value: impl Into<Entity>) -> i32 {
-        unsafe { sys::ecs_meta_set_entity(&mut self.cursor, *value.into()) }
+    pub fn set_entity(&mut self, value: impl Into<Entity>) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_entity(&mut self.cursor, *value.into()) })
     }
 
     /// Set (component) id value
-    pub fn set_id(&mut self, value: impl IntoId) -> i32 {
-        unsafe { sys::ecs_meta_set_id(&mut self.cursor, *value.into()) }
+    pub fn set_id(&mut self, value: impl IntoId) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_id(&mut self.cursor, *value.into()) })
     }
 
     /// Set null value
-    pub fn set_null(&mut self) -> i32 {
-        unsafe { sys::ecs_meta_set_null(&mut self.cursor) }
+    pub fn set_null(&mut self) -> Result<(), CursorError> {
+        Self::check(unsafe { sys::ecs_meta_set_null(&mut self.cursor) })
     }
 
     /// Get boolean value
-    pub fn get_bool(&self) -> bool {
-        unsafe { sys::ecs_meta_get_bool(&self.cursor) }
+    pub fn get_bool(&self) -> Result<bool, CursorError> {
+        use ValueKind::*;
+        self.expect_kind(&[Bool, Char, Number, String, Entity, Id, Enum, Bitmask])?;
+        Ok(unsafe { sys::ecs_meta_get_bool(&self.cursor) })
     }
 
     /// Get char value
-    pub fn get_char(&self) -> char {
-        unsafe { sys::ecs_meta_get_char(&self.cursor) as u8 as char }
+    pub fn get_char(&self) -> Result<char, CursorError> {
+        self.expect_kind(&[ValueKind::Char])?;
+        Ok(unsafe { sys::ecs_meta_get_char(&self.cursor) as u8 as char })
     }
 
     /// Get signed int value
-    pub fn get_int(&self) -> i64 {
-        unsafe { sys::ecs_meta_get_int(&self.cursor) }
+    pub fn get_int(&self) -> Result<i64, CursorError> {
+        use ValueKind::*;
+        self.expect_kind(&[Bool, Char, Number, Enum, Bitmask])?;
+        Ok(unsafe { sys::ecs_meta_get_int(&self.cursor) })
     }
 
     /// Get unsigned int value
-    pub fn get_uint(&self) -> u64 {
-        unsafe { sys::ecs_meta_get_uint(&self.cursor) }
+    pub fn get_uint(&self) -> Result<u64, CursorError> {
+        use ValueKind::*;
+        self.expect_kind(&[Bool, Char, Number, Entity, Id, Enum, Bitmask])?;
+        Ok(unsafe { sys::ecs_meta_get_uint(&self.cursor) })
     }
 
     /// Get float value
-    pub fn get_float(&self) -> f64 {
-        unsafe { sys::ecs_meta_get_float(&self.cursor) }
+    pub fn get_float(&self) -> Result<f64, CursorError> {
+        use ValueKind::*;
+        self.expect_kind(&[Bool, Char, Number, Enum, Bitmask])?;
+        Ok(unsafe { sys::ecs_meta_get_float(&self.cursor) })
     }
 
     /// Get string value
-    pub fn get_string(&self) -> *const std::ffi::c_char {
-        // TODO: Rustify this to return &str
-        unsafe { sys::ecs_meta_get_string(&self.cursor) }
+    ///
+    /// Works for strings and for opaque types that are serialized as a string, such as
+    /// `String`. A null string is returned as an empty string.
+    pub fn get_string(&self) -> Result<String, CursorError> {
+        match self.value_kind() {
+            ValueKind::String => {
+                let ptr = unsafe { sys::ecs_meta_get_string(&self.cursor) };
+                Ok(Self::string_from_ptr(ptr))
+            }
+            ValueKind::StringOpaque => self.opaque_string(),
+            _ => Err(CursorError::TypeMismatch),
+        }
+    }
+
+    /// Serialize an opaque value that is described as a string. The string of the serializer
+    /// may be temporary, so it's copied while serializing.
+    fn opaque_string(&self) -> Result<String, CursorError> {
+        unsafe extern "C-unwind" fn value(
+            ser: *const sys::ecs_serializer_t,
+            type_id: sys::ecs_entity_t,
+            value: *const std::ffi::c_void,
+        ) -> i32 {
+            if type_id != flecs::meta::String::ID {
+                return -1;
+            }
+            unsafe {
+                let out = (*ser).ctx as *mut String;
+                *out = Cursor::string_from_ptr(*(value as *const *const std::ffi::c_char));
+            }
+            0
+        }
+
+        unsafe extern "C-unwind" fn member(
+            _ser: *const sys::ecs_serializer_t,
+            _member: *const std::ffi::c_char,
+        ) -> i32 {
+            -1
+        }
+
+        let world = self.cursor.world;
+        let type_id = unsafe { sys::ecs_meta_get_type(&self.cursor) };
+        let opaque = unsafe { sys::ecs_get_id(world, type_id, flecs::meta::EcsOpaque::ID) }
+            as *const sys::EcsOpaque;
+        let Some(serialize) = (unsafe { (*opaque).serialize }) else {
+            return Err(CursorError::TypeMismatch);
+        };
+
+        let mut result = String::new();
+        let ser = sys::ecs_serializer_t {
+            value: Some(value),
+            member: Some(member),
+            world,
+            ctx: &mut result as *mut String as *mut std::ffi::c_void,
+        };
+
+        let mut cursor = self.cursor;
+        let ptr = unsafe { sys::ecs_meta_get_ptr(&mut cursor) };
+        if unsafe { serialize(&ser, ptr) } != 0 {
+            return Err(CursorError::TypeMismatch);
+        }
+        Ok(result)
+    }
+
+    fn string_from_ptr(ptr: *const std::ffi::c_char) -> String {
+        if ptr.is_null() {
+            String::new()
+        } else {
+            unsafe { std::ffi::CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned()
+        }
     }
 
     /// Get entity value
-    pub fn get_entity(&self) -> EntityView {
-        unsafe {
+    pub fn get_entity(&self) -> Result<EntityView<'_>, CursorError> {
+        self.expect_kind(&[ValueKind::Entity])?;
+        Ok(unsafe {
             EntityView::new_from(
                 WorldRef::from_ptr(self.cursor.world as *mut sys::ecs_world_t),
                 sys::ecs_meta_get_entity(&self.cursor),
             )
-        }
+        })
     }
 }
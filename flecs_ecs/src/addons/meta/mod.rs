@@ -9,6 +9,7 @@ pub mod macros;
 mod meta_functions;
 mod meta_traits;
 mod opaque;
+pub mod rust_types;
 
 use std::ffi::{c_void, CStr};
 
@@ -219,7 +220,7 @@ impl EcsSerializer for sys::ecs_serializer_t {
     }
 
     fn member(&self, name: &str) -> i32 {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
         if let Some(member_func) = self.member {
             unsafe { member_func(self, name.as_ptr() as *const _) }
         } else {
@@ -360,7 +361,7 @@ impl<T: EnumComponentInfo + 'static> Component<'_, T> {
     pub fn constant(&self, name: &str, id: impl Into<Entity>, value: T) -> &Self {
         unsafe { sys::ecs_add_id(self.world_ptr_mut(), *self.id, flecs::meta::EcsEnum::ID) };
 
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let desc = sys::ecs_entity_desc_t {
             name: name.as_ptr() as *const _,
@@ -390,15 +391,24 @@ impl<T: EnumComponentInfo + 'static> Component<'_, T> {
 impl UntypedComponent<'_> {
     /// Add constant.
     ///
+    /// Constants of a type registered with [`UntypedComponent::bitmask()`] are bits, their
+    /// value is used as `u32`. Otherwise the type becomes an enum.
+    ///
     /// # See also
     ///
     /// * C++ API: `untyped_component::constant`
     pub fn constant(&self, name: &str, value: impl Into<i32>) -> &Self {
-        let name = compact_str::format_compact!("{}\0", name);
         let value: i32 = value.into();
         let world = self.world_ptr_mut();
         let id = *self.id;
 
+        if unsafe { sys::ecs_has_id(world, id, flecs::meta::Bitmask::ID) } {
+            self.bit_constant(name, value as u32);
+            return self;
+        }
+
+        let name = SmallCStr::<128>::from_arg(name);
+
         unsafe { sys::ecs_add_id(world, id, flecs::meta::EcsEnum::ID) };
 
         let desc = sys::ecs_entity_desc_t {
@@ -441,7 +451,7 @@ impl UntypedComponent<'_> {
         unit: impl Into<Entity>,
         data: Meta,
     ) -> Self {
-        let name = compact_str::format_compact!("{}\0", data.name());
+        let name = SmallCStr::<128>::from_arg(data.name());
         let world = self.world_ptr_mut();
         let id = *self.id;
         let type_id = *type_id.into();
@@ -561,13 +571,48 @@ impl UntypedComponent<'_> {
         return member(type_id, unit_id, name, std::extent<MemberType>::value, offset);
              */
 
+    /// Register the type as a bitmask, its constants are then added as bits.
+    ///
+    /// Values of the type are 32 bit and serialized as the names of the set bits, separated
+    /// by `|`, e.g. `"Player|Enemy"`. A type that isn't a component yet is created as a `u32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let layers = world
+    ///     .component_untyped_named("CollisionLayers")
+    ///     .bitmask();
+    /// layers.constant("Player", 1).constant("Enemy", 2);
+    ///
+    /// let value: u32 = 3;
+    /// let json = world.to_json_id(layers.id(), &value as *const u32 as *const _);
+    /// assert_eq!(json, "\"Enemy|Player\"");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::bit()`]
+    pub fn bitmask(self) -> Self {
+        unsafe { sys::ecs_add_id(self.world_ptr_mut(), *self.id, flecs::meta::Bitmask::ID) };
+        self
+    }
+
     /// Add bitmask constant
     ///
     /// # See also
     ///
     /// * C++ API: `untyped_component::bit`
     pub fn bit(self, name: &str, value: u32) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        self.bit_constant(name, value);
+        self
+    }
+
+    fn bit_constant(&self, name: &str, value: u32) {
+        let name = SmallCStr::<128>::from_arg(name);
         let world = self.world_ptr_mut();
         let id = *self.id;
 
@@ -596,7 +641,6 @@ impl UntypedComponent<'_> {
                 &value as *const u32 as *const c_void,
             );
         };
-        self
     }
 
     /// register array metadata for component
@@ -615,82 +659,86 @@ impl UntypedComponent<'_> {
         self
     }
 
+    /// register array metadata for component with the element type given by id
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::array()`]
+    /// * C++ API: `untyped_component::array`
+    pub fn array_id(self, elem_id: impl Into<Entity>, elem_count: i32) -> Self {
+        let desc = sys::ecs_array_desc_t {
+            entity: *self.id,
+            type_: *elem_id.into(),
+            count: elem_count,
+        };
+
+        unsafe { sys::ecs_array_init(self.world_ptr_mut(), &desc) };
+        self
+    }
+
     /// add member value range
     ///
+    /// The range is set on the last added member.
+    ///
     /// # See also
     ///
     /// * C++ API: `untyped_component::range`
     pub fn range(self, min: f64, max: f64) -> Self {
-        let m = unsafe { sys::ecs_cpp_last_member(self.world_ptr(), *self.id) };
-        if m.is_null() {
-            return self;
-        }
-
-        let world_ptr = self.world_ptr_mut();
-        let w = unsafe { WorldRef::from_ptr(world_ptr) };
-        let me = w.entity_from_id(unsafe { (*m).member });
-
-        let mr = unsafe {
-            &mut *(sys::ecs_ensure_id(world_ptr, *me.id, flecs::meta::MemberRanges::ID)
-                as *mut flecs::meta::MemberRanges)
-        };
-
-        mr.value.min = min;
-        mr.value.max = max;
-        me.modified::<flecs::meta::MemberRanges>();
+        self.set_last_member_ranges(|ranges| {
+            ranges.value.min = min;
+            ranges.value.max = max;
+        });
         self
     }
 
     /// add member warning range
     ///
+    /// The range is set on the last added member.
+    ///
     /// # See also
     ///
     /// * C++ API: `untyped_component::warning_range`
     pub fn warning_range(self, min: f64, max: f64) -> Self {
-        let m = unsafe { sys::ecs_cpp_last_member(self.world_ptr(), *self.id) };
-        if m.is_null() {
-            return self;
-        }
-
-        let world_ptr = self.world_ptr_mut();
-        let w = unsafe { WorldRef::from_ptr(world_ptr) };
-        let me = w.entity_from_id(unsafe { (*m).member });
-
-        let mr = unsafe {
-            &mut *(sys::ecs_ensure_id(world_ptr, *me.id, flecs::meta::MemberRanges::ID)
-                as *mut flecs::meta::MemberRanges)
-        };
-
-        mr.warning.min = min;
-        mr.warning.max = max;
-        me.modified::<flecs::meta::MemberRanges>();
+        self.set_last_member_ranges(|ranges| {
+            ranges.warning.min = min;
+            ranges.warning.max = max;
+        });
         self
     }
 
     /// add member error range
     ///
+    /// The range is set on the last added member.
+    ///
     /// # See also
     ///
     /// * C++ API: `untyped_component::error_range`
     pub fn error_range(self, min: f64, max: f64) -> Self {
+        self.set_last_member_ranges(|ranges| {
+            ranges.error.min = min;
+            ranges.error.max = max;
+        });
+        self
+    }
+
+    /// Update the ranges of the last added member. Setting `MemberRanges` also updates the
+    /// ranges of the member in the `EcsStruct` of the type.
+    fn set_last_member_ranges(&self, func: impl FnOnce(&mut flecs::meta::MemberRanges)) {
         let m = unsafe { sys::ecs_cpp_last_member(self.world_ptr(), *self.id) };
         if m.is_null() {
-            return self;
+            return;
         }
 
         let world_ptr = self.world_ptr_mut();
-        let w = unsafe { WorldRef::from_ptr(world_ptr) };
-        let me = w.entity_from_id(unsafe { (*m).member });
+        let member = unsafe { (*m).member };
 
-        let mr = unsafe {
-            &mut *(sys::ecs_ensure_id(world_ptr, *me.id, flecs::meta::MemberRanges::ID)
+        let ranges = unsafe {
+            &mut *(sys::ecs_ensure_id(world_ptr, member, flecs::meta::MemberRanges::ID)
                 as *mut flecs::meta::MemberRanges)
         };
 
-        mr.error.min = min;
-        mr.error.max = max;
-        me.modified::<flecs::meta::MemberRanges>();
-        self
+        func(ranges);
+        unsafe { sys::ecs_modified_id(world_ptr, member, flecs::meta::MemberRanges::ID) };
     }
 }
 
@@ -708,7 +756,56 @@ pub fn flecs_entity_support<'a>(world: impl WorldProvider<'a>) -> Opaque<'a, Ent
     opaque
 }
 
-impl EntityView<'_> {
+/// Returns whether values of both types have the same layout: the same size, alignment and
+/// struct members. A value of one type can then be written to a component of the other, e.g. to
+/// set a template component declared in a script with a Rust struct that mirrors its props.
+pub(crate) fn struct_layouts_match(
+    world: WorldRef,
+    type_a: sys::ecs_entity_t,
+    type_b: sys::ecs_entity_t,
+) -> bool {
+    let world = world.world_ptr();
+
+    let members = |type_id| unsafe {
+        let struct_ = sys::ecs_get_id(world, type_id, flecs::meta::EcsStruct::ID)
+            as *const flecs::meta::EcsStruct;
+        if struct_.is_null() || (*struct_).members.count == 0 {
+            return None;
+        }
+        let members = &(*struct_).members;
+        Some(std::slice::from_raw_parts(
+            members.array as *const sys::ecs_member_t,
+            members.count as usize,
+        ))
+    };
+
+    let (ti_a, ti_b) = unsafe {
+        (
+            sys::ecs_get_type_info(world, type_a),
+            sys::ecs_get_type_info(world, type_b),
+        )
+    };
+    if ti_a.is_null() || ti_b.is_null() {
+        return false;
+    }
+    if unsafe { (*ti_a).size != (*ti_b).size || (*ti_a).alignment != (*ti_b).alignment } {
+        return false;
+    }
+
+    let (Some(members_a), Some(members_b)) = (members(type_a), members(type_b)) else {
+        return false;
+    };
+
+    members_a.len() == members_b.len()
+        && members_a.iter().zip(members_b).all(|(a, b)| {
+            a.type_ == b.type_
+                && a.offset == b.offset
+                && a.count == b.count
+                && unsafe { CStr::from_ptr(a.name) == CStr::from_ptr(b.name) }
+        })
+}
+
+impl<'a> EntityView<'a> {
     /// Make entity a unit
     ///
     /// # See also
@@ -725,7 +822,7 @@ impl EntityView<'_> {
         power: i32,
     ) -> &Self {
         if let Some(symbol) = symbol {
-            let symbol = compact_str::format_compact!("{}\0", symbol);
+            let symbol = SmallCStr::<128>::from_arg(symbol);
             let desc = sys::ecs_unit_desc_t {
                 entity: *self.id,
                 symbol: symbol.as_ptr() as *const _,
@@ -761,7 +858,7 @@ impl EntityView<'_> {
     /// * C++ API: `entity_builder::unit_prefix`
     #[doc(alias = "entity_builder::unit_prefix")]
     pub fn unit_prefix(&self, symbol: &str, factor: i32, power: i32) -> &Self {
-        let symbol = compact_str::format_compact!("{}\0", symbol);
+        let symbol = SmallCStr::<128>::from_arg(symbol);
         let desc = sys::ecs_unit_prefix_desc_t {
             entity: *self.id,
             symbol: symbol.as_ptr() as *const _,
@@ -809,6 +906,34 @@ impl EntityView<'_> {
         unsafe { sys::ecs_add_id(self.world_ptr_mut(), *self.id, flecs::meta::Quantity::ID) };
         self
     }
+
+    /// Return meta cursor to a component or pair of the entity, adding it if the entity doesn't
+    /// have it yet.
+    ///
+    /// Changes made through the cursor are not signalled, call `modified_id` when done.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id is not a type.
+    pub fn cursor_id(self, id: impl IntoId) -> Cursor<'a> {
+        let id = *id.into();
+        let world = self.world_ptr_mut();
+        let type_id = unsafe { sys::ecs_get_typeid(world, id) };
+        if type_id == 0 {
+            panic!("id is not a type");
+        }
+
+        let ptr = unsafe { sys::ecs_ensure_id(world, *self.id, id) };
+        Cursor::new(self.world, type_id, ptr)
+    }
+
+    /// Return meta cursor to a component of the entity, adding it if the entity doesn't have it
+    /// yet.
+    ///
+    /// Changes made through the cursor are not signalled, call `modified` when done.
+    pub fn cursor<T: ComponentId>(self) -> Cursor<'a> {
+        self.cursor_id(T::get_id(self.world))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,336 @@
+//! Reflection support for types of the Rust standard library that have no C equivalent.
+//!
+//! [`option_id()`] and [`hash_map_id()`] register the opaque type the first time they're used
+//! with a world and return its id. Fields of these types in a `#[meta]` component are
+//! registered through them.
+
+use core::ffi::CStr;
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use crate::core::external_register_component;
+use crate::prelude::*;
+use crate::sys;
+
+/// Returns the id of `X`, a generic wrapper of `T`, registering it as
+/// `flecs::rust::{wrapper}<{T}>` the first time.
+///
+/// [`id!`] names external types after the last segment of their type name, which is the same
+/// for e.g. `Option<String>` and `HashMap<String, String>`.
+fn wrapper_id<X: 'static, T: 'static>(world: WorldRef, wrapper: &str) -> FetchedId<X> {
+    let map = world.components_map();
+    let id = *(map.entry(std::any::TypeId::of::<X>()).or_insert_with(|| {
+        let name = format!("flecs::rust::{wrapper}<{}>", std::any::type_name::<T>());
+        let name = SmallCStr::<128>::from_arg(&name);
+        external_register_component::<true, X>(world, name.as_ptr())
+    }));
+    FetchedId::new(id)
+}
+
+/// Value types of `Option<T>` that can be assigned when an option is deserialized.
+///
+/// Implemented for the integer and float primitives, `bool`, `String` and [`Entity`], which
+/// are assigned as a single value. Options of structs, vectors, maps or other opaque types
+/// aren't supported, a `#[meta]` field of such an option doesn't compile.
+pub trait OptionValue: 'static {
+    /// Register the assign functions of the value type on the opaque option.
+    fn assign_functions(ts: &mut Opaque<Option<Self>>)
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_option_value_int {
+    ($($t:ty),*) => {
+        $(
+            impl OptionValue for $t {
+                fn assign_functions(ts: &mut Opaque<Option<Self>>) {
+                    ts.assign_int(|data: &mut Option<$t>, value: i64| *data = Some(value as $t));
+                    ts.assign_uint(|data: &mut Option<$t>, value: u64| *data = Some(value as $t));
+                }
+            }
+        )*
+    };
+}
+
+impl_option_value_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_option_value_float {
+    ($($t:ty),*) => {
+        $(
+            impl OptionValue for $t {
+                fn assign_functions(ts: &mut Opaque<Option<Self>>) {
+                    ts.assign_float(|data: &mut Option<$t>, value: f32| *data = Some(value as $t));
+                    ts.assign_int(|data: &mut Option<$t>, value: i64| *data = Some(value as $t));
+                    ts.assign_uint(|data: &mut Option<$t>, value: u64| *data = Some(value as $t));
+                }
+            }
+        )*
+    };
+}
+
+impl_option_value_float!(f32, f64);
+
+impl OptionValue for bool {
+    fn assign_functions(ts: &mut Opaque<Option<Self>>) {
+        ts.assign_bool(|data: &mut Option<bool>, value: bool| *data = Some(value));
+    }
+}
+
+impl OptionValue for String {
+    fn assign_functions(ts: &mut Opaque<Option<Self>>) {
+        ts.assign_string(
+            |data: &mut Option<String>, value: *const std::ffi::c_char| {
+                *data = Some(
+                    unsafe { CStr::from_ptr(value) }
+                        .to_string_lossy()
+                        .into_owned(),
+                );
+            },
+        );
+    }
+}
+
+impl OptionValue for Entity {
+    fn assign_functions(ts: &mut Opaque<Option<Self>>) {
+        ts.assign_entity(
+            |data: &mut Option<Entity>, _world: WorldRef, value: Entity| {
+                *data = Some(value);
+            },
+        );
+    }
+}
+
+/// Reflection support for `Option<T>`, which is serialized as `null` or as the value.
+///
+/// Prefer [`option_id()`], which registers the type once per world.
+pub fn option_support<T: OptionValue>(world: WorldRef) -> Opaque<Option<T>> {
+    let mut ts = Opaque::<Option<T>>::new_id(world, wrapper_id::<Option<T>, T>(world, "Option"));
+
+    // Deserialized values are assigned as T
+    ts.as_type(id!(world, T));
+
+    ts.serialize(|s: &Serializer, data: &Option<T>| {
+        let world = unsafe { WorldRef::from_ptr(s.world as *mut sys::ecs_world_t) };
+        match data {
+            Some(value) => s.value_id(id!(world, T), value as *const T as *const std::ffi::c_void),
+            // a null string is serialized as null
+            None => s.value_id(
+                flecs::meta::String,
+                &std::ptr::null::<std::ffi::c_char>() as *const *const std::ffi::c_char
+                    as *const std::ffi::c_void,
+            ),
+        }
+    });
+
+    ts.assign_null(|data: &mut Option<T>| *data = None);
+    T::assign_functions(&mut ts);
+
+    ts
+}
+
+/// Returns the id of `Option<T>`, registering its reflection support the first time.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::addons::meta::rust_types::option_id;
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+///
+/// let id = option_id::<i32>(&world);
+/// assert_eq!(world.to_json_dyn(id!(&world, Option<i32>), &Some(5)), "5");
+/// assert_eq!(world.to_json_dyn(id!(&world, Option<i32>), &None), "null");
+/// # let _ = id;
+/// ```
+pub fn option_id<'a, T: OptionValue>(world: impl WorldProvider<'a>) -> Entity {
+    let world = world.world();
+    let id = wrapper_id::<Option<T>, T>(world, "Option");
+    if !world.entity_from_id(id).has::<flecs::meta::Type>() {
+        world
+            .component_ext::<Option<T>>(id)
+            .opaque_func_id::<_, ()>(id, option_support::<T>);
+    }
+    id.into()
+}
+
+/// Reflection support for `HashMap<String, T>`, which is serialized as a list of
+/// `{"key": .., "value": ..}` entries. Keys are serialized in sorted order.
+///
+/// A map is described as a vector of entries rather than as an object, since flecs only
+/// deserializes the members that a type declares. Deserializing replaces the contents of the
+/// map. The key of an entry has to come before its value, as it is serialized, entries without
+/// a value aren't inserted. Values are serialized with the reflection data of `T`, which
+/// has to be registered before a map is serialized.
+///
+/// Prefer [`hash_map_id()`], which registers the type once per world.
+pub fn hash_map_support<T: Default + 'static>(
+    world: WorldRef,
+) -> Opaque<HashMap<String, T>, MapEntry<T>> {
+    let entry = map_entry_id::<T>(world);
+
+    let mut ts = Opaque::<HashMap<String, T>, MapEntry<T>>::new_id(
+        world,
+        wrapper_id::<HashMap<String, T>, T>(world, "HashMap"),
+    );
+
+    ts.as_type(world.vector_id(entry));
+
+    ts.serialize(|s: &Serializer, data: &HashMap<String, T>| {
+        let world = unsafe { WorldRef::from_ptr(s.world as *mut sys::ecs_world_t) };
+        let entry = wrapper_id::<MapEntry<T>, T>(world, "MapEntry");
+        let mut keys = data.keys().collect::<Vec<_>>();
+        keys.sort();
+        for key in keys {
+            // the entry serializer looks up the value of the key in the map
+            SERIALIZED_KEY.set(key);
+            if s.value_id(
+                entry,
+                data as *const HashMap<String, T> as *const std::ffi::c_void,
+            ) != 0
+            {
+                return -1;
+            }
+        }
+        0
+    });
+
+    ts.count(|data: &mut HashMap<String, T>| data.len());
+
+    // the entries of a map are deserialized into the map itself
+    fn ensure_entry<T>(data: &mut HashMap<String, T>, elem: usize) -> &mut MapEntry<T> {
+        if elem == 0 {
+            data.clear();
+        }
+        unsafe { &mut *(data as *mut HashMap<String, T> as *mut MapEntry<T>) }
+    }
+
+    ts.ensure_element(ensure_entry::<T>);
+
+    // the entries are inserted when deserialized, only an empty list changes the map here
+    ts.resize(|data: &mut HashMap<String, T>, count: usize| {
+        if count == 0 {
+            data.clear();
+        }
+    });
+
+    ts
+}
+
+/// Returns the id of `HashMap<String, T>`, registering its reflection support the first time.
+///
+/// See [`hash_map_support()`] for how maps are serialized.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use flecs_ecs::addons::meta::rust_types::hash_map_id;
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+///
+/// let id = hash_map_id::<i32>(&world);
+/// let map = HashMap::from([("a".to_string(), 1)]);
+/// let json = world.to_json_dyn(id!(&world, HashMap<String, i32>), &map);
+/// assert_eq!(json, "[{\"key\":\"a\", \"value\":1}]");
+///
+/// let mut parsed = HashMap::<String, i32>::new();
+/// world.from_json_id(id, &mut parsed as *mut _ as *mut _, &json, None);
+/// assert_eq!(parsed, map);
+/// ```
+pub fn hash_map_id<'a, T: Default + 'static>(world: impl WorldProvider<'a>) -> Entity {
+    let world = world.world();
+    let id = wrapper_id::<HashMap<String, T>, T>(world, "HashMap");
+    if !world.entity_from_id(id).has::<flecs::meta::Type>() {
+        world
+            .component_ext::<HashMap<String, T>>(id)
+            .opaque_func_id::<_, MapEntry<T>>(id, hash_map_support::<T>);
+    }
+    id.into()
+}
+
+/// An entry of a `HashMap<String, T>` in its reflection data.
+///
+/// The entry has the same layout as the map. Serializing an entry writes the key set by the
+/// map serializer and its value. Deserializing an entry inserts the value under the key that
+/// was deserialized before it.
+#[repr(transparent)]
+pub struct MapEntry<T>(HashMap<String, T>);
+
+thread_local! {
+    // key of the entry that is being serialized
+    static SERIALIZED_KEY: Cell<*const String> = const { Cell::new(std::ptr::null()) };
+    // key of the entry that is being deserialized, allocated by flecs
+    static DESERIALIZED_KEY: Cell<*mut std::ffi::c_char> = const { Cell::new(std::ptr::null_mut()) };
+}
+
+/// Registers the opaque entry type of `HashMap<String, T>` and returns its id.
+fn map_entry_id<T: Default + 'static>(world: WorldRef) -> Entity {
+    let id = wrapper_id::<MapEntry<T>, T>(world, "MapEntry");
+    if !world.entity_from_id(id).has::<flecs::meta::Type>() {
+        world
+            .component_ext::<MapEntry<T>>(id)
+            .opaque_func_id::<_, ()>(id, map_entry_support::<T>);
+    }
+    id.into()
+}
+
+fn map_entry_support<T: Default + 'static>(world: WorldRef) -> Opaque<MapEntry<T>> {
+    let mut ts =
+        Opaque::<MapEntry<T>>::new_id(world, wrapper_id::<MapEntry<T>, T>(world, "MapEntry"));
+
+    // the struct only describes the members of the entry, so it doesn't need a name
+    let mut desc = sys::ecs_struct_desc_t {
+        entity: 0,
+        members: unsafe { core::mem::zeroed() },
+    };
+    desc.members[0].name = c"key".as_ptr();
+    desc.members[0].type_ = flecs::meta::String::ID;
+    desc.members[1].name = c"value".as_ptr();
+    desc.members[1].type_ = id!(world, T).id();
+    let entry_struct = unsafe { sys::ecs_struct_init(world.world_ptr_mut(), &desc) };
+    ecs_assert!(
+        entry_struct != 0,
+        FlecsErrorCode::InternalError,
+        "failed to create map entry struct"
+    );
+    ts.as_type(entry_struct);
+
+    ts.serialize(|s: &Serializer, data: &MapEntry<T>| {
+        let world = unsafe { WorldRef::from_ptr(s.world as *mut sys::ecs_world_t) };
+        let key = unsafe { &*SERIALIZED_KEY.replace(std::ptr::null()) };
+        if s.member("key") != 0 || s.value_id(id!(world, String), key as *const String as _) != 0 {
+            return -1;
+        }
+        if s.member("value") != 0 {
+            return -1;
+        }
+        s.value_id(
+            id!(world, T),
+            &data.0[key] as *const T as *const std::ffi::c_void,
+        )
+    });
+
+    ts.ensure_member(|data: &mut MapEntry<T>, member: *const std::ffi::c_char| {
+        match unsafe { CStr::from_ptr(member) }.to_bytes() {
+            // flecs assigns the key as a string it allocates
+            b"key" => DESERIALIZED_KEY.with(Cell::as_ptr) as *mut std::ffi::c_void,
+            _ => {
+                let key = DESERIALIZED_KEY.replace(std::ptr::null_mut());
+                assert!(
+                    !key.is_null(),
+                    "the key of a map entry has to be deserialized before its value"
+                );
+                let owned = unsafe { CStr::from_ptr(key) }
+                    .to_string_lossy()
+                    .into_owned();
+                unsafe { sys::ecs_os_api.free_.expect("os api is missing free")(key as _) };
+                data.0.entry(owned).or_default() as *mut T as *mut std::ffi::c_void
+            }
+        }
+    });
+
+    ts
+}
@@ -4,6 +4,9 @@ use std::mem::ManuallyDrop;
 use std::os::raw::c_char;
 
 /// `MetricBuilder` is a builder pattern for creating metrics.
+///
+/// The metric is created by [`build()`](Builder::build), or when the builder is dropped
+/// without being built.
 pub struct MetricBuilder<'a> {
     world: WorldRef<'a>,
     desc: sys::ecs_metric_desc_t,
@@ -274,6 +277,23 @@ impl<'a> MetricBuilder<'a> {
     }
 }
 
+impl<'a> Builder<'a> for MetricBuilder<'a> {
+    type BuiltType = EntityView<'a>;
+
+    /// Create the metric.
+    ///
+    /// # Returns
+    ///
+    /// The metric entity, whose instances are created as its children.
+    fn build(&mut self) -> Self::BuiltType {
+        let id = unsafe { sys::ecs_metric_init(self.world_ptr_mut(), &self.desc) };
+        ecs_assert!(id != 0, FlecsErrorCode::InvalidParameter, "invalid metric");
+        self.created = true;
+
+        EntityView::new_from(self.world, id)
+    }
+}
+
 impl<'a> WorldProvider<'a> for MetricBuilder<'a> {
     fn world(&self) -> WorldRef<'a> {
         self.world
@@ -100,12 +100,99 @@ impl<'a> UntypedComponent<'a> {
 impl World {
     /// Creates a new [`MetricBuilder`] instance.
     ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity of the metric, the metric instances are created as its children.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::addons::metrics::*;
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.import::<MetricsModule>();
+    /// world.component::<Position>().meta();
+    ///
+    /// let metric = world
+    ///     .metric(world.entity_named("position_x"))
+    ///     .member::<Position>("x")
+    ///     .kind::<Gauge>()
+    ///     .build();
+    ///
+    /// world.entity().set(Position { x: 10.0, y: 20.0 });
+    ///
+    /// // metrics are updated every second of world time
+    /// world.progress_time(1.0);
+    ///
+    /// metric.each_metric_instance(|_, _, value| assert_eq!(value, 10.0));
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`EntityView::each_metric_instance()`]
     /// * [`UntypedComponent::metric()`]
     /// * C++ API: `world::metric`
     #[doc(alias = "world::metric")]
-    pub fn metric(&self, entity: impl Into<Entity>) -> MetricBuilder {
+    pub fn metric(&self, entity: impl Into<Entity>) -> MetricBuilder<'_> {
         MetricBuilder::new(self, entity.into())
     }
 }
+
+impl<'a> EntityView<'a> {
+    /// Iterate the instances of a metric entity.
+    ///
+    /// An instance is created for each entity the metric is measured for, as a child of the
+    /// metric. The values are updated every second of world time.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked with the instance, the entity the value is measured
+    ///   for and the last value of the metric.
+    ///
+    /// # See also
+    ///
+    /// * [`World::metric()`]
+    pub fn each_metric_instance(self, mut func: impl FnMut(EntityView<'a>, EntityView<'a>, f64)) {
+        let world = self.world;
+        let world_ptr = world.world_ptr();
+        let mut it =
+            unsafe { sys::ecs_each_id(world_ptr, ecs_pair(flecs::ChildOf::ID, *self.id())) };
+
+        while unsafe { sys::ecs_each_next(&mut it) } {
+            for i in 0..it.count as usize {
+                let id = unsafe { *it.entities.add(i) };
+                let value = unsafe {
+                    sys::ecs_get_id(world_ptr, id, sys::FLECS_IDEcsMetricValueID_)
+                        as *const sys::EcsMetricValue
+                };
+                if value.is_null() {
+                    continue;
+                }
+
+                let source = unsafe {
+                    sys::ecs_get_id(world_ptr, id, sys::FLECS_IDEcsMetricSourceID_)
+                        as *const sys::EcsMetricSource
+                };
+                let source = if source.is_null() {
+                    0
+                } else {
+                    unsafe { (*source).entity }
+                };
+
+                func(
+                    EntityView::new_from(world, id),
+                    EntityView::new_from(world, source),
+                    unsafe { (*value).value },
+                );
+            }
+        }
+    }
+}
@@ -8,6 +8,12 @@ pub struct MetricsModule;
 impl Module for MetricsModule {
     fn module(world: &World) {
         world.module::<MetricsModule>("::flecs::metrics");
-        unsafe { flecs_ecs_sys::FlecsMetricsImport(world.ptr_mut()) };
+
+        unsafe {
+            world.import_c_module(
+                std::ptr::addr_of_mut!(flecs_ecs_sys::FLECS_IDFlecsMetricsID_),
+                flecs_ecs_sys::FlecsMetricsImport,
+            );
+        }
     }
 }
@@ -13,6 +13,9 @@ pub mod system;
 #[cfg(feature = "flecs_pipeline")]
 pub mod pipeline;
 
+#[cfg(feature = "flecs_rest")]
+pub mod rest;
+
 #[cfg(feature = "flecs_stats")]
 pub mod stats;
 
@@ -37,7 +40,41 @@ pub mod metrics;
 #[cfg(feature = "flecs_alerts")]
 pub mod alerts;
 
+#[cfg(feature = "flecs_transform")]
+pub mod transform;
+
 // this is not feature gated to flecs_meta so calling `.meta()` on a component will always work despite meta being disabled.
+/// Describes the members of a component to the reflection framework.
+///
+/// Implemented by `#[derive(Component)]` with the `#[meta]` attribute, which registers every
+/// named field with its type, offset and, for arrays, element count. Fields marked `#[skip]`
+/// are left out. Nested structs reference the component id of the field type.
+///
+/// # Example
+///
+/// ```
+/// # use flecs_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[meta]
+/// struct Path {
+///     points: [f32; 4],
+///     closed: bool,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Path>().meta();
+/// ```
+///
+/// Fields without a layout that can be described fail to compile:
+///
+/// ```compile_fail
+/// # use flecs_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[meta]
+/// struct Named {
+///     name: &'static str,
+/// }
+/// ```
 pub trait Meta<Component> {
     fn meta(component: flecs_ecs::core::Component<Component>);
 }
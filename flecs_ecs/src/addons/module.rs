@@ -5,12 +5,18 @@
 //! * To override the name of a module, see [`World::module()`].
 use crate::core::{
     ecs_pair, flecs, register_componment_data_explicit, ComponentId, EntityView, FlecsConstantId,
-    IdOperations, World, WorldProvider, SEPARATOR,
+    IdOperations, SmallCStr, World, WorldProvider, SEPARATOR,
 };
 use crate::sys;
 
 /// Define a module
 ///
+/// The module entity is named after the Rust path of the module type, with `::` separators,
+/// unless [`World::module()`] is used to override the name. Components registered inside the
+/// module are created as children of the module entity. Their name is relative to the Rust
+/// module that contains the module type, so `physics::Collider` registered by
+/// `physics::Physics` gets the path `physics::Physics::Collider`.
+///
 /// # Examples:
 ///
 /// ```
@@ -28,6 +34,37 @@ use crate::sys;
 /// }
 /// ```
 ///
+/// ```
+/// # use flecs_ecs::prelude::*;
+/// mod physics {
+///     use flecs_ecs::prelude::*;
+///
+///     #[derive(Component)]
+///     pub struct Physics;
+///
+///     #[derive(Component)]
+///     pub struct Collider {
+///         pub radius: f32,
+///     }
+///
+///     impl Module for Physics {
+///         fn module(world: &World) {
+///             world.component::<Collider>();
+///         }
+///     }
+/// }
+///
+/// let world = World::new();
+/// let module = world.import::<physics::Physics>();
+///
+/// let collider = world.component::<physics::Collider>();
+/// assert_eq!(collider.parent().unwrap(), module);
+/// assert!(collider.path().unwrap().ends_with("physics::Physics::Collider"));
+///
+/// // importing a module again returns the existing module entity
+/// assert_eq!(world.import::<physics::Physics>(), module);
+/// ```
+///
 /// # See also
 ///
 /// * [`addons::module`](crate::addons::module)
@@ -91,7 +128,7 @@ impl World {
             #[cfg(feature = "flecs_meta")]
             {
                 self.components_map()
-                    .insert(std::any::TypeId::of::<Self>(), id_u64);
+                    .insert(std::any::TypeId::of::<T>(), id_u64);
             }
             id
         };
@@ -149,7 +186,7 @@ impl World {
         let id = comp.id();
 
         if !name.is_empty() {
-            let name = compact_str::format_compact!("{}\0", name);
+            let name = SmallCStr::<128>::from_arg(name);
             let prev_parent = comp.parent().unwrap_or(EntityView::new_null(self));
             unsafe {
                 sys::ecs_add_path_w_sep(
@@ -197,4 +234,24 @@ impl World {
 
         EntityView::new_from(self, *id)
     }
+
+    /// Import a module of the C API into the current scope.
+    ///
+    /// The id of a C module is a global that may still hold the id of the module in another
+    /// world. It's reset, so the import looks the module up by its C path in this world. A
+    /// Rust module that is named after the same path is reused as the C module.
+    ///
+    /// # Safety
+    ///
+    /// `module_id` has to point to the id global of the module that `import` imports.
+    pub(crate) unsafe fn import_c_module(
+        &self,
+        module_id: *mut sys::ecs_entity_t,
+        import: unsafe extern "C-unwind" fn(*mut sys::ecs_world_t),
+    ) {
+        unsafe {
+            *module_id = 0;
+            import(self.raw_world.as_ptr());
+        }
+    }
 }
@@ -1,7 +1,11 @@
 //! Pipelines order and schedule systems for execution.
 
+mod ordering;
 mod pipeline_builder;
+mod plan;
+pub(crate) use ordering::*;
 pub use pipeline_builder::*;
+pub use plan::*;
 
 use std::ops::{Deref, DerefMut};
 
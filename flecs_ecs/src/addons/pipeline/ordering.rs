@@ -0,0 +1,198 @@
+//! Ordering constraints between systems of the same phase.
+//!
+//! Within a phase, the builtin pipeline runs systems in the order in which they were created.
+//! Constraints added with [`SystemBuilder::before()`](crate::addons::system::SystemBuilder::before)
+//! and [`SystemBuilder::after()`](crate::addons::system::SystemBuilder::after) are stored in the
+//! world, and the systems are ranked with a topological sort of the constraints. The first time a
+//! constraint is added, the world switches from the builtin pipeline to a pipeline with the same
+//! terms that sorts systems by their rank. Constraints can't be added while the world runs a
+//! custom pipeline, as it doesn't sort its systems by rank.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::c_void;
+
+use crate::core::*;
+use crate::sys;
+
+/// The ordering constraints between the systems of a world.
+#[derive(Default)]
+pub(crate) struct SystemOrder {
+    /// `(before, after)` pairs of systems.
+    edges: Vec<(u64, u64)>,
+    /// Position of the systems in the pipeline. Systems without a rank run after ranked systems.
+    ranks: HashMap<u64, u32>,
+    /// The pipeline that sorts systems by rank, 0 if it wasn't created yet.
+    pipeline: u64,
+}
+
+impl SystemOrder {
+    /// Returns the systems that form a cycle when `edges` are added, starting and ending with
+    /// the same system.
+    fn find_cycle(&self, edges: &[(u64, u64)]) -> Option<Vec<u64>> {
+        let mut successors: HashMap<u64, Vec<u64>> = HashMap::new();
+        for &(before, after) in self.edges.iter().chain(edges) {
+            successors.entry(before).or_default().push(after);
+        }
+
+        for &(before, after) in edges {
+            // search a path back from `after` to `before`
+            let mut parents = HashMap::new();
+            let mut queue = VecDeque::from([after]);
+            let mut visited = HashSet::from([after]);
+            while let Some(current) = queue.pop_front() {
+                if current == before {
+                    let mut cycle = vec![before];
+                    let mut node = before;
+                    while node != after {
+                        node = parents[&node];
+                        cycle.push(node);
+                    }
+                    cycle.reverse();
+                    cycle.insert(0, before);
+                    return Some(cycle);
+                }
+
+                for &next in successors.get(&current).into_iter().flatten() {
+                    if visited.insert(next) {
+                        parents.insert(next, current);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Ranks `systems` so that the constraints are met. Systems that aren't constrained keep
+    /// the order of their ids.
+    fn rank(&mut self, systems: &[u64]) {
+        let nodes: HashSet<u64> = systems.iter().copied().collect();
+        let mut successors: HashMap<u64, Vec<u64>> = HashMap::new();
+        let mut in_degree: HashMap<u64, u32> = HashMap::new();
+        for &(before, after) in &self.edges {
+            // constraints with deleted systems no longer apply
+            if nodes.contains(&before) && nodes.contains(&after) {
+                successors.entry(before).or_default().push(after);
+                *in_degree.entry(after).or_default() += 1;
+            }
+        }
+
+        let mut ready: BTreeSet<u64> = systems
+            .iter()
+            .copied()
+            .filter(|system| !in_degree.contains_key(system))
+            .collect();
+
+        self.ranks.clear();
+        while let Some(system) = ready.pop_first() {
+            self.ranks.insert(system, self.ranks.len() as u32);
+            for &next in successors.get(&system).into_iter().flatten() {
+                let degree = in_degree.get_mut(&next).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.insert(next);
+                }
+            }
+        }
+    }
+
+    fn key(&self, system: u64) -> (u32, u64) {
+        (self.ranks.get(&system).copied().unwrap_or(u32::MAX), system)
+    }
+}
+
+/// Order by callback of the pipeline, which is passed the `(Poly, System)` component of the
+/// systems.
+fn compare_systems(e1: Entity, ptr1: *const c_void, e2: Entity, _ptr2: *const c_void) -> i32 {
+    let ctx = unsafe {
+        let poly = &*(ptr1 as *const flecs::Poly);
+        let world =
+            sys::ecs_get_world((*(poly.poly as *const sys::ecs_system_t)).world as *const c_void);
+        &*(sys::ecs_get_binding_ctx(world) as *const WorldCtx)
+    };
+    let order = &ctx.system_order;
+    order.key(*e1).cmp(&order.key(*e2)) as i32
+}
+
+/// Why ordering constraints could not be added.
+pub(crate) enum SystemOrderError {
+    /// The constraints would create a cycle between these systems.
+    Cycle(Vec<Entity>),
+    /// The world runs this custom pipeline, which doesn't sort systems by their constraints.
+    CustomPipeline(Entity),
+}
+
+/// Adds `(before, after)` constraints between systems and reorders the pipeline.
+///
+/// The constraints are not added when they would create a cycle, or when the world runs a
+/// custom pipeline.
+pub(crate) fn add_system_order(
+    world: &World,
+    edges: &[(Entity, Entity)],
+) -> Result<(), SystemOrderError> {
+    let edges: Vec<(u64, u64)> = edges
+        .iter()
+        .map(|&(before, after)| (*before, *after))
+        .collect();
+
+    let current = world.get_pipeline();
+    let pipeline = world.world_ctx().system_order.pipeline;
+    if *current != pipeline {
+        let builtin = world.try_lookup("flecs::pipeline::BuiltinPipeline");
+        if builtin.is_none_or(|builtin| builtin.id() != current) {
+            return Err(SystemOrderError::CustomPipeline(*current));
+        }
+    }
+
+    if let Some(cycle) = world.world_ctx().system_order.find_cycle(&edges) {
+        return Err(SystemOrderError::Cycle(
+            cycle.into_iter().map(Entity).collect(),
+        ));
+    }
+
+    let mut systems = Vec::new();
+    super::plan::systems_query(world.into()).each_entity(|e, _| systems.push(*e.id()));
+    systems.sort_unstable();
+
+    let order = &mut world.world_ctx_mut().system_order;
+    order.edges.extend(edges);
+    order.rank(&systems);
+
+    if pipeline == 0 {
+        let pipeline = ordered_pipeline(world);
+        world.world_ctx_mut().system_order.pipeline = *pipeline;
+        world.set_pipeline_id(pipeline);
+    }
+
+    // the pipeline only sorts tables in which the order by component changed
+    let poly = ecs_pair(flecs::Poly::ID, flecs::system::System::ID);
+    for system in systems {
+        unsafe { sys::ecs_modified_id(world.world_ptr_mut(), system, poly) };
+    }
+
+    Ok(())
+}
+
+/// Creates a pipeline with the terms of the builtin pipeline, which sorts systems by rank.
+fn ordered_pipeline(world: &World) -> Entity {
+    world
+        .pipeline_named("::flecs::pipeline::OrderedPipeline")
+        .with::<flecs::system::System>()
+        .with_id(ecs_pair(flecs::Poly::ID, flecs::system::System::ID))
+        .with::<flecs::pipeline::Phase>()
+        .cascade_type::<flecs::DependsOn>()
+        .without_id(ecs_dependson(flecs::pipeline::OnStart::ID))
+        .self_()
+        .up_type::<flecs::DependsOn>()
+        .without::<flecs::Disabled>()
+        .up_type::<flecs::DependsOn>()
+        .without::<flecs::Disabled>()
+        .parent()
+        .order_by_id(
+            ecs_pair(flecs::Poly::ID, flecs::system::System::ID),
+            compare_systems,
+        )
+        .build()
+        .id()
+}
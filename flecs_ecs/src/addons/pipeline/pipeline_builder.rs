@@ -93,7 +93,7 @@ where
 
     /// Create a new pipeline builder with a name
     pub(crate) fn new_named(world: &'a World, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let mut obj = Self {
             desc: Default::default(),
@@ -0,0 +1,344 @@
+//! Inspect the schedule of a pipeline without running it.
+
+use crate::core::*;
+use crate::sys;
+
+/// The systems of a single phase of a pipeline, in the order in which they run.
+///
+/// Returned by [`World::pipeline_plan()`].
+#[derive(Debug, Clone)]
+pub struct PipelinePhaseReport<'a> {
+    /// The phase the systems depend on, `None` for systems that don't depend on a phase.
+    pub phase: Option<EntityView<'a>>,
+    /// The systems of the phase, with whether they are enabled and multi threaded.
+    pub systems: Vec<(EntityView<'a>, bool, bool)>,
+}
+
+/// Why a system did not run in the last frame.
+///
+/// # See also
+///
+/// * [`World::last_run_log()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The system, one of its parents or its phase is disabled.
+    Disabled,
+    /// The tick source of the system, e.g. its interval or rate, did not tick.
+    IntervalNotElapsed,
+    /// The pipeline did not reach the system, e.g. because it was created during the frame.
+    NotScheduled,
+}
+
+/// Whether a system ran in the last frame.
+///
+/// # See also
+///
+/// * [`World::last_run_log()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemRunStatus {
+    /// The system ran. Note that systems are also ran when their query matches nothing.
+    Ran,
+    /// The system was skipped.
+    Skipped(SkipReason),
+}
+
+/// The status of a system in the last frame, see [`World::last_run_log()`].
+#[derive(Debug, Clone, Copy)]
+pub struct SystemRunReport<'a> {
+    /// The system.
+    pub system: EntityView<'a>,
+    /// Whether the system ran, or why it was skipped.
+    pub status: SystemRunStatus,
+}
+
+/// A system of a pipeline plan, before it is grouped by phase.
+struct PlannedSystem {
+    system: Entity,
+    phase: Entity,
+    depth: u32,
+    enabled: bool,
+}
+
+/// Returns the number of phases the phase depends on.
+fn phase_depth(world: WorldRef, phase: Entity) -> u32 {
+    let mut depth = 0;
+    let mut current = phase;
+    while *current != 0 && depth < 256 {
+        current = Entity::new(unsafe {
+            sys::ecs_get_target(world.world_ptr(), *current, ECS_DEPENDS_ON, 0)
+        });
+        depth += 1;
+    }
+    depth
+}
+
+/// Returns true if the system is disabled by itself, a parent or its phase.
+fn is_disabled(world: WorldRef, system: Entity) -> bool {
+    let world = world.world_ptr();
+    [ECS_CHILD_OF, ECS_DEPENDS_ON].iter().any(|&rel| {
+        let mut current = *system;
+        let mut hops = 0;
+        while current != 0 && hops < 256 {
+            if unsafe { sys::ecs_has_id(world, current, ECS_DISABLED) } {
+                return true;
+            }
+            current = unsafe { sys::ecs_get_target(world, current, rel, 0) };
+            hops += 1;
+        }
+        false
+    })
+}
+
+/// Returns the cached query that matches all systems, including disabled systems.
+///
+/// The query is created the first time it's used with a world and is deleted together with
+/// the world.
+pub(crate) fn systems_query(world: WorldRef) -> Query<()> {
+    let world = world.world();
+    let cached = world.world_ctx().systems_query;
+    if let Some(query) = (cached != 0)
+        .then(|| world.try_query_from(cached))
+        .flatten()
+    {
+        return query;
+    }
+
+    let query = world
+        .query::<()>()
+        .with::<flecs::system::System>()
+        .query_flags(QueryFlags::MatchDisabled)
+        .set_cached()
+        .build();
+    world.world_ctx_mut().systems_query = *query.entity().id();
+    query
+}
+
+/// Returns the systems of a pipeline, ordered like the pipeline orders them.
+///
+/// Enabled systems are taken from the pipeline query. Disabled systems, which the pipeline
+/// query doesn't match, are inserted where they would run if they were enabled.
+fn plan_systems(world: WorldRef, pipeline: Entity) -> Vec<PlannedSystem> {
+    let query = unsafe { sys::ecs_rust_pipeline_query(world.world_ptr(), *pipeline) };
+    if query.is_null() {
+        return Vec::new();
+    }
+
+    let planned = |system: u64, enabled: bool| {
+        let phase = Entity::new(unsafe {
+            sys::ecs_get_target(world.world_ptr(), system, ECS_DEPENDS_ON, 0)
+        });
+        PlannedSystem {
+            system: Entity::new(system),
+            phase,
+            depth: phase_depth(world, phase),
+            enabled,
+        }
+    };
+
+    let mut systems = Vec::new();
+    unsafe {
+        let mut it = sys::ecs_query_iter(world.world_ptr(), query);
+        while sys::ecs_query_next(&mut it) {
+            for i in 0..it.count as usize {
+                systems.push(planned(*it.entities.add(i), true));
+            }
+        }
+    }
+
+    systems_query(world).each_entity(|e, _| {
+        if !is_disabled(world, e.id()) || systems.iter().any(|s| s.system == e.id()) {
+            return;
+        }
+        let disabled = planned(*e.id(), false);
+        let index = systems
+            .iter()
+            .position(|s| (s.depth, s.system) > (disabled.depth, disabled.system))
+            .unwrap_or(systems.len());
+        systems.insert(index, disabled);
+    });
+
+    systems
+}
+
+impl World {
+    /// Returns the systems of the current pipeline grouped by phase, in the order in which
+    /// they run, without running them.
+    ///
+    /// Disabled systems are included at the position where they would run if they were
+    /// enabled. This can be used to find out why a system did not run. The plan also
+    /// contains the builtin systems of the world, such as those of the timer addon.
+    ///
+    /// # Returns
+    ///
+    /// The phases of the pipeline. Each system is reported with whether it is enabled and
+    /// whether it is multi threaded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let update = world.system_named::<()>("Update").run(|_| {});
+    /// let store = world
+    ///     .system_named::<()>("Store")
+    ///     .kind_id(flecs::pipeline::OnStore)
+    ///     .run(|_| {});
+    /// update.disable_self();
+    ///
+    /// let plan = world.pipeline_plan();
+    ///
+    /// let on_update = plan
+    ///     .iter()
+    ///     .find(|report| report.phase.unwrap() == flecs::pipeline::OnUpdate::ID)
+    ///     .unwrap();
+    /// assert!(on_update.systems.contains(&(*update, false, false)));
+    ///
+    /// let on_store = plan
+    ///     .iter()
+    ///     .find(|report| report.phase.unwrap() == flecs::pipeline::OnStore::ID)
+    ///     .unwrap();
+    /// assert!(on_store.systems.contains(&(*store, true, false)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::pipeline_plan_id()`]
+    /// * [`World::last_run_log()`]
+    pub fn pipeline_plan(&self) -> Vec<PipelinePhaseReport<'_>> {
+        self.pipeline_plan_id(self.get_pipeline())
+    }
+
+    /// Returns the systems of a pipeline grouped by phase, in the order in which they run,
+    /// without running them.
+    ///
+    /// # Arguments
+    ///
+    /// * `pipeline` - The pipeline.
+    ///
+    /// # See also
+    ///
+    /// * [`World::pipeline_plan()`]
+    pub fn pipeline_plan_id(&self, pipeline: impl Into<Entity>) -> Vec<PipelinePhaseReport<'_>> {
+        let world = WorldRef::from(self);
+        let mut plan: Vec<PipelinePhaseReport<'_>> = Vec::new();
+
+        for planned in plan_systems(world, pipeline.into()) {
+            let phase = (*planned.phase != 0).then(|| self.entity_from_id(planned.phase));
+            let multi_threaded = unsafe {
+                let system = sys::ecs_system_get(self.world_ptr(), *planned.system);
+                !system.is_null() && (*system).multi_threaded
+            };
+            let system = (
+                self.entity_from_id(planned.system),
+                planned.enabled,
+                multi_threaded,
+            );
+
+            match plan.last_mut() {
+                Some(report) if report.phase.map(|p| p.id()) == phase.map(|p| p.id()) => {
+                    report.systems.push(system);
+                }
+                _ => plan.push(PipelinePhaseReport {
+                    phase,
+                    systems: vec![system],
+                }),
+            }
+        }
+
+        plan
+    }
+}
+
+#[cfg(feature = "flecs_pipeline_log")]
+impl World {
+    /// Returns which systems of the pipeline ran in the last call to [`World::progress()`]
+    /// or [`World::run_pipeline()`], and why the other systems were skipped.
+    ///
+    /// Requires the `flecs_pipeline_log` feature, which records the log after each frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use flecs_ecs::addons::pipeline::{SkipReason, SystemRunStatus};
+    ///
+    /// let world = World::new();
+    ///
+    /// let system = world.system::<()>().run(|_| {});
+    /// let disabled = world.system::<()>().run(|_| {});
+    /// disabled.disable_self();
+    ///
+    /// world.progress();
+    ///
+    /// let log = world.last_run_log();
+    /// let status = |e: EntityView| log.iter().find(|r| r.system == e).unwrap().status;
+    ///
+    /// assert_eq!(status(*system), SystemRunStatus::Ran);
+    /// assert_eq!(
+    ///     status(*disabled),
+    ///     SystemRunStatus::Skipped(SkipReason::Disabled)
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::pipeline_plan()`]
+    pub fn last_run_log(&self) -> Vec<SystemRunReport<'_>> {
+        self.world_ctx()
+            .last_run_log
+            .iter()
+            .map(|&(system, status)| SystemRunReport {
+                system: self.entity_from_id(system),
+                status,
+            })
+            .collect()
+    }
+
+    /// Returns the frame the pipeline is about to run, to pass to
+    /// [`World::record_run_log()`] after the pipeline ran.
+    pub(crate) fn run_log_begin(&self) -> i64 {
+        self.info().frame_count_total + 1
+    }
+
+    /// Record which systems of the pipeline ran in the frame.
+    pub(crate) fn record_run_log(&self, pipeline: Entity, frame: i64) {
+        let world = WorldRef::from(self);
+        let log = plan_systems(world, pipeline)
+            .into_iter()
+            .map(|planned| {
+                let status = if !planned.enabled {
+                    SystemRunStatus::Skipped(SkipReason::Disabled)
+                } else {
+                    let system = unsafe { sys::ecs_system_get(self.world_ptr(), *planned.system) };
+                    let (last_frame, tick_source) = unsafe {
+                        system
+                            .as_ref()
+                            .map_or((0, 0), |s| (s.last_frame, s.tick_source))
+                    };
+                    let ticked = tick_source == 0
+                        || unsafe {
+                            let tick = sys::ecs_get_id(
+                                self.world_ptr(),
+                                tick_source,
+                                flecs::system::TickSource::ID,
+                            )
+                                as *const flecs::system::TickSource;
+                            !tick.is_null() && (*tick).tick
+                        };
+
+                    if last_frame != frame {
+                        SystemRunStatus::Skipped(SkipReason::NotScheduled)
+                    } else if !ticked {
+                        SystemRunStatus::Skipped(SkipReason::IntervalNotElapsed)
+                    } else {
+                        SystemRunStatus::Ran
+                    }
+                };
+                (planned.system, status)
+            })
+            .collect();
+        self.world_ctx_mut().last_run_log = log;
+    }
+}
@@ -0,0 +1,57 @@
+//! REST API for querying the world, used by the [Flecs Explorer](https://www.flecs.dev/explorer).
+
+use crate::core::*;
+
+impl World {
+    /// Start the REST server, so the world can be inspected with the Flecs Explorer.
+    ///
+    /// This sets the [`flecs::rest::Rest`] singleton, and imports the [`Stats`](crate::addons::stats::Stats)
+    /// module when the `flecs_stats` feature is enabled, which the explorer uses for its graphs.
+    /// Requests are handled while the world is progressed.
+    ///
+    /// Calling this when the server is already enabled does nothing, the server keeps running on
+    /// the port it was started with.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - The port to listen on, or `None` for the default port (27750).
+    ///
+    /// # Returns
+    ///
+    /// The singleton entity with the [`flecs::rest::Rest`] component. Removing the component
+    /// from it stops the server.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let rest = world.enable_rest(None);
+    ///
+    /// for _ in 0..100 {
+    ///     world.progress();
+    /// }
+    ///
+    /// // stop the server
+    /// rest.remove::<flecs::rest::Rest>();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`App::enable_rest()`](crate::addons::app::App::enable_rest)
+    pub fn enable_rest(&self, port: Option<u16>) -> EntityView<'_> {
+        #[cfg(feature = "flecs_stats")]
+        self.import::<crate::addons::stats::Stats>();
+
+        let rest = self.singleton_entity::<flecs::rest::Rest>();
+        if !rest.has::<flecs::rest::Rest>() {
+            rest.set(flecs::rest::Rest {
+                port: port.unwrap_or(0),
+                ..Default::default()
+            });
+        }
+        rest
+    }
+}
@@ -1,9 +1,11 @@
 mod script_builder;
 mod script_entity_view;
+mod script_vars;
 mod unmanaged_script;
 
 pub use script_builder::*;
 pub use script_entity_view::*;
+pub use script_vars::*;
 pub use unmanaged_script::*;
 
 use flecs_ecs::core::*;
@@ -100,6 +102,96 @@ impl World {
         Script::to_expr(self, value)
     }
 
+    /// Parse an expression into a value of the provided type.
+    ///
+    /// Members that are not in the expression keep their current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the expression can't be parsed or can't be
+    /// assigned to the type.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn from_expr_id(
+        &self,
+        id_of_value: impl Into<Entity>,
+        value: *mut std::ffi::c_void,
+        expr: &str,
+    ) -> Result<(), FlecsError> {
+        Script::from_expr_id(self, id_of_value, value, expr)
+    }
+
+    /// Parse an expression into a value of type `T`, the inverse of [`World::to_expr()`].
+    ///
+    /// Members that are not in the expression are set to their default value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the expression can't be parsed or can't be
+    /// assigned to `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default, Debug, PartialEq)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>().meta();
+    ///
+    /// let pos = world.from_expr::<Position>("{x: 10, y: 20}").unwrap();
+    /// assert_eq!(pos, Position { x: 10.0, y: 20.0 });
+    ///
+    /// assert!(world.from_expr::<Position>("{x: 10, z: 20}").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub fn from_expr<T: ComponentId + Default>(&self, expr: &str) -> Result<T, FlecsError> {
+        Script::from_expr(self, expr)
+    }
+
+    /// Parse an expression that uses variables into a value of type `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the expression can't be parsed or can't be
+    /// assigned to `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut vars = ScriptVars::new(&world);
+    /// vars.var("a", 3i32).var("b", 4i32);
+    ///
+    /// assert_eq!(world.from_expr_w_vars::<i32>("$a * $b", &vars), Ok(12));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub fn from_expr_w_vars<T: ComponentId + Default>(
+        &self,
+        expr: &str,
+        vars: &ScriptVars,
+    ) -> Result<T, FlecsError> {
+        Script::from_expr_w_vars(self, expr, vars)
+    }
+
     /// Wraps the provided entity id in a [`ScriptEntityView`].
     ///
     /// # Panics
@@ -118,3 +210,38 @@ impl World {
         ScriptEntityView::new_from(self, T::id(self))
     }
 }
+
+/// Error of a script that failed to load, parse or run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    message: String,
+}
+
+impl ScriptError {
+    /// Create an error from the error flecs logged while running a script.
+    pub(crate) fn from_log(error: Option<String>) -> Self {
+        Self {
+            message: error.unwrap_or_else(|| "failed to run script".to_string()),
+        }
+    }
+
+    /// Returns the error message reported by flecs. Parser errors start with the line number,
+    /// followed by the line of the script and a marker at the column of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the line of the script at which the error occurred, if flecs reported one.
+    pub fn line(&self) -> Option<u32> {
+        let (line, _) = self.message.split_once(": ")?;
+        line.parse().ok()
+    }
+}
+
+impl core::fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ScriptError {}
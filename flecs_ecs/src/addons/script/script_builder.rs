@@ -1,11 +1,32 @@
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
-use super::ScriptEntityView;
+use super::{ScriptEntityView, ScriptError, ScriptVars};
 
 /// [`ScriptBuilder`] is a builder pattern for creating scripts.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+///
+/// world
+///     .script()
+///     .code("Ship { Engine {} }")
+///     .run()
+///     .unwrap();
+///
+/// assert!(world.try_lookup("Ship::Engine").is_some());
+///
+/// let err = world.script().code("Ship {\n  Engine {\n").run().unwrap_err();
+/// assert!(err.line().is_some());
+/// ```
 pub struct ScriptBuilder<'a> {
     script: sys::ecs_script_desc_t,
+    code: Option<String>,
+    filename: Option<String>,
     world: WorldRef<'a>,
 }
 
@@ -21,6 +42,8 @@ impl<'a> ScriptBuilder<'a> {
                 filename: std::ptr::null(),
                 code: std::ptr::null(),
             },
+            code: None,
+            filename: None,
             world: world.world(),
         }
     }
@@ -30,7 +53,7 @@ impl<'a> ScriptBuilder<'a> {
     ///
     /// The entity will receive an [`EcsScript`][crate::sys::EcsScript] component.
     pub fn new_named(world: impl WorldProvider<'a>, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
         let entity_desc = sys::ecs_entity_desc_t {
             name: name.as_ptr() as *const _,
             sep: SEPARATOR.as_ptr(),
@@ -43,6 +66,8 @@ impl<'a> ScriptBuilder<'a> {
                 filename: std::ptr::null(),
                 code: std::ptr::null(),
             },
+            code: None,
+            filename: None,
             world: world.world(),
         }
     }
@@ -59,10 +84,195 @@ impl<'a> ScriptBuilder<'a> {
                 filename: std::ptr::null(),
                 code: std::ptr::null(),
             },
+            code: None,
+            filename: None,
             world: world.world(),
         }
     }
 
+    /// Set the code of the script.
+    pub fn code(&mut self, code: &str) -> &mut Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    /// Set the file of the script. The file is loaded when no code is set, otherwise it's used
+    /// as the name of the script in errors.
+    pub fn filename(&mut self, filename: &str) -> &mut Self {
+        self.filename = Some(filename.to_string());
+        self
+    }
+
+    /// Set the entity that manages the script, see [`ScriptBuilder::load()`].
+    pub fn entity(&mut self, entity: impl Into<Entity>) -> &mut Self {
+        self.script.entity = *entity.into();
+        self
+    }
+
+    /// Run the script once. Entities created by the script are not managed by a script entity,
+    /// so they are not removed when the script changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the file can't be loaded, or the script fails to
+    /// parse or run.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_run`, `ecs_script_run_file`
+    #[doc(alias = "ecs_script_run")]
+    pub fn run(&self) -> Result<(), ScriptError> {
+        let world = self.world.world_ptr_mut();
+        let filename = self.filename.as_deref().map(SmallCStr::<128>::from_arg);
+        let filename_ptr = filename
+            .as_ref()
+            .map_or(std::ptr::null(), |filename| filename.as_ptr());
+
+        let (result, error) = match self.code.as_deref() {
+            Some(code) => {
+                let code = SmallCStr::<128>::from_arg(code);
+                log::capture_error(|| unsafe {
+                    sys::ecs_script_run(world, filename_ptr as *const _, code.as_ptr() as *const _)
+                })
+            }
+            None if !filename_ptr.is_null() => log::capture_error(|| unsafe {
+                sys::ecs_script_run_file(world, filename_ptr as *const _)
+            }),
+            None => {
+                return Err(ScriptError::from_log(Some(
+                    "script has no code".to_string(),
+                )))
+            }
+        };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(ScriptError::from_log(error))
+        }
+    }
+
+    /// Run the script once, with variables that the script uses as `$name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, or the error reported by flecs if the script
+    /// fails to parse or run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// let parent = world.entity_named("Fleet");
+    ///
+    /// let mut vars = ScriptVars::new(&world);
+    /// vars.var_id("parent", parent);
+    ///
+    /// world
+    ///     .script()
+    ///     .code("Ship { (ChildOf, $parent) }")
+    ///     .run_w_vars(&vars)
+    ///     .unwrap();
+    ///
+    /// assert!(world.try_lookup("Fleet::Ship").is_some());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_parse`, `ecs_script_eval`
+    #[doc(alias = "ecs_script_eval")]
+    pub fn run_w_vars(&self, vars: &ScriptVars) -> Result<(), ScriptError> {
+        let code = match (&self.code, &self.filename) {
+            (Some(code), _) => code.clone(),
+            (None, Some(filename)) => std::fs::read_to_string(filename).map_err(|err| {
+                ScriptError::from_log(Some(format!("failed to read '{filename}': {err}")))
+            })?,
+            (None, None) => {
+                return Err(ScriptError::from_log(Some(
+                    "script has no code".to_string(),
+                )))
+            }
+        };
+
+        let world = self.world.world_ptr_mut();
+        let name = self.filename.as_deref().map(SmallCStr::<128>::from_arg);
+        let name_ptr = name.as_ref().map_or(std::ptr::null(), |name| name.as_ptr());
+        let code = SmallCStr::<128>::from_arg(&code);
+        let desc = sys::ecs_script_eval_desc_t {
+            vars: vars.as_ptr(),
+            runtime: std::ptr::null_mut(),
+        };
+
+        let (result, error) = log::capture_error(|| unsafe {
+            let script = sys::ecs_script_parse(
+                world,
+                name_ptr as *const _,
+                code.as_ptr() as *const _,
+                &desc,
+            );
+            if script.is_null() {
+                return -1;
+            }
+
+            let prev_scope = sys::ecs_set_scope(world, 0);
+            let result = sys::ecs_script_eval(script, &desc);
+            sys::ecs_set_scope(world, prev_scope);
+            sys::ecs_script_free(script);
+            result
+        });
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(ScriptError::from_log(error))
+        }
+    }
+
+    /// Load the script as a managed script. The script entity gets an
+    /// [`EcsScript`][crate::sys::EcsScript] component, and the entities created by the script
+    /// are replaced when the script is updated with [`ScriptEntityView::update()`].
+    ///
+    /// When no entity is set and the script has a file, the entity is named after the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the file can't be loaded, or the script fails to
+    /// parse or run.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_init`
+    #[doc(alias = "ecs_script_init")]
+    pub fn load(&self) -> Result<ScriptEntityView<'a>, ScriptError> {
+        if self.code.is_none() && self.filename.is_none() {
+            return Err(ScriptError::from_log(Some(
+                "script has no code".to_string(),
+            )));
+        }
+
+        let world = self.world.world_ptr_mut();
+        let code = self.code.as_deref().map(SmallCStr::<128>::from_arg);
+        let filename = self.filename.as_deref().map(SmallCStr::<128>::from_arg);
+
+        let mut desc = self.script;
+        if let Some(code) = &code {
+            desc.code = code.as_ptr() as *const _;
+        }
+        if let Some(filename) = &filename {
+            desc.filename = filename.as_ptr() as *const _;
+        }
+
+        let (entity, error) = log::capture_error(|| unsafe { sys::ecs_script_init(world, &desc) });
+
+        if entity == 0 {
+            Err(ScriptError::from_log(error))
+        } else {
+            Ok(ScriptEntityView::new_from(self.world, entity))
+        }
+    }
+
     /// Loads a managed script from a file into the ECS world.
     ///
     /// This function initializes an ECS script from a file specified by `filename`.
@@ -82,7 +292,7 @@ impl<'a> ScriptBuilder<'a> {
     /// * C API: `ecs_script_init`
     #[doc(alias = "ecs_script_init")]
     pub fn build_from_file(&mut self, filename: &str) -> ScriptEntityView<'a> {
-        let filename = compact_str::format_compact!("{}\0", filename);
+        let filename = SmallCStr::<128>::from_arg(filename);
         let world = self.world.world_ptr_mut();
 
         self.script.filename = filename.as_ptr() as *const _;
@@ -111,7 +321,7 @@ impl<'a> ScriptBuilder<'a> {
     /// * C API: `ecs_script_init`
     #[doc(alias = "ecs_script_init")]
     pub fn build_from_code(&mut self, code: &str) -> ScriptEntityView<'a> {
-        let code = compact_str::format_compact!("{}\0", code);
+        let code = SmallCStr::<128>::from_arg(code);
         let world = self.world.world_ptr_mut();
 
         self.script.code = code.as_ptr() as *const _;
@@ -4,6 +4,8 @@ use std::ops::DerefMut;
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
+use super::ScriptError;
+
 /// [`ScriptEntityView`] is a wrapper around an entity that is associated with a script.
 #[derive(Clone, Copy)]
 pub struct ScriptEntityView<'a> {
@@ -40,6 +42,8 @@ impl<'a> ScriptEntityView<'a> {
 
     /// Update script with new code.
     ///
+    /// Entities created by the previous code of the script are deleted before the new code runs.
+    ///
     /// # Arguments
     ///
     /// * code - The script code.
@@ -48,9 +52,9 @@ impl<'a> ScriptEntityView<'a> {
     ///
     /// * instance - An template instance (optional).
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// True if success, false if failed.
+    /// Returns the error reported by flecs if the script fails to parse or run.
     ///
     /// # See also
     ///
@@ -61,15 +65,22 @@ impl<'a> ScriptEntityView<'a> {
         world: impl WorldProvider<'a>,
         instance: Option<impl Into<Entity>>,
         code: &str,
-    ) -> bool {
-        let code = compact_str::format_compact!("{}\0", code);
-        unsafe {
+    ) -> Result<(), ScriptError> {
+        let code = SmallCStr::<128>::from_arg(code);
+        let world = world.world_ptr_mut();
+        let (result, error) = log::capture_error(|| unsafe {
             sys::ecs_script_update(
-                world.world_ptr_mut(),
+                world,
                 *self.id,
                 instance.map(|e| *e.into()).unwrap_or(0),
                 code.as_ptr() as *const _,
-            ) == 0
+            )
+        });
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(ScriptError::from_log(error))
         }
     }
 
@@ -0,0 +1,135 @@
+use std::alloc::Layout;
+use std::ffi::CString;
+
+use flecs_ecs::core::*;
+use flecs_ecs::sys;
+
+/// Variables that are passed to a script or expression, and used as `$name` in the code.
+///
+/// The variables own copies of their values, which are dropped with the variables.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+///
+/// let mut vars = ScriptVars::new(&world);
+/// vars.var("speed", 5.0f32);
+///
+/// let result = world.from_expr_w_vars::<f32>("$speed * 2", &vars).unwrap();
+/// assert_eq!(result, 10.0);
+/// ```
+pub struct ScriptVars<'a> {
+    vars: *mut sys::ecs_script_vars_t,
+    // flecs doesn't copy the names of variables
+    names: Vec<CString>,
+    values: Vec<(*mut u8, Layout)>,
+    world: WorldRef<'a>,
+}
+
+impl Drop for ScriptVars<'_> {
+    fn drop(&mut self) {
+        // drops the values of the variables that have a destructor
+        unsafe { sys::ecs_script_vars_fini(self.vars) };
+
+        for (ptr, layout) in self.values.drain(..) {
+            unsafe { std::alloc::dealloc(ptr, layout) };
+        }
+    }
+}
+
+impl<'a> ScriptVars<'a> {
+    /// Create an empty set of variables.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_init`
+    #[doc(alias = "ecs_script_vars_init")]
+    pub fn new(world: impl WorldProvider<'a>) -> Self {
+        let world = world.world();
+        Self {
+            vars: unsafe { sys::ecs_script_vars_init(world.world_ptr_mut()) },
+            names: Vec::new(),
+            values: Vec::new(),
+            world,
+        }
+    }
+
+    /// Add a variable with a value of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable with the same name was already added.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_declare`
+    #[doc(alias = "ecs_script_vars_declare")]
+    pub fn var<T: ComponentId + DataComponent>(&mut self, name: &str, value: T) -> &mut Self {
+        const {
+            assert!(
+                std::mem::size_of::<T>() != 0,
+                "cannot declare zero-sized-type / tag variables"
+            );
+        };
+
+        let type_id = T::get_id(self.world);
+        if let Some(ptr) = self.declare(name, type_id, Layout::new::<T>()) {
+            unsafe { std::ptr::write(ptr as *mut T, value) };
+        }
+        self
+    }
+
+    /// Add a variable with an entity value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable with the same name was already added.
+    pub fn var_id(&mut self, name: &str, entity: impl Into<Entity>) -> &mut Self {
+        if let Some(ptr) = self.declare(name, flecs::meta::Entity::ID, Layout::new::<u64>()) {
+            unsafe { std::ptr::write(ptr as *mut u64, *entity.into()) };
+        }
+        self
+    }
+
+    /// Declare a variable and allocate its value, which has to be written by the caller.
+    fn declare(
+        &mut self,
+        name: &str,
+        type_id: sys::ecs_entity_t,
+        layout: Layout,
+    ) -> Option<*mut u8> {
+        let name = CString::new(name).expect("variable name contains a nul byte");
+        let var = unsafe { sys::ecs_script_vars_declare(self.vars, name.as_ptr()) };
+        ecs_assert!(
+            !var.is_null(),
+            FlecsErrorCode::AlreadyDefined,
+            "variable {:?} is already declared",
+            name
+        );
+        if var.is_null() {
+            return None;
+        }
+        self.names.push(name);
+
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        if ptr.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        self.values.push((ptr, layout));
+
+        unsafe {
+            (*var).value.type_ = type_id;
+            (*var).value.ptr = ptr as *mut std::ffi::c_void;
+            (*var).type_info = sys::ecs_get_type_info(self.world.world_ptr(), type_id);
+        }
+        Some(ptr)
+    }
+
+    /// Returns the variables as passed to flecs.
+    pub(crate) fn as_ptr(&self) -> *mut sys::ecs_script_vars_t {
+        self.vars
+    }
+}
@@ -3,6 +3,8 @@ use std::ffi::CStr;
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
+use super::ScriptVars;
+
 /// A Script object is not associated to an entity and will be automatically deleted when it goes out of scope.
 /// For scripts that are associated with an entity, use [`ScriptBuilder`][super::ScriptBuilder] alongside [`ScriptEntityView`][super::ScriptEntityView].
 ///
@@ -53,8 +55,8 @@ impl<'a> Script<'a> {
         code: &str,
         desc: Option<sys::ecs_script_eval_desc_t>,
     ) -> Option<Script<'a>> {
-        let name = compact_str::format_compact!("{}\0", name);
-        let code = compact_str::format_compact!("{}\0", code);
+        let name = SmallCStr::<128>::from_arg(name);
+        let code = SmallCStr::<128>::from_arg(code);
         let world_ptr = world.world_ptr_mut();
 
         let ptr = unsafe {
@@ -125,8 +127,8 @@ impl<'a> Script<'a> {
     /// * C API: `ecs_script_run`
     #[doc(alias = "ecs_script_run")]
     pub fn run_code(world: impl WorldProvider<'a>, name: &str, code: &str) -> bool {
-        let name = compact_str::format_compact!("{}\0", name);
-        let code = compact_str::format_compact!("{}\0", code);
+        let name = SmallCStr::<128>::from_arg(name);
+        let code = SmallCStr::<128>::from_arg(code);
         let world_ptr = world.world_ptr_mut();
 
         unsafe {
@@ -152,7 +154,7 @@ impl<'a> Script<'a> {
     /// # See also
     #[doc(alias = "ecs_script_run_file")]
     pub fn run_file(world: impl WorldProvider<'a>, filename: &str) -> bool {
-        let filename = compact_str::format_compact!("{}\0", filename);
+        let filename = SmallCStr::<128>::from_arg(filename);
         let world_ptr = world.world_ptr_mut();
 
         unsafe { sys::ecs_script_run_file(world_ptr, filename.as_ptr() as *const _) == 0 }
@@ -231,4 +233,122 @@ impl<'a> Script<'a> {
         let id = T::get_id(world);
         Self::to_expr_id(world, id, value as *const T as *const std::ffi::c_void)
     }
+
+    /// Parse an expression into a value of the provided type.
+    ///
+    /// Members that are not in the expression keep their current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the expression can't be parsed or can't be
+    /// assigned to the type.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn from_expr_id(
+        world: impl WorldProvider<'a>,
+        id_of_value: impl Into<Entity>,
+        value: *mut std::ffi::c_void,
+        expr: &str,
+    ) -> Result<(), FlecsError> {
+        Self::expr_run(
+            world.world_ptr_mut(),
+            *id_of_value.into(),
+            value,
+            expr,
+            std::ptr::null(),
+        )
+    }
+
+    fn expr_run(
+        world: *mut sys::ecs_world_t,
+        type_id: sys::ecs_entity_t,
+        value: *mut std::ffi::c_void,
+        expr: &str,
+        vars: *const sys::ecs_script_vars_t,
+    ) -> Result<(), FlecsError> {
+        let mut value = sys::ecs_value_t {
+            type_: type_id,
+            ptr: value,
+        };
+        // a folded expression is copied into the value, which requires the type to be `Clone`
+        let desc = sys::ecs_expr_eval_desc_t {
+            type_: type_id,
+            vars,
+            disable_folding: true,
+            ..unsafe { std::mem::zeroed() }
+        };
+        let expr = SmallCStr::<128>::from_arg(expr);
+        let (result, error) = log::capture_error(|| unsafe {
+            sys::ecs_expr_run(world, expr.as_ptr() as *const _, &mut value, &desc)
+        });
+
+        if result.is_null() {
+            Err(FlecsError::new(error.unwrap_or_else(|| {
+                "failed to parse expression".to_string()
+            })))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Parse an expression into a value of type `T`.
+    ///
+    /// Members that are not in the expression are set to their default value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the expression can't be parsed or can't be
+    /// assigned to `T`.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub fn from_expr<T: ComponentId + Default>(
+        world: impl WorldProvider<'a>,
+        expr: &str,
+    ) -> Result<T, FlecsError> {
+        let world = world.world();
+        let id = T::get_id(world);
+        let mut value = T::default();
+        Self::from_expr_id(
+            world,
+            id,
+            &mut value as *mut T as *mut std::ffi::c_void,
+            expr,
+        )?;
+        Ok(value)
+    }
+
+    /// Parse an expression that uses variables into a value of type `T`.
+    ///
+    /// Members that are not in the expression are set to their default value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by flecs if the expression can't be parsed or can't be
+    /// assigned to `T`.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub fn from_expr_w_vars<T: ComponentId + Default>(
+        world: impl WorldProvider<'a>,
+        expr: &str,
+        vars: &ScriptVars,
+    ) -> Result<T, FlecsError> {
+        let world = world.world();
+        let id = T::get_id(world);
+        let mut value = T::default();
+        Self::expr_run(
+            world.world_ptr_mut(),
+            id,
+            &mut value as *mut T as *mut std::ffi::c_void,
+            expr,
+            vars.as_ptr(),
+        )?;
+        Ok(value)
+    }
 }
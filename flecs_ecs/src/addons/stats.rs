@@ -29,7 +29,12 @@ impl Module for Stats {
          */
 
         world.module::<Stats>("flecs::rust::stats");
-        unsafe { sys::FlecsStatsImport(world.ptr_mut()) };
+        unsafe {
+            world.import_c_module(
+                std::ptr::addr_of_mut!(sys::FLECS_IDFlecsStatsID_),
+                sys::FlecsStatsImport,
+            );
+        }
         world.component::<WorldSummary>();
         world.component::<WorldStats>();
         world.component::<SystemStats>();
@@ -37,6 +42,218 @@ impl Module for Stats {
     }
 }
 
+/// A sample of the statistics of a world, returned by [`World::world_stats()`].
+///
+/// Gauges, such as the number of entities, are the value at the time of the sample. Counters,
+/// such as the number of frames, are the total since the world was created. Times are in
+/// seconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WorldStatsSample {
+    /// Number of alive entities.
+    pub entity_count: f32,
+    /// Number of not alive entity ids that can be recycled.
+    pub not_alive_entity_count: f32,
+    /// Number of tag ids, i.e. ids without data.
+    pub tag_count: f32,
+    /// Number of component ids, i.e. ids with data.
+    pub component_count: f32,
+    /// Number of pair ids.
+    pub pair_count: f32,
+    /// Number of registered types.
+    pub type_count: f32,
+    /// Number of tables.
+    pub table_count: f32,
+    /// Number of times a table was created.
+    pub table_create_count: f64,
+    /// Number of times a table was deleted.
+    pub table_delete_count: f64,
+    /// Number of queries.
+    pub query_count: f32,
+    /// Number of observers.
+    pub observer_count: f32,
+    /// Number of systems.
+    pub system_count: f32,
+    /// Number of frames processed.
+    pub frame_count: f64,
+    /// Number of command merges.
+    pub merge_count: f64,
+    /// Number of query rematches.
+    pub rematch_count: f64,
+    /// Number of pipeline rebuilds.
+    pub pipeline_build_count: f64,
+    /// Number of times a system was ran.
+    pub systems_ran: f64,
+    /// Number of times an observer was invoked.
+    pub observers_ran: f64,
+    /// Number of events emitted.
+    pub event_emit_count: f64,
+    /// Time passed since the first frame, not scaled by the time scale.
+    pub world_time_raw: f64,
+    /// Simulation time passed since the first frame.
+    pub world_time: f64,
+    /// Time spent processing frames.
+    pub frame_time: f64,
+    /// Time spent running systems.
+    pub system_time: f64,
+    /// Time spent notifying observers.
+    pub emit_time: f64,
+    /// Time spent merging commands.
+    pub merge_time: f64,
+    /// Time spent rematching queries.
+    pub rematch_time: f64,
+    /// Number of memory allocations.
+    pub alloc_count: f64,
+    /// Number of memory reallocations.
+    pub realloc_count: f64,
+    /// Number of memory frees.
+    pub free_count: f64,
+    /// Number of allocations that have not been freed.
+    pub outstanding_alloc_count: f32,
+}
+
+/// A sample of the statistics of a system, returned by [`World::system_stats()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SystemStatsSample {
+    /// Total time spent running the system, in seconds.
+    ///
+    /// System time is only measured after the [`Stats`] module is imported.
+    pub time_spent: f64,
+    /// Number of results of the query of the system.
+    pub result_count: f32,
+    /// Number of tables matched by the system.
+    pub matched_table_count: f32,
+    /// Number of entities matched by the system.
+    pub matched_entity_count: f32,
+    /// Whether the system is a task, i.e. it doesn't match any entities.
+    pub task: bool,
+}
+
+fn gauge(metric: &sys::ecs_metric_t, t: i32) -> f32 {
+    unsafe { metric.gauge.avg[t as usize] }
+}
+
+fn counter(metric: &sys::ecs_metric_t, t: i32) -> f64 {
+    unsafe { metric.counter.value[t as usize] }
+}
+
+impl World {
+    /// Collect a sample of the statistics of the world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let before = world.world_stats();
+    /// world.entity();
+    /// world.progress();
+    /// let after = world.world_stats();
+    ///
+    /// assert!(after.entity_count > before.entity_count);
+    /// assert!(after.frame_count > before.frame_count);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::system_stats()`]
+    /// * C API: `ecs_world_stats_get`
+    pub fn world_stats(&self) -> WorldStatsSample {
+        let mut stats: sys::ecs_world_stats_t = unsafe { std::mem::zeroed() };
+        unsafe { sys::ecs_world_stats_get(self.world_ptr(), &mut stats) };
+        let t = stats.t;
+
+        WorldStatsSample {
+            entity_count: gauge(&stats.entities.count, t),
+            not_alive_entity_count: gauge(&stats.entities.not_alive_count, t),
+            tag_count: gauge(&stats.components.tag_count, t),
+            component_count: gauge(&stats.components.component_count, t),
+            pair_count: gauge(&stats.components.pair_count, t),
+            type_count: gauge(&stats.components.type_count, t),
+            table_count: gauge(&stats.tables.count, t),
+            table_create_count: counter(&stats.tables.create_count, t),
+            table_delete_count: counter(&stats.tables.delete_count, t),
+            query_count: gauge(&stats.queries.query_count, t),
+            observer_count: gauge(&stats.queries.observer_count, t),
+            system_count: gauge(&stats.queries.system_count, t),
+            frame_count: counter(&stats.frame.frame_count, t),
+            merge_count: counter(&stats.frame.merge_count, t),
+            rematch_count: counter(&stats.frame.rematch_count, t),
+            pipeline_build_count: counter(&stats.frame.pipeline_build_count, t),
+            systems_ran: counter(&stats.frame.systems_ran, t),
+            observers_ran: counter(&stats.frame.observers_ran, t),
+            event_emit_count: counter(&stats.frame.event_emit_count, t),
+            world_time_raw: counter(&stats.performance.world_time_raw, t),
+            world_time: counter(&stats.performance.world_time, t),
+            frame_time: counter(&stats.performance.frame_time, t),
+            system_time: counter(&stats.performance.system_time, t),
+            emit_time: counter(&stats.performance.emit_time, t),
+            merge_time: counter(&stats.performance.merge_time, t),
+            rematch_time: counter(&stats.performance.rematch_time, t),
+            alloc_count: counter(&stats.memory.alloc_count, t),
+            realloc_count: counter(&stats.memory.realloc_count, t),
+            free_count: counter(&stats.memory.free_count, t),
+            outstanding_alloc_count: gauge(&stats.memory.outstanding_alloc_count, t),
+        }
+    }
+
+    /// Collect a sample of the statistics of a system.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The system entity.
+    ///
+    /// # Returns
+    ///
+    /// The statistics, or `None` if the entity is not a system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use flecs_ecs::addons::stats::Stats;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.import::<Stats>();
+    ///
+    /// let system = world.system::<&Position>().each(|_| {});
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    /// world.progress();
+    ///
+    /// let stats = world.system_stats(system).unwrap();
+    /// assert_eq!(stats.matched_entity_count, 1.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::world_stats()`]
+    /// * C API: `ecs_system_stats_get`
+    pub fn system_stats(&self, system: impl Into<Entity>) -> Option<SystemStatsSample> {
+        let mut stats: sys::ecs_system_stats_t = unsafe { std::mem::zeroed() };
+        let is_system =
+            unsafe { sys::ecs_system_stats_get(self.world_ptr(), *system.into(), &mut stats) };
+        if !is_system {
+            return None;
+        }
+        let t = stats.query.t;
+
+        Some(SystemStatsSample {
+            time_spent: counter(&stats.time_spent, t),
+            result_count: gauge(&stats.query.result_count, t),
+            matched_table_count: gauge(&stats.query.matched_table_count, t),
+            matched_entity_count: gauge(&stats.query.matched_entity_count, t),
+            task: stats.task,
+        })
+    }
+}
+
 ///////////////////////////
 // trait implementations
 ///////////////////////////
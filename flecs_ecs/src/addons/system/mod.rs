@@ -9,12 +9,154 @@ mod system_runner_fluent;
 pub use system_builder::*;
 pub use system_runner_fluent::*;
 
+use std::any::TypeId;
+use std::cell::Cell;
 use std::ops::DerefMut;
 use std::{ops::Deref, os::raw::c_void, ptr::NonNull};
 
 use crate::core::*;
 use crate::sys;
 
+#[derive(Clone, Copy)]
+struct RunParam {
+    ptr: *mut c_void,
+    type_id: Option<TypeId>,
+    borrowed: bool,
+}
+
+thread_local! {
+    // param of the system that is run with `System::run_with` on this thread
+    static RUN_PARAM: Cell<RunParam> = const {
+        Cell::new(RunParam {
+            ptr: std::ptr::null_mut(),
+            type_id: None,
+            borrowed: false,
+        })
+    };
+}
+
+/// Calls `func` with `param` if it was passed to a system with [`System::run_with()`] as a `T`.
+pub(crate) fn with_run_param<T: 'static, R>(
+    param: *mut c_void,
+    func: impl FnOnce(&mut T) -> R,
+) -> R {
+    // releases the param, also when `func` panics
+    struct Release;
+
+    impl Drop for Release {
+        fn drop(&mut self) {
+            RUN_PARAM.with(|run_param| {
+                run_param.set(RunParam {
+                    borrowed: false,
+                    ..run_param.get()
+                });
+            });
+        }
+    }
+
+    let current = RUN_PARAM.with(Cell::get);
+    assert!(
+        current.ptr == param && current.type_id == Some(TypeId::of::<T>()),
+        "the param of the iterator wasn't passed with `System::run_with` as a `{}`",
+        std::any::type_name::<T>()
+    );
+    assert!(!current.borrowed, "the run param is already borrowed");
+
+    RUN_PARAM.with(|run_param| {
+        run_param.set(RunParam {
+            borrowed: true,
+            ..current
+        });
+    });
+    let _release = Release;
+
+    func(unsafe { &mut *(param as *mut T) })
+}
+
+/// Runtime statistics of a system, returned by [`System::stats()`].
+///
+/// The values accumulate over the lifetime of the system. Times are in seconds and are only
+/// measured while [`World::measure_system_time()`] is enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SystemRunStats {
+    /// Number of times the system ran, by the pipeline or manually.
+    pub invoke_count: u64,
+    /// Total time spent running the system.
+    pub time_spent: FTime,
+    /// Time spent in the last run of the system.
+    pub last_frame_time: FTime,
+}
+
+/// The run callback of a system, which is wrapped to count how often the system runs.
+struct RunCounter {
+    run: sys::ecs_run_action_t,
+    run_ctx: *mut c_void,
+    run_ctx_free: sys::ecs_ctx_free_t,
+    invoke_count: Cell<u64>,
+    /// The time spent by the system before its last run.
+    time_spent_before: Cell<FTime>,
+}
+
+impl RunCounter {
+    /// Wraps the run callback of `desc`, so runs of the system are counted.
+    fn wrap(desc: &mut sys::ecs_system_desc_t) {
+        let counter = Box::new(RunCounter {
+            run: desc.run,
+            run_ctx: desc.run_ctx,
+            run_ctx_free: desc.run_ctx_free,
+            invoke_count: Cell::new(0),
+            time_spent_before: Cell::new(0.0),
+        });
+        desc.run = Some(run_counted);
+        desc.run_ctx = Box::into_raw(counter) as *mut c_void;
+        desc.run_ctx_free = Some(free_run_counter);
+    }
+
+    /// Returns the counter of `system`, if its runs are counted.
+    fn get(system: &sys::ecs_system_t) -> Option<&RunCounter> {
+        // `free_run_counter` isn't generic or inlined, so it has a single definition and address
+        #[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+        let is_counted = system.run_ctx_free == Some(free_run_counter);
+        is_counted.then(|| unsafe { &*(system.run_ctx as *const RunCounter) })
+    }
+}
+
+unsafe extern "C-unwind" fn run_counted(it: *mut sys::ecs_iter_t) {
+    let it = &mut *it;
+    let counter = &*(it.run_ctx as *const RunCounter);
+    let system = &*sys::ecs_system_get(it.real_world, it.system);
+
+    // worker threads run the same invocation of the system
+    if sys::ecs_stage_get_id(it.world) == 0 {
+        counter.invoke_count.set(counter.invoke_count.get() + 1);
+        counter.time_spent_before.set(system.time_spent);
+    }
+
+    it.run_ctx = counter.run_ctx;
+    if let Some(run) = counter.run {
+        run(it);
+    } else if let Some(callback) = it.callback {
+        // same as flecs does for systems without a run callback, the iterator of a system
+        // without terms is cleaned up by flecs
+        if (*system.query).term_count == 0 {
+            callback(it);
+        } else {
+            while sys::ecs_iter_next(it) {
+                callback(it);
+            }
+        }
+    } else {
+        sys::ecs_iter_fini(it);
+    }
+}
+
+unsafe extern "C-unwind" fn free_run_counter(ptr: *mut c_void) {
+    let counter = Box::from_raw(ptr as *mut RunCounter);
+    if let Some(run_ctx_free) = counter.run_ctx_free {
+        run_ctx_free(counter.run_ctx);
+    }
+}
+
 /// Systems are a query + function that can be ran manually or by a pipeline.
 #[derive(Clone, Copy)]
 pub struct System<'a> {
@@ -44,6 +186,19 @@ impl<'a> WorldProvider<'a> for System<'a> {
     }
 }
 
+impl std::fmt::Display for System<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.entity, f)
+    }
+}
+
+impl<'a> From<System<'a>> for EntityView<'a> {
+    #[inline]
+    fn from(system: System<'a>) -> Self {
+        system.entity
+    }
+}
+
 impl<'a> System<'a> {
     //todo!() in query etc desc is a pointer, does it need to be?
     /// Create a new system
@@ -53,12 +208,24 @@ impl<'a> System<'a> {
     /// * `world` - The world to create the system in.
     /// * `desc` - The system description.
     ///
+    /// # Panics
+    ///
+    /// Panics with the error flecs reports when the query of the system can't be created.
+    /// Use [`QueryBuilderImpl::validate()`] to check the query of a system builder beforehand.
+    ///
     /// # See also
     ///
     /// * C++ API: `system::system`
     #[doc(alias = "system::system")]
     pub fn new(world: impl WorldProvider<'a>, desc: sys::ecs_system_desc_t) -> Self {
-        let id = unsafe { sys::ecs_system_init(world.world_ptr_mut(), &desc) };
+        let world_ptr = world.world_ptr_mut();
+        let (id, error) = log::capture_error(|| unsafe { sys::ecs_system_init(world_ptr, &desc) });
+        if id == 0 {
+            panic!(
+                "failed to create system: {}",
+                error.unwrap_or_else(|| "unknown error".to_string())
+            );
+        }
         let entity = EntityView::new_from(world.world(), id);
 
         Self { entity }
@@ -81,6 +248,16 @@ impl<'a> System<'a> {
         }
     }
 
+    /// Get the system's entity
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `system::entity`
+    #[doc(alias = "system::entity")]
+    pub fn entity(&self) -> EntityView<'a> {
+        self.entity
+    }
+
     /// Set the context for the system
     ///
     /// # Arguments
@@ -113,6 +290,47 @@ impl<'a> System<'a> {
         unsafe { (*sys::ecs_system_get(self.world.world_ptr(), *self.id())).ctx }
     }
 
+    /// Get the runtime statistics of the system.
+    ///
+    /// Unlike [`World::system_stats()`](crate::core::World::system_stats), this doesn't
+    /// require the stats addon. The invocation count is only tracked for systems built with
+    /// [`SystemBuilder::count_invocations()`], and is 0 for other systems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// world.measure_system_time(true);
+    ///
+    /// let system = world.system::<()>().count_invocations().run(|_| {});
+    ///
+    /// world.progress();
+    /// world.progress();
+    ///
+    /// let stats = system.stats();
+    /// assert_eq!(stats.invoke_count, 2);
+    /// assert!(stats.time_spent >= stats.last_frame_time);
+    /// ```
+    pub fn stats(&self) -> SystemRunStats {
+        let system = unsafe { sys::ecs_system_get(self.world.world_ptr(), *self.id()) };
+        assert!(!system.is_null(), "{} is not a system", self.entity);
+        let system = unsafe { &*system };
+
+        let mut stats = SystemRunStats {
+            time_spent: system.time_spent,
+            ..Default::default()
+        };
+        if let Some(counter) = RunCounter::get(system) {
+            stats.invoke_count = counter.invoke_count.get();
+            if stats.invoke_count > 0 {
+                stats.last_frame_time = system.time_spent - counter.time_spent_before.get();
+            }
+        }
+        stats
+    }
+
     /// Get the underlying query for the system
     ///
     /// # See also
@@ -142,6 +360,80 @@ impl<'a> System<'a> {
         SystemRunnerFluent::new(self.world.real_world(), *self.id(), 0, 0, delta_time, param)
     }
 
+    /// Run the system, passing `param` to the callback. The callback accesses it with
+    /// [`TableIter::run_param()`].
+    ///
+    /// Systems that are run by the pipeline, for example with [`World::progress()`], don't get
+    /// a param.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_time` - The time delta.
+    /// * `param` - The value to pass to the system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity_named("e1").set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let system = world.system::<&Position>().run(|mut it| {
+    ///     while it.next() {
+    ///         for i in it.iter() {
+    ///             let name = it.entity(i).name();
+    ///             it.run_param(|names: &mut Vec<String>| names.push(name));
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// let mut names: Vec<String> = Vec::new();
+    /// system.run_with(0.0, &mut names);
+    /// assert_eq!(names, ["e1"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`System::run_dt_param()`]
+    /// * C++ API: `system::run`
+    #[doc(alias = "system::run")]
+    pub fn run_with<T: 'static>(&self, delta_time: FTime, param: &mut T) {
+        // restores the param of an outer `run_with`, also when the system panics
+        struct Restore(RunParam);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                RUN_PARAM.with(|run_param| run_param.set(self.0));
+            }
+        }
+
+        let param = param as *mut T as *mut c_void;
+        let _restore = Restore(RUN_PARAM.with(|run_param| {
+            run_param.replace(RunParam {
+                ptr: param,
+                type_id: Some(TypeId::of::<T>()),
+                borrowed: false,
+            })
+        }));
+
+        unsafe {
+            sys::ecs_run(
+                self.world.real_world().world_ptr_mut(),
+                *self.id(),
+                delta_time,
+                param,
+            );
+        }
+    }
+
     /// Run the system
     ///
     /// # Arguments
@@ -198,3 +490,30 @@ impl<'a> System<'a> {
         )
     }
 }
+
+impl<'a> EntityView<'a> {
+    /// Returns the entity as a system, or `None` if the entity is not a system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.system_named::<()>("Move").run(|_| {}).entity();
+    ///
+    /// let system = entity.try_as_system().unwrap();
+    /// system.run();
+    ///
+    /// assert!(world.entity().try_as_system().is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::try_as_observer()`]
+    pub fn try_as_system(self) -> Option<System<'a>> {
+        let system = unsafe { sys::ecs_system_get(self.world_ptr(), *self.id) };
+        (!system.is_null()).then(|| System::new_from_existing(self))
+    }
+}
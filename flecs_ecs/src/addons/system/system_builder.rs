@@ -12,6 +12,13 @@ where
 {
     pub(crate) desc: sys::ecs_system_desc_t,
     term_builder: TermBuilder,
+    /// The entity of the query shared with [`SystemBuilder::set_query()`], 0 if none.
+    shared_query: sys::ecs_entity_t,
+    /// Whether the runs of the system are counted for [`System::stats()`].
+    count_invocations: bool,
+    /// `(before, after)` ordering constraints with other systems.
+    #[cfg(feature = "flecs_pipeline")]
+    order: Vec<(Entity, Entity)>,
     world: WorldRef<'a>,
     _phantom: std::marker::PhantomData<&'a T>,
 }
@@ -25,6 +32,10 @@ where
         let mut obj = Self {
             desc: Default::default(),
             term_builder: TermBuilder::default(),
+            shared_query: 0,
+            count_invocations: false,
+            #[cfg(feature = "flecs_pipeline")]
+            order: Vec::new(),
             world: world.into(),
             _phantom: std::marker::PhantomData,
         };
@@ -50,6 +61,10 @@ where
         let mut obj = Self {
             desc,
             term_builder: TermBuilder::default(),
+            shared_query: 0,
+            count_invocations: false,
+            #[cfg(feature = "flecs_pipeline")]
+            order: Vec::new(),
             world: world.into(),
             _phantom: std::marker::PhantomData,
         };
@@ -76,11 +91,15 @@ where
 
     /// Create a new system builder with a name
     pub(crate) fn new_named(world: &'a World, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let mut obj = Self {
             desc: Default::default(),
             term_builder: TermBuilder::default(),
+            shared_query: 0,
+            count_invocations: false,
+            #[cfg(feature = "flecs_pipeline")]
+            order: Vec::new(),
             world: world.into(),
             _phantom: std::marker::PhantomData,
         };
@@ -174,6 +193,222 @@ where
         self.kind_id(enum_id)
     }
 
+    /// Don't add the system to a phase, so it isn't ran by the pipeline.
+    ///
+    /// Systems are added to the `OnUpdate` phase by default. A system without a phase only
+    /// runs when it is ran manually, e.g. with [`System::run()`].
+    ///
+    /// This is the same as `kind_id(0)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let system = world
+    ///     .system::<()>()
+    ///     .no_auto_add_phase()
+    ///     .run(|_| {});
+    ///
+    /// assert!(!system.has_id((flecs::DependsOn::ID, flecs::pipeline::OnUpdate::ID)));
+    /// ```
+    pub fn no_auto_add_phase(&mut self) -> &mut Self {
+        self.kind_id(0)
+    }
+
+    /// Run the system before `system` in each frame.
+    ///
+    /// Systems of the same phase run in the order in which they were created, unless they're
+    /// constrained with [`Self::before()`] or [`Self::after()`]. Constraints between systems of
+    /// different phases have no effect, as phases always run in order.
+    ///
+    /// The first constraint of a world replaces the builtin pipeline with a pipeline that
+    /// sorts systems by their constraints. Constraints have no effect on systems in the
+    /// `OnStart` phase, and can't be added while the world runs a custom pipeline.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The system to run before.
+    ///
+    /// # Panics
+    ///
+    /// [`build()`](Builder::build) panics if `system` isn't a system, if the constraint
+    /// creates a cycle, or if the world runs a custom pipeline. The system isn't created in
+    /// that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let movement = world.system_named::<()>("Movement").run(|_| {
+    ///     println!("movement");
+    /// });
+    ///
+    /// world
+    ///     .system_named::<()>("Input")
+    ///     .before(movement)
+    ///     .run(|_| {
+    ///         println!("input");
+    ///     });
+    ///
+    /// world.progress();
+    ///
+    /// // Output:
+    /// //  input
+    /// //  movement
+    /// ```
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn before(&mut self, system: impl Into<Entity>) -> &mut Self {
+        self.order.push((Entity(self.desc.entity), system.into()));
+        self
+    }
+
+    /// Run the system after `system` in each frame.
+    ///
+    /// See [`Self::before()`] for how the systems are ordered.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The system to run after.
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn after(&mut self, system: impl Into<Entity>) -> &mut Self {
+        self.order.push((system.into(), Entity(self.desc.entity)));
+        self
+    }
+
+    /// Run the system before the system named `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the system to run before, looked up from the current scope.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no entity named `name`.
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn before_named(&mut self, name: &str) -> &mut Self {
+        let system = self.lookup_system(name);
+        self.before(system)
+    }
+
+    /// Run the system after the system named `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the system to run after, looked up from the current scope.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no entity named `name`.
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn after_named(&mut self, name: &str) -> &mut Self {
+        let system = self.lookup_system(name);
+        self.after(system)
+    }
+
+    #[cfg(feature = "flecs_pipeline")]
+    fn lookup_system(&self, name: &str) -> Entity {
+        match self.world().world().try_lookup_recursive(name) {
+            Some(system) => system.id(),
+            None => panic!("no system named `{name}`"),
+        }
+    }
+
+    /// Add a component or tag to the system entity.
+    ///
+    /// Unlike [`with()`](QueryBuilderImpl::with), which adds a term to the query of the system,
+    /// this adds `T` to the system itself, e.g. to enable or disable a group of systems with
+    /// [`World::disable_systems_with()`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component, tag or pair to add.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct RenderSystem;
+    ///
+    /// let world = World::new();
+    ///
+    /// let system = world.system::<()>().add::<RenderSystem>().run(|_| {});
+    ///
+    /// assert!(system.has::<RenderSystem>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Self::add_id()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn add<C>(&mut self) -> &mut Self
+    where
+        C: ComponentOrPairId,
+    {
+        self.system_entity().add::<C>();
+        self
+    }
+
+    /// Add an id to the system entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id to add.
+    ///
+    /// # See also
+    ///
+    /// * [`Self::add()`]
+    /// * C++ API: `entity_builder::add`
+    #[doc(alias = "entity_builder::add")]
+    pub fn add_id(&mut self, id: impl IntoId) -> &mut Self {
+        self.system_entity().add_id(id);
+        self
+    }
+
+    /// Set a component of the system entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `component` - The component to set.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `entity_builder::set`
+    #[doc(alias = "entity_builder::set")]
+    pub fn set<C: ComponentId + DataComponent>(&mut self, component: C) -> &mut Self {
+        self.system_entity().set(component);
+        self
+    }
+
+    /// Make the system entity a child of `parent`.
+    ///
+    /// Systems don't run while their parent is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The parent entity.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `entity_builder::child_of`
+    #[doc(alias = "entity_builder::child_of")]
+    pub fn child_of_id(&mut self, parent: impl Into<Entity>) -> &mut Self {
+        self.system_entity().child_of_id(parent);
+        self
+    }
+
+    fn system_entity(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.desc.entity)
+    }
+
     /// Specify whether system can run on multiple threads.
     ///
     /// # Arguments
@@ -189,6 +424,15 @@ where
         self
     }
 
+    /// Count how often the system runs, see [`System::stats()`].
+    ///
+    /// Counting wraps the run callback of the system, which adds a little overhead to every
+    /// run, so it's disabled by default.
+    pub fn count_invocations(&mut self) -> &mut Self {
+        self.count_invocations = true;
+        self
+    }
+
     /// Specify whether system should be ran in staged context.
     ///
     /// # Arguments
@@ -203,6 +447,126 @@ where
         self.desc.immediate = value;
         self
     }
+
+    /// Iterate an existing query instead of creating a query for the system.
+    ///
+    /// The query is shared, not copied: systems built from the same query iterate the same
+    /// cache, so the tables are only matched once. The terms of the system are replaced by
+    /// the terms of the query, terms added to the builder afterwards are ignored.
+    ///
+    /// Only cached or named queries can be shared, as the system looks up the query by its
+    /// entity each time it runs. The system doesn't keep the query alive: when the query is
+    /// destructed before the system, the system no longer runs. Systems with a shared query
+    /// always run on a single thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The cached query to iterate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query isn't associated with an entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let query = world.query::<&mut Position>().set_cached().build();
+    ///
+    /// world
+    ///     .system::<&mut Position>()
+    ///     .set_query(&query)
+    ///     .each(|pos| pos.x += 1.0);
+    ///
+    /// world
+    ///     .system::<&mut Position>()
+    ///     .set_query(&query)
+    ///     .each(|pos| pos.y += 1.0);
+    ///
+    /// let e = world.entity().set(Position { x: 0.0, y: 0.0 });
+    ///
+    /// world.progress();
+    ///
+    /// e.get::<&Position>(|pos| {
+    ///     assert_eq!(pos.x, 1.0);
+    ///     assert_eq!(pos.y, 1.0);
+    /// });
+    /// ```
+    pub fn set_query(&mut self, query: &Query<T>) -> &mut Self {
+        let entity = *query.entity().id();
+        assert!(
+            entity != 0,
+            "only cached or named queries can be shared with a system"
+        );
+
+        // the system gets an empty query, which matches nothing
+        self.desc.query = Default::default();
+        self.shared_query = entity;
+        self
+    }
+}
+
+/// The callbacks of a system with a shared query, which are invoked with an iterator of the
+/// shared query instead of the iterator of the system.
+struct SharedQuery {
+    query: sys::ecs_entity_t,
+    run: sys::ecs_run_action_t,
+    run_ctx: *mut std::ffi::c_void,
+    run_ctx_free: sys::ecs_ctx_free_t,
+    callback: sys::ecs_iter_action_t,
+}
+
+unsafe extern "C-unwind" fn run_shared_query(it: *mut sys::ecs_iter_t) {
+    let it = &mut *it;
+    let shared = &*(it.run_ctx as *const SharedQuery);
+
+    let poly = sys::ecs_get_id(
+        it.world,
+        shared.query,
+        ecs_pair(flecs::Poly::ID, flecs::Query::ID),
+    ) as *const flecs::Poly;
+
+    // the query was destructed
+    if poly.is_null() {
+        return;
+    }
+
+    let query = (*poly).poly as *mut sys::ecs_query_t;
+    let mut qit = sys::ecs_query_iter(it.world, query);
+    qit.system = it.system;
+    qit.delta_time = it.delta_time;
+    qit.delta_system_time = it.delta_system_time;
+    qit.param = it.param;
+    qit.ctx = it.ctx;
+    qit.callback_ctx = it.callback_ctx;
+    qit.run_ctx = shared.run_ctx;
+    qit.callback = shared.callback;
+
+    if let Some(run) = shared.run {
+        run(&mut qit);
+    } else if let Some(callback) = shared.callback {
+        while sys::ecs_query_next(&mut qit) {
+            callback(&mut qit);
+        }
+    } else {
+        sys::ecs_iter_fini(&mut qit);
+    }
+}
+
+unsafe extern "C-unwind" fn free_shared_query(ptr: *mut std::ffi::c_void) {
+    let shared = Box::from_raw(ptr as *mut SharedQuery);
+    if let Some(run_ctx_free) = shared.run_ctx_free {
+        run_ctx_free(shared.run_ctx);
+    }
 }
 
 #[doc(hidden)]
@@ -249,15 +613,86 @@ where
     /// * C++ API: `node_builder::build`
     #[doc(alias = "node_builder::build")]
     fn build(&mut self) -> Self::BuiltType {
+        resolve_filter_terms(&mut self.desc.query.terms, T::COUNT as usize);
+
+        if self.shared_query != 0 {
+            let shared = Box::new(SharedQuery {
+                query: self.shared_query,
+                run: self.desc.run,
+                run_ctx: self.desc.run_ctx,
+                run_ctx_free: self.desc.run_ctx_free,
+                callback: self.desc.callback,
+            });
+            self.desc.run = Some(run_shared_query);
+            self.desc.run_ctx = Box::into_raw(shared) as *mut std::ffi::c_void;
+            self.desc.run_ctx_free = Some(free_shared_query);
+        }
+
+        if self.count_invocations {
+            RunCounter::wrap(&mut self.desc);
+        }
+
         let system = System::new(self.world(), self.desc);
         for s in self.term_builder.str_ptrs_to_free.iter_mut() {
             unsafe { std::mem::ManuallyDrop::drop(s) };
         }
         self.term_builder.str_ptrs_to_free.clear();
+
+        #[cfg(feature = "flecs_pipeline")]
+        if !self.order.is_empty() {
+            self.apply_order(&system);
+        }
+
         system
     }
 }
 
+impl<T> SystemBuilder<'_, T>
+where
+    T: QueryTuple,
+{
+    /// Adds the ordering constraints of the builder, deletes the system when they're invalid.
+    #[cfg(feature = "flecs_pipeline")]
+    fn apply_order(&self, system: &System) {
+        use crate::addons::pipeline::{add_system_order, SystemOrderError};
+
+        let world = self.world().world();
+
+        let other = self
+            .order
+            .iter()
+            .flat_map(|&(before, after)| [before, after])
+            .find(|&e| !world.entity_from_id(e).has::<flecs::system::System>());
+        if let Some(other) = other {
+            let other = world.entity_from_id(other).to_string();
+            system.destruct();
+            panic!("failed to create system: {other} is not a system");
+        }
+
+        match add_system_order(&world, &self.order) {
+            Ok(()) => {}
+            Err(SystemOrderError::Cycle(cycle)) => {
+                let cycle: Vec<String> = cycle
+                    .into_iter()
+                    .map(|e| world.entity_from_id(e).to_string())
+                    .collect();
+                system.destruct();
+                panic!(
+                    "failed to create system: ordering cycle between systems {}",
+                    cycle.join(" -> ")
+                );
+            }
+            Err(SystemOrderError::CustomPipeline(pipeline)) => {
+                let pipeline = world.entity_from_id(pipeline).to_string();
+                system.destruct();
+                panic!(
+                    "failed to create system: ordering constraints don't apply to the custom pipeline {pipeline}"
+                );
+            }
+        }
+    }
+}
+
 impl<'a, T: QueryTuple> WorldProvider<'a> for SystemBuilder<'a, T> {
     fn world(&self) -> WorldRef<'a> {
         self.world
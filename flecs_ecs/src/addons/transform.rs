@@ -0,0 +1,294 @@
+//! Transform hierarchy: transforms relative to the parent, propagated to transforms in world space.
+//!
+//! Import [`TransformModule`] and give entities a [`LocalTransform`]. Each frame, in the
+//! [`PostUpdate`](crate::core::flecs::pipeline::PostUpdate) phase, the module computes the
+//! [`WorldTransform`] of the entities from their local transform and the world transform
+//! of their `ChildOf` parent. Parents are computed before their children.
+//!
+//! Changes are detected per table: a table is skipped when neither its local transforms nor
+//! the world transforms of the parent table changed since the last frame. A table whose world
+//! transforms all stay the same doesn't mark its children as changed, so an unchanged subtree
+//! is skipped below the first level that is recomputed.
+//!
+//! # Example
+//!
+//! ```
+//! use flecs_ecs::addons::transform::*;
+//! use flecs_ecs::prelude::*;
+//!
+//! let world = World::new();
+//! world.import::<TransformModule>();
+//!
+//! let ship = world
+//!     .entity()
+//!     .set_local(Transform::from_translation([10.0, 0.0, 0.0]));
+//! let turret = world
+//!     .entity()
+//!     .child_of_id(ship)
+//!     .set_local(Transform::from_translation([0.0, 2.0, 0.0]));
+//!
+//! world.progress();
+//!
+//! assert_eq!(turret.world_position(), Some([10.0, 2.0, 0.0]));
+//! ```
+use flecs_ecs_derive::Component;
+
+use crate::prelude::*;
+
+/// Translation, rotation and scale.
+///
+/// The rotation is a unit quaternion stored as `[x, y, z, w]`. Points are scaled first, then
+/// rotated, then translated.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[flecs(no_collect)]
+pub struct Transform {
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    /// The transform that leaves points unchanged.
+    pub const IDENTITY: Self = Self {
+        translation: [0.0; 3],
+        rotation: [0.0, 0.0, 0.0, 1.0],
+        scale: [1.0; 3],
+    };
+
+    /// Create a transform that only translates.
+    pub fn from_translation(translation: [f32; 3]) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Create a transform that only rotates, by `angle` radians around the z axis.
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            rotation: [0.0, 0.0, sin, cos],
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Create a transform that only scales.
+    pub fn from_scale(scale: [f32; 3]) -> Self {
+        Self {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns the transform with the translation replaced.
+    pub fn with_translation(self, translation: [f32; 3]) -> Self {
+        Self {
+            translation,
+            ..self
+        }
+    }
+
+    /// Returns the transform with the rotation replaced.
+    pub fn with_rotation(self, rotation: [f32; 4]) -> Self {
+        Self { rotation, ..self }
+    }
+
+    /// Returns the transform with the scale replaced.
+    pub fn with_scale(self, scale: [f32; 3]) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Transform a point.
+    pub fn transform_point(&self, point: [f32; 3]) -> [f32; 3] {
+        let scaled = [
+            point[0] * self.scale[0],
+            point[1] * self.scale[1],
+            point[2] * self.scale[2],
+        ];
+        let rotated = quat_rotate(self.rotation, scaled);
+        [
+            rotated[0] + self.translation[0],
+            rotated[1] + self.translation[1],
+            rotated[2] + self.translation[2],
+        ]
+    }
+
+    /// Combine the transform with a transform that is relative to it, such as the local
+    /// transform of a child.
+    ///
+    /// Like most engines the scale is combined per axis, which is exact as long as a rotated
+    /// parent has a uniform scale.
+    pub fn mul_transform(&self, child: &Transform) -> Transform {
+        Transform {
+            translation: self.transform_point(child.translation),
+            rotation: quat_mul(self.rotation, child.rotation),
+            scale: [
+                self.scale[0] * child.scale[0],
+                self.scale[1] * child.scale[1],
+                self.scale[2] * child.scale[2],
+            ],
+        }
+    }
+}
+
+fn quat_mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+fn quat_rotate(q: [f32; 4], v: [f32; 3]) -> [f32; 3] {
+    // v + 2w(q x v) + 2(q x (q x v))
+    let [x, y, z, w] = q;
+    let t = [
+        2.0 * (y * v[2] - z * v[1]),
+        2.0 * (z * v[0] - x * v[2]),
+        2.0 * (x * v[1] - y * v[0]),
+    ];
+    [
+        v[0] + w * t[0] + (y * t[2] - z * t[1]),
+        v[1] + w * t[1] + (z * t[0] - x * t[2]),
+        v[2] + w * t[2] + (x * t[1] - y * t[0]),
+    ]
+}
+
+/// Transform of an entity relative to its `ChildOf` parent, or to the world for entities
+/// without a parent.
+///
+/// Adding it also adds a [`WorldTransform`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[flecs(no_collect)]
+pub struct LocalTransform(pub Transform);
+
+/// Transform of an entity in world space, computed by the [`TransformModule`] each frame.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[flecs(no_collect)]
+pub struct WorldTransform(pub Transform);
+
+/// Singleton with counters of the transform propagation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[flecs(no_collect)]
+pub struct TransformStats {
+    /// Number of world transforms that were computed since the module was imported.
+    pub recomputed: u64,
+}
+
+/// Module that propagates [`LocalTransform`] to [`WorldTransform`].
+///
+/// See the [module documentation](self).
+#[derive(Component)]
+#[flecs(no_collect)]
+pub struct TransformModule;
+
+impl Module for TransformModule {
+    fn module(world: &World) {
+        world.module::<TransformModule>("::flecs::transform");
+
+        world.component::<Transform>();
+        world.component::<WorldTransform>();
+        world
+            .component::<LocalTransform>()
+            .add_trait::<(flecs::With, WorldTransform)>();
+        world.set(TransformStats::default());
+
+        world
+            .system_named::<(
+                &LocalTransform,
+                Option<&WorldTransform>,
+                &mut WorldTransform,
+                &mut TransformStats,
+            )>("Propagate")
+            .kind::<flecs::pipeline::PostUpdate>()
+            .term_at(1)
+            .parent()
+            .cascade()
+            // written fields don't count as changes of the table
+            .term_at(2)
+            .set_inout_kind(InOutKind::Out)
+            .term_at(3)
+            .singleton()
+            .set_inout_kind(InOutKind::Out)
+            .run(propagate);
+    }
+}
+
+fn propagate(mut it: TableIter<true>) {
+    let mut recomputed = 0;
+
+    while it.next() {
+        if !it.is_changed() {
+            it.skip();
+            continue;
+        }
+
+        let local = it.field::<LocalTransform>(0).unwrap();
+        let parent = it.field::<WorldTransform>(1);
+        let mut world_transform = it.field::<WorldTransform>(2).unwrap();
+
+        let mut any_changed = false;
+        for i in it.iter() {
+            let transform = match &parent {
+                Some(parent) => parent[0].0.mul_transform(&local[i].0),
+                None => local[i].0,
+            };
+            if world_transform[i].0 != transform {
+                world_transform[i].0 = transform;
+                any_changed = true;
+            }
+            recomputed += 1;
+        }
+
+        it.field::<TransformStats>(3).unwrap()[0].recomputed += recomputed;
+        recomputed = 0;
+
+        // unchanged world transforms don't mark the children as changed
+        if !any_changed {
+            it.skip();
+        }
+    }
+}
+
+impl<'a> EntityView<'a> {
+    /// Set the transform of the entity relative to its parent.
+    ///
+    /// The world transform is updated by the [`TransformModule`] when the world progresses.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::world_transform()`]
+    pub fn set_local(self, transform: Transform) -> Self {
+        self.set(LocalTransform(transform))
+    }
+
+    /// Returns the transform of the entity in world space, as computed by the
+    /// [`TransformModule`] in the last frame.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::world_position()`]
+    pub fn world_transform(self) -> Option<Transform> {
+        self.try_get::<&WorldTransform>(|world_transform| world_transform.0)
+    }
+
+    /// Returns the translation of the entity in world space, as computed by the
+    /// [`TransformModule`] in the last frame.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::world_transform()`]
+    pub fn world_position(self) -> Option<[f32; 3]> {
+        self.world_transform()
+            .map(|transform| transform.translation)
+    }
+}
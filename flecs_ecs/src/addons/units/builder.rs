@@ -0,0 +1,179 @@
+//! Builder for custom units.
+
+use crate::core::*;
+use crate::sys;
+
+/// Builder for custom units, such as a unit derived from a builtin unit with a prefix, or a
+/// unit that is a unit over another unit.
+///
+/// Created with [`World::unit_builder()`] or [`World::unit_builder_named()`]. The builtin units
+/// can only be used after the [`Units`](super::Units) module is imported.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::addons::units::{duration, length, Kilo, Speed, Units};
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+/// world.import::<Units>();
+///
+/// let kilometers = world
+///     .unit_builder_named("KiloMeters")
+///     .prefix::<Kilo>()
+///     .base::<length::Meters>()
+///     .build();
+///
+/// let kilometers_per_second = world
+///     .unit_builder()
+///     .base_id(kilometers)
+///     .over::<duration::Seconds>()
+///     .quantity::<Speed>()
+///     .build();
+///
+/// kilometers.get::<&flecs::meta::Unit>(|unit| {
+///     let symbol = unsafe { std::ffi::CStr::from_ptr(unit.symbol) };
+///     assert_eq!(symbol.to_str(), Ok("km"));
+/// });
+///
+/// kilometers_per_second.get::<&flecs::meta::Unit>(|unit| {
+///     let symbol = unsafe { std::ffi::CStr::from_ptr(unit.symbol) };
+///     assert_eq!(symbol.to_str(), Ok("km/s"));
+/// });
+/// ```
+pub struct UnitBuilder<'a> {
+    desc: sys::ecs_unit_desc_t,
+    symbol: Option<SmallCStr>,
+    world: WorldRef<'a>,
+}
+
+impl<'a> UnitBuilder<'a> {
+    pub(crate) fn new(world: impl WorldProvider<'a>, entity: impl Into<Entity>) -> Self {
+        Self {
+            desc: sys::ecs_unit_desc_t {
+                entity: *entity.into(),
+                symbol: std::ptr::null(),
+                quantity: 0,
+                base: 0,
+                over: 0,
+                translation: sys::ecs_unit_translation_t {
+                    factor: 0,
+                    power: 0,
+                },
+                prefix: 0,
+            },
+            symbol: None,
+            world: world.world(),
+        }
+    }
+
+    /// Set the symbol of the unit, e.g. `"m"` or `"%"`.
+    ///
+    /// When not set, the symbol is derived from the prefix, base and over units.
+    pub fn symbol(&mut self, symbol: &str) -> &mut Self {
+        self.symbol = Some(SmallCStr::from_arg(symbol));
+        self
+    }
+
+    /// Set the quantity of the unit, e.g. length or duration.
+    pub fn quantity_id(&mut self, quantity: impl Into<Entity>) -> &mut Self {
+        self.desc.quantity = *quantity.into();
+        self
+    }
+
+    /// Set the quantity of the unit, e.g. length or duration.
+    pub fn quantity<T: ComponentId>(&mut self) -> &mut Self {
+        self.quantity_id(T::get_id(self.world))
+    }
+
+    /// Set the unit the unit is derived from, e.g. meters for kilometers.
+    pub fn base_id(&mut self, base: impl Into<Entity>) -> &mut Self {
+        self.desc.base = *base.into();
+        self
+    }
+
+    /// Set the unit the unit is derived from, e.g. meters for kilometers.
+    pub fn base<T: ComponentId>(&mut self) -> &mut Self {
+        self.base_id(T::get_id(self.world))
+    }
+
+    /// Set the unit the base unit is divided by, e.g. seconds for meters per second.
+    pub fn over_id(&mut self, over: impl Into<Entity>) -> &mut Self {
+        self.desc.over = *over.into();
+        self
+    }
+
+    /// Set the unit the base unit is divided by, e.g. seconds for meters per second.
+    pub fn over<T: ComponentId>(&mut self) -> &mut Self {
+        self.over_id(T::get_id(self.world))
+    }
+
+    /// Set the prefix of the unit, e.g. kilo for kilometers.
+    ///
+    /// The prefix sets the translation of the unit when it's not set.
+    pub fn prefix_id(&mut self, prefix: impl Into<Entity>) -> &mut Self {
+        self.desc.prefix = *prefix.into();
+        self
+    }
+
+    /// Set the prefix of the unit, e.g. kilo for kilometers.
+    ///
+    /// The prefix sets the translation of the unit when it's not set.
+    pub fn prefix<T: ComponentId>(&mut self) -> &mut Self {
+        self.prefix_id(T::get_id(self.world))
+    }
+
+    /// Set the translation from the base unit to the unit, which is `factor ^ power`.
+    pub fn translation(&mut self, factor: i32, power: i32) -> &mut Self {
+        self.desc.translation = sys::ecs_unit_translation_t { factor, power };
+        self
+    }
+}
+
+impl<'a> Builder<'a> for UnitBuilder<'a> {
+    type BuiltType = EntityView<'a>;
+
+    /// Create the unit.
+    fn build(&mut self) -> Self::BuiltType {
+        if let Some(symbol) = &self.symbol {
+            self.desc.symbol = symbol.as_ptr();
+        }
+
+        let id = unsafe { sys::ecs_unit_init(self.world.world_ptr_mut(), &self.desc) };
+        ecs_assert!(id != 0, FlecsErrorCode::InvalidParameter, "invalid unit");
+
+        EntityView::new_from(self.world, id)
+    }
+}
+
+impl<'a> WorldProvider<'a> for UnitBuilder<'a> {
+    fn world(&self) -> WorldRef<'a> {
+        self.world
+    }
+}
+
+impl World {
+    /// Create a builder for a custom unit.
+    ///
+    /// # See also
+    ///
+    /// * [`World::unit_builder_named()`]
+    /// * [`EntityView::unit()`]
+    pub fn unit_builder(&self) -> UnitBuilder<'_> {
+        UnitBuilder::new(self, 0)
+    }
+
+    /// Create a builder for a custom unit with a name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the unit entity.
+    ///
+    /// # See also
+    ///
+    /// * [`World::unit_builder()`]
+    /// * [`EntityView::unit()`]
+    pub fn unit_builder_named(&self, name: &str) -> UnitBuilder<'_> {
+        UnitBuilder::new(self, self.entity_named(name))
+    }
+}
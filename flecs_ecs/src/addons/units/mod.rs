@@ -1,4 +1,6 @@
+mod builder;
 mod types;
+pub use builder::UnitBuilder;
 pub use types::*;
 
 use super::module::Module;
@@ -8,6 +10,11 @@ use flecs_ecs_derive::Component;
 #[derive(Clone, Copy, Component, Default)]
 pub struct UnitsModule;
 
+/// The units module, same as [`UnitsModule`].
+///
+/// Import it with `world.import::<Units>()` to make sure the builtin units are registered.
+pub type Units = UnitsModule;
+
 impl Module for UnitsModule {
     fn module(world: &World) {
         world.module::<UnitsModule>("::flecs::units");
@@ -1,7 +1,6 @@
 //! An [`Archetype`] type can be used to describe what types of components an entity has.
 
 use std::{
-    ffi::CStr,
     fmt::{Debug, Display},
     ptr::NonNull,
 };
@@ -80,19 +79,14 @@ impl<'a> Archetype<'a> {
     pub fn to_string(&self) -> Option<String> {
         NonNull::new(unsafe {
             sys::ecs_type_str(
-                self.world.world_ptr_mut(),
+                self.world.world_ptr(),
                 &sys::ecs_type_t {
                     array: self.type_vec.as_ptr() as *mut _,
                     count: self.type_vec.len() as i32,
                 },
             )
         })
-        .map(|s| unsafe {
-            let len = CStr::from_ptr(s.as_ptr()).to_bytes().len();
-            // Convert the C string to a Rust String without any new heap allocation.
-            // The String will de-allocate the C string when it goes out of scope.
-            String::from_utf8_unchecked(Vec::from_raw_parts(s.as_ptr() as *mut u8, len, len))
-        })
+        .map(|s| unsafe { take_flecs_string(s.as_ptr()) })
     }
 
     /// Return the number of elements in the type.
@@ -105,6 +99,47 @@ impl<'a> Archetype<'a> {
         self.type_vec.len()
     }
 
+    /// Return the number of elements in the type.
+    pub fn len(&self) -> usize {
+        self.type_vec.len()
+    }
+
+    /// Return whether the type has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.type_vec.is_empty()
+    }
+
+    /// Test if the type contains an id. The id can be a wildcard, such as `(Likes, *)`,
+    /// which matches any id of the type it is a pattern for.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id or wildcard pattern to test.
+    pub fn contains_id(&self, id: impl IntoId) -> bool {
+        let pattern = *id.into();
+        self.type_vec
+            .iter()
+            .any(|type_id| unsafe { sys::ecs_id_match(**type_id, pattern) })
+    }
+
+    /// Test if the type contains a component or pair.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component or pair to test.
+    pub fn contains<T: ComponentOrPairId>(&self) -> bool {
+        self.contains_id(T::get_id(self.world))
+    }
+
+    /// Iterate the [ids](IdView) of the type, in the order in which they are stored.
+    pub fn iter(&self) -> ArchetypeIter<'a> {
+        ArchetypeIter {
+            world: self.world,
+            ids: self.type_vec.iter(),
+            _lock: None,
+        }
+    }
+
     /// Return a slice to the array of types.
     ///
     /// # Returns
@@ -142,7 +177,7 @@ impl<'a> Archetype<'a> {
     ///
     /// [`Some(IdView)`]: IdView
     #[doc(alias = "type::get")]
-    pub fn get(&self, index: usize) -> Option<IdView> {
+    pub fn get(&self, index: usize) -> Option<IdView<'a>> {
         if index < self.count() {
             Some(IdView::new_from_id(self.world, self.type_vec[index]))
         } else {
@@ -150,3 +185,51 @@ impl<'a> Archetype<'a> {
         }
     }
 }
+
+impl<'a> IntoIterator for Archetype<'a> {
+    type Item = IdView<'a>;
+    type IntoIter = ArchetypeIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArchetypeIter {
+            world: self.world,
+            ids: self.type_vec.iter(),
+            _lock: self.lock,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &Archetype<'a> {
+    type Item = IdView<'a>;
+    type IntoIter = ArchetypeIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the [ids](IdView) of an [`Archetype`].
+///
+/// When created from an owned archetype of a table, the table stays locked until the iterator
+/// is dropped.
+pub struct ArchetypeIter<'a> {
+    world: WorldRef<'a>,
+    ids: std::slice::Iter<'a, Id>,
+    _lock: Option<TableLock<'a>>,
+}
+
+impl<'a> Iterator for ArchetypeIter<'a> {
+    type Item = IdView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ids
+            .next()
+            .map(|id| IdView::new_from_id(self.world, *id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ArchetypeIter<'_> {}
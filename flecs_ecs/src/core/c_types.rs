@@ -192,6 +192,7 @@ impl From<OperKind> for i16 {
 /// - `All`: Require that all query terms can be cached
 /// - `None`: No caching
 #[allow(clippy::unnecessary_cast)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u32)]
 pub enum QueryCacheKind {
     Default = sys::ecs_query_cache_kind_t_EcsQueryCacheDefault as u32,
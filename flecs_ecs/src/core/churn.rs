@@ -0,0 +1,146 @@
+//! Counts how often entities move between tables, to find entities that cause archetype churn.
+
+use std::collections::HashMap;
+
+use crate::core::*;
+use crate::sys;
+
+struct ChurnRecord {
+    transitions: u32,
+    /// The table of the last event, to count a move that adds or removes multiple ids once.
+    table: *mut sys::ecs_table_t,
+    was_add: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct ChurnCounts {
+    entities: HashMap<sys::ecs_entity_t, ChurnRecord>,
+}
+
+impl ChurnCounts {
+    fn record(&mut self, entity: sys::ecs_entity_t, table: *mut sys::ecs_table_t, is_add: bool) {
+        let record = self.entities.entry(entity).or_insert(ChurnRecord {
+            transitions: 0,
+            table: std::ptr::null_mut(),
+            was_add: false,
+        });
+
+        // an `OnAdd` event has the table the entity moved to, an `OnRemove` event the table
+        // it moves from
+        if record.table != table || record.was_add != is_add {
+            record.transitions += 1;
+            record.table = table;
+            record.was_add = is_add;
+        }
+    }
+}
+
+impl World {
+    /// Create the observers that count the table moves of entities.
+    pub(crate) fn init_churn_debug(&self) {
+        // a wildcard doesn't match pairs, so pairs need their own observer
+        let wildcards = [
+            flecs::Wildcard::ID,
+            ecs_pair(flecs::Wildcard::ID, flecs::Wildcard::ID),
+        ];
+
+        for wildcard in wildcards {
+            self.observer::<flecs::OnAdd, ()>()
+                .add_event::<flecs::OnRemove>()
+                .with_id(wildcard)
+                .each_iter(|mut it, row, ()| {
+                    let is_add = it.iter_mut().event == flecs::OnAdd::ID;
+                    let table = it.iter_mut().table;
+                    let entity = *it.entity(row).id();
+                    let churn =
+                        unsafe { &mut (*World::get_context(it.world().world_ptr_mut())).churn };
+                    churn.record(entity, table, is_add);
+                });
+        }
+    }
+
+    /// Returns the entities that moved between tables the most, with the number of moves.
+    ///
+    /// A move is counted each time components or tags are added to or removed from an entity,
+    /// adding or removing multiple ids at once is counted once. Entities that are no longer
+    /// alive are not returned.
+    ///
+    /// Requires the `churn_debug` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of entities to return.
+    ///
+    /// # Returns
+    ///
+    /// The entities and their number of moves, the entity with the most moves first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Stunned;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    /// for _ in 0..10 {
+    ///     e.add::<Stunned>();
+    ///     e.remove::<Stunned>();
+    /// }
+    ///
+    /// let top = world.top_churning_entities(1);
+    /// assert_eq!(top[0].0, e);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::transition_count()`]
+    /// * [`World::reset_transition_counts()`]
+    pub fn top_churning_entities(&self, n: usize) -> Vec<(EntityView<'_>, u32)> {
+        let mut top = self
+            .world_ctx()
+            .churn
+            .entities
+            .iter()
+            .map(|(entity, record)| (EntityView::new_from(self, *entity), record.transitions))
+            .filter(|(entity, _)| entity.is_alive())
+            .collect::<Vec<_>>();
+
+        top.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.id().cmp(&b.0.id())));
+        top.truncate(n);
+        top
+    }
+
+    /// Reset the number of table moves of all entities to zero.
+    ///
+    /// Requires the `churn_debug` feature.
+    ///
+    /// # See also
+    ///
+    /// * [`World::top_churning_entities()`]
+    /// * [`EntityView::transition_count()`]
+    pub fn reset_transition_counts(&self) {
+        self.world_ctx_mut().churn.entities.clear();
+    }
+}
+
+impl EntityView<'_> {
+    /// Returns the number of times the entity moved between tables.
+    ///
+    /// Requires the `churn_debug` feature.
+    ///
+    /// # See also
+    ///
+    /// * [`World::top_churning_entities()`]
+    /// * [`World::reset_transition_counts()`]
+    pub fn transition_count(self) -> u32 {
+        let churn = unsafe { &(*World::get_context(self.world().world_ptr_mut())).churn };
+        churn
+            .entities
+            .get(&*self.id())
+            .map_or(0, |record| record.transitions)
+    }
+}
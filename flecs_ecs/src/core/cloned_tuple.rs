@@ -145,6 +145,7 @@ where
     fn populate_array_ptrs<'a, const SHOULD_PANIC: bool>(
         world: impl WorldProvider<'a>, entity: Entity, record: *const ecs_record_t, components: &mut [*mut c_void]
     ) -> bool {
+        let world = world.world();
         let world_ptr = unsafe { sys::ecs_get_world(world.world_ptr() as *const c_void) as *mut sys::ecs_world_t };
         let table = unsafe { (*record).table };
         let entity = *entity;
@@ -155,19 +156,10 @@ where
             if component_ptr.is_null() {
                 components[0] = std::ptr::null_mut();
                 has_all_components = false;
-                if SHOULD_PANIC && !A::IS_OPTION {
-                    ecs_assert!(false, FlecsErrorCode::OperationFailed,
-                        "Component `{}` not found on `EntityView::cloned` operation
-                        with parameters: `{}`.
-                        Use `try_cloned` variant to avoid assert/panicking if you want to handle the error
-                        or use `Option<{}> instead to handle individual cases.",
-                        std::any::type_name::<A::OnlyType>(), std::any::type_name::<Self>(), std::any::type_name::<A::ActualType>());
-                    panic!("Component `{}` not found on `EntityView::cloned` operation
-                    with parameters: `{}`.
-                    Use `try_cloned` variant to avoid assert/panicking if
-                    you want to handle the error or use `Option<{}>
-                    instead to handle individual cases.",
-                    std::any::type_name::<A::OnlyType>(), std::any::type_name::<Self>(), std::any::type_name::<A::ActualType>());
+                if !A::IS_OPTION {
+                    component_miss::<SHOULD_PANIC>(world, entity.into(), "cloned",
+                        std::any::type_name::<A::OnlyType>(), std::any::type_name::<Self>(),
+                        std::any::type_name::<A::ActualType>());
                 }
             } else {
                 components[0] = component_ptr;
@@ -255,20 +247,9 @@ macro_rules! impl_cloned_tuple {
                     } else {
                         components[index] = std::ptr::null_mut();
                         if !$t::IS_OPTION {
-                            if SHOULD_PANIC {
-                                ecs_assert!(false, FlecsErrorCode::OperationFailed,
-                                    "Component `{}` not found on `EntityView::cloned` operation 
-                                    with parameters: `{}`. 
-                                    Use `try_cloned` variant to avoid assert/panicking if you want to handle 
-                                    the error or use `Option<{}> instead to handle individual cases.",
-                                    std::any::type_name::<$t::OnlyType>(), std::any::type_name::<Self>(),
-                                    std::any::type_name::<$t::ActualType>());
-                                panic!("Component `{}` not found on `EntityView::cloned`operation 
-                                with parameters: `{}`. 
-                                Use `try_cloned` variant to avoid assert/panicking if you want to handle the error 
-                                or use `Option<{}> instead to handle individual cases.", std::any::type_name::<$t::OnlyType>(),
-                                std::any::type_name::<Self>(), std::any::type_name::<$t::ActualType>());
-                            }
+                            component_miss::<SHOULD_PANIC>(world_ref, entity.into(), "cloned",
+                                std::any::type_name::<$t::OnlyType>(), std::any::type_name::<Self>(),
+                                std::any::type_name::<$t::ActualType>());
                             has_all_components = false;
                         }
                     }
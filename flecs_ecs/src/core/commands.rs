@@ -0,0 +1,225 @@
+//! A command buffer that records structural changes to apply them at a later point.
+
+use crate::core::*;
+
+enum Command<'a> {
+    Add(Entity, Id),
+    Remove(Entity, Id),
+    Clear(Entity),
+    Destruct(Entity),
+    Custom(Entity, Box<dyn FnOnce(EntityView<'a>) + 'a>),
+}
+
+/// Records structural changes, such as adding components or deleting entities, and applies
+/// them in the recorded order once [`Commands::apply()`] is called.
+///
+/// Structural changes are not allowed on tables that are being iterated, which is why
+/// [`QueryAPI::each_entity_cmd()`] passes a `Commands` buffer to its callback. The buffer is
+/// applied after the iteration completes.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Health {
+///     value: i32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Dead;
+///
+/// let world = World::new();
+///
+/// let e1 = world.entity().set(Health { value: 0 });
+/// let e2 = world.entity().set(Health { value: 10 });
+///
+/// world
+///     .new_query::<&Health>()
+///     .each_entity_cmd(|cmd, e, health| {
+///         if health.value <= 0 {
+///             cmd.remove::<Health>(e).add::<Dead>(e);
+///         }
+///     });
+///
+/// assert!(e1.has::<Dead>());
+/// assert!(!e1.has::<Health>());
+/// assert!(!e2.has::<Dead>());
+/// ```
+///
+/// # See also
+///
+/// * [`QueryAPI::each_entity_cmd()`]
+/// * [`World::defer()`]
+pub struct Commands<'a> {
+    world: WorldRef<'a>,
+    commands: Vec<Command<'a>>,
+}
+
+impl<'a> Commands<'a> {
+    /// Create a new, empty command buffer for the world.
+    pub fn new(world: impl WorldProvider<'a>) -> Self {
+        Self {
+            world: world.world(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Record adding an id to an entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to add the id to.
+    /// * `id` - The id to add.
+    pub fn add_id(&mut self, entity: impl Into<Entity>, id: impl IntoId) -> &mut Self {
+        self.commands.push(Command::Add(entity.into(), id.into()));
+        self
+    }
+
+    /// Record adding a component, tag or pair to an entity.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component, tag or pair to add.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to add the component to.
+    pub fn add<T: ComponentOrPairId>(&mut self, entity: impl Into<Entity>) -> &mut Self {
+        let id = T::get_id(self.world);
+        self.add_id(entity, id)
+    }
+
+    /// Record removing an id from an entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to remove the id from.
+    /// * `id` - The id to remove.
+    pub fn remove_id(&mut self, entity: impl Into<Entity>, id: impl IntoId) -> &mut Self {
+        self.commands
+            .push(Command::Remove(entity.into(), id.into()));
+        self
+    }
+
+    /// Record removing a component, tag or pair from an entity.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component, tag or pair to remove.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to remove the component from.
+    pub fn remove<T: ComponentOrPairId>(&mut self, entity: impl Into<Entity>) -> &mut Self {
+        let world = self.world;
+        if T::IS_ENUM {
+            self.remove_id(entity, (T::get_id(world), ECS_WILDCARD))
+        } else {
+            self.remove_id(entity, T::get_id(world))
+        }
+    }
+
+    /// Record setting a component value on an entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to set the component on.
+    /// * `component` - The component value.
+    pub fn set<T: ComponentId + DataComponent + 'a>(
+        &mut self,
+        entity: impl Into<Entity>,
+        component: T,
+    ) -> &mut Self {
+        self.commands.push(Command::Custom(
+            entity.into(),
+            Box::new(move |entity| {
+                entity.set(component);
+            }),
+        ));
+        self
+    }
+
+    /// Record removing all components from an entity, without deleting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to clear.
+    pub fn clear(&mut self, entity: impl Into<Entity>) -> &mut Self {
+        self.commands.push(Command::Clear(entity.into()));
+        self
+    }
+
+    /// Record deleting an entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to delete.
+    pub fn destruct(&mut self, entity: impl Into<Entity>) -> &mut Self {
+        self.commands.push(Command::Destruct(entity.into()));
+        self
+    }
+
+    /// Record a function that is invoked with the entity when the commands are applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to pass to the function.
+    /// * `func` - The function to invoke.
+    pub fn run(
+        &mut self,
+        entity: impl Into<Entity>,
+        func: impl FnOnce(EntityView<'a>) + 'a,
+    ) -> &mut Self {
+        self.commands
+            .push(Command::Custom(entity.into(), Box::new(func)));
+        self
+    }
+
+    /// Returns the number of recorded commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns true if no commands are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Apply the recorded commands in the order they were recorded.
+    ///
+    /// Commands on entities that are no longer alive are skipped.
+    pub fn apply(self) {
+        let world = self.world;
+        for command in self.commands {
+            match command {
+                Command::Add(entity, id) => {
+                    if world.is_alive(entity) {
+                        EntityView::new_from(world, entity).add_id(id);
+                    }
+                }
+                Command::Remove(entity, id) => {
+                    if world.is_alive(entity) {
+                        EntityView::new_from(world, entity).remove_id(id);
+                    }
+                }
+                Command::Clear(entity) => {
+                    if world.is_alive(entity) {
+                        EntityView::new_from(world, entity).clear();
+                    }
+                }
+                Command::Destruct(entity) => {
+                    if world.is_alive(entity) {
+                        EntityView::new_from(world, entity).destruct();
+                    }
+                }
+                Command::Custom(entity, func) => {
+                    if world.is_alive(entity) {
+                        func(EntityView::new_from(world, entity));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Handling of components that are missing when they are accessed with `get` and `cloned`.
+
+use std::cell::Cell;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::core::*;
+
+pub(crate) type ComponentMissHandler = Box<dyn FnMut(EntityView<'_>, &str) -> bool + Send + Sync>;
+
+#[derive(Default)]
+pub(crate) struct ComponentAccess {
+    // locked, since components can be missed on worker threads while the access is changed
+    config: Mutex<AccessConfig>,
+}
+
+struct AccessConfig {
+    strict: bool,
+    // locked while it runs, since components can be missed on worker threads
+    handler: Option<Arc<Mutex<ComponentMissHandler>>>,
+}
+
+thread_local! {
+    // whether this thread is running the miss handler
+    static IN_MISS_HANDLER: Cell<bool> = const { Cell::new(false) };
+}
+
+impl Default for AccessConfig {
+    fn default() -> Self {
+        Self {
+            strict: true,
+            handler: None,
+        }
+    }
+}
+
+impl ComponentAccess {
+    fn config(&self) -> MutexGuard<'_, AccessConfig> {
+        self.config.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl World {
+    /// Set whether accessing a missing component panics right away.
+    ///
+    /// The world is strict by default: [`EntityViewGet::get()`] and [`EntityView::cloned()`]
+    /// panic when the entity doesn't have a component that isn't marked `Option`, and the
+    /// `try_` variants return `None`.
+    ///
+    /// When the world is lenient, every missing component, also those of the `try_` variants,
+    /// is passed to the handler registered with [`World::on_component_miss()`] first. The
+    /// handler decides whether the access panics. Without a handler, missing components are
+    /// handled the same as in strict mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - True to panic right away, false to invoke the miss handler.
+    ///
+    /// # See also
+    ///
+    /// * [`World::is_strict_access()`]
+    /// * [`World::on_component_miss()`]
+    pub fn set_strict_access(&self, strict: bool) {
+        self.world_ctx().component_access.config().strict = strict;
+    }
+
+    /// Returns whether accessing a missing component panics right away.
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_strict_access()`]
+    pub fn is_strict_access(&self) -> bool {
+        self.world_ctx().component_access.config().strict
+    }
+
+    /// Register the function that handles missing components when the world is lenient.
+    ///
+    /// The function receives the entity and the type name of the missing component, and
+    /// returns whether the access should panic. When it returns false, the `try_` variants
+    /// return `None`. [`EntityViewGet::get()`] and [`EntityView::cloned()`] have no value to
+    /// return, so they panic regardless, after the handler had the chance to log the miss.
+    ///
+    /// Registering a function replaces the previous one. The function is not invoked while the
+    /// world is strict, see [`World::set_strict_access()`].
+    ///
+    /// Components can be missed by systems running on worker threads, so the function has to be
+    /// `Send` and `Sync`. It is invoked behind a lock, one miss at a time. A component missed by
+    /// the function itself is handled as if there was no function.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function to invoke, returns true to panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_strict_access(false);
+    /// world.on_component_miss(|entity, component| {
+    ///     println!("{} has no {}", entity.name(), component);
+    ///     false
+    /// });
+    ///
+    /// let e = world.entity_named("Bob");
+    ///
+    /// assert!(e.try_get::<&Position>(|pos| pos.x).is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_strict_access()`]
+    pub fn on_component_miss(
+        &self,
+        func: impl FnMut(EntityView<'_>, &str) -> bool + Send + Sync + 'static,
+    ) {
+        self.world_ctx().component_access.config().handler =
+            Some(Arc::new(Mutex::new(Box::new(func))));
+    }
+}
+
+/// Handle a component that is missing on a `get` or `cloned` operation.
+///
+/// Returns when the operation should continue without the component, which is only the case
+/// for the `try_` variants.
+#[cold]
+#[inline(never)]
+pub(crate) fn component_miss<const SHOULD_PANIC: bool>(
+    world: WorldRef,
+    entity: Entity,
+    operation: &'static str,
+    component: &'static str,
+    parameters: &'static str,
+    optional: &'static str,
+) {
+    // snapshot the configuration, the handler is invoked without holding the lock
+    let (strict, handler) = {
+        let access = unsafe { &(*World::get_context(world.world_ptr_mut())).component_access };
+        let config = access.config();
+        (config.strict, config.handler.clone())
+    };
+
+    let should_panic = if strict {
+        SHOULD_PANIC
+    } else if let Some(handler) = handler.filter(|_| !IN_MISS_HANDLER.get()) {
+        // resets the flag, also when the handler panics
+        struct Leave;
+
+        impl Drop for Leave {
+            fn drop(&mut self) {
+                IN_MISS_HANDLER.set(false);
+            }
+        }
+
+        // a miss inside the handler would lock the handler again
+        IN_MISS_HANDLER.set(true);
+        let _leave = Leave;
+        let mut handler = handler.lock().unwrap_or_else(PoisonError::into_inner);
+        handler(EntityView::new_from(world, entity), component) || SHOULD_PANIC
+    } else {
+        SHOULD_PANIC
+    };
+
+    if should_panic {
+        let name = EntityView::new_from(world, entity)
+            .path()
+            .unwrap_or_else(|| "<unnamed>".to_string());
+        panic!(
+            "Component `{}` not found on entity `{}` ({}) on `EntityView::{}` operation
+with parameters: `{}`.
+Use `try_{}` variant to avoid assert/panicking if you want to handle the error
+or use `Option<{}> instead to handle individual cases.",
+            component, name, *entity, operation, parameters, operation, optional
+        );
+    }
+}
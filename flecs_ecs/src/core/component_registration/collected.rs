@@ -0,0 +1,88 @@
+//! Components collected at program start up with the `auto_register` feature, so they can be
+//! registered up front in a deterministic order.
+
+use std::sync::Mutex;
+
+use crate::core::*;
+
+#[doc(hidden)]
+pub use ctor::ctor;
+
+struct CollectedComponent {
+    name: &'static str,
+    register: fn(&World),
+}
+
+static COLLECTED: Mutex<Vec<CollectedComponent>> = Mutex::new(Vec::new());
+
+/// Add a component to the components registered by [`World::register_collected()`].
+///
+/// Invoked before `main` by the code `#[derive(Component)]` generates for non-generic types.
+#[doc(hidden)]
+pub fn collect_component<T: ComponentId>() {
+    fn register<T: ComponentId>(world: &World) {
+        world.component::<T>();
+    }
+
+    COLLECTED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .push(CollectedComponent {
+            name: std::any::type_name::<T>(),
+            register: register::<T>,
+        });
+}
+
+impl World {
+    /// Register all components that derive `Component`, sorted by their type name.
+    ///
+    /// Components are otherwise registered when they're first used, so their ids depend on
+    /// the order in which the application uses them. Registering them up front gives the
+    /// components the same ids in every world created by the same binary, as long as
+    /// `register_collected` is called before other components are registered.
+    ///
+    /// Generic components and components marked `#[flecs(no_collect)]` aren't collected, they
+    /// are registered when they're first used. The components of this crate aren't collected
+    /// either, as they're set up by their module or by the feature that uses them.
+    ///
+    /// Requires the `auto_register` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world_a = World::new();
+    /// world_a.register_collected();
+    ///
+    /// let world_b = World::new();
+    /// world_b.register_collected();
+    ///
+    /// assert!(Position::is_registered_with_world(&world_a));
+    /// assert_eq!(
+    ///     world_a.component::<Position>().id(),
+    ///     world_b.component::<Position>().id()
+    /// );
+    /// ```
+    pub fn register_collected(&self) {
+        let mut collected = COLLECTED
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|component| (component.name, component.register))
+            .collect::<Vec<_>>();
+
+        // the lock is released before registering, registration may invoke user code
+        collected.sort_by_key(|(name, _)| *name);
+
+        for (_, register) in collected {
+            register(self);
+        }
+    }
+}
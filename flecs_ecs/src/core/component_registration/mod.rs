@@ -1,10 +1,15 @@
 //! Contains types and traits that define what a component is and how it is registered.
 
+#[cfg(feature = "auto_register")]
+mod collected;
 mod helpers;
 mod registration;
 pub mod registration_traits;
 pub mod registration_types;
 
+#[cfg(feature = "auto_register")]
+#[doc(hidden)]
+pub use collected::*;
 pub(crate) use helpers::*;
 #[doc(hidden)]
 pub use registration::*;
@@ -1,5 +1,5 @@
 #![doc(hidden)]
-use std::ffi::c_char;
+use std::ffi::{c_char, CStr};
 use std::os::raw::c_void;
 
 use crate::core::*;
@@ -28,7 +28,7 @@ where
     };
 
     if T::IS_ENUM {
-        //TODO no full enum support yet with different types
+        // flecs serializes enum values as i32, see `register_enum_data`
         let underlying_enum_type_id = world.component_id::<i32>();
         register_enum_data::<T>(world_ptr, id, *underlying_enum_type_id);
     }
@@ -63,7 +63,7 @@ pub(crate) fn try_register_component_named<'a, const COMPONENT_REGISTRATION: boo
 where
     T: ComponentId,
 {
-    let name = compact_str::format_compact!("{}\0", name);
+    let name = SmallCStr::<128>::from_arg(name);
     const NAMED: bool = true;
     internal_register_component::<NAMED, COMPONENT_REGISTRATION, T>(
         world,
@@ -83,23 +83,50 @@ pub(crate) fn register_enum_data<T>(
     unsafe { sys::ecs_cpp_enum_init(world, id, underlying_type_id) };
     let enum_array_ptr = T::UnderlyingEnumType::__enum_data_mut();
 
-    for (mut index, enum_item) in T::UnderlyingEnumType::iter().enumerate() {
+    let mut constants = vec![0; T::UnderlyingEnumType::SIZE_ENUM_FIELDS as usize];
+    for (index, enum_item) in T::UnderlyingEnumType::iter().enumerate() {
         let name = enum_item.name_cstr();
-        let entity_id: sys::ecs_entity_t = unsafe {
-            sys::ecs_cpp_enum_constant_register(
-                world,
-                id,
-                T::UnderlyingEnumType::id_variant_of_index_unchecked(enum_item.enum_index()),
-                name.as_ptr(),
-                &mut index as *mut usize as *mut c_void,
-                underlying_type_id, //hardcoded
-                4,                  //hardcoded
-            )
+        // every world creates its own constant entities, ids of another world could already be
+        // in use
+        let entity_id: sys::ecs_entity_t = match i32::try_from(enum_item.discriminant()) {
+            // the constant has the value the variant has in memory
+            Ok(mut value) => unsafe {
+                sys::ecs_cpp_enum_constant_register(
+                    world,
+                    id,
+                    0,
+                    name.as_ptr(),
+                    &mut value as *mut i32 as *mut c_void,
+                    underlying_type_id,
+                    std::mem::size_of::<i32>(),
+                )
+            },
+            // the variant still gets an entity so it can be added as a pair, but values of it
+            // can't be serialized
+            Err(_) => unsafe {
+                sys::ecs_log_(
+                    -3,
+                    std::ptr::null(),
+                    0,
+                    c"discriminant of enum constant '%s' is out of the range of an i32".as_ptr(),
+                    name.as_ptr(),
+                );
+                let desc = sys::ecs_entity_desc_t {
+                    parent: id,
+                    name: name.as_ptr(),
+                    ..Default::default()
+                };
+                sys::ecs_entity_init(world, &desc)
+            },
         };
+        constants[enum_item.enum_index()] = entity_id;
         if !T::UnderlyingEnumType::is_index_registered_as_entity(index) {
             unsafe { *enum_array_ptr.add(index) = entity_id };
         }
     }
+
+    let ctx = unsafe { &mut *World::get_context(world) };
+    ctx.enum_constants.insert(id, constants);
 }
 
 /// registers the component with the world.
@@ -198,6 +225,70 @@ pub(crate) fn external_register_component_data<const COMPONENT_REGISTRATION: boo
     id
 }
 
+/// Returns the name of `T` relative to the current scope, like `ecs_cpp_trim_module` does for C++
+/// types, so components registered inside a module become children of the module.
+///
+/// When the type lives in the namespace of the scope, e.g. `physics::Collider` registered in the
+/// `physics` module, the namespace is stripped. Otherwise only the name of the type is kept.
+/// Returns `None` when there is no scope.
+fn scoped_type_name<T>(world: *mut sys::ecs_world_t) -> Option<SmallCStr<128>> {
+    let scope = unsafe { sys::ecs_get_scope(world) };
+    if scope == 0 {
+        return None;
+    }
+
+    let path =
+        unsafe { sys::ecs_get_path_w_sep(world, 0, scope, c"::".as_ptr(), std::ptr::null()) };
+    let scope_path = unsafe { CStr::from_ptr(path) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { sys::ecs_os_api.free_.expect("os api is missing")(path as *mut c_void) };
+
+    let type_name = std::any::type_name::<T>();
+    // the namespace of the scope is the path of the module without its type name
+    let namespace = scope_path
+        .rsplit_once("::")
+        .map_or(scope_path.as_str(), |(namespace, _)| namespace);
+
+    let name = match type_name
+        .strip_prefix(namespace)
+        .and_then(|name| name.strip_prefix("::"))
+    {
+        Some(name) => name,
+        None => {
+            // don't split on the separators of generic arguments
+            let end = type_name.find('<').unwrap_or(type_name.len());
+            let start = type_name[..end].rfind("::").map_or(0, |pos| pos + 2);
+            &type_name[start..]
+        }
+    };
+
+    Some(SmallCStr::from_arg(name))
+}
+
+/// Returns the component with `name` in the current scope and its size and alignment.
+fn find_named_component(
+    world: *mut sys::ecs_world_t,
+    name: *const c_char,
+) -> Option<(sys::ecs_entity_t, *const sys::EcsComponent)> {
+    let entity = unsafe {
+        sys::ecs_lookup_path_w_sep(
+            world,
+            sys::ecs_get_scope(world),
+            name,
+            SEPARATOR.as_ptr(),
+            SEPARATOR.as_ptr(),
+            false,
+        )
+    };
+    if entity == 0 {
+        return None;
+    }
+
+    let component = unsafe { sys::ecs_get_id(world, entity, flecs::Component::ID) };
+    (!component.is_null()).then_some((entity, component as *const sys::EcsComponent))
+}
+
 /// registers the component with the world.
 pub(crate) fn register_componment_data_explicit<T, const ALLOCATE_TAG: bool>(
     world: *mut sys::ecs_world_t,
@@ -206,8 +297,8 @@ pub(crate) fn register_componment_data_explicit<T, const ALLOCATE_TAG: bool>(
 where
     T: ComponentId,
 {
-    let only_type_name = crate::core::get_only_type_name::<T>();
-    let only_type_name = compact_str::format_compact!("{}\0", only_type_name);
+    let only_type_name = T::CUSTOM_SYMBOL.unwrap_or(crate::core::get_only_type_name::<T>());
+    let only_type_name = SmallCStr::<128>::from_arg(only_type_name);
 
     // If no name was provided first check if a type with the provided
     // symbol was already registered.
@@ -226,15 +317,46 @@ where
     }
 
     let type_name = crate::core::type_name_cstring::<T>();
-    let type_name_ptr = type_name.as_ptr();
+    let symbol = T::CUSTOM_SYMBOL.map(SmallCStr::<128>::from_arg);
+    let symbol_ptr = symbol
+        .as_ref()
+        .map_or(type_name.as_ptr(), |symbol| symbol.as_ptr());
+
+    let scoped_name = if !name.is_null() {
+        None
+    } else if let Some(custom_name) = T::CUSTOM_NAME {
+        Some(SmallCStr::from_arg(custom_name))
+    } else {
+        scoped_type_name::<T>(world)
+    };
+    let name = match &scoped_name {
+        Some(scoped_name) => scoped_name.as_ptr(),
+        None if name.is_null() => type_name.as_ptr(),
+        None => name,
+    };
 
-    let name = if name.is_null() { type_name_ptr } else { name };
+    let type_info = create_type_info::<T, ALLOCATE_TAG>();
 
-    let entity_desc = create_entity_desc(name, type_name_ptr);
+    // another Rust type may already be registered with the same name
+    if let Some(existing) = find_named_component(world, name) {
+        let (size, alignment) = unsafe { ((*existing.1).size, (*existing.1).alignment) };
+        if size != type_info.size || alignment != type_info.alignment {
+            panic!(
+                "can't register `{}` (size {}, alignment {}) as `{}`, which is already registered for a component with size {}, alignment {}",
+                std::any::type_name::<T>(),
+                type_info.size,
+                type_info.alignment,
+                unsafe { CStr::from_ptr(name) }.to_string_lossy(),
+                size,
+                alignment
+            );
+        }
+        return existing.0;
+    }
 
-    let entity = unsafe { flecs_ecs_sys::ecs_entity_init(world, &entity_desc) };
+    let entity_desc = create_entity_desc(name, symbol_ptr);
 
-    let type_info = create_type_info::<T, ALLOCATE_TAG>();
+    let entity = unsafe { flecs_ecs_sys::ecs_entity_init(world, &entity_desc) };
 
     let component_desc = create_component_desc(entity, type_info);
 
@@ -254,7 +376,7 @@ pub(crate) fn external_register_componment_data_explicit<T>(
     name: *const c_char,
 ) -> sys::ecs_entity_t {
     let only_type_name = crate::core::get_only_type_name::<T>();
-    let only_type_name = compact_str::format_compact!("{}\0", only_type_name);
+    let only_type_name = SmallCStr::<128>::from_arg(only_type_name);
 
     // If no name was provided first check if a type with the provided
     // symbol was already registered.
@@ -275,7 +397,16 @@ pub(crate) fn external_register_componment_data_explicit<T>(
     let type_name = crate::core::type_name_cstring::<T>();
     let type_name_ptr = type_name.as_ptr();
 
-    let name = if name.is_null() { type_name_ptr } else { name };
+    let scoped_name = if name.is_null() {
+        scoped_type_name::<T>(world)
+    } else {
+        None
+    };
+    let name = match &scoped_name {
+        Some(scoped_name) => scoped_name.as_ptr(),
+        None if name.is_null() => type_name_ptr,
+        None => name,
+    };
 
     let entity_desc = create_entity_desc(name, type_name_ptr);
 
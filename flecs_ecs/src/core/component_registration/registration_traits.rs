@@ -113,15 +113,13 @@ pub trait OnComponentRegistration {
 /// }
 /// ```
 ///
-/// The `ComponentId` trait is designed to maintain component IDs for a Rust type
-/// in a manner that is consistent across different worlds (or instances).
-/// When a component is utilized, this trait will determine whether it has already been registered.
-/// If it hasn't, it registers the component with the current world.
+/// The `ComponentId` trait maintains the component IDs of a Rust type for every world (or instance).
+/// When a component is utilized, this trait will determine whether it has already been registered
+/// with the current world. If it hasn't, it registers the component with the current world.
 ///
-/// If the ID has been previously established, the trait ensures the world recognizes it.
-/// If the world doesn't, this implies the component was registered by a different world.
-/// In such a case, the component is registered with the present world using the pre-existing ID.
-/// If the ID is already known, the trait takes care of the component registration and checks for consistency in the input.
+/// Every world registers the component on its own, so the same type can have a different ID in
+/// each world, and so can the constants of enum components. An ID is only valid in the world it
+/// was obtained from.
 #[diagnostic::on_unimplemented(
     message = "`{Self}` is not a flecs component.",
     label = "use `#[derive(Component)]` on `{Self}` to mark it as one."
@@ -134,6 +132,14 @@ pub trait ComponentId:
     #[doc(hidden)]
     type UnderlyingEnumType: ComponentId + EnumComponentInfo;
 
+    /// The name the component is registered with, set with `#[flecs(name = "...")]`.
+    /// `None` registers the component with the path of the Rust type.
+    const CUSTOM_NAME: Option<&'static str> = None;
+
+    /// The symbol the component is registered with, set with `#[flecs(symbol = "...")]`.
+    /// `None` registers the component with the path of the Rust type.
+    const CUSTOM_SYMBOL: Option<&'static str> = None;
+
     /// attempts to register the component with the world. If it's already registered, it does nothing.
     #[doc(hidden)]
     #[inline(always)]
@@ -442,6 +448,14 @@ pub trait EnumComponentInfo: ComponentType<Enum> + ComponentId {
 
     fn enum_index(&self) -> usize;
 
+    /// The discriminant of the variant, which is the value of its constant.
+    ///
+    /// Flecs enum constants are `i32`, variants of which the discriminant doesn't fit aren't
+    /// registered as a constant of the reflection type.
+    fn discriminant(&self) -> i64 {
+        self.enum_index() as i64
+    }
+
     fn iter() -> Self::VariantIterator;
 
     /// # Note
@@ -460,16 +474,23 @@ pub trait EnumComponentInfo: ComponentType<Enum> + ComponentId {
         result
     }
 
+    /// # Note
+    /// it only means that the enum is registered with a particular world, not necessarily yours.
     fn is_field_registered_as_entity(&self) -> bool {
         let index = self.enum_index();
         unsafe { *Self::__enum_data_mut().add(index) != 0 }
     }
 
+    /// # Note
+    /// it only means that the enum is registered with a particular world, not necessarily yours.
     fn is_index_registered_as_entity(index: usize) -> bool {
         unsafe { *Self::__enum_data_mut().add(index) != 0 }
     }
 
     /// get the entity id of the variant of the enum. This function will register the enum with the world if it's not registered.
+    ///
+    /// # Note
+    /// every world has its own constant entities, the id is only valid in `world`.
     fn id_variant<'a>(&self, world: impl WorldProvider<'a>) -> EntityView<'a> {
         use crate::core::component_registration::registration;
         let world = world.world();
@@ -477,8 +498,7 @@ pub trait EnumComponentInfo: ComponentType<Enum> + ComponentId {
             const COMPONENT_REGISTRATION: bool = false;
             registration::try_register_component::<COMPONENT_REGISTRATION, Self>(world);
         }
-        let index = self.enum_index();
-        EntityView::new_from(world, unsafe { *Self::__enum_data_mut().add(index) })
+        unsafe { self.id_variant_unchecked(world) }
     }
 
     /// # Safety
@@ -486,24 +506,45 @@ pub trait EnumComponentInfo: ComponentType<Enum> + ComponentId {
     /// This function is unsafe because it assumes the enum has been registered as a component with the world.
     /// if uncertain, use `try_register_component::<T>` to try and register it
     unsafe fn id_variant_unchecked<'a>(&self, world: impl WorldProvider<'a>) -> EntityView<'a> {
-        let index = self.enum_index();
-        EntityView::new_from(world, unsafe { *Self::__enum_data_mut().add(index) })
+        let world = world.world();
+        let id = unsafe { Self::id_variant_of_index_unchecked(world, self.enum_index()) };
+        EntityView::new_from(world, id)
     }
 
-    fn id_variant_of_index(index: usize) -> Option<u64> {
-        if index < Self::SIZE_ENUM_FIELDS as usize {
-            Some(unsafe { *Self::__enum_data_mut().add(index) })
-        } else {
-            None
+    /// get the entity id of the variant with `index` in `world`, `None` if the index is out of
+    /// bounds or the enum is not registered with `world`.
+    fn id_variant_of_index<'a>(world: impl WorldProvider<'a>, index: usize) -> Option<u64> {
+        let world = world.world();
+        if !<Self as ComponentId>::is_registered_with_world(world) {
+            return None;
         }
+        let component = <Self as ComponentId>::id(world);
+        world
+            .world_ctx()
+            .enum_constants
+            .get(&component)
+            .and_then(|constants| constants.get(index))
+            .copied()
     }
 
     /// # Safety
-    /// This function is unsafe because it dereferences a raw pointer and you must ensure that the
-    /// index is within the bounds of the number of variants in the enum.
+    /// This function is unsafe because it assumes the enum has been registered as a component
+    /// with `world` and that the index is within the bounds of the number of variants in the enum.
     /// if uncertain, use `SIZE_ENUM_FIELDS` to check the number of variants.
-    unsafe fn id_variant_of_index_unchecked(index: usize) -> u64 {
-        unsafe { *Self::__enum_data_mut().add(index) }
+    unsafe fn id_variant_of_index_unchecked<'a>(
+        world: impl WorldProvider<'a>,
+        index: usize,
+    ) -> u64 {
+        let world = world.world();
+        let component = <Self as ComponentId>::id(world);
+        let id = world.world_ctx().enum_constants[&component][index];
+        ecs_assert!(
+            unsafe { sys::ecs_is_alive(world.world_ptr(), id) },
+            FlecsErrorCode::InvalidParameter,
+            "enum constant {} is not alive in this world",
+            id
+        );
+        id
     }
 
     #[doc(hidden)]
@@ -8,6 +8,7 @@ pub struct Enum;
 pub struct Struct;
 
 #[derive(Component)]
+#[flecs(no_collect)]
 #[repr(C)]
 pub enum NoneEnum {
     None = 1,
@@ -1,6 +1,6 @@
 //! Registering and working with components
 
-use std::{fmt::Debug, fmt::Display, marker::PhantomData, ops::Deref, os::raw::c_void, ptr};
+use std::{fmt::Debug, fmt::Display, marker::PhantomData, ops::Deref, os::raw::c_void};
 
 use crate::core::*;
 #[cfg(feature = "flecs_meta")]
@@ -71,14 +71,28 @@ impl<'a, T: ComponentId> Component<'a, T> {
     /// * `world`: the world.
     /// * `name`: the name of the component.
     ///
+    /// # Panics
+    ///
+    /// Panics if the component is already registered with another name.
+    ///
     /// # See also
     ///
     /// * C++ API: `component::component`
     #[doc(alias = "component::component")]
     pub fn new_named(world: impl WorldProvider<'a>, name: &str) -> Self {
-        let id = T::__register_or_get_id_named::<false>(world.world(), name);
-
         let world = world.world();
+        let is_registered = T::is_registered_with_world(world);
+        let id = T::__register_or_get_id_named::<false>(world, name);
+
+        if is_registered && world.try_lookup(name).is_none_or(|e| e.id() != id) {
+            panic!(
+                "component `{}` is already registered as `{}`, it can't be registered again as `{}`",
+                std::any::type_name::<T>(),
+                world.entity_from_id(id).path().unwrap_or_default(),
+                name
+            );
+        }
+
         Self {
             base: UntypedComponent::new_from(world, id),
             _marker: PhantomData,
@@ -120,7 +134,6 @@ impl<'a, T> Component<'a, T> {
     #[doc(alias = "component::component")]
     #[cfg(feature = "flecs_meta")]
     pub fn new_named_id(world: impl WorldProvider<'a>, id: FetchedId<T>, name: &str) -> Self {
-        let _name = compact_str::format_compact!("{}\0", name);
         let world = world.world();
         let entity = world.entity_from_id(id.id());
         entity.get_name().map_or_else(
@@ -196,9 +209,8 @@ impl<'a, T> Component<'a, T> {
     /// * C++ API: `component::binding_ctx_free`
     #[doc(alias = "component::binding_ctx_free")]
     unsafe extern "C-unwind" fn binding_ctx_drop(ptr: *mut c_void) {
-        let ptr_struct: *mut ComponentBindingCtx = ptr as *mut ComponentBindingCtx;
         unsafe {
-            ptr::drop_in_place(ptr_struct);
+            drop(Box::from_raw(ptr as *mut ComponentBindingCtx));
         }
     }
 
@@ -294,9 +306,8 @@ impl<'a, T> Component<'a, T> {
     where
         Func: FnMut(EntityView, &mut T) + 'static,
     {
-        let ptr_func: *mut Func = func as *mut Func;
         unsafe {
-            ptr::drop_in_place(ptr_func);
+            drop(Box::from_raw(func as *mut Func));
         }
     }
 
@@ -305,9 +316,8 @@ impl<'a, T> Component<'a, T> {
     where
         Func: FnMut(EntityView, &mut T) + 'static,
     {
-        let ptr_func: *mut Func = func as *mut Func;
         unsafe {
-            ptr::drop_in_place(ptr_func);
+            drop(Box::from_raw(func as *mut Func));
         }
     }
 
@@ -316,9 +326,8 @@ impl<'a, T> Component<'a, T> {
     where
         Func: FnMut(EntityView, &mut T) + 'static,
     {
-        let ptr_func: *mut Func = func as *mut Func;
         unsafe {
-            ptr::drop_in_place(ptr_func);
+            drop(Box::from_raw(func as *mut Func));
         }
     }
 
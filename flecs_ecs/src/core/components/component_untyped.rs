@@ -4,6 +4,7 @@ use std::{
 };
 
 use crate::core::*;
+use crate::sys;
 
 /// Untyped component class.
 #[derive(Clone, Copy)]
@@ -92,6 +93,129 @@ impl<'a> UntypedComponent<'a> {
     pub fn as_entity(&self) -> EntityView<'a> {
         self.entity
     }
+
+    /// Set the size and alignment of a component whose layout is only known at runtime.
+    ///
+    /// Values of the component can then be written with [`EntityView::set_bytes()`] and read
+    /// with [`EntityView::get_untyped()`]. Values that are added without being set, such as
+    /// with [`EntityView::add_id()`], are zeroed. Members added with reflection have to add up
+    /// to the same layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `size`: the size of the component in bytes.
+    /// * `alignment`: the alignment of the component in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component already has a different size or alignment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let health = world.component_untyped_named("Health").layout(8, 4);
+    ///
+    /// let value = [1.0f32.to_ne_bytes(), 2.0f32.to_ne_bytes()].concat();
+    /// let e = world.entity().set_bytes(health, &value);
+    ///
+    /// let ptr = e.get_untyped(health) as *const [f32; 2];
+    /// assert_eq!(unsafe { *ptr }, [1.0, 2.0]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::component_untyped_named()`]
+    /// * C API: `ecs_component_init`
+    #[doc(alias = "ecs_component_init")]
+    pub fn layout(self, size: usize, alignment: usize) -> Self {
+        let mut hooks = Default::default();
+        register_zeroed_ctor_lifecycle_action(&mut hooks);
+
+        let type_info = sys::ecs_type_info_t {
+            size: size as i32,
+            alignment: alignment as i32,
+            hooks,
+            component: 0,
+            name: std::ptr::null(),
+        };
+        let desc = create_component_desc(*self.id, type_info);
+
+        let id = unsafe { sys::ecs_component_init(self.world_ptr_mut(), &desc) };
+        ecs_assert!(
+            id == *self.id,
+            FlecsErrorCode::InvalidParameter,
+            "failed to set the layout of component {}",
+            self.entity
+        );
+        self
+    }
+
+    /// Get the size of the component in bytes.
+    ///
+    /// Returns 0 for tags and for components of which the layout isn't known yet.
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::alignment()`]
+    /// * [`UntypedComponent::layout()`]
+    pub fn size(&self) -> usize {
+        self.component_info()
+            .map_or(0, |component| component.size as usize)
+    }
+
+    /// Get the alignment of the component in bytes.
+    ///
+    /// Returns 0 for tags and for components of which the layout isn't known yet.
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::size()`]
+    /// * [`UntypedComponent::layout()`]
+    pub fn alignment(&self) -> usize {
+        self.component_info()
+            .map_or(0, |component| component.alignment as usize)
+    }
+
+    fn component_info(&self) -> Option<&flecs::Component> {
+        let ptr = unsafe {
+            sys::ecs_get_id(self.world_ptr(), *self.id, flecs::Component::ID)
+                as *const flecs::Component
+        };
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Add a flecs trait to the component, such as [`flecs::CanToggle`] or a
+    /// `(flecs::OnInstantiate, flecs::Inherit)` pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: the trait or trait pair to add.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_trait()`]
+    pub fn add_trait_id(self, id: impl IntoId) -> Self {
+        unsafe { self.entity.add_id_unchecked(id) };
+        self
+    }
+
+    /// Set what happens to entities with the component when the component is deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `action`: the cleanup action, one of [`flecs::Remove`], [`flecs::Delete`] or
+    ///   [`flecs::Panic`].
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::add_trait_id()`]
+    pub fn on_delete(self, action: impl Into<Entity>) -> Self {
+        self.add_trait_id((flecs::OnDelete::ID, action.into()))
+    }
 }
 
 #[cfg(feature = "flecs_meta")]
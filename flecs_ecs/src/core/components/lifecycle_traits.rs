@@ -81,6 +81,12 @@ pub fn register_ctor_panic_lifecycle_actions<T>(type_hooks: &mut sys::ecs_type_h
     type_hooks.ctor = Some(panic_ctor::<T>);
 }
 
+/// Registers a constructor that zeroes the memory, for components of which the layout is
+/// only known at runtime.
+pub(crate) fn register_zeroed_ctor_lifecycle_action(type_hooks: &mut sys::ecs_type_hooks_t) {
+    type_hooks.ctor = Some(zeroed_ctor);
+}
+
 pub fn register_copy_lifecycle_action<T: Clone>(type_hooks: &mut sys::ecs_type_hooks_t) {
     type_hooks.copy = Some(copy::<T>);
     type_hooks.copy_ctor = Some(copy_ctor::<T>); //same implementation as copy
@@ -122,6 +128,22 @@ extern "C-unwind" fn ctor<T: Default>(
     }
 }
 
+/// Initialize the memory with zeroes.
+///
+/// # Arguments
+///
+/// * `ptr` - pointer to the memory to be initialized
+/// * `count` - number of elements to be initialized
+/// * `type_info` - type info for the type to be initialized
+extern "C-unwind" fn zeroed_ctor(
+    ptr: *mut c_void,
+    count: i32,
+    type_info: *const sys::ecs_type_info_t,
+) {
+    let size = unsafe { (*type_info).size } as usize;
+    unsafe { std::ptr::write_bytes(ptr as *mut u8, 0, size * count as usize) };
+}
+
 /// Runs the destructor for the type.
 ///
 /// # Arguments
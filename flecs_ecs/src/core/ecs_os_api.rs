@@ -42,9 +42,11 @@ pub fn ensure_initialized() {
         flecs_ecs::sys::ecs_os_set_api_defaults();
         flecs_ecs::sys::ecs_os_get_api()
     };
+    let default_abort = api.abort_;
     for h in hooks {
         (h.0)(&mut api);
     }
+    super::utility::log::install_abort_handler(&mut api, default_abort);
     unsafe {
         flecs_ecs::sys::ecs_os_set_api(&mut api as *mut _);
     };
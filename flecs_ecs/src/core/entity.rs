@@ -5,6 +5,7 @@ use std::ops::Deref;
 use std::ops::{BitAnd, BitOr};
 
 use crate::core::*;
+use crate::sys;
 
 /// An identifier that represents an entity.
 ///
@@ -15,7 +16,7 @@ use crate::core::*;
 /// and a generation counter used to track entity liveliness in the upper 32
 /// bits. When an id is recycled, its generation count is increased. This
 /// causes recycled ids to be very large (>4 billion), which is normal.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Entity(pub u64);
 
@@ -33,6 +34,62 @@ impl Entity {
         self.0 != 0
     }
 
+    /// Returns the index of the entity, the lower 32 bits of the id.
+    ///
+    /// The index is the same for all generations of an entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let e = Entity::new(1000).with_generation(3);
+    ///
+    /// assert_eq!(e.index(), 1000);
+    /// assert_eq!(e.generation(), 3);
+    /// ```
+    #[inline]
+    pub fn index(self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Returns the generation of the entity, which is increased each time the index is
+    /// recycled.
+    ///
+    /// # See also
+    ///
+    /// * [`Entity::with_generation()`]
+    /// * [`EntityView::is_current_generation()`]
+    #[inline]
+    pub fn generation(self) -> u32 {
+        get_generation(self)
+    }
+
+    /// Returns the id with the same index and the given generation.
+    ///
+    /// Generations are stored in 16 bits, higher bits of `generation` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `generation` - The generation of the returned id.
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_version()`]
+    #[inline]
+    pub fn with_generation(self, generation: u32) -> Self {
+        Self(
+            (self.0 & !sys::ECS_GENERATION_MASK)
+                | (((generation as u64) << 32) & sys::ECS_GENERATION_MASK),
+        )
+    }
+
+    /// Whether the id only consists of an index and a generation, i.e. it's not a pair or
+    /// an id with flags.
+    fn is_plain(self) -> bool {
+        self.0 & !(sys::ECS_ENTITY_MASK as u64 | sys::ECS_GENERATION_MASK) == 0
+    }
+
     /// Convert the entity id to an [`EntityView`] with the given world.
     ///
     /// # Safety
@@ -60,6 +117,51 @@ impl Entity {
     pub fn id_view<'a>(&self, world: impl WorldProvider<'a>) -> IdView<'a> {
         IdView::new_from_id(world, *self)
     }
+
+    /// Get the typed component handle of `T` if this entity is the component entity of `T`.
+    ///
+    /// Returns `None` if `T` isn't registered with the world, or if the entity is another
+    /// entity, which includes an id with the same index but a different generation.
+    /// The component isn't registered by this function.
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - The world the entity belongs to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let position = world.component::<Position>().id();
+    ///
+    /// assert!(position.try_as_component::<Position>(&world).is_some());
+    /// assert!(position.try_as_component::<Velocity>(&world).is_none());
+    /// ```
+    pub fn try_as_component<'a, T: ComponentId>(
+        &self,
+        world: impl WorldProvider<'a>,
+    ) -> Option<Component<'a, T>> {
+        let world = world.world();
+        if !T::is_registered_with_world(world) || T::id(world) != self.0 {
+            return None;
+        }
+
+        Some(Component::new(world))
+    }
 }
 
 // impl ComponentInfo for Entity {
@@ -121,10 +223,21 @@ impl Deref for Entity {
     }
 }
 
+/// Shows `index:generation` for entities with a generation, and the id otherwise.
 impl Display for Entity {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
+        if self.is_plain() && self.generation() != 0 {
+            write!(f, "{}:{}", self.index(), self.generation())
+        } else {
+            self.0.fmt(f)
+        }
+    }
+}
+
+impl std::fmt::Debug for Entity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Entity({self})")
     }
 }
 
@@ -237,7 +350,7 @@ mod from_operations {
     {
         #[inline]
         fn from(query: Query<T>) -> Self {
-            Entity(unsafe { query.query.as_ref().entity })
+            Entity(query.entity)
         }
     }
 
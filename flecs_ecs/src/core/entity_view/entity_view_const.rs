@@ -1,7 +1,7 @@
 use std::{
     ffi::{c_void, CStr},
     ops::{Deref, DerefMut},
-    ptr::{self, NonNull},
+    ptr::NonNull,
 };
 
 use crate::sys;
@@ -126,7 +126,7 @@ impl<'a> EntityView<'a> {
     /// * C++ API: `entity::entity`
     #[doc(alias = "entity::entity")]
     pub(crate) fn new_named(world: impl WorldProvider<'a>, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let desc = sys::ecs_entity_desc_t {
             name: name.as_ptr() as *const _,
@@ -227,44 +227,63 @@ impl<'a> EntityView<'a> {
         unsafe { sys::ecs_is_alive(self.world.world_ptr(), *self.id) }
     }
 
+    /// Checks if the generation of the entity is the generation that is alive in the world.
+    ///
+    /// Returns false when the entity was deleted, also when its index was recycled by a
+    /// newer entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    /// let id = e.id();
+    /// e.destruct();
+    ///
+    /// let recycled = world.entity();
+    /// assert_eq!(recycled.id().index(), id.index());
+    /// assert!(recycled.is_current_generation());
+    /// assert!(!world.entity_from_id(id).is_current_generation());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Entity::generation()`]
+    pub fn is_current_generation(self) -> bool {
+        let alive = unsafe { sys::ecs_get_alive(self.world.world_ptr(), self.id.index() as u64) };
+        alive != 0 && alive == *self.id
+    }
+
     /// Returns the entity name.
     ///
     /// if the entity has no name, this will return an empty string
     ///
+    /// The name is copied out of flecs, so it stays valid when the entity is renamed or deleted.
+    ///
     /// # See also
     ///
     /// * C++ API: `entity_view::name`
     #[doc(alias = "entity_view::name")]
     pub fn name(self) -> String {
-        self.get_name().unwrap_or("".to_string())
+        self.get_name().unwrap_or_default()
     }
 
     /// Returns the entity name.
     ///
     /// if the entity has no name, this will return none
     ///
+    /// The name is copied out of flecs, so it stays valid when the entity is renamed or deleted.
+    ///
     /// # See also
     ///
     /// * C++ API: `entity_view::name`
     #[doc(alias = "entity_view::name")]
     pub fn get_name(self) -> Option<String> {
-        // self.get_name_cstr().and_then(|s| s.to_str().ok())
-        let cstr =
-            NonNull::new(unsafe { sys::ecs_get_name(self.world.world_ptr(), *self.id) } as *mut _)
-                .map(|s| unsafe { CStr::from_ptr(s.as_ptr()) });
-        cstr.and_then(|s| s.to_str().ok().map(|s| s.to_string()))
-    }
-
-    // /// Returns the entity name as a `CStr`.
-    // ///
-    // /// if the entity has no name, this will return an empty string
-    // ///
-    // /// # See also
-    // ///
-    // /// * C++ API: `entity_view::name`
-    // pub fn name_cstr(self) -> &'a CStr {
-    //     self.get_name_cstr().unwrap_or(c"")
-    // }
+        unsafe { self.get_name_cstr() }.and_then(|s| s.to_str().ok().map(|s| s.to_string()))
+    }
 
     /// Returns the entity name as a `CStr`.
     ///
@@ -272,8 +291,8 @@ impl<'a> EntityView<'a> {
     ///
     /// # Safety
     ///
-    /// This function is unsafe because it returns a raw pointer to the C string. You have to manually
-    /// ensure that the C string is valid for the lifetime of the pointer.
+    /// The string is owned by flecs and is freed when the entity is renamed or deleted. The caller
+    /// must not use it after such a change, the borrow of `self` does not prevent it.
     ///
     /// # See also
     ///
@@ -283,27 +302,20 @@ impl<'a> EntityView<'a> {
             .map(|s| unsafe { CStr::from_ptr(s.as_ptr()) })
     }
 
-    // /// Returns the entity symbol.
-    // ///
-    // /// # See also
-    // ///
-    // /// * C++ API: `entity_view::symbol`
-    // #[doc(alias = "entity_view::symbol")]
-    // pub fn symbol_cstr(self) -> &'a CStr {
-    //     unsafe { CStr::from_ptr(sys::ecs_get_symbol(self.world.world_ptr(), *self.id)) }
-    // }
-
     /// Returns the entity symbol.
     ///
+    /// if the entity has no symbol, this will return an empty string
+    ///
+    /// The symbol is copied out of flecs, so it stays valid when the symbol is changed or the
+    /// entity is deleted.
+    ///
     /// # See also
     ///
     /// * C++ API: `entity_view::symbol`
     #[doc(alias = "entity_view::symbol")]
     pub fn symbol(self) -> String {
-        //self.symbol_cstr().to_str().unwrap()
-        let cstr = unsafe { CStr::from_ptr(sys::ecs_get_symbol(self.world.world_ptr(), *self.id)) };
-        cstr.to_str()
-            .ok()
+        NonNull::new(unsafe { sys::ecs_get_symbol(self.world.world_ptr(), *self.id) } as *mut _)
+            .and_then(|s| unsafe { CStr::from_ptr(s.as_ptr()) }.to_str().ok())
             .map(|s| s.to_string())
             .unwrap_or_default()
     }
@@ -346,8 +358,8 @@ impl<'a> EntityView<'a> {
         sep: &str,
         init_sep: &str,
     ) -> Option<String> {
-        let sep = compact_str::format_compact!("{}\0", sep);
-        let init_sep = compact_str::format_compact!("{}\0", init_sep);
+        let sep = SmallCStr::<128>::from_arg(sep);
+        let init_sep = SmallCStr::<128>::from_arg(init_sep);
 
         NonNull::new(unsafe {
             sys::ecs_get_path_w_sep(
@@ -358,12 +370,8 @@ impl<'a> EntityView<'a> {
                 init_sep.as_ptr() as *const _,
             )
         })
-        .map(|s| unsafe {
-            let len = CStr::from_ptr(s.as_ptr()).to_bytes().len();
-            // Convert the C string to a Rust String without any new heap allocation.
-            // The String will de-allocate the C string when it goes out of scope.
-            String::from_utf8_unchecked(Vec::from_raw_parts(s.as_ptr() as *mut u8, len, len))
-        })
+        // the path is allocated by flecs, so it's copied and freed with the flecs os api
+        .map(|s| unsafe { take_flecs_string(s.as_ptr()) })
     }
 
     fn path_from_id_default_sep(&self, parent: impl Into<Entity>) -> Option<String> {
@@ -376,12 +384,7 @@ impl<'a> EntityView<'a> {
                 SEPARATOR.as_ptr(),
             )
         })
-        .map(|s| unsafe {
-            let len = CStr::from_ptr(s.as_ptr()).to_bytes().len();
-            // Convert the C string to a Rust String without any new heap allocation.
-            // The String will de-allocate the C string when it goes out of scope.
-            String::from_utf8_unchecked(Vec::from_raw_parts(s.as_ptr() as *mut u8, len, len))
-        })
+        .map(|s| unsafe { take_flecs_string(s.as_ptr()) })
     }
 
     /// Return the hierarchical entity path relative to a parent id using the default separator "::".
@@ -400,13 +403,7 @@ impl<'a> EntityView<'a> {
                 SEPARATOR.as_ptr(),
             )
         })
-        .map(|s| unsafe {
-            let len = CStr::from_ptr(s.as_ptr()).to_bytes().len();
-
-            // Convert the C string to a Rust String without any new heap allocation.
-            // The String will de-allocate the C string when it goes out of scope.
-            String::from_utf8_unchecked(Vec::from_raw_parts(s.as_ptr() as *mut u8, len, len))
-        })
+        .map(|s| unsafe { take_flecs_string(s.as_ptr()) })
     }
 
     /// Return the hierarchical entity path relative to a parent type.
@@ -734,6 +731,95 @@ impl<'a> EntityView<'a> {
         self.each_child_of_id(flecs::ChildOf::ID, func)
     }
 
+    /// Iterate children for entity until the callback stops the iteration.
+    ///
+    /// Returning `false` or [`ControlFlow::Break`](std::ops::ControlFlow::Break) from
+    /// `func` stops the iteration, no further children are visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `relationship` - The relationship to follow
+    /// * `func` - The function invoked for each child. Must match the signature `FnMut(EntityView) -> bool`
+    ///   or `FnMut(EntityView) -> ControlFlow<()>`.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::each_child_of_id()`]
+    pub fn each_child_of_id_while<R: IterControl>(
+        self,
+        relationship: impl Into<Entity>,
+        mut func: impl FnMut(EntityView) -> R,
+    ) {
+        if self.id == flecs::Wildcard::ID || self.id == flecs::Any::ID {
+            return;
+        }
+
+        let mut it: sys::ecs_iter_t =
+            unsafe { sys::ecs_each_id(self.world_ptr(), ecs_pair(*relationship.into(), *self.id)) };
+        while unsafe { sys::ecs_each_next(&mut it) } {
+            for i in 0..it.count as usize {
+                let ent = unsafe { EntityView::new_from(self.world, *it.entities.add(i)) };
+                if !func(ent).should_continue() {
+                    unsafe { sys::ecs_iter_fini(&mut it) };
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Iterate children for entity until the callback stops the iteration.
+    ///
+    /// # Arguments
+    ///
+    /// * T - The relationship to follow
+    /// * `func` - The function invoked for each child. Must match the signature `FnMut(EntityView) -> bool`
+    ///   or `FnMut(EntityView) -> ControlFlow<()>`.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::each_child_of()`]
+    pub fn each_child_of_while<T, R: IterControl>(self, func: impl FnMut(EntityView) -> R)
+    where
+        T: ComponentId,
+    {
+        self.each_child_of_id_while(T::id(self.world), func);
+    }
+
+    /// Iterate children for entity until the callback stops the iteration.
+    /// This operation follows the `ChildOf` relationship.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked for each child. Must match the signature `FnMut(EntityView) -> bool`
+    ///   or `FnMut(EntityView) -> ControlFlow<()>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity();
+    /// let first = world.entity().child_of_id(parent).id();
+    /// world.entity().child_of_id(parent);
+    ///
+    /// let mut found = None;
+    /// parent.each_child_while(|child| {
+    ///     found = Some(child.id());
+    ///     false
+    /// });
+    ///
+    /// assert_eq!(found, Some(first));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::each_child()`]
+    pub fn each_child_while<R: IterControl>(self, func: impl FnMut(EntityView) -> R) {
+        self.each_child_of_id_while(flecs::ChildOf::ID, func);
+    }
+
     /// Returns if the entity has any children.
     ///
     /// # Example
@@ -776,11 +862,62 @@ impl<'a> EntityView<'a> {
     ///
     /// assert_eq!(parent.count_children(), 2);
     /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::count_subtree()`]
+    /// * [`World::count_first()`]
+    #[doc(alias = "children_count")]
     pub fn count_children(self) -> u32 {
         let mut it = unsafe { sys::ecs_children(self.world_ptr(), *self.id) };
         unsafe { sys::ecs_iter_count(&mut it) as u32 }
     }
 
+    /// Returns the count of all descendants of the entity, following the `ChildOf` relationship.
+    ///
+    /// The hierarchy is walked depth first without allocating, the iterators of the levels
+    /// that are being visited are kept on the stack.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity();
+    /// let child = world.entity().child_of_id(parent);
+    /// world.entity().child_of_id(child);
+    ///
+    /// assert_eq!(parent.count_subtree(), 2);
+    /// assert_eq!(child.count_subtree(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::count_children()`]
+    #[doc(alias = "subtree_count")]
+    pub fn count_subtree(self) -> i32 {
+        // wildcard entities don't have children, see `each_child_of_id`
+        if self.id == flecs::Wildcard::ID || self.id == flecs::Any::ID {
+            return 0;
+        }
+
+        fn count_descendants(world: *const sys::ecs_world_t, parent: sys::ecs_entity_t) -> i32 {
+            let mut count = 0;
+            let mut it = unsafe { sys::ecs_each_id(world, ecs_pair(flecs::ChildOf::ID, parent)) };
+            while unsafe { sys::ecs_each_next(&mut it) } {
+                count += it.count;
+                for i in 0..it.count as usize {
+                    count += count_descendants(world, unsafe { *it.entities.add(i) });
+                }
+            }
+            count
+        }
+
+        count_descendants(self.world_ptr(), *self.id)
+    }
+
     /// Returns the count of targets for a given relationship.
     ///
     /// # Arguments
@@ -1027,7 +1164,7 @@ impl<Return> EntityViewGet<Return> for EntityView<'_> {
         let record = unsafe { sys::ecs_record_find(self.world.world_ptr(), *self.id) };
 
         if unsafe { (*record).table.is_null() } {
-            panic!("Entity does not have any components");
+            panic!("Entity `{}` does not have any components", self.id);
         }
 
         let tuple_data = T::create_ptrs::<true>(self.world, self.id, record);
@@ -1100,7 +1237,7 @@ impl<'a> EntityView<'a> {
         let record = unsafe { sys::ecs_record_find(self.world.world_ptr(), *self.id) };
 
         if unsafe { (*record).table.is_null() } {
-            panic!("Entity does not have any components");
+            panic!("Entity `{}` does not have any components", self.id);
         }
 
         let tuple_data = T::create_ptrs::<true>(self.world, self.id, record);
@@ -1637,7 +1774,7 @@ impl<'a> EntityView<'a> {
     #[doc(alias = "entity_view::lookup")]
     #[inline(always)]
     fn try_lookup_impl(self, name: &str, recursively: bool) -> Option<EntityView<'a>> {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         ecs_assert!(
             self.id != 0,
@@ -2353,7 +2490,7 @@ impl EntityView<'_> {
 }
 
 // Event/Observe mixin
-impl EntityView<'_> {
+impl<'a> EntityView<'a> {
     /// Register the callback for the entity observer for empty events.
     ///
     /// The "empty" iterator accepts a function that is invoked for each matching event.
@@ -2364,6 +2501,10 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
+    /// # Returns
+    ///
+    /// The observer, which can be disabled or destructed to stop observing the entity.
+    ///
     /// See also
     ///
     /// * [`EntityView::emit()`]
@@ -2375,14 +2516,14 @@ impl EntityView<'_> {
     /// * [`World::event()`]
     /// * C++ API: `entity_builder::observe`
     #[doc(alias = "entity_builder::observe")]
-    pub fn observe<C>(self, func: impl FnMut() + 'static) -> Self
+    pub fn observe<C>(self, func: impl FnMut() + 'static) -> Observer<'a>
     where
         C: ComponentId + TagComponent,
     {
         self.observe_impl::<C, _>(func)
     }
 
-    fn observe_impl<C, Func>(self, func: Func) -> Self
+    fn observe_impl<C, Func>(self, func: Func) -> Observer<'a>
     where
         Func: FnMut() + 'static,
         C: ComponentId,
@@ -2394,16 +2535,16 @@ impl EntityView<'_> {
         let empty_static_ref = Box::leak(empty_func);
 
         binding_ctx.empty = Some(empty_static_ref as *mut _ as *mut c_void);
-        binding_ctx.free_empty = Some(Self::on_free_empty);
+        binding_ctx.free_empty = Some(Self::on_free_callback::<Func>);
 
-        Self::entity_observer_create(
+        let observer = Self::entity_observer_create(
             self.world.world_ptr_mut(),
             C::id(self.world),
             *self.id,
             binding_ctx,
             Some(Self::run_empty::<Func> as unsafe extern "C-unwind" fn(_)),
         );
-        self
+        Observer::new_from_existing(EntityView::new_from(self.world, observer))
     }
 
     /// Register the callback for the entity observer for empty events with entity parameter.
@@ -2416,6 +2557,10 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
+    /// # Returns
+    ///
+    /// The observer, which can be disabled or destructed to stop observing the entity.
+    ///
     /// See also
     ///
     /// * [`EntityView::emit()`]
@@ -2427,14 +2572,14 @@ impl EntityView<'_> {
     /// * [`World::event()`]
     /// * C++ API: `entity_builder::observe`
     #[doc(alias = "entity_builder::observe")]
-    pub fn observe_entity<C>(self, func: impl FnMut(&mut EntityView) + 'static) -> Self
+    pub fn observe_entity<C>(self, func: impl FnMut(&mut EntityView) + 'static) -> Observer<'a>
     where
         C: ComponentId + TagComponent,
     {
         self.observe_entity_impl::<C, _>(func)
     }
 
-    fn observe_entity_impl<C, Func>(self, func: Func) -> Self
+    fn observe_entity_impl<C, Func>(self, func: Func) -> Observer<'a>
     where
         Func: FnMut(&mut EntityView) + 'static,
         C: ComponentId,
@@ -2446,16 +2591,16 @@ impl EntityView<'_> {
         let empty_static_ref = Box::leak(empty_func);
 
         binding_ctx.empty_entity = Some(empty_static_ref as *mut _ as *mut c_void);
-        binding_ctx.free_empty_entity = Some(Self::on_free_empty_entity);
+        binding_ctx.free_empty_entity = Some(Self::on_free_callback::<Func>);
 
-        Self::entity_observer_create(
+        let observer = Self::entity_observer_create(
             self.world.world_ptr_mut(),
             C::id(self.world),
             *self.id,
             binding_ctx,
             Some(Self::run_empty_entity::<Func> as unsafe extern "C-unwind" fn(_)),
         );
-        self
+        Observer::new_from_existing(EntityView::new_from(self.world, observer))
     }
 
     /// Register the callback for the entity observer for `payload` events.
@@ -2468,6 +2613,10 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
+    /// # Returns
+    ///
+    /// The observer, which can be disabled or destructed to stop observing the entity.
+    ///
     /// See also
     ///
     /// * [`EntityView::emit()`]
@@ -2479,14 +2628,14 @@ impl EntityView<'_> {
     /// * [`World::event()`]
     /// * C++ API: `entity_builder::observe`
     #[doc(alias = "entity_builder::observe")]
-    pub fn observe_payload<C>(self, func: impl FnMut(&C) + 'static) -> Self
+    pub fn observe_payload<C>(self, func: impl FnMut(&C) + 'static) -> Observer<'a>
     where
         C: ComponentId + DataComponent,
     {
         self.observe_payload_impl::<C, _>(func)
     }
 
-    fn observe_payload_impl<C, Func>(self, func: Func) -> Self
+    fn observe_payload_impl<C, Func>(self, func: Func) -> Observer<'a>
     where
         Func: FnMut(&C) + 'static,
         C: ComponentId,
@@ -2498,16 +2647,16 @@ impl EntityView<'_> {
         let empty_static_ref = Box::leak(empty_func);
 
         binding_ctx.payload = Some(empty_static_ref as *mut _ as *mut c_void);
-        binding_ctx.free_payload = Some(Self::on_free_payload::<C>);
+        binding_ctx.free_payload = Some(Self::on_free_callback::<Func>);
 
-        Self::entity_observer_create(
+        let observer = Self::entity_observer_create(
             self.world.world_ptr_mut(),
             C::id(self.world),
             *self.id,
             binding_ctx,
             Some(Self::run_payload::<C, Func> as unsafe extern "C-unwind" fn(_)),
         );
-        self
+        Observer::new_from_existing(EntityView::new_from(self.world, observer))
     }
 
     /// Register the callback for the entity observer for an event with payload and entity parameter.
@@ -2520,6 +2669,10 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
+    /// # Returns
+    ///
+    /// The observer, which can be disabled or destructed to stop observing the entity.
+    ///
     /// See also
     ///
     /// * [`EntityView::emit()`]
@@ -2531,14 +2684,17 @@ impl EntityView<'_> {
     /// * [`World::event()`]
     /// * C++ API: `entity_builder::observe`
     #[doc(alias = "entity_builder::observe")]
-    pub fn observe_payload_entity<C>(self, func: impl FnMut(&mut EntityView, &C) + 'static) -> Self
+    pub fn observe_payload_entity<C>(
+        self,
+        func: impl FnMut(&mut EntityView, &C) + 'static,
+    ) -> Observer<'a>
     where
         C: ComponentId + DataComponent,
     {
         self.observe_payload_entity_impl::<C, _>(func)
     }
 
-    fn observe_payload_entity_impl<C, Func>(self, func: Func) -> Self
+    fn observe_payload_entity_impl<C, Func>(self, func: Func) -> Observer<'a>
     where
         Func: FnMut(&mut EntityView, &C) + 'static,
         C: ComponentId,
@@ -2550,16 +2706,16 @@ impl EntityView<'_> {
         let empty_static_ref = Box::leak(empty_func);
 
         binding_ctx.payload_entity = Some(empty_static_ref as *mut _ as *mut c_void);
-        binding_ctx.free_payload_entity = Some(Self::on_free_payload_entity::<C>);
+        binding_ctx.free_payload_entity = Some(Self::on_free_callback::<Func>);
 
-        Self::entity_observer_create(
+        let observer = Self::entity_observer_create(
             self.world.world_ptr_mut(),
             C::id(self.world),
             *self.id,
             binding_ctx,
             Some(Self::run_payload_entity::<C, Func> as unsafe extern "C-unwind" fn(_)),
         );
-        self
+        Observer::new_from_existing(EntityView::new_from(self.world, observer))
     }
 }
 
@@ -2571,7 +2727,7 @@ impl EntityView<'_> {
         entity: sys::ecs_entity_t,
         binding_ctx: *mut ObserverEntityBindingCtx,
         callback: sys::ecs_iter_action_t,
-    ) {
+    ) -> sys::ecs_entity_t {
         let mut desc = sys::ecs_observer_desc_t::default();
         desc.events[0] = event;
         desc.query.terms[0].id = ECS_ANY;
@@ -2582,6 +2738,7 @@ impl EntityView<'_> {
 
         let observer = unsafe { sys::ecs_observer_init(world, &desc) };
         ecs_add_pair(world, observer, ECS_CHILD_OF, entity);
+        observer
     }
 
     /// Callback of the observe functionality
@@ -2601,13 +2758,10 @@ impl EntityView<'_> {
         let ctx: *mut ObserverEntityBindingCtx = (*iter).callback_ctx as *mut _;
         let empty = (*ctx).empty.unwrap();
         let empty = &mut *(empty as *mut Func);
-        let iter_count = (*iter).count as usize;
 
         sys::ecs_table_lock((*iter).world, (*iter).table);
 
-        for _i in 0..iter_count {
-            empty();
-        }
+        empty();
 
         sys::ecs_table_unlock((*iter).world, (*iter).table);
     }
@@ -2629,17 +2783,14 @@ impl EntityView<'_> {
         let ctx: *mut ObserverEntityBindingCtx = (*iter).callback_ctx as *mut _;
         let empty = (*ctx).empty_entity.unwrap();
         let empty = &mut *(empty as *mut Func);
-        let iter_count = (*iter).count as usize;
 
         sys::ecs_table_lock((*iter).world, (*iter).table);
 
-        for _i in 0..iter_count {
-            let world = WorldRef::from_ptr((*iter).world);
-            empty(&mut EntityView::new_from(
-                world,
-                sys::ecs_field_src(iter, 0),
-            ));
-        }
+        let world = WorldRef::from_ptr((*iter).world);
+        empty(&mut EntityView::new_from(
+            world,
+            sys::ecs_field_src(iter, 0),
+        ));
 
         sys::ecs_table_unlock((*iter).world, (*iter).table);
     }
@@ -2661,15 +2812,12 @@ impl EntityView<'_> {
         let ctx: *mut ObserverEntityBindingCtx = (*iter).callback_ctx as *mut _;
         let empty = (*ctx).payload.unwrap();
         let empty = &mut *(empty as *mut Func);
-        let iter_count = (*iter).count as usize;
 
         sys::ecs_table_lock((*iter).world, (*iter).table);
 
-        for _i in 0..iter_count {
-            let data = (*iter).param as *mut C;
-            let data_ref = &mut *data;
-            empty(data_ref);
-        }
+        let data = (*iter).param as *mut C;
+        let data_ref = &mut *data;
+        empty(data_ref);
 
         sys::ecs_table_unlock((*iter).world, (*iter).table);
     }
@@ -2691,60 +2839,32 @@ impl EntityView<'_> {
         let ctx: *mut ObserverEntityBindingCtx = (*iter).callback_ctx as *mut _;
         let empty = (*ctx).payload_entity.unwrap();
         let empty = &mut *(empty as *mut Func);
-        let iter_count = (*iter).count as usize;
 
         sys::ecs_table_lock((*iter).world, (*iter).table);
 
-        for _i in 0..iter_count {
-            let data = (*iter).param as *mut C;
-            let data_ref = &mut *data;
-            let world = WorldRef::from_ptr((*iter).world);
-            empty(
-                &mut EntityView::new_from(world, sys::ecs_field_src(iter, 0)),
-                data_ref,
-            );
-        }
+        let data = (*iter).param as *mut C;
+        let data_ref = &mut *data;
+        let world = WorldRef::from_ptr((*iter).world);
+        empty(
+            &mut EntityView::new_from(world, sys::ecs_field_src(iter, 0)),
+            data_ref,
+        );
 
         sys::ecs_table_unlock((*iter).world, (*iter).table);
     }
 
-    /// Callback to free the memory of the `empty` callback
-    pub(crate) extern "C-unwind" fn on_free_empty(ptr: *mut c_void) {
-        let ptr_func: *mut fn() = ptr as *mut fn();
-        unsafe {
-            ptr::drop_in_place(ptr_func);
-        }
-    }
-
-    /// Callback to free the memory of the `empty_entity` callback
-    pub(crate) extern "C-unwind" fn on_free_empty_entity(ptr: *mut c_void) {
-        let ptr_func: *mut fn(&mut EntityView) = ptr as *mut fn(&mut EntityView);
-        unsafe {
-            ptr::drop_in_place(ptr_func);
-        }
-    }
-
-    /// Callback to free the memory of the `payload` callback
-    pub(crate) extern "C-unwind" fn on_free_payload<C>(ptr: *mut c_void) {
-        let ptr_func: *mut fn(&mut C) = ptr as *mut fn(&mut C);
-        unsafe {
-            ptr::drop_in_place(ptr_func);
-        }
-    }
-
-    /// Callback to free the memory of the `payload_entity` callback
-    pub(crate) extern "C-unwind" fn on_free_payload_entity<C>(ptr: *mut c_void) {
-        let ptr_func: *mut fn(&mut EntityView, &mut C) = ptr as *mut fn(&mut EntityView, &mut C);
+    /// Callback to free one of the callbacks of the binding context, which were leaked from a
+    /// `Box<Func>`
+    pub(crate) extern "C-unwind" fn on_free_callback<Func>(ptr: *mut c_void) {
         unsafe {
-            ptr::drop_in_place(ptr_func);
+            drop(Box::from_raw(ptr as *mut Func));
         }
     }
 
     /// Executes the drop for the system binding context, meant to be used as a callback
     pub(crate) extern "C-unwind" fn binding_entity_ctx_drop(ptr: *mut c_void) {
-        let ptr_struct: *mut ObserverEntityBindingCtx = ptr as *mut ObserverEntityBindingCtx;
         unsafe {
-            ptr::drop_in_place(ptr_struct);
+            drop(Box::from_raw(ptr as *mut ObserverEntityBindingCtx));
         }
     }
 }
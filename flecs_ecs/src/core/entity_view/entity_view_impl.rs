@@ -41,7 +41,7 @@ impl<'a> IdOperations<'a> for EntityView<'a> {
     /// * C++ API: `Id::Id`
     #[doc(alias = "Id::Id")]
     fn new_from_str(world: impl WorldProvider<'a>, expr: &str) -> Self {
-        let expr = compact_str::format_compact!("{}\0", expr);
+        let expr = SmallCStr::<128>::from_arg(expr);
         let id = unsafe { sys::ecs_id_from_str(world.world_ptr(), expr.as_ptr() as *const _) };
         Self {
             world: world.world(),
@@ -303,7 +303,8 @@ impl<'a> EntityView<'a> {
                 }
                 self.remove_id((first, second))
             } else {
-                self.remove_id(id)
+                // removes the (Enum, *) pair for enums
+                self.remove::<T>()
             }
         }
     }
@@ -378,14 +379,81 @@ impl<'a> EntityView<'a> {
     where
         T: ComponentId + ComponentType<Enum> + EnumComponentInfo,
     {
-        let world = self.world;
-        // SAFETY: we know that the enum_value is a valid because of the T::id call
-        self.add_id_if(
-            (T::id(world), unsafe {
-                enum_value.id_variant_unchecked(world)
-            }),
-            condition,
-        )
+        if condition {
+            self.add_enum(enum_value)
+        } else {
+            // enums are only exclusive relationships with the meta addon, so always remove
+            // the wildcard pair
+            let world = self.world;
+            self.remove_id((T::id(world), ECS_WILDCARD))
+        }
+    }
+
+    /// Conditional remove.
+    /// This operation removes if condition is true, and does nothing otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id to remove.
+    /// * `condition`: The condition to evaluate.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_id_if()`]
+    pub fn remove_id_if(self, id: impl IntoId, condition: bool) -> Self {
+        if condition {
+            self.remove_id(id)
+        } else {
+            self
+        }
+    }
+
+    /// Conditional remove.
+    /// This operation removes if condition is true, and does nothing otherwise.
+    /// For enums, the `(Enum, *)` pair is removed.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T`: The component or pair to remove.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition`: The condition to evaluate.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_if()`]
+    pub fn remove_if<T: ComponentOrPairId>(self, condition: bool) -> Self {
+        if condition {
+            self.remove::<T>()
+        } else {
+            self
+        }
+    }
+
+    /// Conditional set.
+    /// This operation sets the component if condition is true, removes it if condition is false.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T`: The component to set.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition`: The condition to evaluate.
+    /// * `component`: The value to set.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_if()`]
+    /// * [`EntityView::set()`]
+    pub fn set_if<T: ComponentId + DataComponent>(self, condition: bool, component: T) -> Self {
+        if condition {
+            self.set(component)
+        } else {
+            let world = self.world;
+            self.remove_id(T::id(world))
+        }
     }
 
     /// Remove an entity from an entity.
@@ -843,12 +911,70 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Sets multiple components at once.
+    ///
+    /// Zero-sized values in the tuple are added as tags. The operations are batched in a
+    /// deferred scope, so the entity is moved to its destination table once. `OnAdd`
+    /// observers are invoked for the combined move, `OnSet` observers for each component.
+    ///
+    /// # Arguments
+    ///
+    /// * `values`: A tuple of up to 32 component values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set_all((
+    ///     Position { x: 1.0, y: 2.0 },
+    ///     Velocity { x: 0.0, y: 1.0 },
+    ///     Enemy,
+    /// ));
+    ///
+    /// assert!(e.has::<Position>());
+    /// assert!(e.has::<Velocity>());
+    /// assert!(e.has::<Enemy>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set()`]
+    pub fn set_all<T: SetTuple>(self, values: T) -> Self {
+        let world = self.world;
+        world.defer_begin();
+        values.set_all(world, self.id);
+        world.defer_end();
+        self
+    }
+
     /// Sets the data of the specified id. Can be a pair or Component.
     ///
     /// # Safety
     ///
     /// Caller must ensure that `data` is a valid data for the id.
     ///
+    /// The type of `data` must be the type of the id, or a type without drop glue that has the
+    /// same reflected layout. This allows setting a template component of a script with a
+    /// `#[meta]` struct that has the same members as the props of the template.
+    ///
     /// ```no_run
     /// use flecs_ecs::prelude::*;
     ///
@@ -895,7 +1021,16 @@ impl<'a> EntityView<'a> {
         let data_id = T::id(self.world);
         let id_data_id = unsafe { sys::ecs_get_typeid(world, id) };
 
-        if data_id != id_data_id {
+        // types with the same reflected layout, such as a struct mirroring the props of a
+        // script template, can be used for each other
+        #[cfg(feature = "flecs_meta")]
+        let matches = data_id == id_data_id
+            || (!T::NEEDS_DROP
+                && crate::addons::meta::struct_layouts_match(self.world, data_id, id_data_id));
+        #[cfg(not(feature = "flecs_meta"))]
+        let matches = data_id == id_data_id;
+
+        if !matches {
             panic!("Data type does not match id type. For pairs this is the first element occurrence that is not a zero-sized type (ZST).");
         }
 
@@ -1106,29 +1241,232 @@ impl<'a> EntityView<'a> {
         self.set_ptr_w_size(id, unsafe { (*cptr).size } as usize, ptr)
     }
 
-    /// Sets the name of the entity.
+    /// Sets a component or pair of an entity by copying `size` bytes from `ptr`.
+    ///
+    /// `OnSet` hooks and observers are invoked after the value is copied, so this can be used
+    /// by deserialization layers and C interop to write components of which the type is only
+    /// known by id. Use [`EntityView::modified_id()`] instead when the value was written in
+    /// place, such as through [`EntityView::get_untyped_mut()`].
+    ///
+    /// The value is copied with the copy hook of the component, which panics for Rust types
+    /// that don't implement `Clone`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `size` bytes that form a valid value of the type of `id`.
     ///
     /// # Arguments
     ///
-    /// * `name` - A string slice that holds the name to be set.
+    /// * `id` - The id of the component or pair to set.
+    /// * `size` - The size of the value, which must match the size of the component.
+    /// * `ptr` - A pointer to the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use std::ffi::c_void;
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let position = world.component::<Position>();
+    ///
+    /// let value = Position { x: 10.0, y: 20.0 };
+    /// let e = world.entity();
+    /// unsafe {
+    ///     e.set_untyped(
+    ///         position,
+    ///         size_of::<Position>(),
+    ///         &value as *const Position as *const c_void,
+    ///     );
+    /// }
+    ///
+    /// e.get::<&Position>(|pos| assert_eq!(*pos, value));
+    /// ```
     ///
     /// # See also
     ///
-    /// * C++ API: `entity_builder::set_name`
-    #[doc(alias = "entity_builder::set_name")]
-    pub fn set_name(self, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+    /// * [`EntityView::get_untyped()`]
+    /// * [`EntityView::modified_id()`]
+    /// * [`EntityView::set_bytes()`]
+    /// * C API: `ecs_set_id`
+    #[doc(alias = "ecs_set_id")]
+    pub unsafe fn set_untyped(self, id: impl IntoId, size: usize, ptr: *const c_void) -> Self {
+        let id = *id.into();
+        let world = self.world.world_ptr_mut();
+
+        ecs_assert!(
+            {
+                let type_info = sys::ecs_get_type_info(world, id);
+                !type_info.is_null() && (*type_info).size as usize == size
+            },
+            FlecsErrorCode::InvalidParameter,
+            "size {} doesn't match the size of component {}",
+            size,
+            IdView::new_from_id(self.world, id).to_str()
+        );
+
+        sys::ecs_set_id(world, *self.id, id, size, ptr);
+        self
+    }
+
+    /// Sets a component of an entity from the bytes of its value.
+    ///
+    /// This is meant for components of which the layout is only known at runtime, such as
+    /// components created with [`World::component_untyped_named()`]. The bytes are copied
+    /// into the component storage, after which `OnSet` hooks and observers are invoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the component to set.
+    /// * `bytes` - The value of the component, which must be as long as the component size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` isn't a component or if the length of `bytes` doesn't match its size.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_untyped()`]
+    /// * [`EntityView::set_ptr()`]
+    /// * [`UntypedComponent::layout()`]
+    /// * C++ API: `entity_builder::set_ptr`
+    #[doc(alias = "entity_builder::set_ptr")]
+    pub fn set_bytes(self, id: impl IntoId, bytes: &[u8]) -> Self {
+        let id = *id.into();
+        let type_info = unsafe { sys::ecs_get_type_info(self.world.world_ptr(), id) };
+
+        assert!(
+            !type_info.is_null(),
+            "id {} is not a component",
+            IdView::new_from_id(self.world, id).to_str()
+        );
+        let size = unsafe { (*type_info).size } as usize;
+        assert_eq!(
+            bytes.len(),
+            size,
+            "can't set {} bytes for component {} with size {}",
+            bytes.len(),
+            IdView::new_from_id(self.world, id).to_str(),
+            size
+        );
 
         unsafe {
-            sys::ecs_set_name(
+            sys::ecs_set_id(
                 self.world.world_ptr_mut(),
                 *self.id,
-                name.as_ptr() as *const _,
+                id,
+                size,
+                bytes.as_ptr() as *const c_void,
             );
         }
         self
     }
 
+    /// Sets the name of the entity, replacing the name it already has.
+    ///
+    /// An empty name removes the name of the entity. A scoped name such as `"Foo::Bar"` moves
+    /// the entity into the `Foo` scope, which is created if it doesn't exist yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice that holds the name to be set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another entity in the same scope already has the name.
+    /// Use [`EntityView::try_set_name()`] to handle the conflict instead.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::try_set_name()`]
+    /// * C++ API: `entity_builder::set_name`
+    #[doc(alias = "entity_builder::set_name")]
+    pub fn set_name(self, name: &str) -> Self {
+        self.try_set_name(name)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Sets the name of the entity, replacing the name it already has.
+    ///
+    /// An empty name removes the name of the entity. A scoped name such as `"Foo::Bar"` moves
+    /// the entity into the `Foo` scope, which is created if it doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if another entity in the same scope already has the name, in which case
+    /// the entity keeps its current name and scope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity_named("Foo");
+    /// let bar = world.entity_named("Bar");
+    ///
+    /// assert!(bar.try_set_name("Foo").is_err());
+    /// assert_eq!(bar.name(), "Bar");
+    ///
+    /// bar.try_set_name("Foo::Bar").unwrap();
+    /// assert_eq!(bar.path().unwrap(), "::Foo::Bar");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_name()`]
+    /// * C API: `ecs_set_name`
+    #[doc(alias = "ecs_set_name")]
+    pub fn try_set_name(self, name: &str) -> Result<Self, FlecsError> {
+        if name.is_empty() {
+            return Ok(self.remove_name());
+        }
+
+        let world = self.world.world_ptr_mut();
+        let (scope, leaf) = match name.rsplit_once("::") {
+            Some((scope, leaf)) => (Some(scope), leaf),
+            None => (None, name),
+        };
+
+        let parent = match scope {
+            // a leading "::" is the root scope
+            Some("") => 0,
+            Some(scope) => *EntityView::new_named(self.world, scope).id,
+            None => self.parent().map_or(0, |parent| *parent.id),
+        };
+
+        let leaf = SmallCStr::<128>::from_arg(leaf);
+        let existing = unsafe { sys::ecs_lookup_child(world, parent, leaf.as_ptr() as *const _) };
+        if existing != 0 && existing != *self.id {
+            return Err(FlecsError::new(format!(
+                "cannot name entity '{name}', the name is already used by {}",
+                EntityView::new_from(self.world, existing)
+                    .path()
+                    .unwrap_or_default()
+            )));
+        }
+
+        if scope.is_some() {
+            // the name is unset first, so the old name can't conflict in the new scope
+            unsafe { sys::ecs_set_name(world, *self.id, std::ptr::null()) };
+            if parent == 0 {
+                self.remove_id((flecs::ChildOf::ID, flecs::Wildcard::ID));
+            } else {
+                self.child_of_id(parent);
+            }
+        }
+
+        unsafe { sys::ecs_set_name(world, *self.id, leaf.as_ptr() as *const _) };
+        Ok(self)
+    }
+
     /// Removes the name of the entity.
     pub fn remove_name(self) -> Self {
         unsafe {
@@ -1139,16 +1477,41 @@ impl<'a> EntityView<'a> {
 
     /// Sets the alias name of the entity.
     ///
+    /// An alias is a global name, the lookup functions find the entity by its alias from any
+    /// scope. An empty name removes the alias.
+    ///
     /// # Arguments
     ///
     /// * `name` - A string slice that holds the alias name to be set.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let transform = world.entity_named("my::module::Transform");
+    /// transform.set_alias("transform");
+    /// assert_eq!(world.lookup("transform"), transform);
+    ///
+    /// transform.set_alias("");
+    /// assert!(world.try_lookup("transform").is_none());
+    /// ```
+    ///
     /// # See also
     ///
     /// * C++ API: `entity_builder::set_alias`
     #[doc(alias = "entity_builder::set_alias")]
     pub fn set_alias(self, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        if name.is_empty() {
+            unsafe {
+                sys::ecs_set_alias(self.world.world_ptr_mut(), *self.id, std::ptr::null());
+            }
+            return self;
+        }
+
+        let name = SmallCStr::<128>::from_arg(name);
 
         unsafe {
             sys::ecs_set_alias(
@@ -1172,6 +1535,20 @@ impl<'a> EntityView<'a> {
         unsafe { sys::ecs_enable(self.world.world_ptr_mut(), *self.id, true) }
         self
     }
+
+    /// Enables the entity and all of its children, following the `ChildOf` relationship.
+    ///
+    /// The changes are applied inside a deferred scope.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::enable_self()`]
+    /// * [`EntityView::disable_recursive()`]
+    pub fn enable_recursive(self) -> Self {
+        self.set_enabled_recursive(true);
+        self
+    }
+
     /// Enables an ID which represents a component or pair.
     ///
     /// This sets the enabled bit for this component. If this is the first time the component is
@@ -1258,6 +1635,31 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Disables the entity and all of its children, following the `ChildOf` relationship.
+    ///
+    /// Queries stop matching the entire subtree. The changes are applied inside a deferred scope.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::disable_self()`]
+    /// * [`EntityView::enable_recursive()`]
+    pub fn disable_recursive(self) -> Self {
+        self.set_enabled_recursive(false);
+        self
+    }
+
+    fn set_enabled_recursive(self, enabled: bool) {
+        fn apply(entity: EntityView, enabled: bool) {
+            unsafe { sys::ecs_enable(entity.world.world_ptr_mut(), *entity.id, enabled) }
+            entity.each_child(|child| apply(child, enabled));
+        }
+
+        let world = self.world.world_ptr_mut();
+        unsafe { sys::ecs_defer_begin(world) };
+        apply(self, enabled);
+        unsafe { sys::ecs_defer_end(world) };
+    }
+
     /// Disables an ID which represents a component or pair.
     ///
     /// This sets the enabled bit for this ID. If this is the first time the ID is
@@ -1441,7 +1843,7 @@ impl<'a> EntityView<'a> {
     ///
     /// * C++ API: `entity_builder::scope`
     #[doc(alias = "entity_builder::scope")]
-    pub fn scope(self, f: impl FnMut(&World)) -> Self {
+    pub fn scope(self, f: impl FnOnce(&World)) -> Self {
         let world = &*self.world;
         world.scope_id(self.id, f);
         self
@@ -1681,10 +2083,13 @@ impl<'a> EntityView<'a> {
     /// Clear an entity.
     ///
     /// This operation removes all components from an entity without recycling
-    /// the entity id.
+    /// the entity id. `OnRemove` observers and hooks are invoked for every removed
+    /// component, and the entity stays alive with an empty type. Children of the
+    /// entity are not deleted, since their `ChildOf` pairs are not stored on it.
     ///
     /// # See also
     ///
+    /// * [`EntityView::destruct()`]
     /// * C++ API: `entity::clear`
     #[doc(alias = "entity::clear")]
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
@@ -1,8 +1,9 @@
 //! API for emitting events that trigger [`Observer`]s.
 
 use std::marker::PhantomData;
-use std::{alloc::Layout, os::raw::c_void};
+use std::{mem::ManuallyDrop, os::raw::c_void};
 
+use super::event_propagation::emit_propagated;
 use crate::core::*;
 use crate::sys;
 
@@ -25,6 +26,7 @@ pub struct EventBuilder<'a, T = ()> {
     pub(crate) desc: sys::ecs_event_desc_t,
     pub(crate) ids: sys::ecs_type_t,
     pub(crate) ids_array: [sys::ecs_id_t; sys::FLECS_EVENT_DESC_MAX as usize],
+    pub(crate) propagate: sys::ecs_entity_t,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -42,6 +44,7 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
             desc: Default::default(),
             ids: Default::default(),
             ids_array: Default::default(),
+            propagate: 0,
             _phantom: PhantomData,
         };
         obj.desc.event = T::UnderlyingType::id(world);
@@ -74,6 +77,7 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
             desc: Default::default(),
             ids: Default::default(),
             ids_array: Default::default(),
+            propagate: 0,
             _phantom: PhantomData::<()>,
         };
         obj.desc.event = *event.into();
@@ -82,16 +86,28 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
 
     /// Add component id or pair to emit for the event.
     ///
+    /// Only observers of the added ids are invoked for the event. If no ids are added, the
+    /// event is emitted for any id of the entity.
+    ///
     /// # Arguments
     ///
     /// * `id` - The id of the component to add to the event
     ///
+    /// # Panics
+    ///
+    /// Panics if more than [`FLECS_EVENT_DESC_MAX`](sys::FLECS_EVENT_DESC_MAX) ids are added.
+    ///
     /// # See also
     ///
     /// * C++ API: `event_builder_base::id`
     #[doc(alias = "event_builder_base::id")]
     pub fn add_id(&mut self, id: impl IntoId) -> &mut Self {
         let id = *id.into();
+        assert!(
+            (self.ids.count as usize) < self.ids_array.len(),
+            "an event can be emitted for at most {} ids",
+            self.ids_array.len()
+        );
         let ids = &mut self.ids;
         let ids_array = &mut self.ids_array;
         ids.array = ids_array.as_mut_ptr();
@@ -125,8 +141,8 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
         enum_value: C,
     ) -> &mut Self {
         let world = self.world;
-        let rel = T::id(world);
-        // SAFETY: we know that the enum_value is a valid because of the T::id call
+        let rel = C::id(world);
+        // SAFETY: we know that the enum_value is a valid because of the C::id call
         let target = unsafe { enum_value.id_variant_unchecked(world) };
         ecs_assert!(
             target != 0,
@@ -150,7 +166,7 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
     ///
     /// * C++ API: `event_builder_base::id`
     #[doc(alias = "event_builder_base::id")]
-    fn add_first<First>(&mut self, second: impl Into<Entity>) -> &mut Self
+    pub fn add_first<First>(&mut self, second: impl Into<Entity>) -> &mut Self
     where
         First: ComponentId,
     {
@@ -158,8 +174,21 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
         self.add_id(ecs_pair(First::id(world), *second.into()))
     }
 
+    /// Add a pair of components to emit for the event.
+    ///
+    /// # Type parameters
+    ///
+    /// * `Second` - The second component to add to the event
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The id of the first component to add to the event
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `event_builder_base::id`
     #[doc(alias = "event_builder_base::id")]
-    fn add_second<Second>(&mut self, first: impl Into<Entity>) -> &mut Self
+    pub fn add_second<Second>(&mut self, first: impl Into<Entity>) -> &mut Self
     where
         Second: ComponentId,
     {
@@ -203,6 +232,45 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
         self
     }
 
+    /// Emit the event on the entity, and then on each entity it reaches by following the
+    /// relationship `Rel` upwards, for example from a child to its parent for
+    /// [`flecs::ChildOf`].
+    ///
+    /// The event is emitted on each entity separately, so observers of an entity see that
+    /// entity as the entity of the event. [`World::event_origin()`] returns the entity the
+    /// event was emitted on first, and an observer can stop the propagation with
+    /// [`World::stop_propagation()`].
+    ///
+    /// This has no effect if the event isn't emitted for an entity.
+    ///
+    /// # Type parameters
+    ///
+    /// * `Rel` - The relationship to follow
+    ///
+    /// # See also
+    ///
+    /// * [`EventBuilder::propagate_id()`]
+    /// * [`EntityView::emit_to_ancestors()`]
+    pub fn propagate<Rel: ComponentId>(&mut self) -> &mut Self {
+        let rel = Rel::id(self.world);
+        self.propagate_id(rel)
+    }
+
+    /// Emit the event on the entity, and then on each entity it reaches by following the
+    /// relationship `rel` upwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `rel` - The relationship to follow
+    ///
+    /// # See also
+    ///
+    /// * [`EventBuilder::propagate()`]
+    pub fn propagate_id(&mut self, rel: impl Into<Entity>) -> &mut Self {
+        self.propagate = *rel.into();
+        self
+    }
+
     pub fn emit(&mut self, data: &T) {
         let ids = &mut self.ids;
         let ids_array = &mut self.ids_array;
@@ -216,9 +284,26 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
 
         desc.ids = ids;
         desc.observable = world.real_world().world_ptr_mut() as *mut c_void;
-        unsafe { sys::ecs_emit(world.world_ptr_mut(), desc) };
+        if self.propagate != 0 && desc.entity != 0 {
+            emit_propagated(world, desc, self.propagate);
+        } else {
+            unsafe { sys::ecs_emit(world.world_ptr_mut(), desc) };
+        }
     }
 
+    /// Enqueue the event, which is emitted when the world is no longer deferred.
+    ///
+    /// The event takes ownership of `data`, which is dropped after the event is emitted. If the
+    /// world isn't deferred, the event is emitted right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The payload of the event
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `event_builder_base::enqueue`
+    #[doc(alias = "event_builder_base::enqueue")]
     pub fn enqueue(&mut self, data: T) {
         let ids = &mut self.ids;
         let ids_array = &mut self.ids_array;
@@ -226,17 +311,29 @@ impl<'a, T: ComponentId> EventBuilder<'a, T> {
         let world = self.world;
         ids.array = ids_array.as_mut_ptr();
 
-        if !T::IS_TAG {
-            desc.param = Box::leak(Box::new(data)) as *mut T as *mut c_void;
-        }
-
         desc.ids = ids;
         desc.observable = world.real_world().world_ptr_mut() as *mut c_void;
-        unsafe {
-            sys::ecs_enqueue(world.world_ptr_mut(), desc);
-            if !T::IS_TAG {
-                std::alloc::dealloc(desc.param as *mut u8, Layout::new::<T>());
-            }
-        };
+
+        if T::IS_TAG {
+            unsafe { sys::ecs_enqueue(world.world_ptr_mut(), desc) };
+            return;
+        }
+
+        if unsafe { !sys::ecs_is_deferred(world.world_ptr()) } {
+            // flecs doesn't take ownership of the payload of an event that is emitted right
+            // away, so `data` is dropped here
+            desc.const_param = &data as *const T as *const c_void;
+            unsafe { sys::ecs_emit(world.world_ptr_mut(), desc) };
+            // `ecs_emit` moves the pointer to `param`
+            desc.param = std::ptr::null_mut();
+            return;
+        }
+
+        // flecs moves the payload into the command queue, and drops it after the event is
+        // emitted or when the command is discarded
+        let mut data = ManuallyDrop::new(data);
+        desc.param = &mut *data as *mut T as *mut c_void;
+        unsafe { sys::ecs_enqueue(world.world_ptr_mut(), desc) };
+        desc.param = std::ptr::null_mut();
     }
 }
@@ -0,0 +1,190 @@
+//! Directional event dispatch along the `ChildOf` hierarchy and other relationships.
+
+use crate::core::*;
+use crate::sys;
+
+impl World {
+    /// Stop the dispatch of the event that is being emitted with
+    /// [`EntityView::emit_to_ancestors()`] or [`EntityView::emit_to_descendants()`].
+    ///
+    /// Call this from an observer of the event. The observers of the entity the event is
+    /// emitted on are still invoked, the event isn't emitted on any entity after it.
+    /// Outside of such a dispatch this does nothing.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::emit_to_ancestors()`]
+    /// * [`EntityView::emit_to_descendants()`]
+    pub fn stop_propagation(&self) {
+        self.world_ctx_mut().propagation_stopped = true;
+    }
+
+    /// Returns the entity that the event which is being dispatched was emitted on first.
+    ///
+    /// Call this from an observer of an event that is emitted with
+    /// [`EntityView::emit_to_ancestors()`], [`EntityView::emit_to_descendants()`] or
+    /// [`EventBuilder::propagate()`], to find the entity where the event started, for example
+    /// the widget that was clicked when the event reaches its window. Outside of such a
+    /// dispatch this returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Click;
+    ///
+    /// let world = World::new();
+    ///
+    /// let window = world.entity_named("window");
+    /// let panel = world.entity_named("panel").child_of_id(window);
+    /// let button = world.entity_named("button").child_of_id(panel);
+    ///
+    /// let button_id = button.id();
+    /// window.observe_entity::<Click>(move |e| {
+    ///     assert_eq!(e.world().event_origin().unwrap(), button_id);
+    /// });
+    ///
+    /// button.emit_to_ancestors(&Click);
+    /// ```
+    pub fn event_origin(&self) -> Option<EntityView<'_>> {
+        let origin = self.world_ctx().propagation_origin;
+        (origin != 0).then(|| EntityView::new_from(self, origin))
+    }
+}
+
+/// Emit the event on the entity of `desc`, and then on each entity reached by following `rel`
+/// upwards, until an observer stops the propagation.
+pub(crate) fn emit_propagated(
+    world: WorldRef,
+    desc: &sys::ecs_event_desc_t,
+    rel: sys::ecs_entity_t,
+) {
+    dispatch(world, desc.entity, |entity| {
+        // flecs writes the table of the entity into the desc, so each emit gets a fresh copy
+        let mut desc = *desc;
+        desc.entity = entity;
+        unsafe { sys::ecs_emit(world.world_ptr_mut(), &mut desc) };
+        let target = unsafe { sys::ecs_get_target(world.world_ptr(), entity, rel, 0) };
+        (target != 0).then_some(target)
+    });
+}
+
+/// Run a dispatch that starts at `origin`, restoring the state of an outer dispatch after.
+/// `emit` emits the event on an entity and returns the next entity, if any.
+fn dispatch(
+    world: WorldRef,
+    origin: sys::ecs_entity_t,
+    mut emit: impl FnMut(sys::ecs_entity_t) -> Option<sys::ecs_entity_t>,
+) {
+    let outer_stopped = std::mem::take(&mut world.world_ctx_mut().propagation_stopped);
+    let outer_origin = std::mem::replace(&mut world.world_ctx_mut().propagation_origin, origin);
+
+    let mut current = Some(origin);
+    while let Some(entity) = current {
+        current = emit(entity);
+        if world.world_ctx().propagation_stopped {
+            break;
+        }
+    }
+
+    let ctx = world.world_ctx_mut();
+    ctx.propagation_stopped = outer_stopped;
+    ctx.propagation_origin = outer_origin;
+}
+
+impl<'a> EntityView<'a> {
+    /// Emit an event on the entity, and then on each of its ancestors up the `ChildOf`
+    /// hierarchy: its parent, the parent of its parent, up to the root.
+    ///
+    /// An observer can stop the event from bubbling further up with
+    /// [`World::stop_propagation()`].
+    ///
+    /// Unlike events that propagate through a traversable relationship, the event is emitted
+    /// on each entity separately, so observers of an ancestor see the ancestor as the entity
+    /// of the event.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The event to emit.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The payload of the event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Click {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let window = world.entity_named("window");
+    /// let button = world.entity_named("button").child_of_id(window);
+    ///
+    /// window.observe_payload_entity::<Click>(|_, _| panic!("button handled the click"));
+    /// button.observe_payload_entity::<Click>(|e, _| e.world().stop_propagation());
+    ///
+    /// button.emit_to_ancestors(&Click { x: 1.0, y: 2.0 });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::emit()`]
+    /// * [`EntityView::emit_to_descendants()`]
+    /// * [`EventBuilder::propagate()`]
+    /// * [`World::event_origin()`]
+    /// * [`World::stop_propagation()`]
+    pub fn emit_to_ancestors<T: ComponentId>(self, event: &T) {
+        self.world()
+            .event()
+            .entity(self)
+            .propagate::<flecs::ChildOf>()
+            .emit(event);
+    }
+
+    /// Emit an event on the entity, and then on each of its descendants in the `ChildOf`
+    /// hierarchy, depth first: each child is visited before the children of the next child.
+    ///
+    /// The children of an entity are visited in the order in which they are stored, which
+    /// is not necessarily the order in which they were created. An observer can stop the
+    /// dispatch with [`World::stop_propagation()`], after which the event isn't emitted on
+    /// any remaining descendant.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The event to emit.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The payload of the event.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::emit()`]
+    /// * [`EntityView::emit_to_ancestors()`]
+    /// * [`World::stop_propagation()`]
+    pub fn emit_to_descendants<T: ComponentId>(self, event: &T) {
+        let world = self.world();
+
+        let mut stack = Vec::new();
+        dispatch(world, *self.id(), |entity| {
+            let entity = EntityView::new_from(world, entity);
+            entity.emit(event);
+
+            // children are collected before they are visited, so observers can modify the
+            // hierarchy
+            let first_child = stack.len();
+            entity.each_child(|child| stack.push(*child.id()));
+            stack[first_child..].reverse();
+            stack.pop()
+        });
+    }
+}
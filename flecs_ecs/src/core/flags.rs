@@ -0,0 +1,399 @@
+//! Boolean flags that are packed into the bits of a single component.
+
+use std::marker::PhantomData;
+
+use flecs_ecs_derive::Component;
+
+use crate::core::*;
+use crate::sys;
+
+/// A set of flags that can be stored in a [`Flags`] component.
+///
+/// Each value maps to a single bit. Use `#[derive(FlagSet)]` on a fieldless enum to implement
+/// this trait, which assigns the bits in declaration order.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(FlagSet, Clone, Copy)]
+/// enum UnitFlags {
+///     Burning,
+///     Stunned,
+/// }
+///
+/// assert_eq!(UnitFlags::COUNT, 2);
+/// assert_eq!(UnitFlags::Stunned.bit(), 0b10);
+/// ```
+pub trait FlagSet: Copy + Send + Sync + 'static {
+    /// The number of flags in the set, at most 64.
+    const COUNT: u32;
+    /// The names of the flags, in bit order.
+    const NAMES: &'static [&'static str];
+
+    /// Returns the bit index of the flag.
+    fn index(self) -> u32;
+
+    /// Returns the mask of the flag.
+    #[inline(always)]
+    fn bit(self) -> u64 {
+        1 << self.index()
+    }
+}
+
+/// Component that stores the flags of a [`FlagSet`] as bits of a `u64`.
+///
+/// Toggling a flag changes the value of the component instead of adding or removing a tag,
+/// so entities that only differ in their flags stay in the same archetype. The trade-off is
+/// that flags can't be matched by queries directly, see [`QueryAPI::with_flag()`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(FlagSet, Clone, Copy)]
+/// enum UnitFlags {
+///     Burning,
+///     Stunned,
+/// }
+///
+/// let world = World::new();
+///
+/// let e = world.entity().set_flag(UnitFlags::Burning, true);
+///
+/// assert!(e.has_flag(UnitFlags::Burning));
+/// assert!(!e.has_flag(UnitFlags::Stunned));
+/// assert!(e.has::<Flags<UnitFlags>>());
+/// ```
+///
+/// # See also
+///
+/// * [`EntityView::set_flag()`]
+/// * [`EntityView::has_flag()`]
+/// * [`QueryAPI::with_flag()`]
+#[derive(Component)]
+#[repr(C)]
+pub struct Flags<T: FlagSet> {
+    bits: u64,
+    _marker: PhantomData<T>,
+}
+
+impl<T: FlagSet> Flags<T> {
+    /// Create an empty set of flags.
+    pub const fn new() -> Self {
+        Self::from_bits(0)
+    }
+
+    /// Create a set of flags from its bits.
+    pub const fn from_bits(bits: u64) -> Self {
+        Self {
+            bits,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the bits of the set.
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Returns true if no flag is set.
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns true if the flag is set.
+    pub fn has(&self, flag: T) -> bool {
+        self.bits & flag.bit() != 0
+    }
+
+    /// Set or clear a flag.
+    pub fn set(&mut self, flag: T, value: bool) -> &mut Self {
+        if value {
+            self.bits |= flag.bit();
+        } else {
+            self.bits &= !flag.bit();
+        }
+        self
+    }
+}
+
+impl<T: FlagSet> Default for Flags<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FlagSet> Clone for Flags<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: FlagSet> Copy for Flags<T> {}
+
+impl<T: FlagSet> PartialEq for Flags<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<T: FlagSet> Eq for Flags<T> {}
+
+impl<T: FlagSet> std::fmt::Debug for Flags<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut set = f.debug_set();
+        for (index, name) in T::NAMES.iter().enumerate() {
+            if self.bits & (1 << index) != 0 {
+                set.entry(&format_args!("{}", name));
+            }
+        }
+        set.finish()
+    }
+}
+
+/// Returns the id of the `Flags<T>` component, registering its reflection data the first
+/// time it's used with a world.
+fn flags_id<T: FlagSet>(world: WorldRef) -> sys::ecs_id_t {
+    if Flags::<T>::is_registered_with_world(world) {
+        return Flags::<T>::id(world);
+    }
+
+    let id = Flags::<T>::id(world);
+    #[cfg(feature = "flecs_meta")]
+    register_flags_meta::<T>(world);
+    id
+}
+
+/// Describe the bits of `Flags<T>` as a bitmask, so the flags serialize by name.
+///
+/// Flecs bitmasks are 32 bit, so sets with more flags are described as a plain `u64`.
+#[cfg(feature = "flecs_meta")]
+fn register_flags_meta<T: FlagSet>(world: WorldRef) {
+    let component = world.component::<Flags<T>>();
+
+    if T::COUNT > 32 || cfg!(target_endian = "big") {
+        component.member::<u64>("bits");
+        return;
+    }
+
+    let names = T::NAMES
+        .iter()
+        .map(|name| SmallCStr::<128>::from_arg(name))
+        .collect::<Vec<_>>();
+
+    let mut desc = sys::ecs_bitmask_desc_t::default();
+    for (index, name) in names.iter().enumerate() {
+        desc.constants[index].name = name.as_ptr() as *const _;
+        desc.constants[index].value = 1 << index;
+    }
+
+    let bitmask = unsafe { sys::ecs_bitmask_init(world.world_ptr_mut(), &desc) };
+    ecs_assert!(
+        bitmask != 0,
+        FlecsErrorCode::InternalError,
+        "failed to create bitmask type for flags"
+    );
+
+    component.member_id(bitmask, "bits");
+}
+
+impl<'a> EntityView<'a> {
+    /// Set or clear a flag of the [`Flags`] component of the entity.
+    ///
+    /// The component is added when the first flag is set. Changing flags does not move the
+    /// entity to another archetype.
+    ///
+    /// While deferred, flags are written directly to the component of the entity. The component
+    /// can't be added while deferred, as each deferred add would start from an empty set and
+    /// overwrite the flags set before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - The flag to set or clear.
+    /// * `value` - True to set the flag, false to clear it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(FlagSet, Clone, Copy)]
+    /// enum UnitFlags {
+    ///     Burning,
+    ///     Stunned,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world
+    ///     .entity()
+    ///     .set_flag(UnitFlags::Burning, true)
+    ///     .set_flag(UnitFlags::Stunned, true)
+    ///     .set_flag(UnitFlags::Burning, false);
+    ///
+    /// assert!(!e.has_flag(UnitFlags::Burning));
+    /// assert!(e.has_flag(UnitFlags::Stunned));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a flag is set while deferred on an entity that doesn't have the [`Flags`]
+    /// component yet.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::has_flag()`]
+    /// * [`EntityView::flags()`]
+    pub fn set_flag<T: FlagSet>(self, flag: T, value: bool) -> Self {
+        let world = self.world_ptr_mut();
+        let id = flags_id::<T>(self.world);
+
+        if !self.has_id(id) {
+            if !value {
+                return self;
+            }
+            assert!(
+                !unsafe { sys::ecs_is_deferred(world) },
+                "can't add the flags of {} to entity {} while deferred, set a flag before deferring",
+                std::any::type_name::<T>(),
+                self
+            );
+        }
+
+        unsafe {
+            let flags = sys::ecs_ensure_id(world, *self.id, id) as *mut Flags<T>;
+            (*flags).set(flag, value);
+            sys::ecs_modified_id(world, *self.id, id);
+        }
+        self
+    }
+
+    /// Returns true if the entity has the flag set.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - The flag to test.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_flag()`]
+    /// * [`EntityView::flags()`]
+    pub fn has_flag<T: FlagSet>(self, flag: T) -> bool {
+        self.flags::<T>().has(flag)
+    }
+
+    /// Returns the flags of the entity, or an empty set if the entity has no [`Flags`]
+    /// component.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The flag set.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_flag()`]
+    /// * [`EntityView::has_flag()`]
+    pub fn flags<T: FlagSet>(self) -> Flags<T> {
+        let id = flags_id::<T>(self.world);
+        let flags = unsafe { sys::ecs_get_id(self.world_ptr(), *self.id, id) } as *const Flags<T>;
+        if flags.is_null() {
+            Flags::new()
+        } else {
+            unsafe { *flags }
+        }
+    }
+}
+
+/// Query iteration that skips entities that don't have all of the flags set.
+///
+/// Created by [`QueryAPI::with_flag()`].
+///
+/// The entities are matched by the terms of the query plus a [`Flags`] term, so tables
+/// without the component aren't visited. When the query doesn't have the term yet, an
+/// uncached query with the additional term is created for each iteration, which doesn't keep
+/// the order of the query, e.g. of `order_by`. The flags are tested for each matched entity,
+/// which means that the flag itself is not accelerated by the query cache.
+pub struct WithFlag<'q, Q, T: FlagSet> {
+    query: &'q Q,
+    mask: u64,
+    _marker: PhantomData<T>,
+}
+
+impl<'q, Q, T: FlagSet> WithFlag<'q, Q, T> {
+    pub(crate) fn new(query: &'q Q, flag: T) -> Self {
+        Self {
+            query,
+            mask: flag.bit(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Also require the flag to be set.
+    pub fn with_flag(mut self, flag: T) -> Self {
+        self.mask |= flag.bit();
+        self
+    }
+
+    /// Invoke the function for each matching entity that has all of the flags set.
+    ///
+    /// See [`QueryAPI::each_entity()`].
+    pub fn each_entity<'a, P, Tuple>(&self, mut func: impl FnMut(EntityView, Tuple::TupleType<'_>))
+    where
+        Q: QueryAPI<'a, P, Tuple>,
+        Tuple: QueryTuple,
+    {
+        let world = self.query.world();
+        let id = flags_id::<T>(world);
+        let mask = self.mask;
+        let filter = |entity: EntityView, components: Tuple::TupleType<'_>| {
+            let flags =
+                unsafe { sys::ecs_get_id(entity.world_ptr(), *entity.id, id) } as *const Flags<T>;
+            if !flags.is_null() && unsafe { (*flags).bits } & mask == mask {
+                func(entity, components);
+            }
+        };
+
+        let query = unsafe { &*self.query.query_ptr() };
+        let count = query.term_count as usize;
+        if query.terms[..count].iter().any(|term| term.id == id) {
+            self.query.each_entity(filter);
+            return;
+        }
+
+        assert!(
+            count < query.terms.len(),
+            "can't add the Flags term to a query that has the maximum number of terms"
+        );
+        let mut desc = sys::ecs_query_desc_t {
+            cache_kind: QueryCacheKind::None as sys::ecs_query_cache_kind_t,
+            flags: query.flags
+                & (sys::EcsQueryMatchPrefab
+                    | sys::EcsQueryMatchDisabled
+                    | sys::EcsQueryMatchEmptyTables
+                    | sys::EcsQueryAllowUnresolvedByName),
+            ..Default::default()
+        };
+        desc.terms[..count].copy_from_slice(&query.terms[..count]);
+        desc.terms[count] = sys::ecs_term_t {
+            id,
+            inout: InOutKind::None as i16,
+            ..Default::default()
+        };
+
+        Query::<Tuple>::new_from_desc(world, &mut desc).each_entity(filter);
+    }
+
+    /// Returns the number of matching entities that have all of the flags set.
+    pub fn count<'a, P, Tuple>(&self) -> usize
+    where
+        Q: QueryAPI<'a, P, Tuple>,
+        Tuple: QueryTuple,
+    {
+        let mut count = 0;
+        self.each_entity(|_, _| count += 1);
+        count
+    }
+}
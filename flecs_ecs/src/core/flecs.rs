@@ -242,7 +242,11 @@ create_pre_registered_component!(Disabled, ECS_DISABLED);
 create_pre_registered_component!(NotQueryable, ECS_NOT_QUERYABLE);
 create_pre_registered_component!(SlotOf, ECS_SLOT_OF);
 create_pre_registered_component!(Flag, ECS_FLAG);
-create_pre_registered_component!(Monitor, ECS_MONITOR);
+create_pre_registered_component!(
+    Monitor,
+    ECS_MONITOR,
+    "Event. Observers with this event are invoked with `OnAdd` when an entity starts to match the query, and with `OnRemove` when it stops matching."
+);
 create_pre_registered_component!(Empty, ECS_EMPTY);
 
 // Component traits
@@ -383,8 +387,16 @@ create_pre_registered_component!(
     ECS_ON_SET,
     "Event. Invoked whenever a component is assigned a new value."
 );
-create_pre_registered_component!(OnTableCreate, ECS_ON_TABLE_CREATE);
-create_pre_registered_component!(OnTableDelete, ECS_ON_TABLE_DELETE);
+create_pre_registered_component!(
+    OnTableCreate,
+    ECS_ON_TABLE_CREATE,
+    "Event. Invoked when a table is created. The iterator has the table instead of entities."
+);
+create_pre_registered_component!(
+    OnTableDelete,
+    ECS_ON_TABLE_DELETE,
+    "Event. Invoked when a table is deleted. The iterator has the table instead of entities."
+);
 
 // System
 #[cfg(feature = "flecs_system")]
@@ -255,19 +255,10 @@ where
         if component_ptr.is_null() {
             components[0] = std::ptr::null_mut();
             has_all_components = false;
-            if SHOULD_PANIC && !A::IS_OPTION {
-                ecs_assert!(false, FlecsErrorCode::OperationFailed,
-"Component `{}` not found on `EntityView::get` operation 
-with parameters: `{}`. 
-Use `try_get` variant to avoid assert/panicking if you want to handle the error 
-or use `Option<{}> instead to handle individual cases.",
-std::any::type_name::<A::OnlyType>(), std::any::type_name::<Self>(), std::any::type_name::<A::ActualType<'a>>());
-panic!("Component `{}` not found on `EntityView::get` operation 
-with parameters: `{}`. 
-Use `try_get` variant to avoid assert/panicking if 
-you want to handle the error or use `Option<{}> 
-instead to handle individual cases.",
-std::any::type_name::<A::OnlyType>(), std::any::type_name::<Self>(), std::any::type_name::<A::ActualType<'a>>());
+            if !A::IS_OPTION {
+                component_miss::<SHOULD_PANIC>(world, entity.into(), "get",
+                    std::any::type_name::<A::OnlyType>(), std::any::type_name::<Self>(),
+                    std::any::type_name::<A::ActualType<'a>>());
                 }
             } else { 
                 components[0] = component_ptr;
@@ -346,9 +337,11 @@ macro_rules! impl_get_tuple {
                 let entity = *entity;
                 let mut index : usize = 0;
                 let mut has_all_components = true;
+                let mut ids = [0 as sys::ecs_id_t; tuple_count!($($t),*)];
 
                 $(
                     let id = <$t::OnlyType as ComponentOrPairId>::get_id(world_ref);
+                    ids[index] = id;
 
                     if <$t::OnlyType as ComponentOrPairId>::IS_PAIR {
                         ecs_assert!(
@@ -416,26 +409,27 @@ macro_rules! impl_get_tuple {
                     } else {
                         components[index] = std::ptr::null_mut();
                         if !$t::IS_OPTION {
-                            if SHOULD_PANIC {
-                                ecs_assert!(false, FlecsErrorCode::OperationFailed,
-"Component `{}` not found on `EntityView::get` operation 
-with parameters: `{}`. 
-Use `try_get` variant to avoid assert/panicking if you want to handle 
-the error or use `Option<{}> instead to handle individual cases.",
-std::any::type_name::<$t::OnlyType>(), std::any::type_name::<Self>(),
-std::any::type_name::<$t::ActualType<'a>>());
-panic!("Component `{}` not found on `EntityView::get`operation 
-with parameters: `{}`. 
-Use `try_get` variant to avoid assert/panicking if you want to handle the error 
-or use `Option<{}> instead to handle individual cases.", std::any::type_name::<$t::OnlyType>(),
-std::any::type_name::<Self>(), std::any::type_name::<$t::ActualType<'a>>());
-                            }
+                            component_miss::<SHOULD_PANIC>(world_ref, entity.into(), "get",
+                                std::any::type_name::<$t::OnlyType>(), std::any::type_name::<Self>(),
+                                std::any::type_name::<$t::ActualType<'a>>());
                             has_all_components = false;
                         }
                     }
                     index += 1;
                 )*
 
+                // a mutable term must not alias another term of the same id, this is checked in
+                // release builds too since aliasing `&mut` references is undefined behavior
+                if !Self::ALL_IMMUTABLE {
+                    for (i, id) in ids.iter().enumerate() {
+                        assert!(
+                            !ids[i + 1..].contains(id),
+                            "`{}` requests the same component more than once with mutable access",
+                            std::any::type_name::<Self>()
+                        );
+                    }
+                }
+
                 has_all_components
             }
 
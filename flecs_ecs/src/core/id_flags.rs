@@ -0,0 +1,135 @@
+//! The flags stored in the upper bits of an [`Id`].
+
+use std::fmt::{Debug, Formatter};
+use std::ops::{BitAnd, BitOr, BitOrAssign};
+
+use crate::core::*;
+
+/// The flags of an id, such as [`IdFlags::PAIR`] or [`IdFlags::TOGGLE`].
+///
+/// Returned by [`IdOperations::flags()`], and can be added to an id with
+/// [`IdOperations::add_flags()`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+///
+/// let id = IdView::new_from_id(&world, world.component_id::<Position>())
+///     .add_flags(IdFlags::AUTO_OVERRIDE | IdFlags::TOGGLE);
+///
+/// assert!(id.flags().contains(IdFlags::TOGGLE));
+/// assert!(id.has_flags(IdFlags::AUTO_OVERRIDE));
+/// assert!(!id.has_flags(IdFlags::PAIR));
+/// assert!(id.remove_flags().flags().is_empty());
+/// ```
+#[derive(Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct IdFlags(u64);
+
+impl IdFlags {
+    /// The id is a pair.
+    pub const PAIR: Self = Self(ECS_PAIR);
+    /// The component is overridden when it is inherited.
+    pub const AUTO_OVERRIDE: Self = Self(ECS_AUTO_OVERRIDE);
+    /// The component can be enabled and disabled.
+    pub const TOGGLE: Self = Self(ECS_TOGGLE);
+    /// All components of the entity are included.
+    pub const AND: Self = Self(ECS_AND);
+
+    const NAMED: [(Self, &'static str); 4] = [
+        (Self::PAIR, "PAIR"),
+        (Self::AUTO_OVERRIDE, "AUTO_OVERRIDE"),
+        (Self::TOGGLE, "TOGGLE"),
+        (Self::AND, "AND"),
+    ];
+
+    /// Returns the flags of `id`, ignoring all other bits.
+    #[inline]
+    pub fn from_id(id: impl IntoId) -> Self {
+        Self(*id.into() & RUST_ecs_id_FLAGS_MASK)
+    }
+
+    /// Returns an empty set of flags.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the raw bits of the flags.
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `true` if no flags are set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Debug for IdFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut set = f.debug_set();
+        for (flag, name) in Self::NAMED {
+            if self.contains(flag) {
+                set.entry(&format_args!("{name}"));
+            }
+        }
+        let unknown = self.0 & !Self::NAMED.iter().fold(0, |acc, (flag, _)| acc | flag.0);
+        if unknown != 0 {
+            set.entry(&format_args!("{unknown:#x}"));
+        }
+        set.finish()
+    }
+}
+
+impl BitOr for IdFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for IdFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for IdFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl From<IdFlags> for Id {
+    #[inline]
+    fn from(flags: IdFlags) -> Self {
+        Id(flags.0)
+    }
+}
+
+impl IntoId for IdFlags {
+    const IS_PAIR: bool = false;
+}
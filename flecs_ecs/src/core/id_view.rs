@@ -270,7 +270,7 @@ impl<'a> IdView<'a> {
     #[inline(always)]
     pub fn entity_view(self) -> EntityView<'a> {
         ecs_assert!(!self.is_pair(), FlecsErrorCode::InvalidOperation);
-        ecs_assert!(self.flags().id == 0, FlecsErrorCode::InvalidOperation);
+        ecs_assert!(self.flags().is_empty(), FlecsErrorCode::InvalidOperation);
 
         EntityView::new_from(self.world, Entity(*self.id))
     }
@@ -283,13 +283,66 @@ impl<'a> IdView<'a> {
     #[doc(alias = "id::entity")]
     #[inline(always)]
     pub fn get_entity_view(self) -> Option<EntityView<'a>> {
-        if self.is_pair() || self.flags().id != 0 {
+        if self.is_pair() || !self.flags().is_empty() {
             None
         } else {
             Some(EntityView::new_from(self.world, Entity(*self.id)))
         }
     }
 
+    /// Convert the id to an entity, if it is one.
+    ///
+    /// Returns `None` for pairs, for ids with flags such as [`flecs::id_flags::Toggle`],
+    /// for the wildcard entities `*` and `_`, and for ids that aren't alive in the world.
+    /// The generation of the id has to match the generation of the alive entity, so an id
+    /// of a deleted entity doesn't convert to an entity that recycled its index.
+    ///
+    /// # See also
+    ///
+    /// * [`IdView::entity_view()`]
+    /// * [`IdView::try_pair()`]
+    pub fn try_into_entity(self) -> Option<EntityView<'a>> {
+        if *self.id == 0 || self.has_any_flags() || self.is_wildcard() {
+            return None;
+        }
+
+        if !unsafe { sys::ecs_is_alive(self.world.world_ptr(), *self.id) } {
+            return None;
+        }
+
+        Some(EntityView::new_from(self.world, Entity(*self.id)))
+    }
+
+    /// Split a pair into its first and second element.
+    ///
+    /// Returns `None` if the id isn't a pair, if it has flags besides the pair flag, if
+    /// either element is a wildcard, or if either element isn't alive. Pairs only store the
+    /// lower 32 bits of their elements, so the returned entities have the generation of the
+    /// alive entities in the world.
+    ///
+    /// # See also
+    ///
+    /// * [`IdView::get_first_id()`]
+    /// * [`IdView::get_second_id()`]
+    /// * [`IdView::try_into_entity()`]
+    pub fn try_pair(self) -> Option<(EntityView<'a>, EntityView<'a>)> {
+        if *self.id & RUST_ecs_id_FLAGS_MASK != ECS_PAIR || self.is_wildcard() {
+            return None;
+        }
+
+        let world = self.world.world_ptr();
+        let first = unsafe { sys::ecs_get_alive(world, *ecs_first(self.id)) };
+        let second = unsafe { sys::ecs_get_alive(world, *ecs_second(self.id)) };
+        if first == 0 || second == 0 {
+            return None;
+        }
+
+        Some((
+            EntityView::new_from(self.world, first),
+            EntityView::new_from(self.world, second),
+        ))
+    }
+
     /// Get the component type for the id.
     ///
     /// This operation returns the component id for an id,
@@ -396,7 +449,7 @@ impl<'a> IdOperations<'a> for IdView<'a> {
     /// * C++ API: `Id::Id`
     #[doc(alias = "Id::Id")]
     fn new_from_str(world: impl WorldProvider<'a>, expr: &str) -> Self {
-        let expr = compact_str::format_compact!("{}\0", expr);
+        let expr = SmallCStr::<128>::from_arg(expr);
         let id = unsafe { sys::ecs_id_from_str(world.world_ptr(), expr.as_ptr() as *const _) };
         Self {
             world: world.world(),
@@ -0,0 +1,170 @@
+//! Groups of entities that are deleted together.
+
+use flecs_ecs_derive::Component;
+
+use crate::core::*;
+
+/// Relationship that ties the lifetime of an entity to the root of a [`LifetimeScope`].
+///
+/// The relationship has the `(OnDeleteTarget, Delete)` trait, so deleting the target deletes
+/// all entities that are owned by it. It is exclusive, an entity is owned by at most one
+/// scope.
+#[derive(Component)]
+#[flecs(no_collect)]
+#[on_registration]
+pub struct OwnedBy;
+
+impl OnComponentRegistration for OwnedBy {
+    fn on_component_registration(world: WorldRef, component_id: Entity) {
+        // the traits are added however the relationship is registered first
+        world
+            .component_untyped_from_id(component_id)
+            .add_trait::<(flecs::OnDeleteTarget, flecs::Delete)>()
+            .add_trait::<flecs::Exclusive>();
+    }
+}
+
+/// A group of entities that are deleted together when the scope is dropped or closed.
+///
+/// Entities created through the scope are owned by its root entity with the [`OwnedBy`]
+/// relationship. Closing the scope deletes the root, which deletes all entities owned by it,
+/// including the entities of nested scopes. Because ownership is a separate relationship,
+/// entities of a scope can still have a parent.
+///
+/// Created by [`World::lifetime_scope()`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+///
+/// let outside = world.entity();
+///
+/// let explosion = world.lifetime_scope();
+/// let particles = (0..10).map(|_| explosion.entity().id()).collect::<Vec<_>>();
+/// drop(explosion);
+///
+/// assert!(particles.iter().all(|&e| !world.is_alive(e)));
+/// assert!(outside.is_alive());
+/// ```
+///
+/// # See also
+///
+/// * [`World::lifetime_scope()`]
+/// * [`OwnedBy`]
+#[must_use = "the entities of the scope are deleted when it is dropped"]
+pub struct LifetimeScope<'a> {
+    root: EntityView<'a>,
+}
+
+impl<'a> LifetimeScope<'a> {
+    fn new(root: EntityView<'a>) -> Self {
+        Self { root }
+    }
+
+    /// Returns the root entity of the scope, which owns all entities of the scope.
+    pub fn root(&self) -> EntityView<'a> {
+        self.root
+    }
+
+    /// Create an entity that is deleted together with the scope.
+    ///
+    /// # See also
+    ///
+    /// * [`World::entity()`]
+    /// * [`LifetimeScope::entity_named()`]
+    pub fn entity(&self) -> EntityView<'a> {
+        self.own(EntityView::new(self.root.world))
+    }
+
+    /// Create a named entity that is deleted together with the scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the entity.
+    ///
+    /// # See also
+    ///
+    /// * [`World::entity_named()`]
+    /// * [`LifetimeScope::entity()`]
+    pub fn entity_named(&self, name: &str) -> EntityView<'a> {
+        self.own(EntityView::new_named(self.root.world, name))
+    }
+
+    /// Tie the lifetime of an existing entity to the scope.
+    ///
+    /// The entity is removed from the scope it was owned by before, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to delete together with the scope.
+    pub fn own(&self, entity: EntityView<'a>) -> EntityView<'a> {
+        entity.add_id((OwnedBy::id(self.root.world), self.root.id()))
+    }
+
+    /// Create a nested scope, whose entities are deleted when either the nested scope or
+    /// this scope is closed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let screen = world.lifetime_scope();
+    /// let popup = screen.lifetime_scope();
+    /// let button = popup.entity();
+    ///
+    /// screen.close();
+    ///
+    /// assert!(!button.is_alive());
+    /// ```
+    pub fn lifetime_scope(&self) -> LifetimeScope<'a> {
+        LifetimeScope::new(self.entity())
+    }
+
+    /// Delete all entities of the scope, including the entities of nested scopes.
+    ///
+    /// This is the same as dropping the scope.
+    pub fn close(self) {}
+}
+
+impl Drop for LifetimeScope<'_> {
+    fn drop(&mut self) {
+        // the root is gone when an outer scope was closed first
+        if self.root.is_alive() {
+            self.root.destruct();
+        }
+    }
+}
+
+impl World {
+    /// Create a scope for entities that should be deleted together, such as the particles of
+    /// an explosion or the widgets of a screen.
+    ///
+    /// Entities created through the scope are deleted when the scope is dropped or closed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let scope = world.lifetime_scope();
+    /// let popup = scope.entity_named("popup");
+    /// scope.close();
+    ///
+    /// assert!(!popup.is_alive());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`LifetimeScope`]
+    pub fn lifetime_scope(&self) -> LifetimeScope<'_> {
+        LifetimeScope::new(self.entity())
+    }
+}
@@ -0,0 +1,231 @@
+//! Hooks that are invoked after deferred commands are merged into the world.
+
+use crate::core::*;
+use crate::sys;
+
+/// Statistics about the commands that were applied by one or more merges.
+///
+/// The values are the difference of the world info (see [`World::info()`]) before and
+/// after the merges took place. Hooks are invoked once per call to [`World::progress()`],
+/// [`World::run_pipeline()`] or [`World::merge()`], so the statistics of a frame with
+/// multiple sync points are aggregated, and [`MergeInfo::merge_count`] is the number of
+/// sync points.
+///
+/// # See also
+///
+/// * [`World::on_merge()`]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MergeInfo {
+    /// Number of merges (sync points) that happened.
+    pub merge_count: i64,
+    /// Add commands applied.
+    pub add_count: i64,
+    /// Remove commands applied.
+    pub remove_count: i64,
+    /// Set commands applied.
+    pub set_count: i64,
+    /// Delete commands applied.
+    pub delete_count: i64,
+    /// Clear commands applied.
+    pub clear_count: i64,
+    /// Ensure/emplace commands applied.
+    pub ensure_count: i64,
+    /// Modified commands applied.
+    pub modified_count: i64,
+    /// Commands discarded, happens when entity is no longer alive when running the command.
+    pub discard_count: i64,
+    /// Enqueued custom events.
+    pub event_count: i64,
+    /// Other commands applied.
+    pub other_count: i64,
+    /// Time spent merging, in seconds.
+    pub merge_time: FTime,
+}
+
+impl MergeInfo {
+    fn from_delta(before: &sys::WorldInfo, after: &sys::WorldInfo) -> Self {
+        Self {
+            merge_count: after.merge_count_total - before.merge_count_total,
+            add_count: after.cmd.add_count - before.cmd.add_count,
+            remove_count: after.cmd.remove_count - before.cmd.remove_count,
+            set_count: after.cmd.set_count - before.cmd.set_count,
+            delete_count: after.cmd.delete_count - before.cmd.delete_count,
+            clear_count: after.cmd.clear_count - before.cmd.clear_count,
+            ensure_count: after.cmd.ensure_count - before.cmd.ensure_count,
+            modified_count: after.cmd.modified_count - before.cmd.modified_count,
+            discard_count: after.cmd.discard_count - before.cmd.discard_count,
+            event_count: after.cmd.event_count - before.cmd.event_count,
+            other_count: after.cmd.other_count - before.cmd.other_count,
+            merge_time: after.merge_time_total - before.merge_time_total,
+        }
+    }
+}
+
+/// Handle to a hook registered with [`World::on_merge()`].
+///
+/// Pass it to [`World::remove_on_merge()`] to unregister the hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OnMergeHandle(u64);
+
+pub(crate) type MergeHook = Box<dyn FnMut(MergeInfo)>;
+
+#[derive(Default)]
+pub(crate) struct MergeHooks {
+    next_id: u64,
+    hooks: Vec<(u64, MergeHook)>,
+    /// Ids of the hooks that are being invoked, which are not in `hooks`.
+    invoking: Vec<u64>,
+    /// Hooks that were removed while they were being invoked.
+    removed: Vec<u64>,
+}
+
+/// Puts the hooks that are being invoked back into the world, also when a hook panics.
+struct RestoreHooks<'a> {
+    world: &'a World,
+    hooks: Vec<(u64, MergeHook)>,
+}
+
+impl Drop for RestoreHooks<'_> {
+    fn drop(&mut self) {
+        let merge_hooks = &mut self.world.world_ctx_mut().merge_hooks;
+        let removed = std::mem::take(&mut merge_hooks.removed);
+        merge_hooks.invoking.clear();
+
+        let mut hooks = std::mem::take(&mut self.hooks);
+        hooks.retain(|(id, _)| !removed.contains(id));
+        // hooks registered by the invoked hooks run after the existing ones
+        hooks.append(&mut merge_hooks.hooks);
+        merge_hooks.hooks = hooks;
+    }
+}
+
+impl World {
+    /// Register a function that is invoked after deferred commands are merged.
+    ///
+    /// The function is invoked after [`World::progress()`], [`World::run_pipeline()`] and
+    /// [`World::merge()`] when at least one merge happened. It receives the commands
+    /// that were applied and the time spent merging. When a frame has multiple sync
+    /// points, the function is invoked once with the combined statistics, see
+    /// [`MergeInfo::merge_count`].
+    ///
+    /// Registering a hook enables frame time measurement, which is required to
+    /// measure the merge time.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function to invoke.
+    ///
+    /// # Returns
+    ///
+    /// A handle that can be passed to [`World::remove_on_merge()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    ///
+    /// let handle = world.on_merge(|info| {
+    ///     assert_eq!(info.set_count, 1);
+    /// });
+    ///
+    /// let stage = world.create_async_stage();
+    /// e.mut_current_stage(&stage).set(Position { x: 1.0, y: 2.0 });
+    /// stage.merge();
+    ///
+    /// assert!(world.remove_on_merge(handle));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::remove_on_merge()`]
+    /// * [`World::info()`]
+    pub fn on_merge(&self, func: impl FnMut(MergeInfo) + 'static) -> OnMergeHandle {
+        unsafe { sys::ecs_measure_frame_time(self.get_world().world_ptr_mut(), true) };
+
+        let merge_hooks = &mut self.world_ctx_mut().merge_hooks;
+        let id = merge_hooks.next_id;
+        merge_hooks.next_id += 1;
+        merge_hooks.hooks.push((id, Box::new(func)));
+        OnMergeHandle(id)
+    }
+
+    /// Unregister a function registered with [`World::on_merge()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle returned by [`World::on_merge()`].
+    ///
+    /// # Returns
+    ///
+    /// True if the hook was registered, false otherwise.
+    ///
+    /// # See also
+    ///
+    /// * [`World::on_merge()`]
+    pub fn remove_on_merge(&self, handle: OnMergeHandle) -> bool {
+        let merge_hooks = &mut self.world_ctx_mut().merge_hooks;
+
+        // the hooks that are being invoked are removed once they're done
+        if merge_hooks.invoking.contains(&handle.0) {
+            if merge_hooks.removed.contains(&handle.0) {
+                return false;
+            }
+            merge_hooks.removed.push(handle.0);
+            return true;
+        }
+
+        let len = merge_hooks.hooks.len();
+        merge_hooks.hooks.retain(|(id, _)| *id != handle.0);
+        merge_hooks.hooks.len() != len
+    }
+
+    /// Returns the world info when merge hooks are registered, to pass to
+    /// [`World::invoke_merge_hooks()`] once the merges are done.
+    pub(crate) fn merge_hooks_begin(&self) -> Option<sys::WorldInfo> {
+        if self.world_ctx().merge_hooks.hooks.is_empty() {
+            None
+        } else {
+            Some(self.info())
+        }
+    }
+
+    pub(crate) fn invoke_merge_hooks(&self, before: Option<sys::WorldInfo>) {
+        let Some(before) = before else {
+            return;
+        };
+
+        let info = MergeInfo::from_delta(&before, &self.info());
+        if info.merge_count == 0 {
+            return;
+        }
+
+        // take the hooks out, so hooks can (un)register hooks without aliasing the list.
+        let merge_hooks = &mut self.world_ctx_mut().merge_hooks;
+        // merges done by the hooks themselves are part of the merge that invoked them
+        if !merge_hooks.invoking.is_empty() {
+            return;
+        }
+        let mut restore = RestoreHooks {
+            world: self,
+            hooks: std::mem::take(&mut merge_hooks.hooks),
+        };
+        merge_hooks.invoking = restore.hooks.iter().map(|(id, _)| *id).collect();
+
+        for (id, hook) in restore.hooks.iter_mut() {
+            // a hook removed by a previous hook of this merge isn't invoked anymore
+            if !self.world_ctx().merge_hooks.removed.contains(id) {
+                hook(info);
+            }
+        }
+    }
+}
@@ -1,35 +1,53 @@
 mod archetype;
 pub mod builder;
 pub mod c_types;
+#[cfg(feature = "churn_debug")]
+mod churn;
 pub(crate) mod cloned_tuple;
+mod commands;
+mod component_miss;
 pub mod component_registration;
 mod components;
 pub mod ecs_os_api;
 mod entity;
 mod entity_view;
 mod event;
+mod event_propagation;
+mod flags;
 pub mod flecs;
 pub(crate) mod get_tuple;
 mod id;
+mod id_flags;
 mod id_view;
+mod lifetime_scope;
+mod merge_hooks;
 mod observer;
 mod observer_builder;
+mod pair_index;
 mod query;
 pub mod query_builder;
 mod query_iter;
+mod query_rows;
 pub(crate) mod query_tuple;
+pub(crate) mod set_tuple;
+mod stage;
 pub mod table;
 pub mod term;
 pub mod utility;
 mod world;
+mod world_copy;
 pub(crate) mod world_ctx;
 
-pub use archetype::Archetype;
+pub use archetype::{Archetype, ArchetypeIter};
 #[doc(hidden)]
 pub use builder::*;
 #[doc(hidden)]
 pub use c_types::*;
+#[cfg(feature = "churn_debug")]
+pub(crate) use churn::ChurnCounts;
 pub(crate) use cloned_tuple::*;
+pub use commands::Commands;
+pub(crate) use component_miss::*;
 #[doc(hidden)]
 pub use component_registration::*;
 #[doc(inline)]
@@ -38,17 +56,28 @@ pub use entity::Entity;
 pub use entity_view::EntityView;
 pub use entity_view::EntityViewGet;
 pub use event::EventBuilder;
+pub use flags::{FlagSet, Flags, WithFlag};
 pub(crate) use get_tuple::*;
 pub use id::Id;
+pub use id_flags::IdFlags;
 pub use id_view::IdView;
+pub use lifetime_scope::{LifetimeScope, OwnedBy};
+pub(crate) use merge_hooks::MergeHooks;
+pub use merge_hooks::{MergeInfo, OnMergeHandle};
 pub use observer::Observer;
 pub use observer_builder::ObserverBuilder;
+pub use pair_index::PairIndex;
+pub(crate) use pair_index::PairIndexData;
+pub(crate) use query::validate_query_desc;
 pub use query::Query;
 #[doc(hidden)]
 pub use query_builder::*;
 pub use query_iter::QueryIter;
+pub use query_rows::QueryRows;
 #[doc(hidden)]
 pub use query_tuple::*;
+pub(crate) use set_tuple::*;
+pub use stage::Stage;
 #[doc(hidden)]
 pub use table::*;
 #[doc(hidden)]
@@ -56,6 +85,7 @@ pub use term::*;
 #[doc(hidden)]
 pub use utility::*;
 pub(crate) use world::FlecsArray;
+pub use world::ReadonlyGuard;
 pub use world::World;
 pub use world::WorldGet;
 pub(crate) use world_ctx::*;
@@ -38,6 +38,19 @@ impl<'a> WorldProvider<'a> for Observer<'a> {
     }
 }
 
+impl std::fmt::Display for Observer<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.entity, f)
+    }
+}
+
+impl<'a> From<Observer<'a>> for EntityView<'a> {
+    #[inline]
+    fn from(observer: Observer<'a>) -> Self {
+        observer.entity
+    }
+}
+
 impl<'a> Observer<'a> {
     /// Create a new observer
     ///
@@ -50,7 +63,8 @@ impl<'a> Observer<'a> {
             if event == 0 {
                 break;
             }
-            if event == flecs::OnAdd::ID {
+            // a monitor is invoked with `OnAdd` when an entity starts to match
+            if event == flecs::OnAdd::ID || event == flecs::Monitor::ID {
                 for term in desc.query.terms {
                     if (term.first.id | term.id | term.second.id | term.src.id) == 0 {
                         break;
@@ -62,7 +76,7 @@ impl<'a> Observer<'a> {
                         panic!(
                             r#"
                             ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-                            Cannot create typed observers with the `OnAdd` event or when `InOut` is set to `In` or `InOut`. 
+                            Cannot create typed observer `{}` with the `OnAdd` or `Monitor` event or when `InOut` is set to `In` or `InOut`. 
                             This situation occurs when using `&` or `&mut` with `.with`.
                         
                             Accessing the uninitialized value of a component is undefined behavior in Rust.
@@ -86,12 +100,18 @@ impl<'a> Observer<'a> {
                             .observer::<flecs::OnAdd, ()>().with::<&mut T>()
                             ```
                             ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-                            "#
+                            "#,
+                            EntityView::new_from(world.world(), desc.entity)
                         );
                     }
                 }
             }
         }
+        // flecs can't recover from a query that fails to build for an observer
+        if let Err(error) = validate_query_desc(world.world_ptr_mut(), &desc.query) {
+            panic!("failed to create observer: {}", error);
+        }
+
         let id = unsafe { sys::ecs_observer_init(world.world_ptr_mut(), &desc) };
         let entity = EntityView::new_from(world.world(), id);
 
@@ -147,6 +167,40 @@ impl<'a> Observer<'a> {
         }
     }
 
+    /// Enable the observer, after it was disabled with [`Observer::disable()`].
+    ///
+    /// # See also
+    ///
+    /// * [`Observer::disable()`]
+    /// * C API: `ecs_enable`
+    #[doc(alias = "ecs_enable")]
+    pub fn enable(&self) {
+        unsafe { sys::ecs_enable(self.world.world_ptr_mut(), *self.id, true) }
+    }
+
+    /// Disable the observer, which adds [`flecs::Disabled`] to the observer entity.
+    /// A disabled observer is not invoked for events until it is enabled again.
+    ///
+    /// # See also
+    ///
+    /// * [`Observer::enable()`]
+    /// * C API: `ecs_enable`
+    #[doc(alias = "ecs_enable")]
+    pub fn disable(&self) {
+        unsafe { sys::ecs_enable(self.world.world_ptr_mut(), *self.id, false) }
+    }
+
+    /// Destruct the observer. The callbacks of the observer and the values they captured are
+    /// dropped.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_delete`
+    #[doc(alias = "ecs_delete")]
+    pub fn destruct(self) {
+        self.entity.destruct();
+    }
+
     /// Get the observer's entity
     ///
     /// # See also
@@ -157,3 +211,37 @@ impl<'a> Observer<'a> {
         self.entity
     }
 }
+
+impl<'a> EntityView<'a> {
+    /// Returns the entity as an observer, or `None` if the entity is not an observer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world
+    ///     .observer::<flecs::OnSet, &Position>()
+    ///     .each(|_| {})
+    ///     .entity();
+    ///
+    /// assert!(entity.try_as_observer().is_some());
+    /// assert!(world.entity().try_as_observer().is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::try_as_system()`]
+    pub fn try_as_observer(self) -> Option<Observer<'a>> {
+        let observer = unsafe { sys::ecs_observer_get(self.world_ptr(), *self.id) };
+        (!observer.is_null()).then(|| Observer::new_from_existing(self))
+    }
+}
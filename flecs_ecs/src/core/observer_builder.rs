@@ -1,6 +1,6 @@
 //! builder for [`Observer`].
 
-use std::{default, ffi::c_void};
+use std::ffi::c_void;
 
 use crate::core::internals::*;
 use crate::core::private::internal_SystemAPI;
@@ -33,21 +33,8 @@ impl<'a, P: ComponentId, T: QueryTuple> ObserverBuilder<'a, P, T> {
     /// * C++ API: `observer_builder::observer_builder`
     #[doc(alias = "observer_builder::observer_builder")]
     pub(crate) fn new(world: impl WorldProvider<'a>) -> Self {
-        let desc = Default::default();
-        let mut obj = Self {
-            desc,
-            term_builder: TermBuilder::default(),
-            event_count: 1,
-            world: world.world(),
-            _phantom: std::marker::PhantomData,
-        };
-
-        obj.desc.events[0] = P::UnderlyingType::id(world.world());
-        obj.desc.entity =
-            unsafe { sys::ecs_entity_init(world.world_ptr_mut(), &Default::default()) };
-
-        T::populate(&mut obj);
-        obj
+        let entity = unsafe { sys::ecs_entity_init(world.world_ptr_mut(), &Default::default()) };
+        Self::new_w_event(world, entity)
     }
 
     /// Create a new observer builder with a name
@@ -62,26 +49,33 @@ impl<'a, P: ComponentId, T: QueryTuple> ObserverBuilder<'a, P, T> {
     /// * C++ API: `node_builder::node_builder`
     #[doc(alias = "node_builder::node_builder")]
     pub fn new_named(world: impl WorldProvider<'a>, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
-        let desc = Default::default();
+        let entity_desc = sys::ecs_entity_desc_t {
+            name: name.as_ptr() as *const _,
+            sep: SEPARATOR.as_ptr(),
+            root_sep: SEPARATOR.as_ptr(),
+            ..Default::default()
+        };
+        let entity = unsafe { sys::ecs_entity_init(world.world_ptr_mut(), &entity_desc) };
+        Self::new_w_event(world, entity)
+    }
+
+    /// Create the builder for the observer entity, with `P` as the first event.
+    fn new_w_event(world: impl WorldProvider<'a>, entity: sys::ecs_entity_t) -> Self {
         let mut obj = Self {
-            desc,
+            desc: Default::default(),
             term_builder: TermBuilder::default(),
             event_count: 1,
             world: world.world(),
             _phantom: std::marker::PhantomData,
         };
-        let entity_desc: sys::ecs_entity_desc_t = sys::ecs_entity_desc_t {
-            name: name.as_ptr() as *const _,
-            sep: SEPARATOR.as_ptr(),
-            root_sep: SEPARATOR.as_ptr(),
-            ..default::Default::default()
-        };
 
-        obj.desc.events[0] = P::id(world.world());
-        obj.desc.entity = unsafe { sys::ecs_entity_init(obj.world_ptr_mut(), &entity_desc) };
+        obj.desc.entity = entity;
+        obj.desc.events[0] = P::UnderlyingType::id(world.world());
 
+        // the terms are written into `desc.query` of the builder, which is passed as is to
+        // `ecs_observer_init` when the observer is built
         T::populate(&mut obj);
         obj
     }
@@ -89,9 +83,8 @@ impl<'a, P: ComponentId, T: QueryTuple> ObserverBuilder<'a, P, T> {
 
 impl<'a, P, T: QueryTuple> ObserverBuilder<'a, P, T> {
     pub(crate) fn new_untyped(world: impl WorldProvider<'a>) -> ObserverBuilder<'a, (), T> {
-        let desc = Default::default();
         let mut obj = ObserverBuilder {
-            desc,
+            desc: Default::default(),
             term_builder: TermBuilder::default(),
             event_count: 0,
             world: world.world(),
@@ -195,9 +188,9 @@ impl<P, T: QueryTuple> ObserverBuilder<'_, P, T> {
 
     /// Invoke observer for anything that matches its query on creation
     ///
-    /// # Arguments
-    ///
-    /// * `should_yield` - If true, the observer will be invoked for all existing entities that match its query
+    /// The existing entities are passed to the callback the same way as entities of live events,
+    /// once for each event of the observer (except `OnRemove`), with [`TableIter::event()`]
+    /// set to that event.
     ///
     /// # See also
     ///
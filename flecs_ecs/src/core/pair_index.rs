@@ -0,0 +1,195 @@
+//! Index of the entities that have a relationship, keyed by the relationship target.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::core::*;
+
+/// Entities that have `(relationship, target)`, per target.
+pub(crate) type PairIndexData = HashMap<u64, PairMembers>;
+
+/// The entities that have a relationship with a single target.
+#[derive(Default)]
+pub(crate) struct PairMembers {
+    entities: Vec<Entity>,
+    /// Position of each member in `entities`, so members are removed in constant time.
+    slots: HashMap<u64, usize>,
+}
+
+impl PairMembers {
+    fn insert(&mut self, entity: Entity) {
+        if let Entry::Vacant(slot) = self.slots.entry(*entity) {
+            slot.insert(self.entities.len());
+            self.entities.push(entity);
+        }
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(slot) = self.slots.remove(&*entity) {
+            self.entities.swap_remove(slot);
+            if let Some(&moved) = self.entities.get(slot) {
+                self.slots.insert(*moved, slot);
+            }
+        }
+    }
+}
+
+/// Maintained index of the entities that have a relationship, keyed by the relationship target.
+///
+/// The index is kept current by an `OnAdd` and an `OnRemove` observer for
+/// `(relationship, *)`, so looking up the members of a target doesn't need a wildcard query.
+/// When operations are deferred the index is updated when the commands are merged. The
+/// list of a target is removed when its last member is removed, which includes the target
+/// or the members being deleted.
+///
+/// Created by [`World::pair_index()`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct MemberOf;
+///
+/// let world = World::new();
+///
+/// let red = world.entity();
+/// let alice = world.entity().add_first::<MemberOf>(red);
+/// let bob = world.entity().add_first::<MemberOf>(red);
+///
+/// let teams = world.pair_index::<MemberOf>();
+/// teams.members_of(red, |members| assert_eq!(members, &[alice.id(), bob.id()]));
+/// ```
+///
+/// # See also
+///
+/// * [`World::pair_index()`]
+pub struct PairIndex<'a> {
+    world: WorldRef<'a>,
+    relationship: Entity,
+}
+
+impl<'a> PairIndex<'a> {
+    /// Returns the relationship of the index.
+    pub fn relationship(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.relationship)
+    }
+
+    /// Invoke `func` with the entities that have the relationship with `target`.
+    ///
+    /// The index changes whenever the relationship is added or removed, so the world is
+    /// deferred while `func` runs. Changes that `func` makes are applied, and update the index,
+    /// when it returns. The order of the members is not stable, removing a member moves the
+    /// last member in its place.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The target of the relationship.
+    /// * `func` - The function that receives the members, empty when the target has none.
+    pub fn members_of<T>(&self, target: impl Into<Entity>, func: impl FnOnce(&[Entity]) -> T) -> T {
+        let target = *target.into();
+        self.world.world().defer(|| {
+            let members = self.data().get(&target);
+            func(members.map_or(&[], |members| &members.entities))
+        })
+    }
+
+    /// Returns whether any entity has the relationship with `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The target of the relationship.
+    pub fn contains(&self, target: impl Into<Entity>) -> bool {
+        self.data().contains_key(&*target.into())
+    }
+
+    /// Returns the number of targets that have at least one member.
+    pub fn target_count(&self) -> usize {
+        self.data().len()
+    }
+
+    fn data(&self) -> &PairIndexData {
+        &self.world.world_ctx().pair_indices[&*self.relationship]
+    }
+}
+
+impl World {
+    /// Returns an index of the entities that have `(R, target)`, keyed by the target.
+    ///
+    /// The first call for a relationship creates the observers that maintain the index and
+    /// adds the entities that already have the relationship. Later calls return the same
+    /// index.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `R` - The relationship.
+    ///
+    /// # See also
+    ///
+    /// * [`PairIndex`]
+    pub fn pair_index<R: ComponentId>(&self) -> PairIndex<'_> {
+        self.pair_index_id(R::id(self))
+    }
+
+    /// Returns an index of the entities that have `(relationship, target)`, keyed by the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `relationship` - The relationship.
+    ///
+    /// # See also
+    ///
+    /// * [`World::pair_index()`]
+    /// * [`PairIndex`]
+    pub fn pair_index_id(&self, relationship: impl Into<Entity>) -> PairIndex<'_> {
+        let relationship = relationship.into();
+
+        if !self.world_ctx().pair_indices.contains_key(&*relationship) {
+            self.world_ctx_mut()
+                .pair_indices
+                .insert(*relationship, PairIndexData::default());
+
+            let pair = ecs_pair(*relationship, flecs::Wildcard::ID);
+
+            self.observer::<flecs::OnAdd, ()>()
+                .with_id(pair)
+                .yield_existing()
+                .each_iter(move |it, row, _| {
+                    let target = *it.pair(0).unwrap().second_id().id();
+                    it.real_world()
+                        .pair_index_data_mut(relationship)
+                        .entry(target)
+                        .or_default()
+                        .insert(it.entity(row).id());
+                });
+
+            self.observer::<flecs::OnRemove, ()>()
+                .with_id(pair)
+                .each_iter(move |it, row, _| {
+                    let target = *it.pair(0).unwrap().second_id().id();
+                    let entity = it.entity(row).id();
+                    let world = it.real_world();
+                    let data = world.pair_index_data_mut(relationship);
+                    if let Some(members) = data.get_mut(&target) {
+                        members.remove(entity);
+                        if members.entities.is_empty() {
+                            data.remove(&target);
+                        }
+                    }
+                });
+        }
+
+        PairIndex {
+            world: self.world(),
+            relationship,
+        }
+    }
+
+    fn pair_index_data_mut(&self, relationship: Entity) -> &mut PairIndexData {
+        self.world_ctx_mut()
+            .pair_indices
+            .entry(*relationship)
+            .or_default()
+    }
+}
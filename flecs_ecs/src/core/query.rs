@@ -55,6 +55,10 @@ where
     pub(crate) query: NonNull<sys::ecs_query_t>,
     // this is a leaked box, which is valid during the lifecycle of the query object.
     world_ctx: NonNull<WorldCtx>,
+    // copied from the query, so they can be read after the query entity was deleted,
+    // which frees the query.
+    world: *mut sys::ecs_world_t,
+    pub(crate) entity: sys::ecs_entity_t,
     _phantom: PhantomData<T>,
 }
 
@@ -63,7 +67,7 @@ where
     T: QueryTuple,
 {
     fn clone(&self) -> Self {
-        unsafe { Query::<T>::new_from(self.query) }
+        unsafe { Query::<T>::new_from(NonNull::new_unchecked(self.ptr())) }
     }
 }
 
@@ -83,18 +87,23 @@ where
             // fn [`destruct`](crate::core::query::destruct) does not decrease the ref count, because it still calls drop.
             self.world().world_ctx_mut().dec_query_ref_count();
 
+            // the query was freed together with its entity
+            if !self.is_alive() {
+                return;
+            }
+
             // Only free if query is not associated with entity. Queries are associated with entities
             // when they are either named or cached, such as system, cached queries and named queries. These queries have to be either explicitly
             // deleted with the .destruct() method, or will be deleted when the
             // world is deleted.
-            if self.query.as_ref().entity == 0 {
+            if self.entity == 0 {
                 if sys::flecs_poly_release_(self.query.as_ptr() as *mut c_void) == 0 {
                     sys::ecs_query_fini(self.query.as_ptr());
                 }
             }
             // we need to free a poly if the refcount is bigger than 1, this happens when the query is cloned
             else {
-                let header = self.query_ptr() as *const sys::ecs_header_t;
+                let header = self.query.as_ptr() as *const sys::ecs_header_t;
                 let ref_count_bigger_than_1 = (*header).refcount > 1;
                 if ref_count_bigger_than_1 {
                     sys::flecs_poly_release_(self.query.as_ptr() as *mut c_void);
@@ -110,12 +119,12 @@ where
 {
     #[inline(always)]
     fn retrieve_iter(&self) -> sys::ecs_iter_t {
-        unsafe { sys::ecs_query_iter(self.world_ptr(), self.query.as_ptr()) }
+        unsafe { sys::ecs_query_iter(self.world_ptr(), self.ptr()) }
     }
 
     #[inline(always)]
     fn retrieve_iter_stage<'a>(&self, stage: impl WorldProvider<'a>) -> sys::ecs_iter_t {
-        unsafe { sys::ecs_query_iter(stage.world_ptr(), self.query.as_ptr()) }
+        unsafe { sys::ecs_query_iter(stage.world_ptr(), self.ptr()) }
     }
 
     #[inline(always)]
@@ -124,7 +133,7 @@ where
     }
 
     fn query_ptr(&self) -> *const sys::ecs_query_t {
-        self.query.as_ptr()
+        self.ptr()
     }
 
     fn iter_next_func(&self) -> unsafe extern "C-unwind" fn(*mut sys::ecs_iter_t) -> bool {
@@ -138,7 +147,7 @@ where
 {
     #[inline(always)]
     fn entity(&self) -> EntityView {
-        EntityView::new_from(self.world(), unsafe { (*self.query.as_ptr()).entity })
+        EntityView::new_from(self.world(), self.entity)
     }
 }
 
@@ -148,7 +157,7 @@ where
 {
     #[inline(always)]
     fn world(&self) -> WorldRef<'a> {
-        unsafe { WorldRef::from_ptr(self.query.as_ref().world) }
+        unsafe { WorldRef::from_ptr(self.world) }
     }
 }
 
@@ -175,18 +184,65 @@ where
     #[inline]
     pub unsafe fn new_from(query: NonNull<sys::ecs_query_t>) -> Self {
         sys::flecs_poly_claim_(query.as_ptr() as *mut c_void);
+        Self::from_claimed(query)
+    }
 
-        let world_ctx = ecs_get_binding_ctx((*query.as_ptr()).world) as *mut WorldCtx;
+    /// wraps a query pointer that was already claimed or created for this handle
+    unsafe fn from_claimed(query: NonNull<sys::ecs_query_t>) -> Self {
+        let world = (*query.as_ptr()).world;
+        let world_ctx = ecs_get_binding_ctx(world) as *mut WorldCtx;
         (*world_ctx).inc_query_ref_count();
         let world_ctx = NonNull::new_unchecked(world_ctx);
 
         Self {
             query,
             world_ctx,
+            world,
+            entity: (*query.as_ptr()).entity,
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Returns whether the query can still be used.
+    ///
+    /// Queries that are associated with an entity, such as named and cached queries, are
+    /// destroyed when their entity is deleted. Using the query afterwards panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let query = world.query_named::<&Position>("positions").build();
+    /// assert!(query.is_alive());
+    ///
+    /// query.entity().destruct();
+    /// assert!(!query.is_alive());
+    /// ```
+    pub fn is_alive(&self) -> bool {
+        self.entity == 0 || unsafe { sys::ecs_is_alive(self.world, self.entity) }
+    }
+
+    /// Returns the query pointer, panicking when the query entity was deleted.
+    #[inline(always)]
+    fn ptr(&self) -> *mut sys::ecs_query_t {
+        if !self.is_alive() {
+            panic!(
+                "query was used after its entity was deleted, which destroys the query (entity: {})",
+                self.entity
+            );
+        }
+        self.query.as_ptr()
+    }
+
     /// Create a new query from a query descriptor
     ///
     /// # Panics
@@ -206,6 +262,26 @@ where
         world: impl WorldProvider<'a>,
         desc: &mut sys::ecs_query_desc_t,
     ) -> Self {
+        match Self::try_new_from_desc(world, desc) {
+            Ok(query) => query,
+            Err(err) => panic!(
+                "Failed to create query, this is due to the user creating an invalid query. Most likely by using `expr` with a wrong expression: {}",
+                err
+            ),
+        }
+    }
+
+    /// Create a new query from a query descriptor, returning the error flecs reported
+    /// when the descriptor is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - The world to create the query in
+    /// * `desc` - The query descriptor to create the query from
+    pub(crate) fn try_new_from_desc<'a>(
+        world: impl WorldProvider<'a>,
+        desc: &mut sys::ecs_query_desc_t,
+    ) -> Result<Self, FlecsError> {
         if desc.entity != 0 && desc.terms[0].id == 0 {
             let world_ptr = world.world_ptr();
             let query_poly = unsafe {
@@ -219,40 +295,27 @@ where
             if !query_poly.is_null() {
                 unsafe {
                     let query = NonNull::new_unchecked((*query_poly).poly as *mut sys::ecs_query_t);
-                    sys::flecs_poly_claim_(query.as_ptr() as *mut c_void);
-                    let world_ctx = ecs_get_binding_ctx((*query.as_ptr()).world) as *mut WorldCtx;
-                    (*world_ctx).inc_query_ref_count();
-                    let world_ctx = NonNull::new_unchecked(world_ctx);
-
-                    return Self {
-                        query,
-                        world_ctx,
-                        _phantom: PhantomData,
-                    };
+                    return Ok(Self::new_from(query));
                 }
             }
         }
-        let world_ptr = world.world_ptr_mut();
 
-        let query_ptr = unsafe { sys::ecs_query_init(world_ptr, desc) };
+        check_query_desc(desc)?;
 
-        if query_ptr.is_null() {
-            panic!("Failed to create query, this is due to the user creating an invalid query. Most likely by using `expr` with a wrong expression.");
-        }
+        resolve_filter_terms(&mut desc.terms, T::COUNT as usize);
 
-        unsafe {
-            let world_ctx = ecs_get_binding_ctx(world_ptr) as *mut WorldCtx;
-            (*world_ctx).inc_query_ref_count();
-            let world_ctx = NonNull::new_unchecked(world_ctx);
+        let world_ptr = world.world_ptr_mut();
 
-            let query = NonNull::new_unchecked(query_ptr);
+        let (query_ptr, error) =
+            log::capture_error(|| unsafe { sys::ecs_query_init(world_ptr, desc) });
 
-            Self {
-                query,
-                world_ctx,
-                _phantom: PhantomData,
-            }
+        if query_ptr.is_null() {
+            return Err(FlecsError::from_query_error(
+                error.unwrap_or_else(|| "failed to create query".to_string()),
+            ));
         }
+
+        Ok(unsafe { Self::from_claimed(NonNull::new_unchecked(query_ptr)) })
     }
 
     pub(crate) fn new_from_entity<'a>(
@@ -295,14 +358,14 @@ where
     #[doc(alias = "query_base::destruct")]
     pub fn destruct(self) {
         ecs_assert!(
-            unsafe { (*self.query.as_ptr()).entity } != 0,
+            self.entity != 0,
             "destruct() should only be called on queries associated with entities"
         );
 
-        if unsafe { (*self.query.as_ptr()).entity } != 0 {
+        if self.entity != 0 {
             let world = self.world();
             let world_ctx = world.world_ctx_mut();
-            if unsafe { sys::flecs_poly_release_(self.query.as_ptr() as *mut c_void) } > 0 {
+            if unsafe { sys::flecs_poly_release_(self.ptr() as *mut c_void) } > 0 {
                 world_ctx.set_is_panicking_true();
                 unsafe { sys::ecs_query_fini(self.query.as_ptr()) };
                 panic!("The code base still has lingering references to `Query` objects. This is a bug in the user code. 
@@ -313,8 +376,54 @@ where
         }
     }
 
+    /// Delete all entities matched by the query.
+    ///
+    /// The deletes are deferred until all matched entities are collected, so the
+    /// query iterator is not invalidated. If the world is already deferred, the
+    /// deletes are applied at the end of the outer deferred block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().add::<Enemy>();
+    /// world.entity().add::<Enemy>();
+    ///
+    /// let query = world.query::<()>().with::<Enemy>().build();
+    /// assert_eq!(query.count(), 2);
+    ///
+    /// query.destruct_entities();
+    /// assert_eq!(query.count(), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::delete_entities_with()`]
+    /// * [`EntityView::destruct()`]
+    pub fn destruct_entities(&self) {
+        let world = self.world();
+        world.defer_begin();
+
+        let mut iter = self.retrieve_iter();
+        while self.iter_next(&mut iter) {
+            for i in 0..iter.count as usize {
+                unsafe {
+                    sys::ecs_delete(world.world_ptr_mut(), *iter.entities.add(i));
+                }
+            }
+        }
+
+        world.defer_end();
+    }
+
     pub(crate) fn reference_count(&self) -> i32 {
-        unsafe { sys::flecs_poly_refcount(self.query.as_ptr() as *mut c_void) }
+        unsafe { sys::flecs_poly_refcount(self.ptr() as *mut c_void) }
     }
 
     /// Get the iterator for the query
@@ -328,7 +437,7 @@ where
     /// * C++ API: `query::get_iter`
     #[doc(alias = "query::get_iter")]
     unsafe fn get_iter_raw(&mut self) -> sys::ecs_iter_t {
-        unsafe { sys::ecs_query_iter(self.world_ptr(), self.query.as_ptr()) }
+        unsafe { sys::ecs_query_iter(self.world_ptr(), self.ptr()) }
     }
 
     /// Returns whether the query data changed since the last iteration.
@@ -351,7 +460,19 @@ where
     /// * C++ API: `query_base::changed`
     #[doc(alias = "query_base::changed")]
     pub fn is_changed(&self) -> bool {
-        unsafe { sys::ecs_query_changed(self.query.as_ptr()) }
+        unsafe { sys::ecs_query_changed(self.ptr()) }
+    }
+
+    /// Returns the cache kind the query was created with.
+    ///
+    /// A query built with [`QueryCacheKind::Default`] or [`QueryCacheKind::Auto`] reports the
+    /// kind flecs picked for it, which is [`QueryCacheKind::None`] when no term could be cached.
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilderImpl::set_cache_kind()`]
+    pub fn cache_kind(&self) -> QueryCacheKind {
+        unsafe { (*self.ptr()).cache_kind }.into()
     }
 
     /// Get info for group
@@ -369,7 +490,7 @@ where
     /// * C++ API: `query_base::get_group_info`
     #[doc(alias = "query_base::get_group_info")]
     pub fn group_info(&self, group_id: impl Into<Entity>) -> *const sys::ecs_query_group_info_t {
-        unsafe { sys::ecs_query_get_group_info(self.query.as_ptr(), *group_id.into()) }
+        unsafe { sys::ecs_query_get_group_info(self.ptr(), *group_id.into()) }
     }
 
     /// Get context for group
@@ -403,3 +524,62 @@ impl<T: QueryTuple> From<&Query<T>> for NonNull<sys::ecs_query_t> {
         q.query
     }
 }
+
+/// Checks the combinations flecs asserts on instead of reporting an error, so building the query
+/// returns an error for them instead of aborting.
+pub(crate) fn check_query_desc(desc: &sys::ecs_query_desc_t) -> Result<(), FlecsError> {
+    let has_group_by = desc.group_by != 0 || desc.group_by_callback.is_some();
+    let has_order_by = desc.order_by != 0 || desc.order_by_callback.is_some();
+
+    if desc.cache_kind == sys::ecs_query_cache_kind_t_EcsQueryCacheNone
+        && (has_group_by || has_order_by)
+    {
+        return Err(FlecsError::new(
+            "group_by and order_by require a cached query, they can't be used with `QueryCacheKind::None`",
+        ));
+    }
+
+    if has_group_by {
+        let cascade_term = desc
+            .terms
+            .iter()
+            .take_while(|term| (term.first.id | term.id | term.second.id | term.src.id) != 0)
+            .position(|term| term.src.id & ECS_CASCADE != 0);
+
+        if let Some(index) = cascade_term {
+            return Err(
+                FlecsError::new("cannot mix cascade and group_by").with_term_index(index as u32)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a query can be created from `desc` by creating an uncached query from its terms.
+/// Used by builders that can't recover when flecs fails to create their query.
+pub(crate) fn validate_query_desc(
+    world: *mut sys::ecs_world_t,
+    desc: &sys::ecs_query_desc_t,
+) -> Result<(), FlecsError> {
+    check_query_desc(desc)?;
+
+    let desc = sys::ecs_query_desc_t {
+        terms: desc.terms,
+        expr: desc.expr,
+        flags: desc.flags,
+        cache_kind: sys::ecs_query_cache_kind_t_EcsQueryCacheNone,
+        ..Default::default()
+    };
+
+    let (query, error) = log::capture_error(|| unsafe { sys::ecs_query_init(world, &desc) });
+
+    if query.is_null() {
+        return Err(FlecsError::from_query_error(
+            error.unwrap_or_else(|| "failed to create query".to_string()),
+        ));
+    }
+
+    unsafe { sys::ecs_query_fini(query) };
+    Ok(())
+}
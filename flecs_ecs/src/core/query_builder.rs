@@ -104,7 +104,7 @@ where
     /// * C++ API: `query_builder::query_builder`
     #[doc(alias = "query_builder::query_builder")]
     pub fn new_named(world: &'a World, name: &str) -> Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let desc = Default::default();
 
@@ -186,6 +186,42 @@ where
         T::populate(&mut obj);
         obj
     }
+
+    /// Build the `query_builder` into a query, returning the error flecs reported
+    /// when the query is invalid instead of panicking.
+    ///
+    /// This is useful for queries that are created from user input with [`expr`](QueryBuilderImpl::expr).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let query = world.query::<&Position>().expr("!Position(").try_build();
+    /// assert!(query.is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Builder::build()`]
+    /// * [`World::query_expr()`]
+    pub fn try_build(&mut self) -> Result<Query<T>, FlecsError> {
+        let world = self.world;
+        let query = Query::<T>::try_new_from_desc(world, &mut self.desc);
+        for s in self.term_builder.str_ptrs_to_free.iter_mut() {
+            unsafe { ManuallyDrop::drop(s) };
+        }
+        self.term_builder.str_ptrs_to_free.clear();
+        query
+    }
 }
 
 #[doc(hidden)]
@@ -269,7 +305,7 @@ type GroupByFn = extern "C-unwind" fn(
 pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
     /// set the name of the query-like object
     fn named(&mut self, name: &str) -> &mut Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
         let world_ptr = self.world_ptr_mut();
 
         let entity_desc: sys::ecs_entity_desc_t = sys::ecs_entity_desc_t {
@@ -287,6 +323,36 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
         self
     }
 
+    /// Checks whether flecs can create the query from the terms added so far, returning the error
+    /// flecs reports if it can't.
+    ///
+    /// System and observer builders are built by their callback functions such as `each` and
+    /// `run`, which panic when the query is invalid. Call this first to handle the error instead.
+    /// Query builders can use [`QueryBuilder::try_build()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut builder = world.system::<&Position>();
+    /// builder.with_name("DoesNotExist");
+    ///
+    /// let error = builder.validate().unwrap_err();
+    /// assert_eq!(error.term_index(), Some(1));
+    /// ```
+    fn validate(&self) -> Result<(), FlecsError> {
+        validate_query_desc(self.world_ptr_mut(), self.query_desc())
+    }
+
     /// set querylags
     ///
     /// # Arguments
@@ -304,6 +370,12 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
 
     /// Set what cache method to use for the query
     ///
+    /// Uncached queries are cheaper to create and match new tables while they are iterated,
+    /// cached queries are faster to iterate. When not set, named queries and queries that use
+    /// `group_by` or `order_by` are cached, other queries are not.
+    ///
+    /// `group_by` and `order_by` require a cache and can't be combined with [`QueryCacheKind::None`].
+    ///
     /// # Arguments
     ///
     /// * `kind` - the cache kind to set
@@ -327,17 +399,62 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
         self.set_cache_kind(QueryCacheKind::Auto)
     }
 
+    /// Set the cache method to uncached
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilderImpl::set_cache_kind()`]
+    fn set_uncached(&mut self) -> &mut Self {
+        self.set_cache_kind(QueryCacheKind::None)
+    }
+
     /// set expression
     ///
+    /// The expression uses the flecs query language, e.g. `"Position, !Velocity, (ChildOf, scene)"`.
+    /// Its terms are added after the terms of the query tuple and the terms added with `with`.
+    ///
     /// # Arguments
     ///
     /// * `expr` - the expression to set
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component_named::<Velocity>("Velocity");
+    ///
+    /// world.entity().set(Position { x: 1, y: 2 });
+    /// world
+    ///     .entity()
+    ///     .set(Position { x: 3, y: 4 })
+    ///     .set(Velocity { x: 1, y: 1 });
+    ///
+    /// let query = world.query::<&Position>().expr("!Velocity").build();
+    ///
+    /// assert_eq!(query.count(), 1);
+    /// ```
+    ///
     /// # See also
     ///
+    /// * [`QueryBuilder::try_build()`]
+    /// * [`World::query_expr()`]
     /// * C++ API: `query_builder_i::expr`
     #[doc(alias = "query_builder_i::expr")]
-    fn expr(&mut self, expr: &'a str) -> &mut Self {
+    fn expr(&mut self, expr: &str) -> &mut Self {
         let expr = ManuallyDrop::new(format!("{}\0", expr));
         ecs_assert!(
             *self.expr_count_mut() == 0,
@@ -650,6 +767,96 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
         self.with_second_id(first, second).not()
     }
 
+    /// Set terms that match entities without any of the components or pairs of the tuple.
+    ///
+    /// This adds the terms `!{ A || B || C }`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - A tuple of components or pairs, such as `(A, B, (Rel, Tgt))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Renderable;
+    ///
+    /// #[derive(Component)]
+    /// struct Lod0;
+    ///
+    /// #[derive(Component)]
+    /// struct Lod1;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e1 = world.entity().add::<Renderable>();
+    /// world.entity().add::<Renderable>().add::<Lod0>();
+    /// world.entity().add::<Renderable>().add::<Lod1>();
+    ///
+    /// let q = world
+    ///     .query::<()>()
+    ///     .with::<Renderable>()
+    ///     .without_any::<(Lod0, Lod1)>()
+    ///     .build();
+    ///
+    /// assert_eq!(q.count(), 1);
+    /// assert_eq!(q.first_entity(), e1);
+    /// ```
+    fn without_any<T: ComponentOrPairIdTuple>(&mut self) -> &mut Self {
+        self.scope_open().not();
+
+        let mut first = true;
+        T::for_each_id(self.world(), |id| {
+            if !first {
+                self.or();
+            }
+            first = false;
+            self.with_id(id);
+        });
+
+        self.scope_close()
+    }
+
+    /// Set a term that matches entities that have no children.
+    ///
+    /// This adds the term `!ChildOf(_, $this)`, which doesn't match if any entity is a child of
+    /// the matched entity.
+    ///
+    /// The term checks whether there is a table with `(ChildOf, $this)`, so an entity of which
+    /// all children were deleted only matches again once that empty table is deleted, e.g. by
+    /// [`World::delete_empty_tables()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Node;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity().add::<Node>();
+    /// let leaf = world.entity().add::<Node>().child_of_id(parent);
+    ///
+    /// let q = world
+    ///     .query::<()>()
+    ///     .with::<Node>()
+    ///     .without_children()
+    ///     .build();
+    ///
+    /// assert_eq!(q.count(), 1);
+    /// assert_eq!(q.first_entity(), leaf);
+    /// ```
+    fn without_children(&mut self) -> &mut Self {
+        self.with_id(flecs::ChildOf::ID)
+            .set_second_id(flecs::This_::ID)
+            .set_src_id(flecs::Any::ID)
+            .not()
+    }
+
     /// Term notation for more complex query features
     ///
     /// # See also
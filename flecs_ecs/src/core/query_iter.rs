@@ -10,6 +10,8 @@ where
 {
     iter: sys::ecs_iter_t,
     iter_next: unsafe extern "C-unwind" fn(*mut sys::ecs_iter_t) -> bool,
+    // iterators wrapped by `page` and `worker`, each one points to the one before it
+    pub(crate) chain: Vec<sys::ecs_iter_t>,
     _phantom: std::marker::PhantomData<&'a (P, T)>,
 }
 
@@ -24,10 +26,126 @@ where
         Self {
             iter,
             iter_next,
+            chain: Vec::new(),
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Wraps the iterator in a chained iterator created by `chain_iter`.
+    fn chain(
+        mut self,
+        chain_iter: impl FnOnce(*const sys::ecs_iter_t) -> sys::ecs_iter_t,
+        iter_next: unsafe extern "C-unwind" fn(*mut sys::ecs_iter_t) -> bool,
+    ) -> Self {
+        self.chain.push(self.iter);
+
+        // the push may have moved the chained iterators, so link them up again
+        let chain = self.chain.as_mut_ptr();
+        let len = self.chain.len();
+        for index in 1..len {
+            unsafe { (*chain.add(index)).chain_it = chain.add(index - 1) };
+        }
+
+        self.iter = chain_iter(unsafe { chain.add(len - 1) });
+        self.iter_next = iter_next;
+        self
+    }
+
+    /// Limit the results to a page of entities, skipping the first `offset` entities and
+    /// returning at most `limit` entities.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: the number of entities to skip
+    /// * `limit`: the maximum number of entities to return
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// for _ in 0..10 {
+    ///     world.entity().set(Position { x: 0.0, y: 0.0 });
+    /// }
+    ///
+    /// let query = world.new_query::<&Position>();
+    ///
+    /// let mut count = 0;
+    /// query.iterable().page(2, 5).each(|_| count += 1);
+    /// assert_eq!(count, 5);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `iter_iterable::page`
+    #[doc(alias = "iter_iterable::page")]
+    pub fn page(self, offset: u32, limit: u32) -> Self {
+        self.chain(
+            |it| unsafe { sys::ecs_page_iter(it, offset as i32, limit as i32) },
+            sys::ecs_page_next,
+        )
+    }
+
+    /// Limit the results to the slice of worker `index` out of `count` workers. Each worker
+    /// visits a different part of the matched entities, so a query can be split across the
+    /// threads of a job system. Threads should iterate their own stage, see
+    /// [`QueryAPI::iter_stage()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: the index of the worker, must be smaller than `count`
+    /// * `count`: the total number of workers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// for _ in 0..10 {
+    ///     world.entity().set(Position { x: 0.0, y: 0.0 });
+    /// }
+    ///
+    /// let query = world.new_query::<&Position>();
+    ///
+    /// let mut count = 0;
+    /// for index in 0..3 {
+    ///     query.iterable().worker(index, 3).each(|_| count += 1);
+    /// }
+    /// assert_eq!(count, 10);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `iter_iterable::worker`
+    #[doc(alias = "iter_iterable::worker")]
+    pub fn worker(self, index: u32, count: u32) -> Self {
+        ecs_assert!(
+            index < count,
+            FlecsErrorCode::InvalidParameter,
+            "worker index must be smaller than the worker count"
+        );
+        self.chain(
+            |it| unsafe { sys::ecs_worker_iter(it, index as i32, count as i32) },
+            sys::ecs_worker_next,
+        )
+    }
+
     /// Limit results to tables with specified group id (grouped queries only)
     ///
     /// # Arguments
@@ -107,7 +225,7 @@ where
     /// * C++ API: `iter_iterable::set_var`
     #[doc(alias = "iter_iterable::set_var")]
     pub fn set_var_expr(&mut self, name: &str, value: impl Into<Entity>) -> &mut Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let qit = unsafe { &mut self.iter.priv_.iter.query };
         let var_id = unsafe { sys::ecs_query_find_var(qit.query, name.as_ptr() as *const _) };
@@ -132,7 +250,7 @@ where
     /// * C++ API: `iter_iterable::set_var`
     #[doc(alias = "iter_iterable::set_var")]
     pub fn set_var_table_expr(&mut self, name: &str, table: impl IntoTableRange) -> &mut Self {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let qit = unsafe { &mut self.iter.priv_.iter.query };
         let var_id = unsafe { sys::ecs_query_find_var(qit.query, name.as_ptr() as *const _) };
@@ -177,7 +295,7 @@ where
     T: QueryTuple,
     Self: WorldProvider<'a>,
 {
-    fn entity(&self) -> EntityView {
+    fn entity(&self) -> EntityView<'_> {
         let world = unsafe { WorldRef::from_ptr(self.iter.real_world) };
         EntityView::new_from(world, unsafe {
             sys::ecs_get_entity(self.iter.query as *const c_void)
@@ -193,5 +311,3 @@ where
         unsafe { WorldRef::from_ptr(self.iter.world) }
     }
 }
-
-// TODO : worker_iterable and page_iterable not implemented yet
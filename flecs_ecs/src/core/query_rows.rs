@@ -0,0 +1,380 @@
+//! Row iterator that lets a [`Query`] be iterated in a `for` loop instead of with a closure.
+
+use std::cell::{Cell, UnsafeCell};
+use std::rc::Rc;
+
+use crate::core::*;
+use crate::sys;
+
+/// Iterator over the entities matched by a query, yielding a [`QueryRow`] for each of them.
+///
+/// The iterator walks the matched tables one at a time. The table that is being iterated is locked
+/// and the world is deferred for as long as the iterator or one of its rows is alive, so structural
+/// changes made inside the loop body are queued and applied once both are dropped, just like with
+/// [`QueryAPI::each_entity()`].
+///
+/// A row gives access to the components of its entity with [`QueryRow::get()`]. Only one row can
+/// be alive at a time, so component references can't alias between rows: requesting the next row
+/// while the previous one is still alive panics. This means the iterator can be used with `for`
+/// loops and adapters such as [`Iterator::filter()`], but not with ones that keep several items
+/// around, such as [`Iterator::collect()`] or [`Iterator::peekable()`].
+///
+/// Rows can also be requested with [`QueryRows::next_row()`], which borrows the iterator instead.
+///
+/// Created by [`Query::rows()`] or [`QueryIter::rows()`], or by iterating a `&Query` or a
+/// [`QueryIter`] with a `for` loop.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Velocity {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let world = World::new();
+///
+/// world
+///     .entity()
+///     .set(Position { x: 1, y: 2 })
+///     .set(Velocity { x: 1, y: 1 });
+///
+/// let query = world.new_query::<(&mut Position, &Velocity)>();
+///
+/// for mut row in &query {
+///     let (e, (pos, vel)) = row.get();
+///     pos.x += vel.x;
+///     pos.y += vel.y;
+///     println!("{}: {}, {}", e.id(), pos.x, pos.y);
+/// }
+///
+/// let mut rows = query.rows();
+/// while let Some((e, (pos, _))) = rows.next_row() {
+///     println!("{}: {}, {}", e.id(), pos.x, pos.y);
+/// }
+/// ```
+///
+/// Components can't be kept around after their row:
+///
+/// ```compile_fail
+/// # use flecs_ecs::prelude::*;
+/// # #[derive(Component)]
+/// # struct Position {
+/// #     x: i32,
+/// #     y: i32,
+/// # }
+/// let world = World::new();
+/// let query = world.new_query::<&mut Position>();
+///
+/// let mut all = Vec::new();
+/// for mut row in &query {
+///     let (_, pos) = row.get();
+///     all.push(pos);
+/// }
+/// ```
+pub struct QueryRows<'a, T>
+where
+    T: QueryTuple,
+{
+    shared: Rc<RowsShared<'a, T>>,
+}
+
+/// A row of a [`QueryRows`] iterator.
+///
+/// The world stays deferred and the table of the row stays locked while the row is alive.
+pub struct QueryRow<'a, T>
+where
+    T: QueryTuple,
+{
+    shared: Rc<RowsShared<'a, T>>,
+    entity: EntityView<'a>,
+    row: usize,
+}
+
+struct RowsShared<'a, T>
+where
+    T: QueryTuple,
+{
+    state: UnsafeCell<RowsState<'a, T>>,
+    // whether a `QueryRow` is alive, which has exclusive access to `state`
+    row_alive: Cell<bool>,
+}
+
+struct RowsState<'a, T>
+where
+    T: QueryTuple,
+{
+    iter: sys::ecs_iter_t,
+    iter_next: unsafe extern "C-unwind" fn(*mut sys::ecs_iter_t) -> bool,
+    world: WorldRef<'a>,
+    components: Option<Box<T::Pointers>>,
+    row: usize,
+    count: usize,
+    is_locked: bool,
+    is_done: bool,
+    // iterators chained by `QueryIter::page` and `QueryIter::worker`, which `iter` points to
+    chain: Vec<sys::ecs_iter_t>,
+}
+
+impl<'a, T> QueryRows<'a, T>
+where
+    T: QueryTuple,
+{
+    pub(crate) fn new(
+        world: WorldRef<'a>,
+        mut iter: sys::ecs_iter_t,
+        iter_next: unsafe extern "C-unwind" fn(*mut sys::ecs_iter_t) -> bool,
+        chain: Vec<sys::ecs_iter_t>,
+    ) -> Self {
+        const {
+            assert!(
+                !T::CONTAINS_ANY_TAG_TERM,
+                "a type provided in the query signature is a Tag and cannot be used with a `for` loop. use `.run` instead or provide the tag with `.with()`"
+            );
+        }
+
+        iter.flags |= sys::EcsIterCppEach;
+
+        unsafe { sys::ecs_defer_begin(iter.world) };
+
+        let state = RowsState {
+            iter,
+            iter_next,
+            world,
+            components: None,
+            row: 0,
+            count: 0,
+            is_locked: false,
+            is_done: false,
+            chain,
+        };
+
+        Self {
+            shared: Rc::new(RowsShared {
+                state: UnsafeCell::new(state),
+                row_alive: Cell::new(false),
+            }),
+        }
+    }
+
+    fn state(&mut self) -> &mut RowsState<'a, T> {
+        assert!(
+            !self.shared.row_alive.get(),
+            "the previous row has to be dropped before the next one is requested"
+        );
+        // SAFETY: no row is alive, so the iterator has exclusive access to the state
+        unsafe { &mut *self.shared.state.get() }
+    }
+}
+
+impl<'a, T> QueryRows<'a, T>
+where
+    T: QueryTuple + 'a,
+{
+    /// Advance to the next matched entity and return it together with its components.
+    ///
+    /// Returns `None` once all matched tables have been visited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`QueryRow`] returned by this iterator is still alive.
+    pub fn next_row(&mut self) -> Option<(EntityView<'a>, T::TupleType<'_>)> {
+        let state = self.state();
+        let row = state.advance()?;
+        let entity = state.entity(row);
+        let components = state.components.as_deref_mut()?;
+        Some((entity, components.get_tuple(&state.iter, row)))
+    }
+}
+
+impl<'a, T> Iterator for QueryRows<'a, T>
+where
+    T: QueryTuple + 'a,
+{
+    type Item = QueryRow<'a, T>;
+
+    /// # Panics
+    ///
+    /// Panics if the previous [`QueryRow`] is still alive.
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = self.state();
+        let row = state.advance()?;
+        let entity = state.entity(row);
+        self.shared.row_alive.set(true);
+        Some(QueryRow {
+            shared: self.shared.clone(),
+            entity,
+            row,
+        })
+    }
+}
+
+impl<'a, T> QueryRow<'a, T>
+where
+    T: QueryTuple + 'a,
+{
+    /// The entity of the row.
+    pub fn entity(&self) -> EntityView<'a> {
+        self.entity
+    }
+
+    /// The entity of the row together with its components.
+    pub fn get(&mut self) -> (EntityView<'a>, T::TupleType<'_>) {
+        // SAFETY: only one row is alive at a time and the iterator doesn't access the state
+        // while it is, so the row has exclusive access to it
+        let state = unsafe { &mut *self.shared.state.get() };
+        let components = state
+            .components
+            .as_deref_mut()
+            .expect("the table of a row is iterated while the row is alive");
+        (self.entity, components.get_tuple(&state.iter, self.row))
+    }
+}
+
+impl<T> Drop for QueryRow<'_, T>
+where
+    T: QueryTuple,
+{
+    fn drop(&mut self) {
+        self.shared.row_alive.set(false);
+    }
+}
+
+impl<'a, T> RowsState<'a, T>
+where
+    T: QueryTuple,
+{
+    /// Advance to the next row, moving on to the next table once the current one is done.
+    fn advance(&mut self) -> Option<usize> {
+        loop {
+            if self.row < self.count {
+                let row = self.row;
+                self.row += 1;
+                return Some(row);
+            }
+
+            self.unlock_table();
+
+            if self.is_done {
+                return None;
+            }
+
+            if !unsafe { (self.iter_next)(&mut self.iter) } {
+                // the iterator is finalized by flecs once it runs out of results
+                self.is_done = true;
+                self.components = None;
+                self.count = 0;
+                return None;
+            }
+
+            ecs_assert!(
+                !self.iter.entities.is_null(),
+                FlecsErrorCode::InvalidParameter,
+                "query does not return entities ($this variable is not populated)"
+            );
+
+            self.components = Some(Box::new(T::create_ptrs(&self.iter)));
+            self.row = 0;
+            self.count = self.iter.count as usize;
+
+            unsafe { sys::ecs_table_lock(self.iter.world, self.iter.table) };
+            self.is_locked = true;
+        }
+    }
+
+    fn entity(&self, row: usize) -> EntityView<'a> {
+        EntityView::new_from(self.world, unsafe { *self.iter.entities.add(row) })
+    }
+
+    fn unlock_table(&mut self) {
+        if self.is_locked {
+            unsafe { sys::ecs_table_unlock(self.iter.world, self.iter.table) };
+            self.is_locked = false;
+        }
+    }
+}
+
+impl<T> Drop for RowsState<'_, T>
+where
+    T: QueryTuple,
+{
+    fn drop(&mut self) {
+        self.unlock_table();
+
+        unsafe {
+            if !self.is_done {
+                sys::ecs_iter_fini(&mut self.iter);
+            }
+            sys::ecs_defer_end(self.iter.world);
+        }
+    }
+}
+
+impl<T> Query<T>
+where
+    T: QueryTuple,
+{
+    /// Iterate the matched entities row by row with a [`QueryRows`] iterator.
+    ///
+    /// Unlike [`QueryAPI::each_entity()`] the loop body isn't a closure, so it can use `?`,
+    /// `break` and `continue`. Iterating a `&Query` with a `for` loop does the same.
+    pub fn rows(&self) -> QueryRows<'_, T> {
+        QueryRows::new(
+            self.world(),
+            self.retrieve_iter(),
+            self.iter_next_func(),
+            Vec::new(),
+        )
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Query<T>
+where
+    T: QueryTuple + 'a,
+{
+    type Item = QueryRow<'a, T>;
+    type IntoIter = QueryRows<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows()
+    }
+}
+
+impl<'a, P, T> QueryIter<'a, P, T>
+where
+    T: QueryTuple,
+{
+    /// Consume the iterator and iterate its results row by row with a [`QueryRows`] iterator.
+    ///
+    /// Iterating a [`QueryIter`] with a `for` loop does the same. See [`Query::rows()`].
+    pub fn rows(mut self) -> QueryRows<'a, T> {
+        let chain = std::mem::take(&mut self.chain);
+        QueryRows::new(
+            self.world(),
+            self.retrieve_iter(),
+            self.iter_next_func(),
+            chain,
+        )
+    }
+}
+
+impl<'a, P, T> IntoIterator for QueryIter<'a, P, T>
+where
+    T: QueryTuple + 'a,
+{
+    type Item = QueryRow<'a, T>;
+    type IntoIter = QueryRows<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows()
+    }
+}
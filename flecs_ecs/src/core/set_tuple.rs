@@ -0,0 +1,52 @@
+//! Setting multiple components on an entity at once, see [`EntityView::set_all()`].
+
+use crate::core::*;
+use crate::sys;
+use flecs_ecs_derive::tuples;
+
+/// A single value of a tuple passed to [`EntityView::set_all()`].
+///
+/// Zero-sized values (tags) are added, all other values are set.
+pub trait SetTupleElement {
+    fn set_element(self, world: WorldRef, entity: Entity);
+}
+
+impl<T: ComponentId> SetTupleElement for T {
+    fn set_element(self, world: WorldRef, entity: Entity) {
+        let world_ptr = world.world_ptr_mut();
+        let id = T::id(world);
+        if std::mem::size_of::<T>() == 0 {
+            unsafe { sys::ecs_add_id(world_ptr, *entity, id) }
+        } else {
+            set_helper_sized(world_ptr, *entity, self, id);
+        }
+    }
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can not be used with `set_all`",
+    label = "not a tuple of components",
+    note = "Valid syntax: `.set_all((Position::default(), Velocity::default(), Tag))`"
+)]
+pub trait SetTuple {
+    /// Sets or adds every value of the tuple on `entity`.
+    ///
+    /// The caller is responsible for deferring the world, so that the entity is moved once.
+    fn set_all(self, world: WorldRef, entity: Entity);
+}
+
+macro_rules! impl_set_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: SetTupleElement),*> SetTuple for ($($t,)*) {
+            #[allow(non_snake_case)]
+            fn set_all(self, world: WorldRef, entity: Entity) {
+                let ($($t,)*) = self;
+                $(
+                    $t.set_element(world, entity);
+                )*
+            }
+        }
+    }
+}
+
+tuples!(impl_set_tuple, 1, 32);
@@ -0,0 +1,63 @@
+//! Asynchronous stages that queue commands for a world.
+
+use std::marker::PhantomData;
+
+use crate::core::*;
+
+/// An asynchronous stage that queues commands until it is merged with its world.
+///
+/// The stage borrows the world it was created from, so it can't outlive it. It is freed when
+/// it's dropped. The stage can be moved to another thread, e.g. with [`std::thread::scope`],
+/// to enqueue commands from there.
+///
+/// Created by [`World::create_async_stage()`].
+///
+/// # Example
+///
+/// The stage can't be used after the world is dropped:
+///
+/// ```compile_fail
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+/// let stage = world.create_async_stage();
+/// drop(world);
+/// stage.merge();
+/// ```
+///
+/// # See also
+///
+/// * [`World::create_async_stage()`]
+/// * [`EntityView::mut_current_stage()`]
+pub struct Stage<'a> {
+    stage: World,
+    _world: PhantomData<&'a ()>,
+}
+
+impl<'a> Stage<'a> {
+    pub(crate) fn new(stage: World) -> Self {
+        Self {
+            stage,
+            _world: PhantomData,
+        }
+    }
+
+    /// Apply the commands of the stage to the world.
+    ///
+    /// The application must ensure that no commands are added to the stage while it's being
+    /// merged.
+    ///
+    /// # See also
+    ///
+    /// * [`World::merge()`]
+    pub fn merge(&self) {
+        self.stage.merge();
+    }
+}
+
+impl<'a> WorldProvider<'a> for &'a Stage<'_> {
+    #[inline(always)]
+    fn world(&self) -> WorldRef<'a> {
+        (&self.stage).into()
+    }
+}
@@ -89,6 +89,26 @@ impl FieldUntyped {
         }
     }
 
+    /// Pointer to the first element of the column.
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.array
+    }
+
+    /// Size of a single element in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Number of elements in the column, 1 if the column is shared.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// whether the column / component is shared.
+    pub fn is_shared(&self) -> bool {
+        self.is_shared
+    }
+
     pub fn at(&self, index: usize) -> *const c_void {
         ecs_assert!(
             index < self.count,
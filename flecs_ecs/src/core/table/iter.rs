@@ -239,7 +239,7 @@ where
     /// * C++ API: `iter::get_var`
     #[doc(alias = "iter::get_var")]
     pub fn get_var_by_name(&self, name: &str) -> EntityView<'a> {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let world = self.world();
         let rule_query = unsafe { self.iter.priv_.iter.query.query };
@@ -322,6 +322,31 @@ where
         unsafe { &*ptr }
     }
 
+    /// Calls `func` with the param passed to [`System::run_with()`][crate::addons::system::System::run_with].
+    ///
+    /// Returns `None` without calling `func` when the system was run without a param, for
+    /// example by the pipeline.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the param wasn't passed with [`System::run_with()`][crate::addons::system::System::run_with], when it isn't of type
+    /// `T`, or when it's accessed from within `func`.
+    ///
+    /// # See also
+    ///
+    /// * [`Self::param_untyped()`]
+    /// * C++ API: `iter::param`
+    #[doc(alias = "iter::param")]
+    #[cfg(feature = "flecs_system")]
+    pub fn run_param<T: 'static, R>(&self, func: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let param = self.iter.param;
+        if param.is_null() {
+            return None;
+        }
+
+        Some(crate::addons::system::with_run_param(param, func))
+    }
+
     /// # Arguments
     ///
     /// * `index` - Index of the field to check
@@ -444,6 +469,55 @@ where
         }
     }
 
+    /// Obtain the first and second element of the pair matched for field.
+    /// This operation will return `None` if the field is not a pair.
+    ///
+    /// For a wildcard term such as `(Likes, *)`, the second element is the target that was
+    /// matched for the current result.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The field index.
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::pair()`]
+    pub fn pair_elements(&self, index: i8) -> Option<(EntityView<'a>, EntityView<'a>)> {
+        let id = unsafe { sys::ecs_field_id(self.iter, index) };
+        if unsafe { sys::ecs_id_is_pair(id) } {
+            let world = self.world();
+            let alive = |e: Entity| unsafe {
+                EntityView::new_from(world, sys::ecs_get_alive(world.world_ptr(), *e))
+            };
+            Some((alive(ecs_first(id)), alive(ecs_second(id))))
+        } else {
+            None
+        }
+    }
+
+    /// Obtain the id of the query term for field, as it was written in the query.
+    ///
+    /// Unlike [`TableIter::id()`], wildcards are not replaced with the id that was matched. When
+    /// the iterator does not belong to a query, this returns the matched id.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The field index.
+    pub fn field_id(&self, index: i8) -> IdView<'a> {
+        ecs_assert!(
+            index < self.iter.field_count,
+            FlecsErrorCode::InvalidParameter,
+            index
+        );
+
+        let query = self.iter.query;
+        if query.is_null() {
+            self.id(index)
+        } else {
+            IdView::new_from_id(self.world(), unsafe { (*query).ids[index as usize] })
+        }
+    }
+
     /// Obtain column index for field.
     ///
     /// # Arguments
@@ -644,6 +718,13 @@ where
             return unsafe { field.array.add(0) };
         }
         let field = self.field_untyped_internal(index);
+        ecs_assert!(
+            row >= 0 && (row as usize) < field.count,
+            FlecsErrorCode::OutOfRange,
+            "Row {} is out of range {}",
+            row,
+            field.count
+        );
         unsafe { &mut *(field.array.add(row as usize * field.size)) }
     }
 
@@ -1089,21 +1170,24 @@ where
     #[doc(alias = "iter::next")]
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> bool {
+        let Some(next) = self.iter.next else {
+            self.iter.flags &= !sys::EcsIterIsValid;
+            return false;
+        };
+
         if self.iter.flags & sys::EcsIterIsValid != 0 && !self.iter.table.is_null() {
             unsafe {
                 sys::ecs_table_unlock(self.iter.world, self.iter.table);
             };
         }
 
-        let result = {
-            if let Some(next) = self.iter.next {
-                //sets flag invalid
-                unsafe { next(self.iter) }
-            } else {
-                self.iter.flags &= !sys::EcsIterIsValid;
-                return false;
-            }
-        };
+        //sets flag invalid
+        let result = unsafe { next(self.iter) };
+
+        if !result {
+            // flecs cleans up the iterator once it's exhausted, mark it as finished
+            self.iter.next = None;
+        }
 
         self.iter.flags |= sys::EcsIterIsValid;
         if result && !self.iter.table.is_null() {
@@ -1119,8 +1203,8 @@ where
     /// This operation only needs to be called when the iterator is not iterated
     /// until completion (e.g. the last call to `next()` did not return false).
     ///
-    /// Failing to call this operation on an unfinished iterator will throw a
-    /// `fatal LEAK_DETECTED` error.
+    /// Iterators passed to `run` callbacks are cleaned up when the callback returns,
+    /// so returning early from the callback doesn't require calling this.
     ///
     /// # Example
     ///
@@ -1150,7 +1234,27 @@ where
         unsafe {
             sys::ecs_iter_fini(self.iter);
         }
+        self.iter.next = None;
+    }
+}
+
+/// Cleans up the iterator of a `run` callback that returned before the iterator was exhausted
+/// or finished with [`TableIter::fini()`].
+///
+/// # Safety
+///
+/// `iter` must be the iterator that was passed to the `run` callback.
+pub(crate) unsafe fn fini_unfinished_run_iter(iter: &mut sys::ecs_iter_t) {
+    if iter.next.is_none() {
+        return;
     }
+
+    if iter.flags & sys::EcsIterIsValid != 0 && !iter.table.is_null() {
+        sys::ecs_table_unlock(iter.world, iter.table);
+    }
+
+    sys::ecs_iter_fini(iter);
+    iter.next = None;
 }
 
 /// Iterator to iterate over rows in a table
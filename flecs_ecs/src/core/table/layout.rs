@@ -0,0 +1,241 @@
+//! Memory layout of the columns of a table, to find components that waste cache space.
+
+use crate::core::*;
+use crate::sys;
+
+/// The layout of a single column of a table.
+///
+/// Returned by [`Table::layout_report()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnLayout {
+    /// The component id of the column.
+    pub id: Id,
+    /// The name of the component, or of the pair.
+    pub name: String,
+    /// The size of a single element of the column.
+    pub size: usize,
+    /// The alignment of the component.
+    pub alignment: usize,
+    /// The bytes between the end of the last member of an element and the start of the next
+    /// element of the column, i.e. the padding at the end of the component.
+    ///
+    /// `None` if the component has no struct reflection, in which case the members are unknown.
+    pub padding_to_next: Option<usize>,
+    /// The bytes of each element that are not used by the members of the component, both
+    /// between members and at the end.
+    ///
+    /// `None` if the component has no struct reflection.
+    pub padding: Option<usize>,
+}
+
+/// A component of which a large part of each value is padding, or of which the padding is
+/// unknown as it has no struct reflection.
+///
+/// Returned by [`World::layout_warnings()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutWarning {
+    /// The component.
+    pub id: Entity,
+    /// The name of the component.
+    pub name: String,
+    /// The size of the component.
+    pub size: usize,
+    /// The alignment of the component.
+    pub alignment: usize,
+    /// The bytes of each value that are padding, or `None` if the component has no struct
+    /// reflection.
+    pub padding: Option<usize>,
+}
+
+/// Returns the name of an id, such as `Position` or `(ChildOf, parent)`.
+fn id_name(world: WorldRef, id: sys::ecs_id_t) -> String {
+    unsafe {
+        let raw_ptr = sys::ecs_id_str(world.world_ptr(), id);
+        let name = std::ffi::CStr::from_ptr(raw_ptr)
+            .to_string_lossy()
+            .into_owned();
+        sys::ecs_os_api.free_.expect("os api is missing")(raw_ptr as *mut std::ffi::c_void);
+        name
+    }
+}
+
+/// The padding of a value of a type, computed from its reflected members.
+#[derive(Debug, Clone, Copy)]
+struct TypePadding {
+    /// Bytes that are not covered by a member.
+    total: usize,
+    /// Bytes after the end of the last member.
+    trailing: usize,
+}
+
+/// Returns the padding of a value of the type, or `None` if the type has no struct
+/// reflection.
+#[cfg(feature = "flecs_meta")]
+fn type_padding(world: WorldRef, type_id: sys::ecs_entity_t, size: usize) -> Option<TypePadding> {
+    let struct_ = unsafe { sys::ecs_get_id(world.world_ptr(), type_id, flecs::meta::EcsStruct::ID) }
+        as *const flecs::meta::EcsStruct;
+    if struct_.is_null() {
+        return None;
+    }
+
+    let members = unsafe {
+        let members = &(*struct_).members;
+        if members.count == 0 {
+            return None;
+        }
+        std::slice::from_raw_parts(
+            members.array as *const sys::ecs_member_t,
+            members.count as usize,
+        )
+    };
+    // the size of a member includes all elements of an array member
+    let used = members.iter().map(|m| m.size as usize).sum::<usize>();
+    let end = members
+        .iter()
+        .map(|m| m.offset as usize + m.size as usize)
+        .max()
+        .unwrap_or(0);
+
+    Some(TypePadding {
+        total: size.saturating_sub(used),
+        trailing: size.saturating_sub(end),
+    })
+}
+
+#[cfg(not(feature = "flecs_meta"))]
+fn type_padding(
+    _world: WorldRef,
+    _type_id: sys::ecs_entity_t,
+    _size: usize,
+) -> Option<TypePadding> {
+    None
+}
+
+impl Table<'_> {
+    /// Returns the layout of the columns of the table, in the order in which they are stored.
+    ///
+    /// Tags and sparse components don't have a column in the table and are not reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 }).add::<Enemy>();
+    ///
+    /// let report = e.table().unwrap().layout_report();
+    ///
+    /// assert_eq!(report.len(), 1);
+    /// assert_eq!(report[0].id, world.component_id::<Position>());
+    /// assert_eq!(report[0].size, 8);
+    /// assert_eq!(report[0].alignment, 4);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::layout_warnings()`]
+    pub fn layout_report(&self) -> Vec<ColumnLayout> {
+        let world = self.world();
+        let table = self.table_ptr_mut();
+        let column_count = unsafe { sys::ecs_table_column_count(table) };
+        let type_ = unsafe { &*sys::ecs_table_get_type(table) };
+
+        (0..column_count)
+            .map(|column| {
+                let type_index = unsafe { sys::ecs_table_column_to_type_index(table, column) };
+                let id = unsafe { *type_.array.add(type_index as usize) };
+                let type_info = unsafe { &*sys::ecs_get_type_info(world.world_ptr(), id) };
+                let size = type_info.size as usize;
+                let padding = type_padding(world, type_info.component, size);
+
+                ColumnLayout {
+                    id: Id(id),
+                    name: id_name(world, id),
+                    size,
+                    alignment: type_info.alignment as usize,
+                    padding_to_next: padding.map(|padding| padding.trailing),
+                    padding: padding.map(|padding| padding.total),
+                }
+            })
+            .collect()
+    }
+}
+
+impl World {
+    /// Returns the components of which at least a quarter of each value is padding.
+    ///
+    /// Padding is computed from struct reflection. Components that have data but no registered
+    /// members are reported with an unknown padding, as their layout can't be checked.
+    /// Reordering the fields of a padded component, or splitting it up, makes more of it fit in
+    /// a cache line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[repr(C)]
+    /// struct Padded {
+    ///     a: u8,
+    ///     b: u64,
+    ///     c: u8,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .component::<Padded>()
+    ///     .member::<u8>("a")
+    ///     .member::<u64>("b")
+    ///     .member::<u8>("c");
+    ///
+    /// let warnings = world.layout_warnings();
+    /// let padded = warnings.iter().find(|w| w.id == world.component_id::<Padded>());
+    /// assert_eq!(padded.unwrap().padding, Some(14));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Table::layout_report()`]
+    pub fn layout_warnings(&self) -> Vec<LayoutWarning> {
+        let world = WorldRef::from(self);
+        let mut warnings = Vec::new();
+
+        self.query::<&flecs::Component>()
+            .query_flags(QueryFlags::MatchDisabled | QueryFlags::MatchPrefab)
+            .build()
+            .each_entity(|e, component| {
+                let size = component.size as usize;
+                if size == 0 {
+                    return;
+                }
+
+                let padding = type_padding(world, *e.id(), size).map(|padding| padding.total);
+                if padding.is_some_and(|padding| padding == 0 || padding * 4 < size) {
+                    return;
+                }
+
+                warnings.push(LayoutWarning {
+                    id: e.id(),
+                    name: id_name(world, *e.id()),
+                    size,
+                    alignment: component.alignment as usize,
+                    padding,
+                });
+            });
+
+        warnings
+    }
+}
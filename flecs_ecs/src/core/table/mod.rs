@@ -2,11 +2,14 @@
 
 mod field;
 mod iter;
+mod layout;
 
 pub use field::{Field, FieldUntyped};
+pub(crate) use iter::fini_unfinished_run_iter;
 pub use iter::{TableIter, TableRowIter};
+pub use layout::{ColumnLayout, LayoutWarning};
 
-use std::{ffi::CStr, os::raw::c_void, ptr::NonNull};
+use std::{os::raw::c_void, ptr::NonNull};
 
 use crate::core::*;
 use crate::sys;
@@ -133,13 +136,7 @@ pub trait TableOperations<'a>: IntoTable {
                 return None;
             }
 
-            let len = CStr::from_ptr(raw_ptr).to_bytes().len();
-
-            Some(String::from_utf8_unchecked(Vec::from_raw_parts(
-                raw_ptr as *mut u8,
-                len,
-                len,
-            )))
+            Some(take_flecs_string(raw_ptr))
         }
     }
 
@@ -553,6 +550,75 @@ pub trait TableOperations<'a>: IntoTable {
         }
     }
 
+    /// Get an untyped view of the column at the provided column index.
+    ///
+    /// The view holds the pointer to the first element, the size of an element and the number
+    /// of elements in the table (range).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the column
+    ///
+    /// # Returns
+    ///
+    /// Some(FieldUntyped) for the column, or `None` if not a component
+    fn column_field_untyped(&self, index: i32) -> Option<FieldUntyped> {
+        self.column_untyped(index).map(|ptr| {
+            FieldUntyped::new(ptr, self.column_size(index), self.count() as usize, false)
+        })
+    }
+
+    /// Returns the entities stored in the table (range).
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `table::entities`
+    #[doc(alias = "table::entities")]
+    fn entities(&self) -> &[Entity] {
+        let count = self.count() as usize;
+        if count == 0 {
+            return &[];
+        }
+
+        unsafe {
+            let entities = sys::ecs_table_entities(self.table_ptr_mut()) as *const Entity;
+            std::slice::from_raw_parts(entities.add(self.offset() as usize), count)
+        }
+    }
+
+    /// Get the column of a component as a slice, with one element per entity in the
+    /// table (range) in the same order as [`TableOperations::entities()`].
+    ///
+    /// The slice is invalidated by any operation that adds entities to or removes entities from
+    /// the table, such as adding a component to one of its entities. Only hold on to it while the
+    /// world is deferred (inside systems and `world.defer`), or while no other operations run.
+    ///
+    /// # Type parameters
+    ///
+    /// * `T` - The type of the component
+    ///
+    /// # Returns
+    ///
+    /// The column, or `None` if the table does not have the component
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `T` does not match the size of the column.
+    #[allow(clippy::mut_from_ref)]
+    fn column<T: ComponentId + DataComponent>(&self) -> Option<&mut [T]> {
+        let index = self.find_column_index::<T>()?;
+        ecs_assert!(
+            self.column_size(index) == std::mem::size_of::<T>(),
+            FlecsErrorCode::InvalidParameter,
+            "column size does not match the size of `{}`",
+            std::any::type_name::<T>()
+        );
+
+        self.column_untyped(index).map(|ptr| unsafe {
+            std::slice::from_raw_parts_mut(ptr as *mut T, self.count() as usize)
+        })
+    }
+
     /// Get column, components array ptr from table by component type.
     ///
     /// # Type parameters
@@ -565,6 +631,7 @@ pub trait TableOperations<'a>: IntoTable {
     ///
     /// # See also
     ///
+    /// * [`TableOperations::column()`]
     /// * C++ API: `table::get`
     #[doc(alias = "table::get")]
     fn get_mut<T: ComponentId>(&self) -> Option<&mut [T]> {
@@ -158,6 +158,64 @@ fn check_term_access_validity<'a>(term: &impl TermBuilderImpl<'a>) {
         panic!("This function should only be used on terms that are not part of the generic type signature. ")
     }
 }
+
+/// Flecs only accepts [`InOutKind::Filter`] on observers. For queries and systems a filter term is
+/// a term that isn't read or written, except for terms of the type signature, which are read only
+/// since their data is handed to the callback.
+pub(crate) fn resolve_filter_terms(terms: &mut [sys::ecs_term_t], generic_terms: usize) {
+    for (index, term) in terms.iter_mut().enumerate() {
+        if term.inout == InOutKind::Filter as i16 {
+            term.inout = if index < generic_terms {
+                InOutKind::In as i16
+            } else {
+                InOutKind::None as i16
+            };
+        }
+    }
+}
+
+/// Terms created from an id, such as with `with_id`, only have `term.id` set. Flecs fills the
+/// first and second refs from that id and ignores values that were set on them, so move the id
+/// into the refs before `replaced` is changed.
+fn detach_term_id<'a>(builder: &mut impl TermBuilderImpl<'a>, replaced: TermRefMode) {
+    let world = builder.world_ptr();
+    let term = builder.current_term_mut();
+    let id = term.id;
+    let ref_flags = sys::EcsTermRefFlags as u64;
+
+    if id & RUST_ECS_COMPONENT_MASK == 0
+        || term.first.id & !ref_flags != 0
+        || !term.first.name.is_null()
+        || term.second.id & !ref_flags != 0
+        || !term.second.name.is_null()
+    {
+        return;
+    }
+
+    let alive = |e: u64| {
+        let alive = unsafe { sys::ecs_get_alive(world, e) };
+        if alive != 0 {
+            alive
+        } else {
+            e
+        }
+    };
+
+    if id & ECS_PAIR != 0 {
+        if replaced != TermRefMode::First {
+            term.first.id |= alive(*ecs_first(id));
+        }
+        if replaced != TermRefMode::Second {
+            term.second.id |= alive(*ecs_second(id));
+        }
+    } else if replaced != TermRefMode::First {
+        term.first.id |= id & RUST_ECS_COMPONENT_MASK;
+    }
+
+    // keep the id flags, flecs builds the id from the refs when it has no entity part
+    term.id = id & RUST_ecs_id_FLAGS_MASK;
+}
+
 /// Term builder interface for building queries, observers and systems.
 /// A term is a single element of a query expression.
 pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfig<'a> {
@@ -442,6 +500,7 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
     fn first(&mut self) -> &mut Self {
         check_term_access_validity(self);
 
+        detach_term_id(self, TermRefMode::First);
         self.set_term_ref_mode(TermRefMode::First);
         self
     }
@@ -455,6 +514,7 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
     #[doc(alias = "term_builder_i::second")]
     fn second(&mut self) -> &mut Self {
         check_term_access_validity(self);
+        detach_term_id(self, TermRefMode::Second);
         self.set_term_ref_mode(TermRefMode::Second);
         self
     }
@@ -1106,7 +1166,12 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
         self
     }
 
-    /// Query terms are not triggered on by observers
+    /// Filter terms are matched, but not read or written. Observers are not triggered by changes
+    /// to filter terms.
+    ///
+    /// Queries and systems treat a filter term like [`Self::set_inout_none`], so iterating the
+    /// query doesn't mark the component as changed. Filter terms of the type signature are
+    /// read only instead, since their data is still passed to the callback.
     ///
     /// # See also
     ///
@@ -1,6 +1,63 @@
 #![doc(hidden)]
 use std::fmt::{Display, Formatter};
 
+/// Error of an operation that failed in flecs, containing the error flecs reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlecsError {
+    message: String,
+    term_index: Option<u32>,
+}
+
+impl FlecsError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            term_index: None,
+        }
+    }
+
+    /// Creates the error for a query that failed to build. Flecs lists the terms of the query
+    /// below the error and marks the term that caused it with `>`.
+    pub(crate) fn from_query_error(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let term_index = message
+            .lines()
+            .skip(1)
+            .filter(|line| line.starts_with("   ") || line.starts_with(" > "))
+            .position(|line| line.starts_with(" > "))
+            .map(|index| index as u32);
+
+        Self {
+            message,
+            term_index,
+        }
+    }
+
+    pub(crate) fn with_term_index(mut self, term_index: u32) -> Self {
+        self.term_index = Some(term_index);
+        self
+    }
+
+    /// Returns the error message reported by flecs.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the index of the term that caused the error, if the error was caused by a
+    /// query term.
+    pub fn term_index(&self) -> Option<u32> {
+        self.term_index
+    }
+}
+
+impl Display for FlecsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for FlecsError {}
+
 #[doc(hidden)]
 /// Enum representing the error codes that can be used by `ecs_asserts` and `ecs_abort`
 pub enum FlecsErrorCode {
@@ -248,6 +248,21 @@ pub(crate) fn set_helper<T: ComponentId>(
         );
     };
 
+    set_helper_sized(world, entity, value, id);
+}
+
+/// Same as [`set_helper`], without the compile time check that `T` is not a zero-sized type.
+///
+/// Used by generic code that branches on the size of `T` at runtime. Must not be called
+/// with a zero-sized type.
+pub(crate) fn set_helper_sized<T: ComponentId>(
+    world: *mut sys::ecs_world_t,
+    entity: u64,
+    value: T,
+    id: u64,
+) {
+    debug_assert!(std::mem::size_of::<T>() != 0);
+
     let mut is_new = false;
     unsafe {
         if sys::ecs_is_deferred(world) {
@@ -445,6 +460,26 @@ pub(crate) fn copy_and_allocate_c_char_from_rust_str(data: &str) -> *mut c_char
     memory_c_str as *mut c_char
 }
 
+/// Copies a string allocated by flecs into a Rust `String` and frees the flecs allocation.
+///
+/// Flecs returns a null pointer for empty strings, which results in an empty `String`.
+///
+/// # Safety
+///
+/// `c_string` must be null or a null terminated string allocated with the flecs os api,
+/// ownership of which is passed to this function.
+pub(crate) unsafe fn take_flecs_string(c_string: *mut c_char) -> String {
+    if c_string.is_null() {
+        return String::new();
+    }
+
+    let string = std::ffi::CStr::from_ptr(c_string)
+        .to_string_lossy()
+        .into_owned();
+    sys::ecs_os_api.free_.expect("os api is missing")(c_string as *mut std::ffi::c_void);
+    string
+}
+
 /// Prints the given C string to the console.
 ///
 /// # Note
@@ -1,4 +1,10 @@
 //! sets various internal logging options
+use std::cell::{Cell, RefCell};
+use std::ffi::{c_char, CStr};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
 use crate::sys;
 
 /// Sets the logging level to the specified value.
@@ -53,3 +59,208 @@ pub fn enable_timedelta_logging(enabled: bool) {
         sys::ecs_log_enable_timedelta(enabled);
     }
 }
+
+static PANIC_ON_ABORT: AtomicBool = AtomicBool::new(false);
+static DEFAULT_LOG: OnceLock<sys::ecs_os_api_log_t> = OnceLock::new();
+static DEFAULT_ABORT: OnceLock<sys::ecs_os_api_abort_t> = OnceLock::new();
+
+thread_local! {
+    static PANIC_ON_ABORT_SCOPE: Cell<Option<bool>> = const { Cell::new(None) };
+    static LAST_FATAL: RefCell<Option<String>> = const { RefCell::new(None) };
+    static CAPTURED_ERROR: RefCell<Option<Option<String>>> = const { RefCell::new(None) };
+}
+
+/// Sets whether flecs panics instead of aborting the process when it hits an internal assert or
+/// an unrecoverable error. Disabled by default.
+///
+/// When enabled, the panic message contains the error that flecs logged before aborting, and
+/// the panic can be caught, e.g. by `#[should_panic]` tests. When the crate is built with
+/// `panic = "abort"`, unwinding is impossible and the process is aborted after the error is
+/// logged, the same as when this is disabled.
+///
+/// The handler is installed when the OS API is initialized, see
+/// [`ecs_os_api::ensure_initialized()`](crate::core::ecs_os_api::ensure_initialized). It's not
+/// installed when an init hook replaces the abort function.
+///
+/// # Arguments
+///
+/// * `enabled` - True to panic, false to abort the process.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// flecs_ecs::log::panic_on_abort(true);
+///
+/// let world = World::new();
+/// let e = world.entity();
+///
+/// // adding id 0 is invalid
+/// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+///     flecs_ecs::sys::ecs_add_id(world.ptr_mut(), *e.id(), 0);
+/// }));
+/// # // asserts of flecs are compiled out in release builds
+/// # #[cfg(debug_assertions)]
+/// assert!(result.is_err());
+/// ```
+pub fn panic_on_abort(enabled: bool) {
+    PANIC_ON_ABORT.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether flecs panics instead of aborting the process on the current thread.
+///
+/// # See also
+///
+/// * [`panic_on_abort()`]
+/// * [`panic_on_abort_scope()`]
+pub fn is_panic_on_abort() -> bool {
+    PANIC_ON_ABORT_SCOPE
+        .get()
+        .unwrap_or_else(|| PANIC_ON_ABORT.load(Ordering::Relaxed))
+}
+
+/// Sets whether flecs panics instead of aborting the process on the current thread, until the
+/// returned guard is dropped, which restores the previous setting.
+///
+/// The scope takes precedence over [`panic_on_abort()`]. As it only applies to the current
+/// thread, scopes of tests that run in parallel don't affect each other. Aborts on other
+/// threads, e.g. in multi threaded systems, use the global setting.
+///
+/// # Arguments
+///
+/// * `enabled` - True to panic, false to abort the process.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// {
+///     let _guard = flecs_ecs::log::panic_on_abort_scope(true);
+///     assert!(flecs_ecs::log::is_panic_on_abort());
+/// }
+/// assert!(!flecs_ecs::log::is_panic_on_abort());
+/// ```
+///
+/// # See also
+///
+/// * [`panic_on_abort()`]
+pub fn panic_on_abort_scope(enabled: bool) -> PanicOnAbortGuard {
+    PanicOnAbortGuard {
+        previous: PANIC_ON_ABORT_SCOPE.replace(Some(enabled)),
+        _not_send: PhantomData,
+    }
+}
+
+/// Restores the previous setting of the current thread when dropped.
+///
+/// Returned by [`panic_on_abort_scope()`].
+#[must_use = "the previous setting is restored when the guard is dropped"]
+pub struct PanicOnAbortGuard {
+    previous: Option<bool>,
+    // the setting belongs to the thread that created the guard
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for PanicOnAbortGuard {
+    fn drop(&mut self) {
+        PANIC_ON_ABORT_SCOPE.set(self.previous);
+    }
+}
+
+/// Wrap the log function to remember fatal errors, and install the abort handler when the abort
+/// function is still the default one.
+pub(crate) fn install_abort_handler(
+    api: &mut sys::ecs_os_api_t,
+    default_abort: sys::ecs_os_api_abort_t,
+) {
+    DEFAULT_LOG.get_or_init(|| api.log_);
+    api.log_ = Some(log_capture_fatal);
+
+    // an init hook installed its own abort function
+    if api.abort_.map(|f| f as usize) != default_abort.map(|f| f as usize) {
+        return;
+    }
+
+    DEFAULT_ABORT.get_or_init(|| default_abort);
+    api.abort_ = Some(abort_panic);
+}
+
+/// Run `func` while capturing the errors flecs logs on this thread, instead of logging them.
+/// Returns the first captured error, which is used as the message of a [`FlecsError`].
+///
+/// [`FlecsError`]: crate::core::FlecsError
+pub(crate) fn capture_error<R>(func: impl FnOnce() -> R) -> (R, Option<String>) {
+    // restores the outer capture, also when `func` panics
+    struct Restore(Option<Option<String>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let prev = self.0.take();
+            CAPTURED_ERROR.with(|captured| *captured.borrow_mut() = prev);
+        }
+    }
+
+    let _restore = Restore(CAPTURED_ERROR.with(|captured| captured.borrow_mut().replace(None)));
+    let result = func();
+    let error = CAPTURED_ERROR.with(|captured| captured.borrow_mut().take());
+    (result, error.flatten())
+}
+
+unsafe extern "C-unwind" fn log_capture_fatal(
+    level: i32,
+    file: *const c_char,
+    line: i32,
+    msg: *const c_char,
+) {
+    // errors that are returned to the caller aren't logged
+    if level == -3 && !msg.is_null() {
+        let captured = CAPTURED_ERROR.with(|captured| match &mut *captured.borrow_mut() {
+            Some(error) => {
+                error.get_or_insert_with(|| CStr::from_ptr(msg).to_string_lossy().into_owned());
+                true
+            }
+            None => false,
+        });
+        if captured {
+            return;
+        }
+    }
+
+    if let Some(log) = DEFAULT_LOG.get().copied().flatten() {
+        log(level, file, line, msg);
+    }
+
+    // fatal errors are logged right before flecs aborts
+    if level == -4 && !msg.is_null() {
+        let msg = CStr::from_ptr(msg).to_string_lossy();
+        let fatal = if file.is_null() {
+            msg.into_owned()
+        } else {
+            format!(
+                "{}:{}: {}",
+                CStr::from_ptr(file).to_string_lossy(),
+                line,
+                msg
+            )
+        };
+        LAST_FATAL.with(|last| *last.borrow_mut() = Some(fatal));
+    }
+}
+
+unsafe extern "C-unwind" fn abort_panic() {
+    let fatal = LAST_FATAL.with(|last| last.borrow_mut().take());
+
+    if cfg!(panic = "unwind") && is_panic_on_abort() {
+        match fatal {
+            Some(fatal) => panic!("flecs aborted: {}", fatal),
+            None => panic!("flecs aborted"),
+        }
+    }
+
+    if let Some(abort) = DEFAULT_ABORT.get().copied().flatten() {
+        abort();
+    }
+    std::process::abort();
+}
@@ -4,7 +4,8 @@
 mod errors;
 mod functions;
 pub(crate) mod id_map;
-mod log;
+pub(crate) mod log;
+mod small_cstr;
 pub mod traits;
 pub mod types;
 
@@ -12,6 +13,7 @@ pub use errors::*;
 pub use functions::*;
 pub(crate) use id_map::*;
 pub use log::*;
+pub use small_cstr::{NulByteError, SmallCStr};
 
 #[doc(hidden)]
 pub use traits::*;
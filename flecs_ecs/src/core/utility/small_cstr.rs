@@ -0,0 +1,207 @@
+//! Nul terminated strings that are stored inline when they are short.
+
+use std::ffi::{c_char, CStr, CString};
+use std::fmt::{Debug, Display, Formatter};
+use std::mem::MaybeUninit;
+
+use crate::core::*;
+
+/// Error returned by [`SmallCStr::new()`] when the string contains a nul byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NulByteError {
+    position: usize,
+}
+
+impl NulByteError {
+    /// Returns the position of the first nul byte in the string.
+    pub fn nul_position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for NulByteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "nul byte found in string at position {}", self.position)
+    }
+}
+
+impl std::error::Error for NulByteError {}
+
+enum Repr<const N: usize> {
+    Inline {
+        buf: MaybeUninit<[u8; N]>,
+        len: usize,
+    },
+    Heap(CString),
+}
+
+/// A nul terminated copy of a string, to pass to C functions that take a `*const c_char`.
+///
+/// Strings of less than `N` bytes are stored inline, longer strings are stored on the heap. The
+/// default of 128 bytes fits all but the longest entity names and paths, so converting a name
+/// doesn't allocate. This is used by the string accepting functions of this crate, such as
+/// [`World::lookup()`] and [`EntityView::set_name()`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+/// use flecs_ecs::sys;
+///
+/// let world = World::new();
+/// let e = world.entity_named("Bob");
+///
+/// let name = SmallCStr::<128>::new("Bob").unwrap();
+/// assert!(name.is_inline());
+///
+/// let found = unsafe { sys::ecs_lookup(world.ptr_mut(), name.as_ptr()) };
+/// assert_eq!(found, *e.id());
+///
+/// assert!(SmallCStr::<128>::new("Bo\0b").is_err());
+/// ```
+pub struct SmallCStr<const N: usize = 128> {
+    repr: Repr<N>,
+}
+
+impl<const N: usize> SmallCStr<N> {
+    /// Create a nul terminated copy of the string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string contains a nul byte, which would end the string early
+    /// when it's read by C.
+    pub fn new(s: &str) -> Result<Self, NulByteError> {
+        match Self::copy(s) {
+            (c_str, None) => Ok(c_str),
+            (_, Some(position)) => Err(NulByteError { position }),
+        }
+    }
+
+    /// Create a nul terminated copy of the string, which ends at the first nul byte if it
+    /// contains one.
+    pub fn new_truncated(s: &str) -> Self {
+        Self::copy(s).0
+    }
+
+    /// Copy a string argument of a wrapper function, asserting that it doesn't contain a nul
+    /// byte.
+    pub(crate) fn from_arg(s: &str) -> Self {
+        let (c_str, _position) = Self::copy(s);
+        ecs_assert!(
+            _position.is_none(),
+            FlecsErrorCode::InvalidParameter,
+            "string argument contains a nul byte: {:?}",
+            s
+        );
+        c_str
+    }
+
+    /// Copy the string up to its first nul byte, and return the position of that nul byte.
+    fn copy(s: &str) -> (Self, Option<usize>) {
+        let bytes = s.as_bytes();
+        if bytes.len() >= N {
+            return match CString::new(bytes) {
+                Ok(heap) => (
+                    Self {
+                        repr: Repr::Heap(heap),
+                    },
+                    None,
+                ),
+                Err(err) => {
+                    let position = err.nul_position();
+                    (Self::copy(&s[..position]).0, Some(position))
+                }
+            };
+        }
+
+        let mut buf = MaybeUninit::<[u8; N]>::uninit();
+        let ptr = buf.as_mut_ptr() as *mut u8;
+        // SAFETY: the string and the nul terminator fit in the buffer
+        let terminated = unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+            ptr.add(bytes.len()).write(0);
+            std::slice::from_raw_parts(ptr, bytes.len() + 1)
+        };
+        let len = CStr::from_bytes_until_nul(terminated)
+            .map_or(bytes.len(), |c_str| c_str.to_bytes().len());
+
+        (
+            Self {
+                repr: Repr::Inline { buf, len },
+            },
+            (len < bytes.len()).then_some(len),
+        )
+    }
+
+    /// Returns a pointer to the nul terminated string, which is valid for as long as `self` is
+    /// not moved or dropped.
+    #[inline]
+    pub fn as_ptr(&self) -> *const c_char {
+        match &self.repr {
+            Repr::Inline { buf, .. } => buf.as_ptr() as *const c_char,
+            Repr::Heap(heap) => heap.as_ptr(),
+        }
+    }
+
+    /// Returns the string as a `CStr`.
+    #[inline]
+    pub fn as_c_str(&self) -> &CStr {
+        match &self.repr {
+            // SAFETY: the buffer holds `len` bytes without nul, followed by a nul terminator
+            Repr::Inline { buf, len } => unsafe {
+                CStr::from_bytes_with_nul_unchecked(std::slice::from_raw_parts(
+                    buf.as_ptr() as *const u8,
+                    len + 1,
+                ))
+            },
+            Repr::Heap(heap) => heap.as_c_str(),
+        }
+    }
+
+    /// Returns the string, without the nul terminator.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the bytes were copied from a `&str`
+        unsafe { std::str::from_utf8_unchecked(self.as_c_str().to_bytes()) }
+    }
+
+    /// Returns the length of the string in bytes, without the nul terminator.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { len, .. } => *len,
+            Repr::Heap(heap) => heap.as_bytes().len(),
+        }
+    }
+
+    /// Returns true if the string is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the string is stored inline, false if it was too long and is stored on
+    /// the heap.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline { .. })
+    }
+}
+
+impl<const N: usize> Debug for SmallCStr<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_c_str(), f)
+    }
+}
+
+impl<const N: usize> Display for SmallCStr<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> AsRef<CStr> for SmallCStr<N> {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
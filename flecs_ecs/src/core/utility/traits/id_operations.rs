@@ -65,15 +65,26 @@ pub trait IdOperations<'a>: WorldProvider<'a> + IntoId + Sized + Copy {
     ///
     /// # Returns
     ///
-    /// The flags associated with the id or 0 Entity if the id is not in use
+    /// The flags associated with the id, empty if the id has no flags
     ///
     /// # See also
     ///
     /// * C++ API: `id::flags`
     #[doc(alias = "id::flags")]
     #[inline(always)]
-    fn flags(self) -> Self {
-        Self::new_from_id(self.world(), self.into() & RUST_ecs_id_FLAGS_MASK)
+    fn flags(self) -> IdFlags {
+        IdFlags::from_id(self)
+    }
+
+    /// Test if id has all of the specified flags
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `id::has_flags`
+    #[doc(alias = "id::has_flags")]
+    #[inline(always)]
+    fn has_flags(self, flags: IdFlags) -> bool {
+        self.flags().contains(flags)
     }
 
     /// Test if id has specified role
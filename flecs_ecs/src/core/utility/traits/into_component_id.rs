@@ -71,3 +71,35 @@ where
         std::any::type_name::<(T, U)>()
     }
 }
+
+/// A tuple of components or pairs, such as `(A, B, (Rel, Tgt))`.
+///
+/// Used by [`QueryBuilderImpl::without_any()`](crate::core::QueryBuilderImpl::without_any).
+pub trait ComponentOrPairIdTuple {
+    /// Call `func` with the id of each element of the tuple, in order.
+    fn for_each_id<'a>(world: impl WorldProvider<'a>, func: impl FnMut(sys::ecs_id_t));
+}
+
+macro_rules! impl_component_or_pair_id_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: ComponentOrPairId),*> ComponentOrPairIdTuple for ($($t,)*) {
+            fn for_each_id<'a>(world: impl WorldProvider<'a>, mut func: impl FnMut(sys::ecs_id_t)) {
+                let world = world.world();
+                $( func($t::get_id(world)); )*
+            }
+        }
+    };
+}
+
+impl_component_or_pair_id_tuple!(A);
+impl_component_or_pair_id_tuple!(A, B);
+impl_component_or_pair_id_tuple!(A, B, C);
+impl_component_or_pair_id_tuple!(A, B, C, D);
+impl_component_or_pair_id_tuple!(A, B, C, D, E);
+impl_component_or_pair_id_tuple!(A, B, C, D, E, F);
+impl_component_or_pair_id_tuple!(A, B, C, D, E, F, G);
+impl_component_or_pair_id_tuple!(A, B, C, D, E, F, G, H);
+impl_component_or_pair_id_tuple!(A, B, C, D, E, F, G, H, I);
+impl_component_or_pair_id_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_component_or_pair_id_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_component_or_pair_id_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,25 @@
+use std::ops::ControlFlow;
+
+/// Return type of the callbacks passed to the `*_while` iteration functions,
+/// such as [`QueryAPI::each_while()`](super::QueryAPI::each_while).
+///
+/// Returning `true` or [`ControlFlow::Continue`] moves on to the next item,
+/// returning `false` or [`ControlFlow::Break`] stops the iteration.
+pub trait IterControl {
+    /// Returns `true` if the iteration should continue with the next item.
+    fn should_continue(self) -> bool;
+}
+
+impl IterControl for bool {
+    #[inline(always)]
+    fn should_continue(self) -> bool {
+        self
+    }
+}
+
+impl IterControl for ControlFlow<()> {
+    #[inline(always)]
+    fn should_continue(self) -> bool {
+        self.is_continue()
+    }
+}
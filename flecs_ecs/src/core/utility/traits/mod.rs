@@ -3,6 +3,7 @@ mod inout_oper;
 mod into_component_id;
 mod into_id;
 mod into_table;
+mod iter_control;
 mod query_api;
 mod system_api;
 mod world_provider;
@@ -12,6 +13,7 @@ pub use inout_oper::*;
 pub use into_component_id::*;
 pub use into_id::*;
 pub use into_table::*;
+pub use iter_control::*;
 pub use query_api::*;
 pub use system_api::*;
 pub use world_provider::*;
@@ -232,11 +234,15 @@ pub mod private {
                 let mut iter_t = TableIter::new(&mut *iter);
                 iter_t.iter_mut().flags &= !sys::EcsIterIsValid;
                 run(iter_t);
-                // ecs_assert!(
-                //     iter.flags & sys::EcsIterIsValid == 0,
-                //     FlecsErrorCode::InvalidOperation,
-                //     "iterators must be manually finished with ecs_iter_fini"
-                // );
+
+                // systems that match nothing and observers don't allocate iterator resources,
+                // for other systems an early return from the callback would leak the iterator
+                #[cfg(feature = "flecs_system")]
+                if (*iter.query).flags & sys::EcsQueryMatchNothing == 0
+                    && sys::ecs_has_id(iter.real_world, iter.system, flecs::system::System::ID)
+                {
+                    fini_unfinished_run_iter(iter);
+                }
             }
         }
 
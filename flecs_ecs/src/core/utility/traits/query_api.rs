@@ -1,5 +1,3 @@
-use std::ffi::c_char;
-
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
@@ -40,6 +38,40 @@ where
     /// The following function signatures is valid:
     ///  - func(comp1 : &mut T1, comp2 : &mut T2, ...)
     ///
+    /// A pair `&(First, Second)` passes the element of the pair that holds the data: `First` if
+    /// it is not a tag, otherwise `Second`. Pairs of two tags fail to compile, add them with
+    /// `.with()` instead.
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Eats {
+    ///     amount: u32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Apples;
+    ///
+    /// let world = World::new();
+    /// world.entity().set_pair::<Eats, Apples>(Eats { amount: 2 });
+    ///
+    /// world
+    ///     .new_query::<&mut (Eats, Apples)>()
+    ///     .each(|eats| eats.amount += 1);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Likes;
+    ///
+    /// #[derive(Component)]
+    /// struct Apples;
+    ///
+    /// let world = World::new();
+    /// world.new_query::<&(Likes, Apples)>().each(|_| {});
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`World::each()`]
@@ -143,6 +175,274 @@ where
         }
     }
 
+    /// Each iterator that can stop early.
+    /// Like [`each()`](Self::each), but the callback returns whether iteration should continue.
+    /// Returning `false` or [`ControlFlow::Break`](std::ops::ControlFlow::Break) stops the
+    /// iteration, no further rows or tables are visited.
+    /// The following function signatures is valid:
+    ///  - `func(comp1 : &mut T1, comp2 : &mut T2, ...) -> bool`
+    ///  - `func(comp1 : &mut T1, comp2 : &mut T2, ...) -> ControlFlow<()>`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use std::ops::ControlFlow;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// for x in 0..10 {
+    ///     world.entity().set(Position { x, y: 0 });
+    /// }
+    ///
+    /// let mut visited = 0;
+    /// world.new_query::<&Position>().each_while(|pos| {
+    ///     visited += 1;
+    ///     if pos.x == 2 {
+    ///         ControlFlow::Break(())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(visited, 3);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::each()`]
+    /// * [`QueryAPI::each_entity_while()`]
+    fn each_while<R: IterControl>(&self, mut func: impl FnMut(T::TupleType<'_>) -> R) {
+        const {
+            assert!(
+                !T::CONTAINS_ANY_TAG_TERM,
+                "a type provided in the query signature is a Tag and cannot be used with `.each`. use `.run` instead or provide the tag with `.with()`"
+            );
+        }
+
+        unsafe {
+            let world = self.world_ptr_mut();
+            let mut iter = self.retrieve_iter();
+            iter.flags |= sys::EcsIterCppEach;
+
+            while self.iter_next(&mut iter) {
+                let mut components_data = T::create_ptrs(&iter);
+                let iter_count = {
+                    if iter.count == 0 && iter.table.is_null() {
+                        1_usize
+                    } else {
+                        iter.count as usize
+                    }
+                };
+
+                let mut stopped = false;
+
+                sys::ecs_table_lock(world, iter.table);
+
+                for i in 0..iter_count {
+                    let tuple = components_data.get_tuple(&iter, i);
+                    if !func(tuple).should_continue() {
+                        stopped = true;
+                        break;
+                    }
+                }
+
+                sys::ecs_table_unlock(world, iter.table);
+
+                if stopped {
+                    // stop iterating the remaining tables
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Each iterator with the entity that can stop early.
+    /// Like [`each_entity()`](Self::each_entity), but the callback returns whether iteration should continue.
+    /// Returning `false` or [`ControlFlow::Break`](std::ops::ControlFlow::Break) stops the
+    /// iteration, no further rows or tables are visited.
+    /// The following function signatures is valid:
+    ///  - `func(e : Entity , comp1 : &mut T1, comp2 : &mut T2, ...) -> bool`
+    ///  - `func(e : Entity , comp1 : &mut T1, comp2 : &mut T2, ...) -> ControlFlow<()>`
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::each_entity()`]
+    /// * [`QueryAPI::each_while()`]
+    fn each_entity_while<R: IterControl>(
+        &self,
+        mut func: impl FnMut(EntityView, T::TupleType<'_>) -> R,
+    ) {
+        const {
+            assert!(
+                !T::CONTAINS_ANY_TAG_TERM,
+                "a type provided in the query signature is a Tag and cannot be used with `.each`. use `.run` instead or provide the tag with `.with()`"
+            );
+        }
+
+        unsafe {
+            let world = self.world_ptr_mut();
+            let mut iter = self.retrieve_iter();
+            iter.flags |= sys::EcsIterCppEach;
+
+            while self.iter_next(&mut iter) {
+                ecs_assert!(
+                    !iter.entities.is_null(),
+                    FlecsErrorCode::InvalidParameter,
+                    "query does not return entities ($this variable is not populated)"
+                );
+
+                let mut components_data = T::create_ptrs(&iter);
+                let iter_count = iter.count as usize;
+
+                ecs_assert!(
+                    iter.count > 0,
+                    FlecsErrorCode::InvalidOperation,
+                    "no entities returned, use each_while() without flecs::entity argument",
+                );
+
+                let mut stopped = false;
+
+                sys::ecs_table_lock(world, iter.table);
+
+                for i in 0..iter_count {
+                    let world = self.world();
+                    let tuple = components_data.get_tuple(&iter, i);
+                    let entity = EntityView::new_from(world, *iter.entities.add(i));
+                    if !func(entity, tuple).should_continue() {
+                        stopped = true;
+                        break;
+                    }
+                }
+
+                sys::ecs_table_unlock(world, iter.table);
+
+                if stopped {
+                    // stop iterating the remaining tables
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Each iterator with a command buffer.
+    /// The "each" iterator accepts a function that is invoked for each matching entity.
+    /// The following function signatures is valid:
+    ///  - func(cmd : &mut Commands, e : Entity , comp1 : &mut T1, comp2 : &mut T2, ...)
+    ///
+    /// Structural changes, such as deleting entities or adding components, can't be made
+    /// to tables that are being iterated. The [`Commands`] buffer records these changes
+    /// and applies them in the recorded order once the iteration completes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e1 = world.entity().set(Position { x: 10, y: 20 });
+    /// let e2 = world.entity().set(Position { x: -10, y: 20 });
+    ///
+    /// world
+    ///     .new_query::<&Position>()
+    ///     .each_entity_cmd(|cmd, e, p| {
+    ///         if p.x < 0 {
+    ///             cmd.destruct(e);
+    ///         }
+    ///     });
+    ///
+    /// assert!(e1.is_alive());
+    /// assert!(!e2.is_alive());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Commands`]
+    /// * [`QueryAPI::each_entity()`]
+    fn each_entity_cmd(
+        &self,
+        mut func: impl FnMut(&mut Commands<'a>, EntityView, T::TupleType<'_>),
+    ) {
+        let mut commands = Commands::new(self.world());
+        self.each_entity(|e, components| func(&mut commands, e, components));
+        commands.apply();
+    }
+
+    /// Iterate only the matched entities that have a flag set in their [`Flags`] component.
+    ///
+    /// Entities are matched by the terms of the query plus a [`Flags`] term, so entities without
+    /// flags are skipped. The flag itself is tested for each matched entity. When the query
+    /// doesn't have the [`Flags`] term, each iteration creates an uncached query with the
+    /// additional term; add the term to the query to reuse its cache, see [`WithFlag`].
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - The flag that must be set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// #[derive(FlagSet, Clone, Copy)]
+    /// enum UnitFlags {
+    ///     Burning,
+    ///     Stunned,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e1 = world
+    ///     .entity()
+    ///     .set(Position { x: 1, y: 2 })
+    ///     .set_flag(UnitFlags::Burning, true);
+    /// world
+    ///     .entity()
+    ///     .set(Position { x: 3, y: 4 })
+    ///     .set_flag(UnitFlags::Stunned, true);
+    ///
+    /// let query = world.query::<&Position>().build();
+    ///
+    /// query
+    ///     .with_flag(UnitFlags::Burning)
+    ///     .each_entity(|e, pos| {
+    ///         assert_eq!(e, e1);
+    ///         assert_eq!(pos.x, 1);
+    ///     });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_flag()`]
+    /// * [`Flags`]
+    fn with_flag<F: FlagSet>(&self, flag: F) -> WithFlag<'_, Self, F>
+    where
+        Self: Sized,
+    {
+        WithFlag::new(self, flag)
+    }
+
     /// Each iterator. This variant of `each` provides access to the [`TableIter`] object,
     /// which contains more information about the object being iterated.
     /// The `usize` argument contains the index of the entity being iterated,
@@ -224,7 +524,7 @@ where
 
     /// find iterator to find an entity
     /// The "find" iterator accepts a function that is invoked for each matching entity and checks if the condition is true.
-    /// if it is, it returns that entity.
+    /// if it is, it returns that entity. Iteration stops at the first match.
     /// The following function signatures is valid:
     ///  - func(comp1 : &mut T1, comp2 : &mut T2, ...)
     ///
@@ -258,6 +558,12 @@ where
                 }
 
                 sys::ecs_table_unlock(world, iter.table);
+
+                if entity.is_some() {
+                    // stop iterating the remaining tables
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
             }
             entity
         }
@@ -265,7 +571,7 @@ where
 
     /// find iterator to find an entity
     /// The "find" iterator accepts a function that is invoked for each matching entity and checks if the condition is true.
-    /// if it is, it returns that entity.
+    /// if it is, it returns that entity. Iteration stops at the first match.
     /// The following function signatures is valid:
     ///  - func(entity : Entity, comp1 : &mut T1, comp2 : &mut T2, ...)
     ///
@@ -304,6 +610,12 @@ where
                 }
 
                 sys::ecs_table_unlock(world, iter.table);
+
+                if entity_result.is_some() {
+                    // stop iterating the remaining tables
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
             }
             entity_result
         }
@@ -311,7 +623,7 @@ where
 
     /// find iterator to find an entity.
     /// The "find" iterator accepts a function that is invoked for each matching entity and checks if the condition is true.
-    /// if it is, it returns that entity.
+    /// if it is, it returns that entity. Iteration stops at the first match.
     /// The following function signatures is valid:
     ///  - func(iter : `TableIter`, index : usize, comp1 : &mut T1, comp2 : &mut T2, ...)
     ///
@@ -358,6 +670,12 @@ where
                 }
 
                 sys::ecs_table_unlock(world, iter.table);
+
+                if entity_result.is_some() {
+                    // stop iterating the remaining tables
+                    sys::ecs_iter_fini(&mut iter);
+                    break;
+                }
             }
             entity_result
         }
@@ -446,21 +764,9 @@ where
         iter_t.iter_mut().flags &= !sys::EcsIterIsValid;
         func(iter_t);
 
-        //TODO flecs will integrate this before V4 release.
-        // // if the while loop with `iter_next()` exited early, the table should be unlocked
-        // // and iter should be cleaned up
-        // if (iter.flags & sys::EcsIterIsValid) != 0 && !iter.table.is_null() {
-        //     unsafe {
-        //         sys::ecs_table_unlock(iter.world, iter.table);
-        //         sys::ecs_iter_fini(&mut iter)
-        //     };
-        // }
-
-        // ecs_assert!(
-        //     iter.flags & sys::EcsIterIsValid != 0,
-        //     FlecsErrorCode::InvalidOperation,
-        //     "when using `run()`, you must call `while it.next()` in the callback"
-        // );
+        // if the while loop with `it.next()` exited early, the table should be unlocked
+        // and iter should be cleaned up
+        unsafe { fini_unfinished_run_iter(&mut iter) };
     }
 
     /// Run iterator with each forwarding.
@@ -553,6 +859,7 @@ where
         func(iter_t);
         iter.callback = None;
         iter.callback_ctx = std::ptr::null_mut();
+        unsafe { fini_unfinished_run_iter(&mut iter) };
     }
 
     /// Run iterator with each entity forwarding.
@@ -649,6 +956,7 @@ where
         func(iter_t);
         iter.callback = None;
         iter.callback_ctx = std::ptr::null_mut();
+        unsafe { fini_unfinished_run_iter(&mut iter) };
     }
 
     /// Get the entity of the current query
@@ -759,20 +1067,11 @@ where
     #[doc(alias = "query_base::str")]
     #[allow(clippy::inherent_to_string)] // this is a wrapper around a c function
     fn to_string(&self) -> String {
-        let query = self.query_ptr();
-        let result: *mut c_char = unsafe { sys::ecs_query_str(query as *const _) };
-        let rust_string =
-            String::from(unsafe { std::ffi::CStr::from_ptr(result).to_str().unwrap() });
-        unsafe {
-            if let Some(free_func) = sys::ecs_os_api.free_ {
-                free_func(result as *mut _);
-            }
-        }
-        rust_string
+        unsafe { take_flecs_string(sys::ecs_query_str(self.query_ptr())) }
     }
 
     fn find_var(&self, name: &str) -> Option<i32> {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let var_index =
             unsafe { sys::ecs_query_find_var(self.query_ptr(), name.as_ptr() as *const _) };
@@ -783,17 +1082,33 @@ where
         }
     }
 
+    /// Returns the instruction plan flecs compiled for the query, which shows how the query is
+    /// evaluated. Useful to find out why a query matches differently than expected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let query = world.new_query::<&Position>();
+    /// println!("{}", query.plan());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Self::to_string()`]
+    /// * C++ API: `query_base::plan`
+    #[doc(alias = "query_base::plan")]
     fn plan(&self) -> String {
-        let query = self.query_ptr();
-        let result: *mut c_char = unsafe { sys::ecs_query_plan(query as *const _) };
-        let rust_string =
-            String::from(unsafe { std::ffi::CStr::from_ptr(result).to_str().unwrap() });
-        unsafe {
-            if let Some(free_func) = sys::ecs_os_api.free_ {
-                free_func(result as *mut _);
-            }
-        }
-        rust_string
+        unsafe { take_flecs_string(sys::ecs_query_plan(self.query_ptr())) }
     }
 
     fn iterable(&self) -> QueryIter<P, T> {
@@ -1129,7 +1444,12 @@ where
     }
 
     /// Returns true if iterator yields at least once result.
-    fn is_true(&mut self) -> bool {
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `iter_iterable::is_true`
+    #[doc(alias = "iter_iterable::is_true")]
+    fn is_true(&self) -> bool {
         let mut it = self.retrieve_iter();
 
         let result = self.iter_next(&mut it);
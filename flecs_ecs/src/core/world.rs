@@ -1,5 +1,6 @@
 //! World operations.
 
+use std::any::Any;
 use std::ffi::CStr;
 use std::{os::raw::c_void, ptr::NonNull};
 
@@ -14,6 +15,64 @@ use crate::sys;
 
 pub(crate) type FlecsArray = std::vec::Vec<u64>;
 
+/// Restores the previous scope or with id of a world once dropped.
+///
+/// This makes sure the closures passed to [`World::scope_id()`] and [`World::with_id()`]
+/// leave the world in its original state, even when they panic.
+struct RestoreOnDrop {
+    world: *mut sys::ecs_world_t,
+    prev: sys::ecs_id_t,
+    set: unsafe extern "C-unwind" fn(*mut sys::ecs_world_t, sys::ecs_id_t) -> sys::ecs_id_t,
+}
+
+impl RestoreOnDrop {
+    fn scope(world: &World, scope: sys::ecs_entity_t) -> Self {
+        Self::new(world, scope, sys::ecs_set_scope)
+    }
+
+    fn with(world: &World, id: sys::ecs_id_t) -> Self {
+        Self::new(world, id, sys::ecs_set_with)
+    }
+
+    fn new(
+        world: &World,
+        id: sys::ecs_id_t,
+        set: unsafe extern "C-unwind" fn(*mut sys::ecs_world_t, sys::ecs_id_t) -> sys::ecs_id_t,
+    ) -> Self {
+        let world = world.raw_world.as_ptr();
+        let prev = unsafe { set(world, id) };
+        Self { world, prev, set }
+    }
+}
+
+impl Drop for RestoreOnDrop {
+    fn drop(&mut self) {
+        unsafe {
+            (self.set)(self.world, self.prev);
+        }
+    }
+}
+
+/// Ends readonly mode of a world once dropped.
+///
+/// Returned by [`World::readonly_scope()`]. The world stays in readonly mode for as long as
+/// the guard is alive, also when the code using it panics.
+///
+/// # See also
+///
+/// * [`World::readonly_scope()`]
+/// * [`World::readonly_end()`]
+#[must_use = "readonly mode ends when the guard is dropped"]
+pub struct ReadonlyGuard<'a> {
+    world: &'a World,
+}
+
+impl Drop for ReadonlyGuard<'_> {
+    fn drop(&mut self) {
+        self.world.readonly_end();
+    }
+}
+
 /// The `World` is the container for all ECS data. It stores the entities and
 /// their components, does queries and runs systems.
 ///
@@ -78,6 +137,11 @@ impl Default for World {
     }
 }
 
+/// Free hook of the context set with [`World::set_ctx()`].
+unsafe extern "C-unwind" fn free_typed_ctx(ctx: *mut c_void) {
+    drop(unsafe { Box::from_raw(ctx as *mut Box<dyn Any>) });
+}
+
 impl Drop for World {
     fn drop(&mut self) {
         if std::thread::panicking() {
@@ -122,6 +186,9 @@ impl World {
         // used for event handling with no data
         self.component_named::<()>("flecs::rust::() - None");
 
+        #[cfg(feature = "churn_debug")]
+        self.init_churn_debug();
+
         #[cfg(feature = "flecs_meta")]
         {
             self.component_named::<crate::prelude::meta::EcsTypeKind>("flecs::meta::type_kind");
@@ -210,7 +277,7 @@ impl World {
     /// let world_info = world.info();
     ///
     /// assert!(world_info.delta_time > 0.0);
-    /// //assert!(world_info.world_time_total_raw > 0.0); //BUG TODO
+    /// assert!(world_info.world_time_total_raw > 0.0);
     /// //assert!(world_info.systems_ran_frame == 0);
     /// ```
     ///
@@ -223,6 +290,43 @@ impl World {
         unsafe { *sys::ecs_get_world_info(self.raw_world.as_ptr()) }
     }
 
+    /// Returns the time passed since the last frame, scaled by the time scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_time_scale(0.5);
+    /// world.progress_time(2.0);
+    ///
+    /// assert_eq!(world.delta_time(), 1.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::world_time()`]
+    /// * [`World::set_time_scale()`]
+    /// * C++ API: `world::delta_time`
+    #[doc(alias = "world::delta_time")]
+    pub fn delta_time(&self) -> super::FTime {
+        self.info().delta_time
+    }
+
+    /// Returns the total time passed in the simulation, scaled by the time scale.
+    ///
+    /// # See also
+    ///
+    /// * [`World::delta_time()`]
+    /// * [`World::reset_clock()`]
+    /// * C++ API: `world::time`
+    #[doc(alias = "world::time")]
+    pub fn world_time(&self) -> f64 {
+        self.info().world_time_total
+    }
+
     /// Signals the application to quit.
     ///
     /// After calling this function, the next call to [`World::progress()`] returns false.
@@ -467,6 +571,7 @@ impl World {
     ///
     /// * [`World::is_readonly()`]
     /// * [`World::readonly_end()`]
+    /// * [`World::readonly_scope()`]
     /// * C++ API: `world::readonly_begin`
     #[doc(alias = "world::readonly_begin")]
     pub fn readonly_begin(&self, multi_threaded: bool) -> bool {
@@ -502,6 +607,59 @@ impl World {
         }
     }
 
+    /// Begin readonly mode, and end it when the returned guard is dropped.
+    ///
+    /// This is the scoped version of [`World::readonly_begin()`], which makes sure that
+    /// [`World::readonly_end()`] is not forgotten.
+    ///
+    /// Operations on the world while it is readonly are enqueued, and applied when readonly
+    /// mode ends. Use [`EntityView::mut_current_stage()`] to write to a specific stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_threaded` - Whether to enable readonly/multi threaded mode.
+    ///
+    /// # Returns
+    ///
+    /// A guard that ends readonly mode when dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let stage = world.stage(0);
+    ///
+    /// {
+    ///     let _readonly = world.readonly_scope(false);
+    ///     assert!(world.is_readonly());
+    ///
+    ///     stage.entity().set(Position { x: 10, y: 20 });
+    ///     assert_eq!(stage.count::<Position>(), 0);
+    /// }
+    ///
+    /// assert!(!world.is_readonly());
+    /// assert_eq!(world.count::<Position>(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::readonly_begin()`]
+    /// * [`World::readonly_end()`]
+    /// * [`World::is_readonly()`]
+    pub fn readonly_scope(&self, multi_threaded: bool) -> ReadonlyGuard<'_> {
+        self.readonly_begin(multi_threaded);
+        ReadonlyGuard { world: self }
+    }
+
     /// Test whether the current world object is readonly.
     ///
     /// This function allows the code to test whether the currently used world
@@ -810,6 +968,7 @@ impl World {
     /// * [`World::stage_id()`]
     /// * C++ API: `world::get_stage_count`
     #[doc(alias = "world::get_stage_count")]
+    #[doc(alias = "stage_count")]
     pub fn get_stage_count(&self) -> i32 {
         unsafe { sys::ecs_get_stage_count(self.raw_world.as_ptr()) }
     }
@@ -932,7 +1091,7 @@ impl World {
     ///
     /// let stage = world.create_async_stage();
     ///
-    /// e.mut_current_stage(stage).set(Position { x: 10, y: 20 });
+    /// e.mut_current_stage(&stage).set(Position { x: 10, y: 20 });
     ///
     /// assert!(!e.has::<Position>());
     ///
@@ -951,7 +1110,9 @@ impl World {
     /// * C++ API: `world::merge`
     #[doc(alias = "world::merge")]
     pub fn merge(&self) {
+        let merge_hooks = self.merge_hooks_begin();
         unsafe { sys::ecs_merge(self.raw_world.as_ptr()) };
+        self.invoke_merge_hooks(merge_hooks);
     }
 
     /// Get stage-specific world pointer.
@@ -1019,11 +1180,13 @@ impl World {
     /// The application must ensure that no commands are added to the stage while the
     /// stage is being merged.
     ///
-    /// An asynchronous stage will be cleaned up when it is dropped.
+    /// An asynchronous stage will be cleaned up when it is dropped. It borrows the world, so
+    /// it can't outlive it. The stage can be moved to another thread to enqueue commands from
+    /// there.
     ///
     /// # Returns
     ///
-    /// The stage.
+    /// The stage, which owns the asynchronous stage.
     ///
     /// # Example
     ///
@@ -1042,7 +1205,7 @@ impl World {
     ///
     /// let stage = world.create_async_stage();
     ///
-    /// e.mut_current_stage(stage).set(Position { x: 10, y: 20 });
+    /// e.mut_current_stage(&stage).set(Position { x: 10, y: 20 });
     ///
     /// assert!(!e.has::<Position>());
     ///
@@ -1054,8 +1217,13 @@ impl World {
     ///
     /// * C++ API: `world::async_stage`
     #[doc(alias = "world::async_stage")]
-    pub fn create_async_stage(&self) -> WorldRef {
-        unsafe { WorldRef::from_ptr(sys::ecs_stage_new(self.raw_world.as_ptr())) }
+    pub fn create_async_stage(&self) -> Stage<'_> {
+        let stage = unsafe { sys::ecs_stage_new(self.raw_world.as_ptr()) };
+        Stage::new(World {
+            raw_world: NonNull::new(stage).expect("failed to create async stage"),
+            components: self.components,
+            components_array: self.components_array,
+        })
     }
 
     /// Get actual world.
@@ -1149,6 +1317,100 @@ impl World {
         unsafe { sys::ecs_get_ctx(self.raw_world.as_ptr()) }
     }
 
+    /// Set a typed context value that can be accessed by anyone that has a reference to the
+    /// world, for example from inside a system with `it.world().ctx::<T>()`.
+    ///
+    /// The value is dropped when the world is destroyed, or when it is replaced by another call to
+    /// `set_ctx`. Setting a context with [`World::set_context()`] replaces the value without
+    /// dropping it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// struct Config {
+    ///     speed: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_ctx(Config { speed: 5 });
+    ///
+    /// assert_eq!(world.ctx::<Config>().map(|c| c.speed), Some(5));
+    /// assert!(world.ctx::<i32>().is_none());
+    ///
+    /// world.ctx_mut(|config: &mut Config| config.speed = 10);
+    /// assert_eq!(world.ctx::<Config>().map(|c| c.speed), Some(10));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::ctx()`]
+    /// * [`World::ctx_mut()`]
+    pub fn set_ctx<T: 'static>(&self, value: T) {
+        let previous = self.typed_ctx();
+        let ctx: Box<Box<dyn Any>> = Box::new(Box::new(value));
+        unsafe {
+            sys::ecs_set_ctx(
+                self.raw_world.as_ptr(),
+                Box::into_raw(ctx) as *mut c_void,
+                Some(free_typed_ctx),
+            );
+        }
+        self.world_ctx_mut().typed_ctx = self.context();
+        if let Some(previous) = previous {
+            unsafe { free_typed_ctx(previous as *mut c_void) };
+        }
+    }
+
+    /// Get the typed context value set with [`World::set_ctx()`].
+    ///
+    /// # Returns
+    ///
+    /// The context value, or `None` if no typed context is set or if it is not of type `T`.
+    ///
+    /// # Example
+    ///
+    /// See [`World::set_ctx`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_ctx()`]
+    /// * [`World::ctx_mut()`]
+    pub fn ctx<T: 'static>(&self) -> Option<&T> {
+        let ctx = self.typed_ctx()?;
+        unsafe { (*ctx).downcast_ref::<T>() }
+    }
+
+    /// Call `func` with a mutable reference to the typed context value set with
+    /// [`World::set_ctx()`].
+    ///
+    /// # Returns
+    ///
+    /// The result of `func`, or `None` if no typed context is set or if it is not of type `T`,
+    /// in which case `func` is not called.
+    ///
+    /// # Example
+    ///
+    /// See [`World::set_ctx`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_ctx()`]
+    /// * [`World::ctx()`]
+    pub fn ctx_mut<T: 'static, R>(&self, func: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let ctx = self.typed_ctx()?;
+        unsafe { (*ctx).downcast_mut::<T>() }.map(func)
+    }
+
+    /// Returns the world context if it was set by [`World::set_ctx()`].
+    fn typed_ctx(&self) -> Option<*mut Box<dyn Any>> {
+        let ctx = unsafe { sys::ecs_get_ctx(self.raw_world.as_ptr()) };
+        // a context set with `set_context` afterwards is not a typed context
+        (!ctx.is_null() && ctx == self.world_ctx().typed_ctx).then_some(ctx as *mut Box<dyn Any>)
+    }
+
     pub(crate) fn get_context(world: *mut sys::ecs_world_t) -> *mut WorldCtx {
         unsafe { sys::ecs_get_binding_ctx(world) as *mut WorldCtx }
     }
@@ -1552,7 +1814,7 @@ impl World {
 
     /// Helper function for [`World::try_lookup()`] and [`World::try_lookup_recursive()`].
     fn try_lookup_impl(&self, name: &str, recursively: bool) -> Option<EntityView> {
-        let name = compact_str::format_compact!("{}\0", name);
+        let name = SmallCStr::<128>::from_arg(name);
 
         let entity_id = unsafe {
             sys::ecs_lookup_path_w_sep(
@@ -1733,7 +1995,36 @@ impl World {
         self.modified_id(T::id(self));
     }
 
-    /// set the version of the provided entity.
+    /// Set the generation of an entity index to the generation of `entity`.
+    ///
+    /// Handles of the index with another generation are no longer alive afterwards. Use this
+    /// to force the generation of ids that are assigned elsewhere, e.g. by a server.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity id with the generation to set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    /// let versioned = e.id().with_generation(5);
+    ///
+    /// world.set_version(versioned);
+    ///
+    /// assert!(world.is_alive(versioned));
+    /// assert!(!e.is_alive());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Entity::with_generation()`]
+    /// * C++ API: `world::set_version`
+    #[doc(alias = "world::set_version")]
     pub fn set_version(&self, entity: impl Into<Entity>) {
         unsafe { sys::ecs_set_version(self.raw_world.as_ptr(), *entity.into()) };
     }
@@ -1995,6 +2286,9 @@ impl World {
 
     /// Get singleton entity for type.
     ///
+    /// Singletons are stored on the entity of their component, so this is the entity with the id
+    /// of `T`.
+    ///
     /// # Type Parameters
     ///
     /// * `T` - The component type to get the singleton entity for.
@@ -2003,15 +2297,186 @@ impl World {
     ///
     /// The entity representing the component.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Gravity {
+    ///     value: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_singleton(Gravity { value: 9.81 });
+    ///
+    /// let entity = world.singleton_entity::<Gravity>();
+    /// assert_eq!(entity, world.component_id::<Gravity>());
+    /// assert!(entity.has::<Gravity>());
+    /// ```
+    ///
     /// # See also
     ///
     /// * C++ API: `world::singleton`
     #[doc(alias = "world::singleton")]
     #[inline(always)]
-    pub fn singleton<T: ComponentId>(&self) -> EntityView {
+    pub fn singleton_entity<T: ComponentId>(&self) -> EntityView<'_> {
         EntityView::new_from(self, T::id(self))
     }
 
+    /// Sets a singleton component of type `T` on the world.
+    ///
+    /// This is the same as [`World::set()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The singleton component to set on the world.
+    ///
+    /// # See also
+    ///
+    /// * [`World::singleton()`]
+    /// * [`World::singleton_mut()`]
+    /// * [`World::singleton_entity()`]
+    pub fn set_singleton<T: ComponentId + DataComponent + ComponentType<Struct>>(&self, value: T) {
+        self.set(value);
+    }
+
+    /// Calls `func` with the singleton component of type `T` and returns its result.
+    ///
+    /// The type of the singleton is inferred from the argument of `func`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the singleton is not set. Use [`World::try_singleton()`] if unsure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Gravity {
+    ///     value: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_singleton(Gravity { value: 9.81 });
+    ///
+    /// let value = world.singleton(|g: &Gravity| g.value);
+    /// assert_eq!(value, 9.81);
+    ///
+    /// world.singleton_mut(|g: &mut Gravity| g.value = 1.62);
+    /// assert!(world.try_singleton::<Gravity>(|g| assert_eq!(g.value, 1.62)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::singleton_mut()`]
+    /// * [`World::try_singleton()`]
+    pub fn singleton<T: ComponentId + DataComponent, R>(&self, func: impl FnOnce(&T) -> R) -> R {
+        let ptr = self.singleton_ptr::<T>();
+        if ptr.is_null() {
+            panic!("singleton `{}` is not set", std::any::type_name::<T>());
+        }
+
+        self.defer_begin();
+        let ret = func(unsafe { &*ptr });
+        self.defer_end();
+        ret
+    }
+
+    /// Calls `func` with the singleton component of type `T` mutably, and signals that the
+    /// singleton was modified afterwards, which invokes `OnSet` observers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the singleton is not set. Use [`World::try_singleton_mut()`] if unsure.
+    ///
+    /// # Example
+    ///
+    /// See [`World::singleton`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::singleton()`]
+    /// * [`World::try_singleton_mut()`]
+    pub fn singleton_mut<T: ComponentId + DataComponent, R>(
+        &self,
+        func: impl FnOnce(&mut T) -> R,
+    ) -> R {
+        let ptr = self.singleton_ptr::<T>();
+        if ptr.is_null() {
+            panic!("singleton `{}` is not set", std::any::type_name::<T>());
+        }
+
+        self.defer_begin();
+        let ret = func(unsafe { &mut *ptr });
+        self.defer_end();
+        self.modified::<T>();
+        ret
+    }
+
+    /// Calls `func` with the singleton component of type `T` if it is set.
+    ///
+    /// # Returns
+    ///
+    /// Whether the singleton is set and `func` was called.
+    ///
+    /// # Example
+    ///
+    /// See [`World::singleton`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::singleton()`]
+    /// * [`World::try_singleton_mut()`]
+    pub fn try_singleton<T: ComponentId + DataComponent>(&self, func: impl FnOnce(&T)) -> bool {
+        let ptr = self.singleton_ptr::<T>();
+        if ptr.is_null() {
+            return false;
+        }
+
+        self.defer_begin();
+        func(unsafe { &*ptr });
+        self.defer_end();
+        true
+    }
+
+    /// Calls `func` with the singleton component of type `T` mutably if it is set, and signals
+    /// that the singleton was modified afterwards.
+    ///
+    /// # Returns
+    ///
+    /// Whether the singleton is set and `func` was called.
+    ///
+    /// # See also
+    ///
+    /// * [`World::singleton_mut()`]
+    /// * [`World::try_singleton()`]
+    pub fn try_singleton_mut<T: ComponentId + DataComponent>(
+        &self,
+        func: impl FnOnce(&mut T),
+    ) -> bool {
+        let ptr = self.singleton_ptr::<T>();
+        if ptr.is_null() {
+            return false;
+        }
+
+        self.defer_begin();
+        func(unsafe { &mut *ptr });
+        self.defer_end();
+        self.modified::<T>();
+        true
+    }
+
+    /// Returns a pointer to the singleton component of type `T`, null if it is not set.
+    fn singleton_ptr<T: ComponentId>(&self) -> *mut T {
+        let id = T::id(self);
+        unsafe { sys::ecs_get_mut_id(self.raw_world.as_ptr(), id, id) as *mut T }
+    }
+
     /// Gets the target for a given pair from a singleton entity.
     ///
     /// This operation returns the target for a given pair. The optional
@@ -2424,7 +2889,7 @@ impl World {
     #[doc(alias = "world::use")]
     #[inline(always)]
     pub fn set_alias_component<T: ComponentId>(&self, alias: &str) -> EntityView {
-        let alias = compact_str::format_compact!("{}\0", alias);
+        let alias = SmallCStr::<128>::from_arg(alias);
 
         let id = T::id(self);
         if alias.is_empty() {
@@ -2458,8 +2923,8 @@ impl World {
     #[doc(alias = "world::use")]
     #[inline(always)]
     pub fn set_alias_entity_by_name(&self, name: &str, alias: &str) -> EntityView {
-        let name = compact_str::format_compact!("{}\0", name);
-        let alias = compact_str::format_compact!("{}\0", alias);
+        let name = SmallCStr::<128>::from_arg(name);
+        let alias = SmallCStr::<128>::from_arg(alias);
 
         let id = unsafe {
             sys::ecs_lookup_path_w_sep(
@@ -2489,7 +2954,7 @@ impl World {
     #[doc(alias = "world::use")]
     #[inline(always)]
     pub fn set_alias_entity(&self, entity: impl Into<Entity>, alias: &str) {
-        let alias = compact_str::format_compact!("{}\0", alias);
+        let alias = SmallCStr::<128>::from_arg(alias);
 
         let entity = *entity.into();
         if alias.is_empty() {
@@ -2587,29 +3052,69 @@ impl World {
         self.count_id((First::id(self), second.into()))
     }
 
-    /// Count entities with the provided enum constant.
+    /// Count entities that have a relationship with the provided target.
     ///
     /// # Type Parameters
     ///
-    /// * `T` - The enum type.
+    /// * `R` - The relationship.
     ///
     /// # Arguments
     ///
-    /// * `constant` - The enum constant to count.
+    /// * `target` - The target of the relationship.
     ///
     /// # Returns
     ///
-    /// The number of entities with the provided enum constant.
+    /// The number of entities with the `(R, target)` pair.
     ///
-    /// # See also
+    /// # Example
     ///
-    /// * C++ API: `world::count`
-    #[doc(alias = "world::count")]
-    pub fn count_enum<T: ComponentId + ComponentType<Enum> + EnumComponentInfo>(
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Likes;
+    ///
+    /// let world = World::new();
+    ///
+    /// let bob = world.entity();
+    /// world.entity().add_first::<Likes>(bob);
+    /// world.entity().add_first::<Likes>(bob);
+    ///
+    /// assert_eq!(world.count_pair_target::<Likes>(bob), 2);
+    /// assert_eq!(world.count_pair_target::<flecs::ChildOf>(bob), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::count_first()`]
+    /// * [`EntityView::count_children()`]
+    pub fn count_pair_target<R: ComponentId>(&self, target: impl Into<Entity>) -> i32 {
+        self.count_first::<R>(target)
+    }
+
+    /// Count entities with the provided enum constant.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The enum type.
+    ///
+    /// # Arguments
+    ///
+    /// * `constant` - The enum constant to count.
+    ///
+    /// # Returns
+    ///
+    /// The number of entities with the provided enum constant.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `world::count`
+    #[doc(alias = "world::count")]
+    pub fn count_enum<T: ComponentId + ComponentType<Enum> + EnumComponentInfo>(
         &self,
         enum_value: T,
     ) -> i32 {
-        unsafe { sys::ecs_count_id(self.raw_world.as_ptr(), *(enum_value.id_variant(self).id)) }
+        self.count_id((T::id(self), enum_value.id_variant(self)))
     }
 
     /// Count entities with the provided pair enum tag.
@@ -2656,13 +3161,9 @@ impl World {
     ///
     /// * C++ API: `world::scope`
     #[doc(alias = "world::scope")]
-    pub fn run_in_scope_with_id(&self, parent_id: impl Into<Entity>, mut func: impl FnMut()) {
-        let prev: sys::ecs_id_t =
-            unsafe { sys::ecs_set_scope(self.raw_world.as_ptr(), *parent_id.into()) };
+    pub fn run_in_scope_with_id(&self, parent_id: impl Into<Entity>, func: impl FnOnce()) {
+        let _restore = RestoreOnDrop::scope(self, *parent_id.into());
         func();
-        unsafe {
-            sys::ecs_set_scope(self.raw_world.as_ptr(), prev);
-        }
     }
 
     /// All entities created in function are created in scope. All operations
@@ -2680,72 +3181,82 @@ impl World {
     ///
     /// * C++ API: `world::scope`
     #[doc(alias = "world::scope")]
-    pub fn run_in_scope_with<T: ComponentId>(&self, func: impl FnMut()) {
+    pub fn run_in_scope_with<T: ComponentId>(&self, func: impl FnOnce()) {
         self.run_in_scope_with_id(T::id(self), func);
     }
 
-    /// Use provided scope for operations ran on returned world.
-    /// Operations need to be ran in a single statement
+    /// Use provided scope for operations ran on the world passed to the closure.
+    /// Entities created in the closure are created as children of the scope.
+    /// The previous scope is restored afterwards, also when the closure panics.
     ///
     /// # Arguments
     ///
     /// * `parent_id` - The id of the scope to use.
+    /// * `f` - The function to run.
     ///
-    /// # Returns
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity_named("parent");
+    ///
+    /// world.scope_id(parent, |world| {
+    ///     let child = world.entity_named("child");
+    ///     assert!(child.has_first::<flecs::ChildOf>(parent));
+    /// });
     ///
-    /// A scoped world.
+    /// assert!(world.get_scope().is_none());
+    /// ```
     ///
     /// # See also
     ///
     /// * C++ API: `world::scope`
     #[doc(alias = "world::scope")]
-    pub fn scope_id(&self, parent_id: impl IntoId, mut f: impl FnMut(&World)) {
-        let previous_scope = self.set_scope_id(parent_id);
+    pub fn scope_id(&self, parent_id: impl IntoId, f: impl FnOnce(&World)) {
+        let _restore = RestoreOnDrop::scope(self, *parent_id.into());
         f(self);
-        self.set_scope_id(previous_scope);
     }
 
-    /// Use provided scope for operations ran on returned world.
-    /// Operations need to be ran in a single statement
+    /// Use provided scope for operations ran on the world passed to the closure.
+    /// The previous scope is restored afterwards, also when the closure panics.
     ///
     /// # Type Parameters
     ///
     /// * `T` - The component type to use as scope.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// A scoped world.
+    /// * `f` - The function to run.
     ///
     /// # See also
     ///
     /// * C++ API: `world::scope`
     #[doc(alias = "world::scope")]
-    pub fn scope<T: ComponentId>(&self, mut f: impl FnMut(&World)) {
-        let previous_scope = self.set_scope_id(T::id(self));
-        f(self);
-        self.set_scope_id(previous_scope);
+    pub fn scope<T: ComponentId>(&self, f: impl FnOnce(&World)) {
+        self.scope_id(T::id(self), f);
     }
 
-    /// Use provided scope of name for operations ran on returned world.
-    /// Operations need to be ran in a single statement
+    /// Use provided scope of name for operations ran on the world passed to the closure.
+    /// The previous scope is restored afterwards, also when the closure panics.
     ///
     /// # Arguments
     ///
     /// * `name` - The name of the scope to use.
-    ///
-    /// # Returns
-    ///
-    /// A scoped world.
+    /// * `f` - The function to run.
     ///
     /// # See also
     ///
     /// * C++ API: `world::scope`
     #[doc(alias = "world::scope")]
-    pub fn scope_name(&self, name: &str, f: impl FnMut(&World)) {
+    pub fn scope_name(&self, name: &str, f: impl FnOnce(&World)) {
         self.scope_id(EntityView::new_named(self, name).id, f);
     }
 
-    /// all entities created in function are created with id
+    /// all entities created in function are created with id.
+    /// The previous with id is restored afterwards, also when the function panics.
     ///
     /// # Arguments
     ///
@@ -2756,12 +3267,9 @@ impl World {
     ///
     /// * C++ API: `world::with`
     #[doc(alias = "world::with")]
-    pub fn with_id(&self, id: impl IntoId, mut func: impl FnMut()) {
-        let prev: sys::ecs_id_t = unsafe { sys::ecs_set_with(self.raw_world.as_ptr(), *id.into()) };
+    pub fn with_id(&self, id: impl IntoId, func: impl FnOnce()) {
+        let _restore = RestoreOnDrop::with(self, *id.into());
         func();
-        unsafe {
-            sys::ecs_set_with(self.raw_world.as_ptr(), prev);
-        }
     }
 
     /// Entities created in function are created with component
@@ -2778,7 +3286,7 @@ impl World {
     ///
     /// * C++ API: `world::with`
     #[doc(alias = "world::with")]
-    pub fn with<T: ComponentOrPairId>(&self, func: impl FnMut()) {
+    pub fn with<T: ComponentOrPairId>(&self, func: impl FnOnce()) {
         self.with_id(T::get_id(self), func);
     }
 
@@ -2797,7 +3305,7 @@ impl World {
     ///
     /// * C++ API: `world::with`
     #[doc(alias = "world::with")]
-    pub fn with_second<Second: ComponentId>(&self, first: impl Into<Entity>, func: impl FnMut()) {
+    pub fn with_second<Second: ComponentId>(&self, first: impl Into<Entity>, func: impl FnOnce()) {
         self.with_id(ecs_pair(*first.into(), Second::id(self)), func);
     }
 
@@ -2816,7 +3324,7 @@ impl World {
     ///
     /// * C++ API: `world::with`
     #[doc(alias = "world::with")]
-    pub fn with_first<First: ComponentId>(&self, second: impl Into<Entity>, func: impl FnMut()) {
+    pub fn with_first<First: ComponentId>(&self, second: impl Into<Entity>, func: impl FnOnce()) {
         self.with_id(ecs_pair(First::id(self), *second.into()), func);
     }
 
@@ -2835,11 +3343,11 @@ impl World {
     ///
     /// * C++ API: `world::with`
     #[doc(alias = "world::with")]
-    pub fn with_enum<T>(&self, enum_value: T, func: impl FnMut())
+    pub fn with_enum<T>(&self, enum_value: T, func: impl FnOnce())
     where
         T: ComponentId + ComponentType<Enum> + EnumComponentInfo,
     {
-        self.with_id(enum_value.id_variant(self), func);
+        self.with_id((T::id(self), enum_value.id_variant(self)), func);
     }
 
     /// Entities created in function are created with enum tag pair
@@ -2858,7 +3366,7 @@ impl World {
     ///
     /// * C++ API: `world::with`
     #[doc(alias = "world::with")]
-    pub fn with_enum_pair<First, Second>(&self, enum_value: Second, func: impl FnMut())
+    pub fn with_enum_pair<First, Second>(&self, enum_value: Second, func: impl FnOnce())
     where
         First: ComponentId,
         Second: ComponentId + ComponentType<Enum> + EnumComponentInfo,
@@ -2871,12 +3379,16 @@ impl World {
 
     /// Delete all entities with the given id
     ///
+    /// The id can be a pair or a wildcard. When the world is deferred, the operation
+    /// is enqueued and applied when the deferred block ends.
+    ///
     /// # Arguments
     ///
     /// * `id`: The id to delete.
     ///
     /// # See also
     ///
+    /// * [`Query::destruct_entities()`]
     /// * C++ API: `world::delete_with`
     #[doc(alias = "world::delete_with")]
     pub fn delete_entities_with_id(&self, id: impl IntoId) {
@@ -2953,7 +3465,7 @@ impl World {
         &self,
         enum_value: T,
     ) {
-        self.delete_entities_with_id(enum_value.id_variant(self));
+        self.delete_entities_with_id((T::id(self), enum_value.id_variant(self)));
     }
 
     /// Delete all entities with the given enum tag pair / relationship
@@ -2980,6 +3492,9 @@ impl World {
 
     /// Remove all instances of the given id from entities
     ///
+    /// The id can be a pair or a wildcard. When the world is deferred, the operation
+    /// is enqueued and applied when the deferred block ends.
+    ///
     /// # Arguments
     ///
     /// * `id`: The id to remove.
@@ -3062,7 +3577,7 @@ impl World {
         &self,
         enum_value: T,
     ) {
-        self.remove_all_id(enum_value.id_variant(self));
+        self.remove_all_id((T::id(self), enum_value.id_variant(self)));
     }
 
     /// Remove all instances with the given enum tag pair / relationship from entities
@@ -3643,6 +4158,31 @@ impl World {
     ///
     /// The found or registered component.
     ///
+    /// # Panics
+    ///
+    /// Panics if `T` is already registered with another name, or if another component with a
+    /// different size or alignment is registered as `name`. When another Rust type with the same
+    /// size and alignment is registered as `name`, the existing component is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let position = world.component_named::<Position>("game::Position");
+    ///
+    /// assert_eq!(world.lookup("game::Position"), position.entity());
+    /// assert_eq!(world.component_id::<Position>(), position.id());
+    /// ```
+    ///
     /// # See also
     ///
     /// * C++ API: `world::component`
@@ -3710,6 +4250,53 @@ impl World {
         UntypedComponent::new_from(self, id)
     }
 
+    /// Get an untyped handle to an existing component, such as one obtained from a lookup.
+    ///
+    /// The handle can be used to add reflection members, constants and traits to the component.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The component entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity is not alive or not a component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.component_untyped_named("Health").layout(8, 4);
+    ///
+    /// let health = world.component_from(world.lookup("Health"));
+    /// assert_eq!(health.size(), 8);
+    /// assert_eq!(health.alignment(), 4);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::component_untyped_from_id()`]
+    pub fn component_from(&self, entity: impl Into<Entity>) -> UntypedComponent<'_> {
+        let entity = entity.into();
+        let world = self.raw_world.as_ptr();
+        if !unsafe { sys::ecs_is_alive(world, *entity) } {
+            panic!(
+                "entity #{} is not alive, it can't be used as a component",
+                *entity
+            );
+        }
+        if !unsafe { sys::ecs_has_id(world, *entity, flecs::Component::ID) } {
+            panic!(
+                "entity {} is not a component, components are created with `World::component` or `World::component_untyped_named`",
+                EntityView::new_from(self, entity)
+            );
+        }
+        UntypedComponent::new_from(self, entity)
+    }
+
     /// Convert enum constant to entity
     ///
     /// # Type Parameters
@@ -3994,6 +4581,49 @@ impl World {
         QueryBuilder::<Components>::new_named(self, name)
     }
 
+    /// Create a query from an expression in the flecs query language.
+    ///
+    /// The query has no typed terms, its results are accessed through the untyped
+    /// field API of [`TableIter`], such as [`TableIter::field_untyped()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The query expression, e.g. `"Position, !Velocity, (ChildOf, scene)"`.
+    ///
+    /// # Returns
+    ///
+    /// The query, or the error flecs reported when the expression can't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component_named::<Position>("Position");
+    ///
+    /// world.entity().set(Position { x: 1, y: 2 });
+    ///
+    /// let query = world.query_expr("Position").unwrap();
+    /// assert_eq!(query.count(), 1);
+    ///
+    /// assert!(world.query_expr("Position, (ChildOf,").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilderImpl::expr()`]
+    /// * [`QueryBuilder::try_build()`]
+    pub fn query_expr(&self, expr: &str) -> Result<Query<()>, FlecsError> {
+        QueryBuilder::<()>::new(self).expr(expr).try_build()
+    }
+
     /// Attempts to convert an entity into a query.
     ///
     /// Returns the untyped query if the entity is alive and valid; otherwise, returns `None`.
@@ -4009,6 +4639,48 @@ impl World {
         Query::<()>::new_from_entity(self, query_entity)
     }
 
+    /// Looks up a named query and returns an untyped handle to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the query entity, as passed to [`World::query_named()`].
+    ///
+    /// # Returns
+    ///
+    /// The query, or `None` if no entity with that name exists or the entity is not a query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().set(Position { x: 1, y: 2 });
+    /// let query = world.query_named::<&Position>("positions").build();
+    ///
+    /// let found = world.try_lookup_query("positions").unwrap();
+    /// assert_eq!(found.entity(), query.entity());
+    /// assert_eq!(found.count(), 1);
+    ///
+    /// assert!(world.try_lookup_query("missing").is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::try_query_from()`]
+    /// * [`World::try_lookup()`]
+    pub fn try_lookup_query(&self, name: &str) -> Option<Query<()>> {
+        self.try_lookup(name)
+            .and_then(|entity| self.try_query_from(entity))
+    }
+
     /// Converts an entity into a query, automatically unwrapping the result.
     ///
     /// This method panics if the entity is not alive or not a valid query.
@@ -4032,7 +4704,12 @@ impl World {
 
     /// Create and iterate an uncached query.
     ///
-    /// This function creates a query and immediately iterates it.
+    /// This function creates a query and immediately iterates it. The query supports the same
+    /// component tuples as [`World::new_query()`], including optional and pair terms.
+    ///
+    /// Creating an uncached query is cheap, but matching it walks the component index on every
+    /// iteration. Queries that run every frame should be built once with [`World::new_query()`]
+    /// and reused instead. See the `world_each` benchmark for a comparison.
     ///
     /// # Returns
     ///
@@ -4042,6 +4719,38 @@ impl World {
     ///
     /// * `Components`: The components to match on.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world
+    ///     .entity()
+    ///     .set(Position { x: 10, y: 20 })
+    ///     .set(Velocity { x: 1, y: 2 });
+    ///
+    /// world.each::<(&mut Position, &Velocity)>(|(p, v)| {
+    ///     p.x += v.x;
+    ///     p.y += v.y;
+    /// });
+    ///
+    /// e.get::<&Position>(|p| assert_eq!((p.x, p.y), (11, 22)));
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`QueryAPI::each()`]
@@ -4174,6 +4883,144 @@ impl World {
     {
         SystemBuilder::<Components>::new_from_desc(self, desc)
     }
+
+    /// Returns the systems of the world, including disabled systems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let system = world.system::<()>().run(|_| {});
+    ///
+    /// assert!(world.systems().iter().any(|s| s.id() == system.id()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`System::stats()`]
+    pub fn systems(&self) -> Vec<System<'_>> {
+        let mut systems = Vec::new();
+        self.query::<()>()
+            .with::<flecs::system::System>()
+            .query_flags(QueryFlags::MatchDisabled)
+            .build()
+            .each_entity(|e, _| systems.push(e.id()));
+        systems
+            .into_iter()
+            .map(|system| System::new_from_existing(self.entity_from_id(system)))
+            .collect()
+    }
+
+    /// Enable or disable measuring the time spent by systems, see [`System::stats()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to measure the time spent by systems.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `world::measure_system_time`
+    #[doc(alias = "world::measure_system_time")]
+    pub fn measure_system_time(&self, enable: bool) {
+        unsafe { sys::ecs_measure_system_time(self.raw_world.as_ptr(), enable) };
+    }
+
+    /// Enables the systems that have `T`, by removing `flecs::Disabled` from them.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component, tag or pair of the systems, e.g. added with
+    ///   [`SystemBuilder::add()`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::disable_systems_with()`]
+    /// * [`World::enable_systems_with_id()`]
+    pub fn enable_systems_with<T: ComponentOrPairId>(&self) {
+        self.enable_systems_with_id(T::get_id(self));
+    }
+
+    /// Enables the systems that have `id`, by removing `flecs::Disabled` from them.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the systems.
+    ///
+    /// # See also
+    ///
+    /// * [`World::enable_systems_with()`]
+    pub fn enable_systems_with_id(&self, id: impl IntoId) {
+        for system in self.systems_with_id(id) {
+            system.enable_self();
+        }
+    }
+
+    /// Disables the systems that have `T`, by adding `flecs::Disabled` to them. Disabled
+    /// systems are skipped by the pipeline.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component, tag or pair of the systems, e.g. added with
+    ///   [`SystemBuilder::add()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct RenderSystem;
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .system::<()>()
+    ///     .add::<RenderSystem>()
+    ///     .run(|_| panic!("render systems are disabled"));
+    ///
+    /// world.disable_systems_with::<RenderSystem>();
+    /// world.progress();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::enable_systems_with()`]
+    /// * [`World::disable_systems_with_id()`]
+    pub fn disable_systems_with<T: ComponentOrPairId>(&self) {
+        self.disable_systems_with_id(T::get_id(self));
+    }
+
+    /// Disables the systems that have `id`, by adding `flecs::Disabled` to them.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the systems.
+    ///
+    /// # See also
+    ///
+    /// * [`World::disable_systems_with()`]
+    pub fn disable_systems_with_id(&self, id: impl IntoId) {
+        for system in self.systems_with_id(id) {
+            system.disable_self();
+        }
+    }
+
+    fn systems_with_id(&self, id: impl IntoId) -> Vec<EntityView<'_>> {
+        let mut systems = Vec::new();
+        self.query::<()>()
+            .with::<flecs::system::System>()
+            .with_id(id)
+            .query_flags(QueryFlags::MatchDisabled)
+            .build()
+            .each_entity(|e, _| systems.push(e.id()));
+        systems
+            .into_iter()
+            .map(|system| self.entity_from_id(system))
+            .collect()
+    }
 }
 
 /// Pipeline mixin implementation
@@ -4181,10 +5028,59 @@ impl World {
 impl World {
     /// Create a new [`Pipeline`](crate::addons::pipeline::Pipeline).
     ///
+    /// The terms of the pipeline select the systems that it runs. To run systems in the
+    /// order of custom phases, match the `Phase` tag of the phase a system depends on with
+    /// `cascade`, which sorts systems by the depth of their phase in the `DependsOn` hierarchy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Simulate;
+    ///
+    /// #[derive(Component)]
+    /// struct Render;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.component::<Simulate>().add::<flecs::pipeline::Phase>();
+    /// world
+    ///     .component::<Render>()
+    ///     .add::<flecs::pipeline::Phase>()
+    ///     .depends_on::<Simulate>();
+    ///
+    /// let pipeline = world
+    ///     .pipeline()
+    ///     .with::<flecs::system::System>()
+    ///     .with::<flecs::pipeline::Phase>()
+    ///     .cascade_type::<flecs::DependsOn>()
+    ///     .build();
+    ///
+    /// // declared first, but runs after the systems of the Simulate phase
+    /// world.system::<()>().kind::<Render>().run(|_| {
+    ///     println!("render");
+    /// });
+    ///
+    /// world.system::<()>().kind::<Simulate>().run(|_| {
+    ///     println!("simulate");
+    /// });
+    ///
+    /// world.set_pipeline_id(pipeline.id());
+    /// world.progress();
+    ///
+    /// // Output:
+    /// //  simulate
+    /// //  render
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`World::pipeline_named()`]
     /// * [`World::pipeline_type()`]
+    /// * [`World::set_pipeline_id()`]
+    /// * [`World::run_pipeline_id_time()`]
     /// * C++ API: `world::pipeline`
     #[doc(alias = "world::pipeline")]
     #[inline(always)]
@@ -4339,7 +5235,14 @@ impl World {
     #[doc(alias = "world::progress")]
     #[inline(always)]
     pub fn progress_time(&self, delta_time: f32) -> bool {
-        unsafe { sys::ecs_progress(self.raw_world.as_ptr(), delta_time) }
+        let merge_hooks = self.merge_hooks_begin();
+        #[cfg(feature = "flecs_pipeline_log")]
+        let frame = self.run_log_begin();
+        let result = unsafe { sys::ecs_progress(self.raw_world.as_ptr(), delta_time) };
+        #[cfg(feature = "flecs_pipeline_log")]
+        self.record_run_log(self.get_pipeline().id(), frame);
+        self.invoke_merge_hooks(merge_hooks);
+        result
     }
 
     /// Run pipeline.
@@ -4398,9 +5301,23 @@ impl World {
     #[doc(alias = "world::run_pipeline")]
     #[inline(always)]
     pub fn run_pipeline_id_time(&self, pipeline: impl Into<Entity>, delta_time: super::FTime) {
+        let pipeline = *pipeline.into();
+        let merge_hooks = self.merge_hooks_begin();
+        #[cfg(feature = "flecs_pipeline_log")]
+        let frame = self.run_log_begin();
         unsafe {
-            sys::ecs_run_pipeline(self.raw_world.as_ptr(), *pipeline.into(), delta_time);
+            sys::ecs_run_pipeline(self.raw_world.as_ptr(), pipeline, delta_time);
         }
+        #[cfg(feature = "flecs_pipeline_log")]
+        self.record_run_log(
+            if pipeline == 0 {
+                self.get_pipeline().id()
+            } else {
+                Entity::new(pipeline)
+            },
+            frame,
+        );
+        self.invoke_merge_hooks(merge_hooks);
     }
 
     /// Run pipeline.
@@ -4434,9 +5351,7 @@ impl World {
     where
         Component: ComponentType<Struct> + ComponentId,
     {
-        unsafe {
-            sys::ecs_run_pipeline(self.raw_world.as_ptr(), Component::id(self), delta_time);
-        }
+        self.run_pipeline_id_time(Component::id(self), delta_time);
     }
 
     /// Run pipeline.
@@ -0,0 +1,406 @@
+//! Copy the entities and components of one world into another.
+
+use crate::core::*;
+use crate::sys;
+
+/// Returns the ids of the type of a table.
+fn table_ids<'a>(table: *const sys::ecs_table_t) -> &'a [sys::ecs_id_t] {
+    if table.is_null() {
+        return &[];
+    }
+    unsafe {
+        let type_ = sys::ecs_table_get_type(table);
+        if type_.is_null() || (*type_).count == 0 {
+            return &[];
+        }
+        std::slice::from_raw_parts((*type_).array, (*type_).count as usize)
+    }
+}
+
+/// Returns true if the id makes an entity part of the setup of a world rather than of its
+/// data, such as components, modules, systems, observers, queries and pipelines.
+fn is_setup_id(id: sys::ecs_id_t) -> bool {
+    #[cfg(feature = "flecs_pipeline")]
+    if id == flecs::pipeline::Pipeline::ID {
+        return true;
+    }
+
+    id == ECS_COMPONENT || id == ECS_MODULE || (ecs_is_pair(id) && *ecs_first(id) == ECS_POLY)
+}
+
+/// Returns true if the entity or one of its parents is part of the setup of the world.
+///
+/// These entities are reconciled by [`World::copy_from()`] instead of copied.
+fn is_setup(world: *const sys::ecs_world_t, entity: sys::ecs_entity_t) -> bool {
+    let mut current = entity;
+    let mut depth = 0;
+    while current != 0 && depth < 256 {
+        let table = unsafe { sys::ecs_get_table(world, current) };
+        if table_ids(table).iter().any(|&id| is_setup_id(id)) {
+            return true;
+        }
+        current = unsafe { sys::ecs_get_target(world, current, ECS_CHILD_OF, 0) };
+        depth += 1;
+    }
+    false
+}
+
+/// Returns true if the id is defined by flecs itself, e.g. `ChildOf` or `(Identifier, Name)`.
+///
+/// Components registered from Rust are never builtin, even though their path can place them
+/// in the `flecs` scope, e.g. for a crate named `flecs`.
+fn is_builtin_id(world: &World, id: sys::ecs_id_t) -> bool {
+    let first = if ecs_is_pair(id) {
+        *ecs_first(id)
+    } else {
+        id & RUST_ECS_COMPONENT_MASK
+    };
+    if world.components_array().contains(&first)
+        || world.components_map().values().any(|&v| v == first)
+    {
+        return false;
+    }
+
+    let mut current = first;
+    let mut depth = 0;
+    while current != 0 && depth < 256 {
+        if current == ECS_FLECS {
+            return true;
+        }
+        current = unsafe { sys::ecs_get_target(world.world_ptr(), current, ECS_CHILD_OF, 0) };
+        depth += 1;
+    }
+    false
+}
+
+/// Returns the value of a component of an entity, or null if the id has no data.
+fn component_ptr(
+    world: *const sys::ecs_world_t,
+    entity: sys::ecs_entity_t,
+    id: sys::ecs_id_t,
+) -> Option<(*const std::ffi::c_void, usize)> {
+    let type_info = unsafe { sys::ecs_get_type_info(world, id) };
+    if type_info.is_null() || unsafe { (*type_info).size } == 0 {
+        return None;
+    }
+    let ptr = unsafe { sys::ecs_get_id(world, entity, id) };
+    (!ptr.is_null()).then(|| (ptr, unsafe { (*type_info).size } as usize))
+}
+
+impl World {
+    /// Replace the entities and components of this world with a copy of those of another
+    /// world.
+    ///
+    /// All entities of `other` are recreated with the same ids, including their generation,
+    /// and with the same component values. Components without a copy hook are copied with a
+    /// single `memcpy` per table column, other components are copied with their copy hook,
+    /// which for Rust components means [`Clone`]. Entities of this world that don't exist in
+    /// `other` are deleted.
+    ///
+    /// Components, modules, systems, observers, queries and pipelines are part of the setup
+    /// of a world and are reconciled instead of copied:
+    ///
+    /// * Components of `other` that are not registered in this world are registered with the
+    ///   same id, name, size and lifecycle hooks. Component hooks such as `on_add` and
+    ///   reflection data are not copied.
+    /// * Components registered in both worlds must have the same id in both worlds. This is
+    ///   the case when both worlds register the same components in the same order.
+    /// * Systems, observers and modules are never copied. This world keeps its own, so both
+    ///   worlds should be set up by the same code.
+    /// * Singletons and other components that were added to these entities are copied.
+    ///
+    /// This makes it possible to store a snapshot of a simulation in a second world, and to
+    /// roll the simulation back by copying the snapshot back into it.
+    ///
+    /// Entities are deleted and recreated, which is why this takes the world by mutable
+    /// reference: no [`EntityView`] of this world can be alive during the copy.
+    ///
+    /// Copying invokes the `OnAdd` and `OnSet` hooks and observers of this world for the
+    /// copied components. Ids of entities created after the copy are not guaranteed to be the
+    /// same as in `other`, as the order in which deleted ids are recycled is not copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The world to copy from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component is registered with a different id in both worlds, or if this
+    /// world is deferred or in readonly mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let e = world
+    ///     .entity_named("Bob")
+    ///     .set(Position { x: 10.0, y: 20.0 })
+    ///     .id();
+    ///
+    /// let mut snapshot = World::new();
+    /// snapshot.copy_from(&world);
+    ///
+    /// world.entity_from_id(e).set(Position { x: 30.0, y: 40.0 });
+    /// world.entity_named("Alice");
+    ///
+    /// world.copy_from(&snapshot);
+    ///
+    /// world
+    ///     .entity_from_id(e)
+    ///     .get::<&Position>(|pos| assert_eq!(*pos, Position { x: 10.0, y: 20.0 }));
+    /// assert!(world.try_lookup_recursive("Bob").is_some());
+    /// assert!(world.try_lookup_recursive("Alice").is_none());
+    /// ```
+    pub fn copy_from(&mut self, other: &World) {
+        let dst = self.ptr_mut();
+        let src = other.ptr_mut() as *const sys::ecs_world_t;
+
+        assert!(!std::ptr::eq(dst, src), "cannot copy a world into itself");
+        assert!(
+            !self.is_deferred() && !self.is_readonly(),
+            "cannot copy into a world that is deferred or in readonly mode"
+        );
+
+        let src_entities = unsafe {
+            let entities = sys::ecs_get_entities(src);
+            std::slice::from_raw_parts(entities.ids, entities.alive_count as usize).to_vec()
+        };
+        let (src_setup, src_data): (Vec<u64>, Vec<u64>) =
+            src_entities.into_iter().partition(|&e| is_setup(src, e));
+
+        // Remove the data of this world. Entities that also exist in `other` are cleared
+        // instead of deleted, so relationships and queries that use them stay valid.
+        let dst_entities = unsafe {
+            let entities = sys::ecs_get_entities(dst);
+            std::slice::from_raw_parts(entities.ids, entities.alive_count as usize).to_vec()
+        };
+        for e in dst_entities {
+            if !unsafe { sys::ecs_is_alive(dst, e) } || is_setup(dst, e) {
+                continue;
+            }
+            if unsafe { sys::ecs_is_alive(src, e) } && !is_setup(src, e) {
+                unsafe { sys::ecs_clear(dst, e) };
+            } else {
+                unsafe { sys::ecs_delete(dst, e) };
+            }
+        }
+
+        // Make the entities alive before registering components, so that the scopes of the
+        // components can't reuse their ids.
+        for &e in &src_data {
+            let alive = unsafe { sys::ecs_get_alive(dst, e & 0xFFFF_FFFF) };
+            assert!(
+                alive == 0 || alive == e,
+                "entity {} of the source world is used by another entity in the destination world",
+                e
+            );
+            unsafe { sys::ecs_make_alive(dst, e) };
+        }
+
+        for &component in &src_setup {
+            if unsafe { sys::ecs_has_id(src, component, ECS_COMPONENT) } {
+                self.copy_component_from(other, component);
+            }
+        }
+        self.copy_component_ids_from(other);
+
+        let mut tables = Vec::new();
+        let mut visited = std::collections::HashSet::new();
+        for &e in &src_data {
+            let table = unsafe { sys::ecs_get_table(src, e) };
+            if !table.is_null() && visited.insert(table) {
+                tables.push(table);
+            }
+        }
+
+        for table in tables {
+            let ids = table_ids(table);
+            for &id in ids {
+                assert!(
+                    unsafe { sys::ecs_id_is_valid(dst, id) },
+                    "id {} of the source world does not exist in the destination world",
+                    id
+                );
+            }
+
+            unsafe { sys::ecs_rust_table_copy(dst, table) };
+
+            // sparse components are not stored in the table
+            for (index, &id) in ids.iter().enumerate() {
+                if unsafe { sys::ecs_table_type_to_column_index(table, index as i32) } != -1 {
+                    continue;
+                }
+                let entities = unsafe {
+                    std::slice::from_raw_parts(
+                        sys::ecs_table_entities(table),
+                        sys::ecs_table_count(table) as usize,
+                    )
+                };
+                for &e in entities {
+                    if let Some((ptr, size)) = component_ptr(src, e, id) {
+                        unsafe { sys::ecs_set_id(dst, e, id, size, ptr) };
+                    }
+                }
+            }
+        }
+
+        // Copy singletons and other components that were added to components, systems
+        // and modules.
+        for &e in &src_setup {
+            if !unsafe { sys::ecs_is_alive(dst, e) } {
+                continue;
+            }
+
+            let src_ids = table_ids(unsafe { sys::ecs_get_table(src, e) });
+            let dst_ids = table_ids(unsafe { sys::ecs_get_table(dst, e) }).to_vec();
+
+            for id in dst_ids {
+                if !is_builtin_id(self, id) && !src_ids.contains(&id) {
+                    unsafe { sys::ecs_remove_id(dst, e, id) };
+                }
+            }
+
+            for &id in src_ids {
+                if is_builtin_id(other, id) {
+                    continue;
+                }
+                match component_ptr(src, e, id) {
+                    Some((ptr, size)) => unsafe { sys::ecs_set_id(dst, e, id, size, ptr) },
+                    None => unsafe { sys::ecs_add_id(dst, e, id) },
+                }
+            }
+        }
+    }
+
+    /// Register a component of `other` with the same id in this world, or check that it is
+    /// the same component if this world already has it.
+    fn copy_component_from(&self, other: &World, component: sys::ecs_entity_t) {
+        let dst = self.ptr_mut();
+        let src = other.ptr_mut() as *const sys::ecs_world_t;
+
+        let src_component = unsafe {
+            &*(sys::ecs_get_id(src, component, ECS_COMPONENT) as *const sys::EcsComponent)
+        };
+
+        if unsafe { sys::ecs_is_alive(dst, component) } {
+            let dst_component = unsafe { sys::ecs_get_id(dst, component, ECS_COMPONENT) }
+                as *const sys::EcsComponent;
+            let name = |world| unsafe {
+                let name = sys::ecs_get_name(world, component);
+                (!name.is_null()).then(|| std::ffi::CStr::from_ptr(name))
+            };
+            assert!(
+                !dst_component.is_null()
+                    && unsafe { (*dst_component).size } == src_component.size
+                    && name(dst) == name(src),
+                "component {} is registered with a different id in the destination world",
+                component
+            );
+            return;
+        }
+
+        assert!(
+            unsafe { sys::ecs_get_alive(dst, component & 0xFFFF_FFFF) } == 0,
+            "component {} of the source world is used by another entity in the destination world",
+            component
+        );
+
+        self.copy_scope_from(other, component);
+
+        unsafe {
+            let type_info = sys::ecs_type_info_t {
+                size: src_component.size,
+                alignment: src_component.alignment,
+                hooks: Default::default(),
+                component: 0,
+                name: std::ptr::null(),
+            };
+            sys::ecs_component_init(dst, &create_component_desc(component, type_info));
+
+            let src_info = sys::ecs_get_type_info(src, component);
+            if !src_info.is_null() {
+                let src_hooks = &(*src_info).hooks;
+                let hooks = sys::ecs_type_hooks_t {
+                    ctor: src_hooks.ctor,
+                    dtor: src_hooks.dtor,
+                    copy: src_hooks.copy,
+                    move_: src_hooks.move_,
+                    copy_ctor: src_hooks.copy_ctor,
+                    move_ctor: src_hooks.move_ctor,
+                    ctor_move_dtor: src_hooks.ctor_move_dtor,
+                    move_dtor: src_hooks.move_dtor,
+                    flags: src_hooks.flags & sys::ECS_TYPE_HOOKS_ILLEGAL,
+                    ..Default::default()
+                };
+                sys::ecs_set_hooks_id(dst, component, &hooks);
+            }
+        }
+    }
+
+    /// Create an entity of `other` with the same id, name and parent in this world, if it
+    /// doesn't exist yet.
+    fn copy_scope_from(&self, other: &World, entity: sys::ecs_entity_t) {
+        let dst = self.ptr_mut();
+        let src = other.ptr_mut() as *const sys::ecs_world_t;
+
+        if unsafe { sys::ecs_is_alive(dst, entity) } {
+            return;
+        }
+
+        assert!(
+            unsafe { sys::ecs_get_alive(dst, entity & 0xFFFF_FFFF) } == 0,
+            "entity {} of the source world is used by another entity in the destination world",
+            entity
+        );
+
+        unsafe {
+            sys::ecs_make_alive(dst, entity);
+
+            let parent = sys::ecs_get_target(src, entity, ECS_CHILD_OF, 0);
+            if parent != 0 {
+                self.copy_scope_from(other, parent);
+                sys::ecs_add_id(dst, entity, ecs_pair(ECS_CHILD_OF, parent));
+            }
+            sys::ecs_set_name(dst, entity, sys::ecs_get_name(src, entity));
+        }
+    }
+
+    /// Copy the ids that Rust types are registered with in `other` to this world.
+    fn copy_component_ids_from(&self, other: &World) {
+        let src_array = other.components_array();
+        let dst_array = self.components_array();
+        if dst_array.len() < src_array.len() {
+            dst_array.resize(src_array.len(), 0);
+        }
+        for (index, &id) in src_array.iter().enumerate() {
+            if id == 0 {
+                continue;
+            }
+            assert!(
+                dst_array[index] == 0 || dst_array[index] == id,
+                "component {} is registered with a different id in the destination world",
+                id
+            );
+            dst_array[index] = id;
+        }
+
+        let dst_map = self.components_map();
+        for (type_id, &id) in other.components_map().iter() {
+            let dst_id = dst_map.entry(*type_id).or_insert(id);
+            assert!(
+                *dst_id == id,
+                "component {} is registered with a different id in the destination world",
+                id
+            );
+        }
+    }
+}
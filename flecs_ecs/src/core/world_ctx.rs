@@ -1,10 +1,28 @@
-use super::{FlecsArray, FlecsIdMap, World};
+use super::{ComponentAccess, FlecsArray, FlecsIdMap, MergeHooks, World};
 use crate::sys;
 
 pub(crate) struct WorldCtx {
     query_ref_count: i32,
     pub(crate) components: FlecsIdMap,
     pub(crate) components_array: FlecsArray,
+    pub(crate) merge_hooks: MergeHooks,
+    pub(crate) component_access: ComponentAccess,
+    pub(crate) propagation_stopped: bool,
+    pub(crate) propagation_origin: sys::ecs_entity_t,
+    pub(crate) pair_indices: std::collections::HashMap<u64, super::PairIndexData>,
+    /// The constant entities of enum components in this world, by enum component and variant index.
+    pub(crate) enum_constants: std::collections::HashMap<u64, Vec<u64>>,
+    /// The world context set with `World::set_ctx`.
+    pub(crate) typed_ctx: *mut std::ffi::c_void,
+    #[cfg(feature = "flecs_pipeline_log")]
+    pub(crate) last_run_log: Vec<(super::Entity, crate::addons::pipeline::SystemRunStatus)>,
+    #[cfg(feature = "flecs_pipeline")]
+    pub(crate) system_order: crate::addons::pipeline::SystemOrder,
+    /// The cached query that matches all systems, 0 if it wasn't created yet.
+    #[cfg(feature = "flecs_pipeline")]
+    pub(crate) systems_query: sys::ecs_entity_t,
+    #[cfg(feature = "churn_debug")]
+    pub(crate) churn: super::ChurnCounts,
     is_panicking: bool,
 }
 
@@ -14,6 +32,21 @@ impl WorldCtx {
             query_ref_count: 0,
             components: Default::default(),
             components_array: vec![0; 500],
+            merge_hooks: MergeHooks::default(),
+            component_access: ComponentAccess::default(),
+            propagation_stopped: false,
+            propagation_origin: 0,
+            pair_indices: Default::default(),
+            enum_constants: Default::default(),
+            typed_ctx: std::ptr::null_mut(),
+            #[cfg(feature = "flecs_pipeline_log")]
+            last_run_log: Vec::new(),
+            #[cfg(feature = "flecs_pipeline")]
+            system_order: Default::default(),
+            #[cfg(feature = "flecs_pipeline")]
+            systems_query: 0,
+            #[cfg(feature = "churn_debug")]
+            churn: Default::default(),
             is_panicking: false,
         }
     }
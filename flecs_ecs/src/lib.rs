@@ -20,6 +20,11 @@ pub mod core;
 
 pub mod addons;
 
+pub mod log {
+    //! Logging options, and how errors reported by flecs are handled.
+    pub use crate::core::utility::log::*;
+}
+
 /// this is to allow using the proc macro's inside lib itself that implements its own traits.
 extern crate self as flecs_ecs;
 
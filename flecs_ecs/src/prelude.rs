@@ -9,3 +9,6 @@ pub use flecs_ecs_sys::EcsComponent;
 pub use crate::addons::meta::*;
 #[cfg(feature = "flecs_meta")]
 pub use crate::{component, component_ext, member, member_ext};
+
+#[cfg(feature = "flecs_script")]
+pub use crate::addons::script::*;
@@ -0,0 +1,105 @@
+//! This test needs to be a separate process, since the OS API is process-global.
+//!
+//! The allocator below hands out pointers that are offset from the allocations made with the
+//! Rust allocator, so strings allocated by flecs that are freed by Rust instead of through the
+//! OS API are detected.
+
+use std::alloc::Layout;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ecs_os_api::try_add_init_hook;
+use flecs_ecs::prelude::*;
+
+const HEADER: usize = 16;
+const MAGIC: usize = 0xf1ec5;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static FREES: AtomicUsize = AtomicUsize::new(0);
+
+fn layout(size: usize) -> Layout {
+    Layout::from_size_align(size + HEADER, HEADER).unwrap()
+}
+
+unsafe fn write_header(base: *mut u8, size: usize) -> *mut c_void {
+    assert!(!base.is_null(), "out of memory");
+    let header = base as *mut usize;
+    header.write(MAGIC);
+    header.add(1).write(size);
+    ALLOCS.fetch_add(1, Ordering::SeqCst);
+    base.add(HEADER) as *mut c_void
+}
+
+unsafe fn read_header(ptr: *mut c_void) -> (*mut u8, usize) {
+    let base = (ptr as *mut u8).sub(HEADER);
+    let header = base as *mut usize;
+    assert_eq!(
+        header.read(),
+        MAGIC,
+        "freed memory that wasn't allocated by flecs"
+    );
+    (base, header.add(1).read())
+}
+
+unsafe extern "C-unwind" fn malloc(size: i32) -> *mut c_void {
+    let size = size as usize;
+    write_header(std::alloc::alloc(layout(size)), size)
+}
+
+unsafe extern "C-unwind" fn calloc(size: i32) -> *mut c_void {
+    let size = size as usize;
+    write_header(std::alloc::alloc_zeroed(layout(size)), size)
+}
+
+unsafe extern "C-unwind" fn realloc(ptr: *mut c_void, size: i32) -> *mut c_void {
+    if ptr.is_null() {
+        return malloc(size);
+    }
+
+    let (base, old_size) = read_header(ptr);
+    let size = size as usize;
+    let base = std::alloc::realloc(base, layout(old_size), size + HEADER);
+    assert!(!base.is_null(), "out of memory");
+    (base as *mut usize).add(1).write(size);
+    base.add(HEADER) as *mut c_void
+}
+
+unsafe extern "C-unwind" fn free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+
+    let (base, size) = read_header(ptr);
+    (base as *mut usize).write(0);
+    std::alloc::dealloc(base, layout(size));
+    FREES.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn strings_are_freed_with_os_api() {
+    try_add_init_hook(Box::new(|api| {
+        api.malloc_ = Some(malloc);
+        api.calloc_ = Some(calloc);
+        api.realloc_ = Some(realloc);
+        api.free_ = Some(free);
+    }))
+    .unwrap();
+
+    let world = World::new();
+    assert!(ALLOCS.load(Ordering::SeqCst) > 0);
+
+    let parent = world.entity_named("parent");
+    let child = world.entity_named("parent::child");
+
+    let frees = FREES.load(Ordering::SeqCst);
+    for _ in 0..10 {
+        assert_eq!(child.path().unwrap(), "::parent::child");
+        assert_eq!(child.path_w_sep(".", "/").unwrap(), "/parent.child");
+        assert_eq!(child.path_from_id(parent).unwrap(), "child");
+        assert_eq!(child.path_from_id_w_sep(parent, ".", "/").unwrap(), "child");
+        assert!(child.archetype().to_string().is_some());
+    }
+    assert!(FREES.load(Ordering::SeqCst) - frees >= 50);
+
+    drop(world);
+}
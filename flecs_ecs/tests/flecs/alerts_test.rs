@@ -0,0 +1,89 @@
+#![cfg(feature = "flecs_alerts")]
+
+use flecs_ecs::addons::alerts::*;
+use flecs_ecs::prelude::*;
+
+use crate::common_test::*;
+
+#[test]
+fn alerts_instance() {
+    let world = World::new();
+    world.import::<AlertsModule>();
+
+    let alert = world
+        .alert::<&Position>()
+        .without::<Velocity>()
+        .message("$this has Position but not Velocity")
+        .severity_id(AlertSeverity::Warning)
+        .build();
+
+    let e = world.entity_named("e").set(Position { x: 10, y: 20 });
+    world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Velocity { x: 1, y: 2 });
+
+    // alerts are monitored every 0.5 seconds
+    world.progress_time(1.0);
+
+    assert_eq!(alert.instance_count(), 1);
+    assert_eq!(e.alert_count(0), 1);
+    assert_eq!(e.alert_count(*alert), 1);
+
+    alert.each_instance(|instance, source, message| {
+        assert!(instance.has_id(*AlertInstance));
+        assert_eq!(instance.parent().unwrap(), *alert);
+        assert_eq!(source, e);
+        assert_eq!(message, "e has Position but not Velocity");
+    });
+
+    let mut count = 0;
+    world
+        .query::<()>()
+        .with::<AlertInstance>()
+        .build()
+        .each(|_| count += 1);
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn alerts_severity() {
+    let world = World::new();
+    world.import::<AlertsModule>();
+
+    let alert = world
+        .alert::<&Position>()
+        .severity_id(AlertSeverity::Warning)
+        .build();
+
+    assert_eq!(
+        alert.target_id(Alert, 0).unwrap(),
+        Entity::from(AlertSeverity::Warning)
+    );
+    assert_eq!(Entity::from(AlertSeverity::Info), *Info);
+    assert_eq!(Entity::from(AlertSeverity::Error), *Error);
+    assert_eq!(Entity::from(AlertSeverity::Critical), *Critical);
+}
+
+#[test]
+fn alerts_instance_removed() {
+    let world = World::new();
+    world.import::<AlertsModule>();
+
+    let alert = world
+        .alert::<&Position>()
+        .without::<Velocity>()
+        .message("$this has Position but not Velocity")
+        .build();
+
+    let e = world.entity().set(Position { x: 10, y: 20 });
+
+    world.progress_time(1.0);
+    assert_eq!(alert.instance_count(), 1);
+
+    e.set(Velocity { x: 1, y: 2 });
+
+    world.progress_time(1.0);
+    assert_eq!(alert.instance_count(), 0);
+    assert_eq!(e.alert_count(0), 0);
+}
@@ -151,3 +151,278 @@ fn on_component_registration() {
         assert_eq!(count.0, 2);
     });
 }
+
+#[derive(Component)]
+#[flecs(no_collect)]
+struct NotCollected;
+
+#[test]
+#[cfg(feature = "auto_register")]
+fn component_register_collected() {
+    // `OnRegistration` expects the singleton when it's registered
+    let world_a = World::new();
+    world_a.set(Count(0));
+    world_a.register_collected();
+    assert!(Position::is_registered_with_world(&world_a));
+    assert!(Velocity::is_registered_with_world(&world_a));
+    assert!(!NotCollected::is_registered_with_world(&world_a));
+    // components of the crate are set up by the features that use them
+    assert!(!OwnedBy::is_registered_with_world(&world_a));
+
+    // components used in a different order in the second world get the same ids
+    let world_b = World::new();
+    world_b.component::<Velocity>();
+    world_b.component::<Mass>();
+
+    let world_c = World::new();
+    world_c.set(Count(0));
+    world_c.register_collected();
+    world_c.component::<Velocity>();
+    world_c.component::<Mass>();
+
+    assert_eq!(
+        world_a.component::<Position>().id(),
+        world_c.component::<Position>().id()
+    );
+    assert_eq!(
+        world_a.component::<Velocity>().id(),
+        world_c.component::<Velocity>().id()
+    );
+    assert_eq!(
+        world_a.component::<Mass>().id(),
+        world_c.component::<Mass>().id()
+    );
+    assert_ne!(
+        world_a.component::<Mass>().id(),
+        world_b.component::<Mass>().id()
+    );
+}
+
+#[derive(Component)]
+#[flecs(name = "game::Health", symbol = "game_health")]
+struct Health {
+    value: f32,
+}
+
+#[derive(Component)]
+#[flecs(name = "game::Armor")]
+struct Armor {
+    value: f32,
+}
+
+// `ArmorV2` and `ArmorBig` are named `game::Armor` by the tests that need it, a custom name
+// would make them collide when `auto_register` registers all derived components
+#[derive(Component)]
+struct ArmorV2 {
+    value: f32,
+}
+
+#[derive(Component)]
+struct ArmorBig {
+    value: f64,
+    bonus: f64,
+}
+
+#[test]
+fn component_custom_name_and_symbol() {
+    let world = World::new();
+
+    // implicit registration uses the custom name as well
+    let e = world.entity().set(Health { value: 10.0 });
+    let health = world.component::<Health>();
+
+    assert_eq!(health.path().unwrap(), "::game::Health");
+    assert_eq!(health.symbol(), "game_health");
+    assert_eq!(world.lookup("game::Health"), health.entity());
+    let by_symbol = unsafe {
+        flecs_ecs::sys::ecs_lookup_symbol(world.ptr_mut(), c"game_health".as_ptr(), false, false)
+    };
+    assert_eq!(by_symbol, *health.id());
+    assert!(e.has_id(health.id()));
+}
+
+#[test]
+fn component_named_lookup() {
+    let world = World::new();
+
+    let position = world.component_named::<Position>("Pos");
+    assert_eq!(world.lookup("Pos"), position.entity());
+
+    // registering again with the same name returns the component
+    assert_eq!(world.component_named::<Position>("Pos").id(), position.id());
+}
+
+#[test]
+#[should_panic(expected = "is already registered as `::Pos`")]
+fn component_named_twice_with_other_name() {
+    let world = World::new();
+
+    world.component_named::<Position>("Pos");
+    world.component_named::<Position>("Position2");
+}
+
+#[test]
+fn component_same_name_same_layout() {
+    let world = World::new();
+
+    let armor = world.component_id::<Armor>();
+    assert_eq!(world.component_named::<ArmorV2>("game::Armor").id(), armor);
+
+    world.entity().set(ArmorV2 { value: 1.0 });
+    assert_eq!(world.lookup("game::Armor"), armor);
+}
+
+#[test]
+#[should_panic(expected = "which is already registered for a component with size 4, alignment 4")]
+fn component_same_name_other_layout() {
+    let world = World::new();
+
+    world.component::<Armor>();
+    world.component_named::<ArmorBig>("game::Armor");
+}
+
+macro_rules! sized_components {
+    ($($name:ident($size:literal)),*) => {
+        $(
+            #[derive(Component)]
+            struct $name {
+                value: [u8; $size],
+            }
+        )*
+
+        /// Registers the components in `world` in order, or in reverse order, and checks their
+        /// name and size.
+        fn register_sized_components(world: &World, reverse: bool) -> Vec<Entity> {
+            let mut register: Vec<Box<dyn Fn(&World) -> (Entity, &'static str, i32)>> = vec![
+                $(Box::new(|world: &World| {
+                    let id = world.component_id::<$name>();
+                    (id, stringify!($name), $size)
+                }),)*
+            ];
+            if reverse {
+                register.reverse();
+            }
+
+            register
+                .iter()
+                .map(|register| {
+                    let (id, name, size) = register(world);
+                    let component = world.component_untyped_from_id(id);
+                    assert_eq!(
+                        component.path().unwrap(),
+                        format!("::flecs::component_test::{name}")
+                    );
+                    component.get::<&flecs::Component>(|c| assert_eq!(c.size, size));
+                    id
+                })
+                .collect()
+        }
+    };
+}
+
+sized_components!(
+    Sized1(1),
+    Sized2(2),
+    Sized3(3),
+    Sized4(4),
+    Sized5(5),
+    Sized6(6),
+    Sized7(7),
+    Sized8(8),
+    Sized9(9),
+    Sized10(10),
+    Sized11(11),
+    Sized12(12)
+);
+
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+#[test]
+fn component_ids_per_world() {
+    let world_a = World::new();
+    let world_b = World::new();
+
+    // offset the ids of the worlds, so a mixed up id points to another component
+    world_b.component::<Velocity>();
+
+    let ids_a = register_sized_components(&world_a, false);
+    let ids_b = register_sized_components(&world_b, true);
+    assert_ne!(ids_a, ids_b.iter().rev().copied().collect::<Vec<_>>());
+
+    // interleave registrations of components that are new in both worlds
+    let position_b = world_b.component_id::<Position>();
+    let position_a = world_a.component_id::<Position>();
+    let template_a = world_a.component_id::<Template<u64>>();
+    let template_b = world_b.component_id::<Template<u64>>();
+    assert_eq!(world_a.component_id::<Position>(), position_a);
+    assert_eq!(world_b.component_id::<Position>(), position_b);
+    assert_eq!(world_a.component_id::<Template<u64>>(), template_a);
+    assert_eq!(world_b.component_id::<Template<u64>>(), template_b);
+    assert_ne!(position_a, position_b);
+
+    let e_a = world_a
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Template::<u64> { value: 3 })
+        .set(Sized3 { value: [1; 3] });
+    let e_b = world_b
+        .entity()
+        .set(Position { x: 4, y: 5 })
+        .set(Template::<u64> { value: 6 })
+        .set(Sized3 { value: [2; 3] });
+
+    assert!(e_a.has_id(position_a));
+    assert!(e_b.has_id(position_b));
+    // the id of the component in the other world is another component
+    assert_ne!(
+        world_a.entity_from_id(position_b).path(),
+        world_b.entity_from_id(position_b).path()
+    );
+    e_a.get::<(&Position, &Template<u64>, &Sized3)>(|(p, t, s)| {
+        assert_eq!((p.x, p.y, t.value, s.value), (1, 2, 3, [1; 3]));
+    });
+    e_b.get::<(&Position, &Template<u64>, &Sized3)>(|(p, t, s)| {
+        assert_eq!((p.x, p.y, t.value, s.value), (4, 5, 6, [2; 3]));
+    });
+}
+
+#[test]
+fn component_enum_constants_per_world() {
+    let world_a = World::new();
+    let world_b = World::new();
+
+    world_a.component::<Channel>();
+    // the constants of the first world are already in use in the second world
+    let taken: Vec<_> = (0..1000).map(|_| world_b.entity().id()).collect();
+
+    let green_a = Channel::Green.id_variant(&world_a);
+    let green_b = Channel::Green.id_variant(&world_b);
+
+    assert_ne!(green_a.id(), green_b.id());
+    assert!(!taken.contains(&green_b.id()));
+    assert_eq!(green_a.path().unwrap(), green_b.path().unwrap());
+    assert_eq!(green_b.parent().unwrap(), world_b.component_id::<Channel>());
+    assert_eq!(
+        Channel::id_variant_of_index(&world_b, 1),
+        Some(*green_b.id())
+    );
+
+    let e_a = world_a.entity().set(Channel::Red);
+    let e_b = world_b.entity().set(Channel::Blue);
+    e_a.get::<&Channel>(|c| assert_eq!(*c, Channel::Red));
+    e_b.get::<&Channel>(|c| assert_eq!(*c, Channel::Blue));
+
+    let tagged = world_b.entity().add_enum(Channel::Blue);
+    assert!(tagged.has_id((
+        world_b.component_id::<Channel>(),
+        Channel::Blue.id_variant(&world_b)
+    )));
+    assert!(tagged.has_enum(Channel::Blue));
+    assert!(!tagged.has_enum(Channel::Red));
+}
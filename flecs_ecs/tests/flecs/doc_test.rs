@@ -0,0 +1,162 @@
+#![cfg(feature = "flecs_doc")]
+
+use flecs_ecs::addons::doc::Doc;
+use flecs_ecs::prelude::*;
+
+use crate::common_test::*;
+
+#[test]
+fn doc_entity_round_trip() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    assert_eq!(e.doc_name(), None);
+    assert_eq!(e.doc_brief(), None);
+    assert_eq!(e.doc_detail(), None);
+    assert_eq!(e.doc_link(), None);
+    assert_eq!(e.doc_color(), None);
+
+    e.set_doc_name("Hero *")
+        .set_doc_brief("The main character")
+        .set_doc_detail("Saves the world, most of the time")
+        .set_doc_link("https://www.flecs.dev")
+        .set_doc_color("#ff0000");
+
+    assert_eq!(e.doc_name().as_deref(), Some("Hero *"));
+    assert_eq!(e.doc_brief().as_deref(), Some("The main character"));
+    assert_eq!(
+        e.doc_detail().as_deref(),
+        Some("Saves the world, most of the time")
+    );
+    assert_eq!(e.doc_link().as_deref(), Some("https://www.flecs.dev"));
+    assert_eq!(e.doc_color().as_deref(), Some("#ff0000"));
+
+    assert_eq!(world.doc_name_id(e).as_deref(), Some("Hero *"));
+    assert_eq!(world.doc_color_id(e).as_deref(), Some("#ff0000"));
+}
+
+#[test]
+fn doc_entity_overwrite() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    e.set_doc_brief("first");
+    e.set_doc_brief("second");
+
+    assert_eq!(e.doc_brief().as_deref(), Some("second"));
+}
+
+#[test]
+fn doc_name_defaults_to_entity_name() {
+    let world = World::new();
+
+    let e = world.entity_named("Bob");
+
+    assert_eq!(e.doc_name().as_deref(), Some("Bob"));
+
+    e.set_doc_name("Robert");
+    assert_eq!(e.doc_name().as_deref(), Some("Robert"));
+    assert_eq!(e.name(), "Bob");
+}
+
+#[test]
+fn doc_component_round_trip() {
+    let world = World::new();
+
+    world
+        .component::<Position>()
+        .set_doc_name("Position")
+        .set_doc_brief("Position in world space")
+        .set_doc_detail("Units are in meters")
+        .set_doc_link("https://en.wikipedia.org/wiki/Position_(geometry)")
+        .set_doc_color("#00ff00");
+
+    let position = world.component::<Position>();
+    assert_eq!(position.doc_name().as_deref(), Some("Position"));
+    assert_eq!(
+        world.doc_brief::<Position>().as_deref(),
+        Some("Position in world space")
+    );
+    assert_eq!(
+        world.doc_detail::<Position>().as_deref(),
+        Some("Units are in meters")
+    );
+    assert_eq!(
+        world.doc_link::<Position>().as_deref(),
+        Some("https://en.wikipedia.org/wiki/Position_(geometry)")
+    );
+    assert_eq!(world.doc_color::<Position>().as_deref(), Some("#00ff00"));
+
+    world.set_doc_brief::<Position>("Position in local space");
+    assert_eq!(
+        position.doc_brief().as_deref(),
+        Some("Position in local space")
+    );
+}
+
+#[derive(Component)]
+struct DocModule;
+
+#[derive(Component)]
+struct DocModuleComponent {
+    value: i32,
+}
+
+impl Module for DocModule {
+    fn module(world: &World) {
+        world.module::<DocModule>("doc_module");
+        world
+            .component::<DocModuleComponent>()
+            .set_doc_brief("Component of the module")
+            .set_doc_color("#0000ff");
+    }
+}
+
+#[test]
+fn doc_survives_module_import() {
+    let world = World::new();
+
+    world.import::<DocModule>();
+
+    let component = world.component::<DocModuleComponent>();
+    assert_eq!(
+        component.doc_brief().as_deref(),
+        Some("Component of the module")
+    );
+    assert_eq!(component.doc_color().as_deref(), Some("#0000ff"));
+
+    // importing again doesn't reset the documentation
+    world.set_doc_brief::<DocModuleComponent>("Changed after import");
+    world.import::<DocModule>();
+
+    assert_eq!(
+        world.doc_brief::<DocModuleComponent>().as_deref(),
+        Some("Changed after import")
+    );
+    assert_eq!(
+        world.doc_color::<DocModuleComponent>().as_deref(),
+        Some("#0000ff")
+    );
+}
+
+#[test]
+fn doc_set_before_module_import() {
+    let world = World::new();
+
+    world.set_doc_brief::<DocModuleComponent>("Set before import");
+    world.set_doc_link::<DocModuleComponent>("https://www.flecs.dev");
+
+    world.import::<DocModule>();
+
+    // the module sets its own brief, the link is kept
+    assert_eq!(
+        world.doc_brief::<DocModuleComponent>().as_deref(),
+        Some("Component of the module")
+    );
+    assert_eq!(
+        world.doc_link::<DocModuleComponent>().as_deref(),
+        Some("https://www.flecs.dev")
+    );
+}
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use crate::common_test::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[test]
 fn count_target_ids() {
@@ -46,3 +47,577 @@ fn entity_id_reuse() {
     );
     assert_eq!(b.archetype().to_string(), first_archetype);
 }
+
+#[test]
+fn entity_count_children_and_subtree() {
+    let world = World::new();
+
+    // root
+    // ├── a
+    // │   ├── a1
+    // │   └── a2
+    // │       └── a2x
+    // └── b
+    let root = world.entity();
+    let a = world.entity().child_of_id(root);
+    let a1 = world.entity().child_of_id(a);
+    let a2 = world.entity().child_of_id(a);
+    world.entity().child_of_id(a2);
+    let b = world.entity().child_of_id(root);
+
+    assert_eq!(root.count_children(), 2);
+    assert_eq!(a.count_children(), 2);
+    assert_eq!(b.count_children(), 0);
+    assert_eq!(world.count_pair_target::<flecs::ChildOf>(root), 2);
+
+    assert_eq!(root.count_subtree(), 5);
+    assert_eq!(a.count_subtree(), 3);
+    assert_eq!(a1.count_subtree(), 0);
+    assert_eq!(b.count_subtree(), 0);
+
+    let b1 = world.entity().child_of_id(b);
+    assert_eq!(b.count_children(), 1);
+    assert_eq!(root.count_subtree(), 6);
+
+    a2.destruct();
+    b1.remove_first::<flecs::ChildOf>(b);
+    assert_eq!(a.count_children(), 1);
+    assert_eq!(b.count_children(), 0);
+    assert_eq!(root.count_subtree(), 3);
+}
+
+#[test]
+fn world_count_pair_target_custom_relationship() {
+    #[derive(Component)]
+    struct Likes;
+
+    let world = World::new();
+
+    let bob = world.entity();
+    world.entity().add_first::<Likes>(bob);
+    world.entity().add_first::<Likes>(bob);
+    world.entity().add_first::<Likes>(world.entity());
+
+    assert_eq!(world.count_pair_target::<Likes>(bob), 2);
+    assert_eq!(world.count_pair_target::<Likes>(flecs::Wildcard::ID), 3);
+
+    world.entity().add_first::<Likes>(bob);
+    assert_eq!(world.count_pair_target::<Likes>(bob), 3);
+}
+
+#[test]
+#[should_panic(expected = "not found on entity `::Bob`")]
+fn entity_get_missing_strict_panics_with_entity() {
+    let world = World::new();
+
+    let e = world.entity_named("Bob").set(Velocity { x: 1, y: 2 });
+
+    e.get::<&Position>(|_| {});
+}
+
+#[test]
+fn entity_try_get_missing_lenient_invokes_handler() {
+    let world = World::new();
+    let misses = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    world.set_strict_access(false);
+    assert!(!world.is_strict_access());
+
+    let misses_handler = misses.clone();
+    world.on_component_miss(move |entity, component| {
+        misses_handler
+            .lock()
+            .unwrap()
+            .push((entity.id(), component.to_string()));
+        false
+    });
+
+    let e = world.entity_named("Bob").set(Velocity { x: 1, y: 2 });
+
+    assert!(e.try_get::<&Position>(|_| {}).is_none());
+    assert!(e.try_get::<(&Velocity, &Position)>(|_| {}).is_none());
+    assert!(e.try_cloned::<&Position>().is_none());
+    assert_eq!(e.get::<&Velocity>(|vel| vel.x), 1);
+
+    let misses = misses.lock().unwrap();
+    assert_eq!(misses.len(), 3);
+    for (entity, component) in misses.iter() {
+        assert_eq!(*entity, e.id());
+        assert_eq!(component, std::any::type_name::<Position>());
+    }
+}
+
+#[test]
+fn entity_try_get_missing_strict_skips_handler() {
+    let world = World::new();
+    let misses = std::sync::Arc::new(AtomicUsize::new(0));
+
+    let misses_handler = misses.clone();
+    world.on_component_miss(move |_, _| {
+        misses_handler.fetch_add(1, Ordering::Relaxed);
+        false
+    });
+
+    let e = world.entity().set(Velocity { x: 1, y: 2 });
+
+    assert!(e.try_get::<&Position>(|_| {}).is_none());
+    assert_eq!(misses.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn entity_try_get_missing_lenient_worker_threads() {
+    let world = World::new();
+    world.set_threads(4);
+    world.set_strict_access(false);
+    // components can't be registered on worker threads
+    world.component::<Position>();
+
+    let misses = std::sync::Arc::new(AtomicUsize::new(0));
+    let misses_handler = misses.clone();
+    world.on_component_miss(move |entity, _| {
+        // misses inside the handler don't invoke it again
+        assert!(entity.try_get::<&Position>(|_| {}).is_none());
+        misses_handler.fetch_add(1, Ordering::Relaxed);
+        false
+    });
+
+    for _ in 0..64 {
+        world.entity().set(Velocity { x: 1, y: 2 });
+    }
+
+    world
+        .system::<&Velocity>()
+        .multi_threaded()
+        .each_entity(|e, _| {
+            assert!(e.try_get::<&Position>(|_| {}).is_none());
+        });
+
+    world.progress();
+    assert_eq!(misses.load(Ordering::Relaxed), 64);
+}
+
+#[test]
+#[should_panic(expected = "not found on entity")]
+fn entity_try_get_missing_lenient_handler_panics() {
+    let world = World::new();
+
+    world.set_strict_access(false);
+    world.on_component_miss(|_, _| true);
+
+    let e = world.entity().set(Velocity { x: 1, y: 2 });
+
+    let _ = e.try_get::<&Position>(|_| {});
+}
+
+#[cfg(feature = "churn_debug")]
+#[test]
+fn entity_transition_count() {
+    #[derive(Component)]
+    struct Churn;
+
+    let world = World::new();
+
+    let calm = world.entity().set(Position { x: 1, y: 2 });
+    let churning = world.entity();
+
+    for _ in 0..100 {
+        churning.add::<Churn>();
+        churning.remove::<Churn>();
+    }
+
+    assert_eq!(churning.transition_count(), 200);
+    assert_eq!(calm.transition_count(), 1);
+
+    let top = world.top_churning_entities(3);
+    assert_eq!(top[0], (churning, 200));
+    assert!(top.len() <= 3);
+
+    world.reset_transition_counts();
+    assert_eq!(churning.transition_count(), 0);
+
+    // adding multiple ids at once is a single move
+    world.defer(|| {
+        calm.set(Velocity { x: 1, y: 2 });
+        calm.add_first::<Churn>(churning);
+    });
+    assert_eq!(calm.transition_count(), 1);
+
+    calm.remove_first::<Churn>(churning);
+    assert_eq!(calm.transition_count(), 2);
+}
+
+#[test]
+fn id_fallible_conversions() {
+    let world = World::new();
+
+    let position = world.component::<Position>().id();
+    let tag = world.entity().id();
+    let rel = world.entity().id();
+    let tgt = world.entity().id();
+
+    let dead = world.entity();
+    let dead_id = dead.id();
+    dead.destruct();
+    // recycles the index of the dead entity with a new generation
+    let recycled = world.entity().id();
+    assert_eq!(*recycled as u32, *dead_id as u32);
+
+    let gone = world.entity();
+    let gone_id = gone.id();
+    gone.destruct();
+
+    let pair = ecs_pair(*rel, *tgt);
+    let override_flag = flecs::id_flags::AutoOverride::ID;
+    let toggle_flag = flecs::id_flags::Toggle::ID;
+
+    // (id, expected entity, expected pair)
+    type Expected = (u64, Option<Entity>, Option<(Entity, Entity)>);
+    let ids: Vec<Expected> = vec![
+        (*position, Some(position), None),
+        (*tag, Some(tag), None),
+        (*recycled, Some(recycled), None),
+        (*dead_id, None, None),
+        (0, None, None),
+        (flecs::Wildcard::ID, None, None),
+        (flecs::Any::ID, None, None),
+        (override_flag | *position, None, None),
+        (toggle_flag | *tag, None, None),
+        (pair, None, Some((rel, tgt))),
+        (
+            ecs_pair(flecs::ChildOf::ID, *tag),
+            None,
+            Some((flecs::ChildOf::ID.into(), tag)),
+        ),
+        (ecs_pair(*rel, flecs::Wildcard::ID), None, None),
+        (ecs_pair(flecs::Wildcard::ID, *tgt), None, None),
+        (ecs_pair(*rel, flecs::Any::ID), None, None),
+        (override_flag | pair, None, None),
+        (ecs_pair(*rel, *gone_id), None, None),
+        // pairs don't store generations, the element resolves to the alive entity
+        (ecs_pair(*rel, *dead_id), None, Some((rel, recycled))),
+    ];
+
+    for (id, entity, pair) in ids {
+        let id_view = IdView::new_from_id(&world, id);
+        assert_eq!(
+            id_view.try_into_entity().map(|e| e.id()),
+            entity,
+            "try_into_entity of {id:#x}"
+        );
+        assert_eq!(
+            id_view
+                .try_pair()
+                .map(|(first, second)| (first.id(), second.id())),
+            pair,
+            "try_pair of {id:#x}"
+        );
+    }
+}
+
+#[test]
+fn entity_try_as_component() {
+    let world = World::new();
+
+    let position = world.component::<Position>().id();
+    let tag = world.entity().id();
+
+    let component = position.try_as_component::<Position>(&world).unwrap();
+    assert_eq!(component.id(), position);
+
+    assert!(tag.try_as_component::<Position>(&world).is_none());
+    assert!(position.try_as_component::<Velocity>(&world).is_none());
+    // not registered, and not registered by the check either
+    assert!(tag.try_as_component::<Velocity>(&world).is_none());
+    assert!(!Velocity::is_registered_with_world(&world));
+
+    // same index with another generation
+    let other_generation = Entity(*position | (1 << 32));
+    assert!(other_generation
+        .try_as_component::<Position>(&world)
+        .is_none());
+}
+
+#[test]
+fn entity_get_tuple_w_optional_and_pair() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 })
+        .set_pair::<Likes, Position>(Position { x: 5, y: 6 });
+
+    e.get::<(
+        &Position,
+        &mut Velocity,
+        Option<&Mass>,
+        &mut (Likes, Position),
+    )>(|(pos, vel, mass, likes)| {
+        assert!(mass.is_none());
+        vel.x += pos.x;
+        likes.y += pos.y;
+    });
+
+    e.get::<(&Velocity, &(Likes, Position))>(|(vel, likes)| {
+        assert_eq!(vel.x, 4);
+        assert_eq!(likes.y, 8);
+    });
+}
+
+#[test]
+fn entity_try_get_tuple_missing_required() {
+    let world = World::new();
+
+    let e = world.entity().set(Position { x: 1, y: 2 });
+
+    let mut ran = false;
+    let res = e.try_get::<(&mut Position, &Velocity)>(|(pos, _)| {
+        pos.x = 10;
+        ran = true;
+    });
+    assert!(res.is_none());
+    assert!(!ran);
+    assert_eq!(e.get::<&Position>(|pos| pos.x), 1);
+}
+
+#[test]
+#[should_panic(expected = "requests the same component more than once")]
+fn entity_get_tuple_aliased_mut_panics() {
+    let world = World::new();
+
+    let e = world.entity().set(Position { x: 1, y: 2 });
+
+    e.get::<(&mut Position, &Position)>(|_| {});
+}
+
+#[test]
+fn entity_set_all() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    // the entity moves once, so all ids are present when the first one is added
+    world
+        .observer::<flecs::OnAdd, ()>()
+        .with::<Position>()
+        .each_entity(|e, _| {
+            assert!(e.has::<Velocity>());
+            assert!(e.has::<Tag>());
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(|e, pos| {
+            assert_eq!(pos.x, 1);
+            e.world().get::<&mut Count>(|count| count.0 += 10);
+        });
+
+    world
+        .observer::<flecs::OnSet, &Velocity>()
+        .each_entity(|e, vel| {
+            assert_eq!(vel.x, 3);
+            e.world().get::<&mut Count>(|count| count.0 += 100);
+        });
+
+    let e = world
+        .entity()
+        .set_all((Position { x: 1, y: 2 }, Velocity { x: 3, y: 4 }, Tag {}));
+
+    world.get::<&Count>(|count| assert_eq!(count.0, 111));
+    assert!(e.has::<Tag>());
+    e.get::<(&Position, &Velocity)>(|(pos, vel)| {
+        assert_eq!(pos.y, 2);
+        assert_eq!(vel.y, 4);
+    });
+}
+
+#[test]
+fn entity_set_all_overwrites() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set_all((Position { x: 5, y: 6 }, Mass { value: 7 }));
+
+    assert_eq!(e.get::<&Position>(|pos| pos.x), 5);
+    assert_eq!(e.get::<&Mass>(|mass| mass.value), 7);
+}
+
+#[test]
+fn id_view_flags_pair() {
+    let world = World::new();
+
+    let pair = IdView::new_from_id(&world, (world.component_id::<Likes>(), world.entity()));
+    assert!(pair.is_pair());
+    assert!(!pair.is_entity());
+    assert!(!pair.is_wildcard());
+    assert_eq!(pair.flags(), IdFlags::PAIR);
+    assert!(pair.has_flags(IdFlags::PAIR));
+
+    let toggled = pair.add_flags(IdFlags::TOGGLE);
+    assert!(toggled.has_flags(IdFlags::PAIR | IdFlags::TOGGLE));
+    assert!(!toggled.has_flags(IdFlags::AUTO_OVERRIDE));
+    assert_eq!(toggled.first_id(), pair.first_id());
+    assert_eq!(toggled.second_id(), pair.second_id());
+    assert_eq!(
+        format!("{:?}", toggled.flags()),
+        "{PAIR, TOGGLE}".to_string()
+    );
+
+    let data_pair =
+        IdView::new_from_id(&world, (world.component_id::<Position>(), pair.second_id()));
+    assert_eq!(data_pair.type_id(), world.component_id::<Position>());
+    assert!(pair.get_type_id().is_none());
+}
+
+#[test]
+fn id_view_flags_wildcard_and_entity() {
+    let world = World::new();
+
+    let wildcard =
+        IdView::new_from_id(&world, (world.component_id::<Likes>(), flecs::Wildcard::ID));
+    assert!(wildcard.is_pair());
+    assert!(wildcard.is_wildcard());
+    assert_eq!(wildcard.second_id(), flecs::Wildcard::ID);
+
+    let entity = IdView::new_from_id(&world, world.entity());
+    assert!(entity.is_entity());
+    assert!(!entity.is_pair());
+    assert!(!entity.is_wildcard());
+    assert!(entity.flags().is_empty());
+    assert!(entity.get_first_id().is_none());
+
+    let overridden = entity.add_flags(IdFlags::AUTO_OVERRIDE);
+    assert!(!overridden.is_entity());
+    assert_eq!(overridden.flags(), IdFlags::AUTO_OVERRIDE);
+    assert_eq!(overridden.remove_flags(), entity);
+}
+
+#[test]
+fn entity_archetype_contains() {
+    let world = World::new();
+
+    let apples = world.entity();
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .add_first::<Likes>(apples);
+
+    let archetype = e.archetype();
+    assert_eq!(archetype.len(), 2);
+    assert!(!archetype.is_empty());
+    assert!(archetype.contains::<Position>());
+    assert!(!archetype.contains::<Velocity>());
+    assert!(archetype.contains_id((world.component_id::<Likes>(), apples)));
+    assert!(archetype.contains_id((world.component_id::<Likes>(), flecs::Wildcard::ID)));
+    assert!(archetype.contains_id((flecs::Wildcard::ID, apples)));
+    assert!(!archetype.contains_id((world.component_id::<Eats>(), flecs::Wildcard::ID)));
+    assert!(archetype.get(2).is_none());
+}
+
+#[test]
+fn entity_archetype_iter() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .add::<TagA>()
+        .set(Position { x: 1, y: 2 })
+        .add::<TagB>();
+    let e2 = world
+        .entity()
+        .add::<TagB>()
+        .add::<TagA>()
+        .set(Position { x: 1, y: 2 });
+
+    let ids: Vec<Id> = e1.archetype().into_iter().map(|id| id.id()).collect();
+    assert_eq!(ids.as_slice(), e1.archetype().as_slice());
+
+    // the order doesn't depend on the order in which ids were added
+    let mut ids2 = vec![];
+    for id in &e2.archetype() {
+        ids2.push(id.id());
+    }
+    assert_eq!(ids, ids2);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(e1.archetype().iter().len(), 3);
+    assert_eq!(
+        e1.archetype().to_string().unwrap(),
+        e2.archetype().to_string().unwrap()
+    );
+}
+
+#[test]
+fn entity_each_child_while() {
+    let world = World::new();
+
+    let parent = world.entity();
+    let children: Vec<_> = (0..5)
+        .map(|_| world.entity().child_of_id(parent).id())
+        .collect();
+
+    let mut visited = Vec::new();
+    parent.each_child_while(|child| {
+        visited.push(child.id());
+        visited.len() < 2
+    });
+    assert_eq!(visited, children[..2]);
+
+    let mut visited = 0;
+    parent.each_child_while(|_| {
+        visited += 1;
+        std::ops::ControlFlow::Continue(())
+    });
+    assert_eq!(visited, 5);
+
+    let mut visited = 0;
+    parent.each_child_of_while::<flecs::ChildOf, _>(|_| {
+        visited += 1;
+        std::ops::ControlFlow::Break(())
+    });
+    assert_eq!(visited, 1);
+}
+
+#[test]
+fn entity_generation_recycled() {
+    let world = World::new();
+
+    let stale = world.entity().set(Position { x: 1, y: 2 });
+    let stale_id = stale.id();
+    assert_eq!(stale_id.generation(), 0);
+    stale.destruct();
+
+    // create and delete entities until the index of the stale handle is recycled
+    let mut recycled = world.entity();
+    while recycled.id().index() != stale_id.index() {
+        recycled.destruct();
+        recycled = world.entity();
+    }
+
+    assert_ne!(recycled.id(), stale_id);
+    assert!(recycled.id().generation() > stale_id.generation());
+    assert!(recycled.is_current_generation());
+
+    let stale = world.entity_from_id(stale_id);
+    assert!(!stale.is_alive());
+    assert!(!stale.is_current_generation());
+    assert_eq!(world.get_alive(stale_id.index() as u64).id(), recycled.id());
+
+    let id = recycled.id();
+    assert_eq!(id.with_generation(0).index(), id.index());
+    assert_eq!(id.with_generation(id.generation()), id);
+    assert_eq!(
+        format!("{id}"),
+        format!("{}:{}", id.index(), id.generation())
+    );
+    assert_eq!(format!("{stale_id}"), stale_id.index().to_string());
+
+    // force a newer generation, which makes the current handle stale
+    let forced = id.with_generation(id.generation() + 10);
+    world.set_version(forced);
+    assert!(world.is_alive(forced));
+    assert!(!recycled.is_current_generation());
+    assert!(world.entity_from_id(forced).is_current_generation());
+}
@@ -2,6 +2,7 @@
 use std::ffi::c_void;
 
 use crate::common_test::*;
+use crate::enum_test::StandardEnum;
 
 #[test]
 fn entity_new() {
@@ -443,6 +444,93 @@ fn entity_set_generic_no_size() {
     });
 }
 
+#[test]
+fn entity_set_untyped_on_set() {
+    let world = World::new();
+    let position = world.component::<Position>();
+
+    world.set(Count(0));
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(|e, pos| {
+            assert_eq!(pos.x, 10);
+            assert_eq!(pos.y, 20);
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+
+    let bytes = [10i32.to_ne_bytes(), 20i32.to_ne_bytes()].concat();
+    let entity = unsafe {
+        world.entity().set_untyped(
+            position,
+            std::mem::size_of::<Position>(),
+            bytes.as_ptr() as *const c_void,
+        )
+    };
+
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+    entity.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 10);
+        assert_eq!(pos.y, 20);
+    });
+}
+
+#[test]
+fn entity_set_untyped_pair() {
+    let world = World::new();
+
+    let pos = Position { x: 10, y: 20 };
+    let pair = (world.component::<Position>(), world.entity());
+    let entity = unsafe {
+        world.entity().set_untyped(
+            pair,
+            std::mem::size_of::<Position>(),
+            &pos as *const _ as *const c_void,
+        )
+    };
+
+    let p = unsafe { &*(entity.get_untyped(pair) as *const Position) };
+    assert_eq!(p.x, 10);
+    assert_eq!(p.y, 20);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "size 4 doesn't match the size of component")]
+fn entity_set_untyped_size_mismatch() {
+    let world = World::new();
+    let position = world.component::<Position>();
+
+    let value = 10i32;
+    unsafe {
+        world
+            .entity()
+            .set_untyped(position, 4, &value as *const _ as *const c_void);
+    }
+}
+
+#[test]
+fn entity_modified_id_on_set() {
+    let world = World::new();
+    let position = world.component::<Position>();
+
+    world.set(Count(0));
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(|e, _| e.world().get::<&mut Count>(|count| count.0 += 1));
+
+    let entity = world.entity().set(Position { x: 1, y: 2 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    let p = entity.get_untyped_mut(position) as *mut Position;
+    unsafe { (*p).x = 10 };
+    entity.modified_id(position);
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+
+    entity.get::<&mut Position>(|pos| pos.y = 20);
+    entity.modified::<Position>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 3));
+}
+
 #[test]
 fn entity_add_role() {
     let world = World::new();
@@ -701,6 +789,58 @@ fn entity_change_name() {
     assert_eq!(entity.name(), "Bar");
 }
 
+#[test]
+fn entity_clear_name() {
+    let world = World::new();
+
+    let entity = world.entity_named("Foo");
+
+    entity.set_name("");
+    assert_eq!(entity.get_name(), None);
+    assert!(world.try_lookup("Foo").is_none());
+}
+
+#[test]
+fn entity_set_scoped_name() {
+    let world = World::new();
+
+    let entity = world.entity_named("Bar");
+    entity.set_name("Parent::Foo");
+
+    assert_eq!(entity.name(), "Foo");
+    assert_eq!(entity.path().unwrap(), "::Parent::Foo");
+    assert_eq!(world.lookup("Parent::Foo"), entity);
+    assert!(world.try_lookup("Bar").is_none());
+
+    entity.set_name("::Foo");
+    assert!(entity.parent().is_none());
+    assert_eq!(world.lookup("Foo"), entity);
+}
+
+#[test]
+fn entity_set_name_conflict() {
+    let world = World::new();
+
+    let parent = world.entity_named("Parent");
+    let foo = world.entity_named("Foo");
+    let bar = world.entity_named("Bar");
+
+    assert!(bar.try_set_name("Foo").is_err());
+    assert_eq!(bar.name(), "Bar");
+    assert_eq!(world.lookup("Foo"), foo);
+
+    // the same name in another scope is fine
+    let child = world.entity().child_of_id(parent);
+    child.try_set_name("Foo").unwrap();
+    assert_eq!(world.lookup("Parent::Foo"), child);
+
+    assert!(bar.try_set_name("Parent::Foo").is_err());
+    assert_eq!(bar.path().unwrap(), "::Bar");
+
+    // renaming an entity to its own name is not a conflict
+    foo.try_set_name("Foo").unwrap();
+}
+
 #[test]
 fn entity_delete() {
     let world = World::new();
@@ -736,6 +876,84 @@ fn entity_clear() {
     assert!(entity2 > entity);
 }
 
+#[test]
+fn entity_clear_w_on_remove() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    world.component::<Velocity>().on_remove(|e, _| {
+        e.world().get::<&mut Count>(|count| {
+            count.0 += 1;
+        });
+    });
+
+    world
+        .observer::<flecs::OnRemove, &Position>()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|count| {
+                count.0 += 1;
+            });
+        });
+
+    let entity = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 });
+
+    entity.clear();
+
+    world.get::<&Count>(|count| {
+        assert_eq!(count.0, 2);
+    });
+    assert!(entity.is_alive());
+    assert_eq!(entity.archetype().count(), 0);
+}
+
+#[test]
+fn entity_clear_w_child_of() {
+    let world = World::new();
+
+    let parent = world.entity().set(Position { x: 1, y: 2 });
+    let child = world
+        .entity()
+        .child_of_id(parent)
+        .set(Position { x: 3, y: 4 });
+    let grandchild = world.entity().child_of_id(child);
+
+    parent.clear();
+    assert!(parent.is_alive());
+    assert!(child.is_alive());
+    assert!(child.has_id((flecs::ChildOf::ID, parent)));
+
+    child.clear();
+    assert!(child.is_alive());
+    assert!(!child.has_id((flecs::ChildOf::ID, parent)));
+    assert!(!child.has::<Position>());
+    assert!(grandchild.is_alive());
+    assert!(grandchild.has_id((flecs::ChildOf::ID, child)));
+}
+
+#[test]
+fn entity_clear_deferred() {
+    let world = World::new();
+
+    let entity = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 });
+
+    world.defer_begin();
+    entity.clear();
+    assert!(entity.has::<Position>());
+    assert!(entity.has::<Velocity>());
+    world.defer_end();
+
+    assert!(entity.is_alive());
+    assert!(!entity.has::<Position>());
+    assert!(!entity.has::<Velocity>());
+}
+
 #[test]
 fn entity_force_owned() {
     let world = World::new();
@@ -834,6 +1052,38 @@ fn entity_get_null_name() {
     assert_eq!(name, None);
 }
 
+#[test]
+fn entity_name_outlives_rename() {
+    let world = World::new();
+
+    let entity = world.entity_named("Foo");
+    let name = entity.name();
+    let get_name = entity.get_name().unwrap();
+
+    // the name storage of flecs is freed by a rename
+    entity.set_name("A much longer name that doesn't fit the old buffer");
+    assert_eq!(name, "Foo");
+    assert_eq!(get_name, "Foo");
+
+    let name = entity.name();
+    entity.destruct();
+    assert_eq!(name, "A much longer name that doesn't fit the old buffer");
+}
+
+#[test]
+fn entity_get_null_symbol() {
+    let world = World::new();
+
+    let entity = world.entity_named("Foo");
+    assert_eq!(entity.symbol(), "");
+
+    let position = world.component::<Position>();
+    let symbol = position.symbol();
+    assert!(!symbol.is_empty());
+    position.destruct();
+    assert!(symbol.ends_with("Position"));
+}
+
 #[test]
 fn entity_get_target() {
     let world = World::new();
@@ -3633,6 +3883,85 @@ fn entity_add_if_pair_w_0_object() {
     assert!(!e.has_id((r, *flecs::Wildcard)));
 }
 
+#[test]
+fn entity_add_if_exclusive_first_false() {
+    let world = World::new();
+
+    world.component::<Likes>().add::<flecs::Exclusive>();
+
+    let e = world.entity();
+    let o_1 = world.entity();
+    let o_2 = world.entity();
+
+    e.add_first::<Likes>(o_1);
+
+    // false removes (Likes, *) even though the target doesn't match
+    e.add_first_if::<Likes>(o_2, false);
+    assert!(!e.has_first::<Likes>(o_1));
+    assert!(!e.has_first::<Likes>(flecs::Wildcard::ID));
+}
+
+#[test]
+fn entity_add_if_enum() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    e.add_enum_if(StandardEnum::Green, true);
+    assert!(e.has_enum(StandardEnum::Green));
+
+    e.add_enum_if(StandardEnum::Blue, true);
+    assert!(!e.has_enum(StandardEnum::Green));
+    assert!(e.has_enum(StandardEnum::Blue));
+
+    e.add_enum_if(StandardEnum::Red, false);
+    assert!(!e.has_enum(StandardEnum::Blue));
+    assert!(!e.has_first::<StandardEnum>(flecs::Wildcard::ID));
+
+    e.add_enum(StandardEnum::Red);
+    e.add_if::<StandardEnum>(false);
+    assert!(!e.has_first::<StandardEnum>(flecs::Wildcard::ID));
+}
+
+#[test]
+fn entity_remove_if() {
+    let world = World::new();
+
+    let e = world.entity().add::<Tag>().add::<Position>();
+
+    e.remove_if::<Tag>(false);
+    assert!(e.has::<Tag>());
+
+    e.remove_if::<Tag>(true);
+    assert!(!e.has::<Tag>());
+
+    let position = world.component_id::<Position>();
+    e.remove_id_if(position, false);
+    assert!(e.has::<Position>());
+
+    e.remove_id_if(position, true);
+    assert!(!e.has::<Position>());
+}
+
+#[test]
+fn entity_set_if() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    e.set_if(false, Position { x: 1, y: 2 });
+    assert!(!e.has::<Position>());
+
+    e.set_if(true, Position { x: 1, y: 2 });
+    assert_eq!(e.get::<&Position>(|p| p.y), 2);
+
+    e.set_if(true, Position { x: 3, y: 4 });
+    assert_eq!(e.get::<&Position>(|p| p.y), 4);
+
+    e.set_if(false, Position { x: 5, y: 6 });
+    assert!(!e.has::<Position>());
+}
+
 #[test]
 fn entity_children_w_custom_relation() {
     let world = World::new();
@@ -3790,6 +4119,29 @@ fn entity_set_alias() {
     assert_eq!(e, world.lookup_recursive("parent_child"));
 }
 
+#[test]
+fn entity_lookup_alias_from_scope() {
+    let world = World::new();
+
+    let transform = world.entity_named("my::module::Transform");
+    transform.set_alias("transform");
+
+    assert_eq!(world.lookup("transform"), transform);
+    assert_eq!(world.try_lookup_recursive("transform"), Some(transform));
+
+    let other = world.entity_named("other::scope");
+    assert_eq!(other.lookup("transform"), transform);
+
+    world.set_scope_id(other);
+    assert_eq!(world.lookup("transform"), transform);
+    world.set_scope_id(0);
+
+    transform.set_alias("");
+    assert!(world.try_lookup("transform").is_none());
+    assert!(other.try_lookup("transform").is_none());
+    assert_eq!(world.lookup("my::module::Transform"), transform);
+}
+
 #[test]
 fn entity_insert_w_observer() {
     let world = World::new();
@@ -3910,3 +4262,36 @@ fn entity_iter_empty_type() {
 
     assert_eq!(count, 0);
 }
+
+#[test]
+fn entity_disable_recursive() {
+    let world = World::new();
+
+    let root = world.entity().set(Position { x: 1, y: 2 });
+    let middle = world
+        .entity()
+        .child_of_id(root)
+        .set(Position { x: 3, y: 4 });
+    let leaf = world
+        .entity()
+        .child_of_id(middle)
+        .set(Position { x: 5, y: 6 });
+
+    let q = world.new_query::<&Position>();
+    assert_eq!(q.count(), 3);
+
+    root.disable_recursive();
+    assert!(!root.is_enabled_self());
+    assert!(!middle.is_enabled_self());
+    assert!(!leaf.is_enabled_self());
+    assert_eq!(q.count(), 0);
+
+    middle.enable_recursive();
+    assert!(!root.is_enabled_self());
+    assert!(middle.is_enabled_self());
+    assert!(leaf.is_enabled_self());
+    assert_eq!(q.count(), 2);
+
+    root.enable_recursive();
+    assert_eq!(q.count(), 3);
+}
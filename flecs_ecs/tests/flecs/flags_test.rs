@@ -0,0 +1,189 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+#[derive(FlagSet, Clone, Copy, Debug, PartialEq)]
+enum UnitFlags {
+    Burning,
+    Stunned,
+    Invisible,
+}
+
+#[derive(FlagSet, Clone, Copy)]
+enum OtherFlags {
+    Selected,
+}
+
+#[test]
+fn flags_set_clear() {
+    let world = World::new();
+
+    let e = world.entity();
+    assert!(!e.has_flag(UnitFlags::Burning));
+    assert!(e.flags::<UnitFlags>().is_empty());
+
+    // clearing a flag doesn't add the component
+    e.set_flag(UnitFlags::Burning, false);
+    assert!(!e.has::<Flags<UnitFlags>>());
+
+    e.set_flag(UnitFlags::Burning, true)
+        .set_flag(UnitFlags::Invisible, true);
+    assert!(e.has_flag(UnitFlags::Burning));
+    assert!(!e.has_flag(UnitFlags::Stunned));
+    assert!(e.has_flag(UnitFlags::Invisible));
+    assert_eq!(e.flags::<UnitFlags>().bits(), 0b101);
+
+    e.set_flag(UnitFlags::Burning, false);
+    assert!(!e.has_flag(UnitFlags::Burning));
+    assert!(e.has_flag(UnitFlags::Invisible));
+    assert!(e.has::<Flags<UnitFlags>>());
+
+    // flag sets are independent components
+    e.set_flag(OtherFlags::Selected, true);
+    assert!(e.has_flag(OtherFlags::Selected));
+    assert_eq!(e.flags::<UnitFlags>().bits(), 0b100);
+}
+
+#[test]
+fn flags_set_deferred() {
+    let world = World::new();
+
+    let e = world.entity().set_flag(UnitFlags::Invisible, true);
+
+    world.defer_begin();
+    e.set_flag(UnitFlags::Burning, true)
+        .set_flag(UnitFlags::Stunned, true)
+        .set_flag(UnitFlags::Invisible, false);
+    world.defer_end();
+
+    assert!(e.has_flag(UnitFlags::Burning));
+    assert!(e.has_flag(UnitFlags::Stunned));
+    assert!(!e.has_flag(UnitFlags::Invisible));
+}
+
+#[test]
+#[should_panic(expected = "while deferred")]
+fn flags_add_deferred_panics() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    world.defer_begin();
+    e.set_flag(UnitFlags::Burning, true);
+}
+
+#[test]
+fn flags_query_1000_entities_single_archetype() {
+    let world = World::new();
+
+    let entities = (0..1000)
+        .map(|i| {
+            let e = world.entity().set(Position { x: i, y: i });
+            if i % 2 == 0 {
+                e.set_flag(UnitFlags::Burning, true);
+            }
+            if i % 3 == 0 {
+                e.set_flag(UnitFlags::Stunned, true);
+            }
+            e.set_flag(UnitFlags::Invisible, true)
+                .set_flag(UnitFlags::Invisible, false);
+            e
+        })
+        .collect::<Vec<_>>();
+
+    let query = world
+        .query::<&Position>()
+        .with::<Flags<UnitFlags>>()
+        .build();
+
+    let mut tables = 0;
+    query.run(|mut it| {
+        while it.next() {
+            tables += 1;
+        }
+    });
+    assert_eq!(tables, 1);
+
+    let mut count = 0;
+    query.with_flag(UnitFlags::Burning).each_entity(|e, pos| {
+        assert_eq!(pos.x % 2, 0);
+        assert!(e.has_flag(UnitFlags::Burning));
+        count += 1;
+    });
+    assert_eq!(count, 500);
+
+    assert_eq!(
+        query
+            .with_flag(UnitFlags::Burning)
+            .with_flag(UnitFlags::Stunned)
+            .count(),
+        167
+    );
+    assert_eq!(query.with_flag(UnitFlags::Invisible).count(), 0);
+
+    for e in &entities {
+        e.set_flag(UnitFlags::Burning, false);
+    }
+    assert_eq!(query.with_flag(UnitFlags::Burning).count(), 0);
+    assert_eq!(query.with_flag(UnitFlags::Stunned).count(), 334);
+}
+
+#[test]
+fn flags_query_without_flags_term() {
+    let world = World::new();
+
+    let burning = world
+        .entity()
+        .set(Position { x: 1, y: 1 })
+        .set(Velocity { x: 2, y: 2 })
+        .set_flag(UnitFlags::Burning, true);
+    world
+        .entity()
+        .set(Position { x: 3, y: 3 })
+        .set_flag(UnitFlags::Stunned, true);
+    world.entity().set(Position { x: 5, y: 5 });
+
+    let query = world.query::<(&Position, Option<&Velocity>)>().build();
+
+    let mut matched = Vec::new();
+    query
+        .with_flag(UnitFlags::Burning)
+        .each_entity(|e, (pos, vel)| {
+            assert_eq!(pos.x, 1);
+            assert_eq!(vel.map(|vel| vel.x), Some(2));
+            matched.push(e.id());
+        });
+    assert_eq!(matched, [burning.id()]);
+
+    assert_eq!(query.with_flag(UnitFlags::Stunned).count(), 1);
+    assert_eq!(query.with_flag(UnitFlags::Invisible).count(), 0);
+
+    // the query itself still matches entities without flags
+    assert_eq!(query.count(), 3);
+}
+
+#[test]
+fn flags_debug() {
+    let mut flags = Flags::<UnitFlags>::new();
+    flags
+        .set(UnitFlags::Burning, true)
+        .set(UnitFlags::Invisible, true);
+    assert_eq!(format!("{:?}", flags), "{Burning, Invisible}");
+}
+
+#[test]
+#[cfg(feature = "flecs_json")]
+fn flags_to_json() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set_flag(UnitFlags::Burning, true)
+        .set_flag(UnitFlags::Invisible, true);
+
+    let flags = e.flags::<UnitFlags>();
+    let json = world.to_json::<Flags<UnitFlags>>(&flags);
+    assert!(json.starts_with("{\"bits\":\""));
+    assert!(json.contains("Burning"));
+    assert!(json.contains("Invisible"));
+    assert!(!json.contains("Stunned"));
+}
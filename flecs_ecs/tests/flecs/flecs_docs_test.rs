@@ -2084,8 +2084,10 @@ fn flecs_docs_observers_compile_test() {
 
     // Monitor observer
     world
-        .observer::<flecs::Monitor, (&Position, &Velocity)>()
-        .each_iter(|it, i, (p, v)| {
+        .observer::<flecs::Monitor, ()>()
+        .with::<Position>()
+        .with::<Velocity>()
+        .each_iter(|it, i, _| {
             if it.event() == flecs::OnAdd::ID {
                 // Entity started matching query
             } else if it.event() == flecs::OnRemove::ID {
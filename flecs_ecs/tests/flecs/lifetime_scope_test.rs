@@ -0,0 +1,167 @@
+#![allow(dead_code)]
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::common_test::*;
+
+#[test]
+fn lifetime_scope_drop_deletes_entities() {
+    let world = World::new();
+
+    let before = world.entity().set(Position { x: 1, y: 2 });
+
+    let scope = world.lifetime_scope();
+    let entities = (0..10)
+        .map(|i| scope.entity().set(Position { x: i, y: i }).id())
+        .collect::<Vec<_>>();
+    let root = scope.root().id();
+
+    let after = world.entity();
+
+    assert!(entities.iter().all(|&e| world.is_alive(e)));
+    assert_eq!(
+        world
+            .query::<()>()
+            .with_first::<OwnedBy>(root)
+            .build()
+            .count(),
+        10
+    );
+
+    drop(scope);
+
+    assert!(entities.iter().all(|&e| !world.is_alive(e)));
+    assert!(!world.is_alive(root));
+    assert!(before.is_alive());
+    assert!(after.is_alive());
+    assert_eq!(world.count::<Position>(), 1);
+}
+
+#[test]
+fn lifetime_scope_owned_by_registered_first() {
+    let world = World::new();
+
+    // registered without the scope, e.g. by a query
+    world.query::<()>().with::<OwnedBy>().build();
+    assert!(world
+        .component::<OwnedBy>()
+        .has_id((flecs::OnDeleteTarget::ID, flecs::Delete::ID)));
+
+    let scope = world.lifetime_scope();
+    let e = scope.entity();
+    drop(scope);
+
+    assert!(!e.is_alive());
+}
+
+#[test]
+fn lifetime_scope_close() {
+    let world = World::new();
+
+    let scope = world.lifetime_scope();
+    let e = scope.entity_named("popup");
+    assert_eq!(world.lookup("popup"), e);
+
+    scope.close();
+
+    assert!(!e.is_alive());
+    assert!(world.try_lookup("popup").is_none());
+}
+
+#[test]
+fn lifetime_scope_keeps_parent() {
+    let world = World::new();
+
+    let parent = world.entity();
+
+    let scope = world.lifetime_scope();
+    let child = scope.entity().child_of_id(parent);
+    assert_eq!(child.parent().unwrap(), parent);
+
+    scope.close();
+
+    assert!(!child.is_alive());
+    assert!(parent.is_alive());
+}
+
+#[test]
+fn lifetime_scope_own_existing_entity() {
+    let world = World::new();
+
+    let first = world.lifetime_scope();
+    let second = world.lifetime_scope();
+
+    let e = first.entity();
+    second.own(e);
+
+    // an entity is owned by a single scope
+    assert!(!e.has_id((world.component_id::<OwnedBy>(), first.root())));
+    assert!(e.has_id((world.component_id::<OwnedBy>(), second.root())));
+
+    first.close();
+    assert!(e.is_alive());
+
+    second.close();
+    assert!(!e.is_alive());
+}
+
+#[test]
+fn lifetime_scope_nested() {
+    let world = World::new();
+
+    let outer = world.lifetime_scope();
+    let outer_entity = outer.entity();
+
+    let inner = outer.lifetime_scope();
+    let inner_entity = inner.entity();
+
+    // closing the inner scope leaves the outer scope intact
+    let closed = outer.lifetime_scope();
+    let closed_entity = closed.entity();
+    closed.close();
+    assert!(!closed_entity.is_alive());
+    assert!(outer_entity.is_alive());
+    assert!(inner_entity.is_alive());
+
+    outer.close();
+    assert!(!outer_entity.is_alive());
+    assert!(!inner_entity.is_alive());
+    assert!(!inner.root().is_alive());
+
+    // dropping a nested scope after its outer scope was closed is a no-op
+    drop(inner);
+}
+
+#[test]
+fn lifetime_scope_nested_cleanup_order() {
+    let world = World::new();
+
+    let removed = Rc::new(RefCell::new(Vec::new()));
+    let log = removed.clone();
+    world
+        .observer::<flecs::OnRemove, &Position>()
+        .each_entity(move |e, _| log.borrow_mut().push(e.id()));
+
+    let outer = world.lifetime_scope();
+    let outer_root = outer.root().id();
+    let outer_entity = outer.entity().set(Position { x: 0, y: 0 }).id();
+
+    let inner = outer.lifetime_scope();
+    let inner_root = inner.root().id();
+    inner.root().set(Position { x: 1, y: 1 });
+    let inner_entity = inner.entity().set(Position { x: 2, y: 2 }).id();
+
+    outer.root().set(Position { x: 3, y: 3 });
+
+    outer.close();
+
+    // owned entities are deleted before their owner, so the entities of the inner scope
+    // go first, then its root, and the root of the outer scope goes last
+    let removed = removed.borrow();
+    let position = |e| removed.iter().position(|&r| r == e).unwrap();
+    assert_eq!(removed.len(), 4);
+    assert!(position(inner_entity) < position(inner_root));
+    assert!(position(outer_entity) < position(outer_root));
+    assert!(position(inner_root) < position(outer_root));
+    assert_eq!(*removed.last().unwrap(), outer_root);
+}
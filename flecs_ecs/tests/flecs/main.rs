@@ -5,24 +5,34 @@
 
 pub mod common_test;
 
+mod alerts_test;
 mod clone_default_impl_test;
 mod component_lifecycle_test;
 mod component_test;
+mod doc_test;
 mod entity_bulk_rust_test;
 mod entity_rust_test;
 mod entity_test;
 mod enum_test;
 mod eq_test;
+mod flags_test;
 mod flecs_docs_test;
 mod is_ref_test;
+mod lifetime_scope_test;
 mod meta_macro_test;
 mod meta_test;
 mod meta_test_rust;
 mod meta_trait_test;
+mod metrics_test;
+mod module_test;
 mod observer_rust_test;
 mod observer_test;
+mod pair_index_test;
 mod query_builder_test;
 mod query_rust_test;
 mod query_test;
+mod script_test;
+mod small_cstr_test;
 mod system_test;
+mod transform_test;
 mod world_test;
@@ -235,10 +235,90 @@ fn meta_struct_w_portable_type() {
     // });
 }
 
-//TODO meta_units -- units addon is not yet implemented in Rust
-//TODO Meta_unit_w_quantity -- units addon is not yet implemented in Rust
-//TODO Meta_unit_w_prefix -- units addon is not yet implemented in Rust
-//TODO Meta_unit_w_over -- units addon is not yet implemented in Rust
+#[test]
+fn meta_units() {
+    use flecs_ecs::addons::units::{length, Units};
+
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+    world.import::<Units>();
+
+    let c = world
+        .component::<Position>()
+        .member_unit_type::<f32, length::Meters>("x")
+        .member_unit_type::<f32, length::Meters>("y");
+
+    let x = c.lookup("x");
+    assert!(x.has::<flecs::meta::Member>());
+    x.get::<&flecs::meta::Member>(|m| {
+        assert_eq!(m.type_, flecs::meta::F32::ID);
+        assert_eq!(m.unit, world.component_id::<length::Meters>());
+    });
+
+    let y = c.lookup("y");
+    y.get::<&flecs::meta::Member>(|m| {
+        assert_eq!(m.type_, flecs::meta::F32::ID);
+        assert_eq!(m.unit, world.component_id::<length::Meters>());
+    });
+}
+
+#[test]
+fn meta_unit_w_quantity() {
+    let world = World::new();
+
+    let l = world.entity();
+    l.quantity_self();
+    let u = world.unit_builder().symbol("u").quantity_id(l).build();
+
+    assert!(l.has::<flecs::meta::Quantity>());
+    assert!(u.has::<flecs::meta::Unit>());
+    assert!(u.has_first::<flecs::meta::Quantity>(l));
+}
+
+#[test]
+fn meta_unit_w_prefix() {
+    let world = World::new();
+
+    let prefix = world.entity();
+    prefix.unit_prefix("P", 10, 3);
+
+    prefix.get::<&flecs::meta::UnitPrefix>(|p| {
+        assert_eq!(unsafe { CStr::from_ptr(p.symbol) }.to_str(), Ok("P"));
+        assert_eq!(p.translation.factor, 10);
+        assert_eq!(p.translation.power, 3);
+    });
+
+    let u = world.unit_builder().symbol("U").build();
+    let u2 = world.unit_builder().prefix_id(prefix).base_id(u).build();
+
+    u2.get::<&flecs::meta::Unit>(|unit| {
+        assert_eq!(unit.prefix, *prefix.id());
+        assert_eq!(unit.base, *u.id());
+        assert_eq!(unit.translation.factor, 10);
+        assert_eq!(unit.translation.power, 3);
+        assert_eq!(unsafe { CStr::from_ptr(unit.symbol) }.to_str(), Ok("PU"));
+    });
+}
+
+#[test]
+fn meta_unit_w_over() {
+    let world = World::new();
+
+    let u1 = world.unit_builder().symbol("U1").build();
+    let u2 = world.unit_builder().symbol("U2").build();
+    let u3 = world.unit_builder().base_id(u1).over_id(u2).build();
+
+    u3.get::<&flecs::meta::Unit>(|unit| {
+        assert_eq!(unit.base, *u1.id());
+        assert_eq!(unit.over, *u2.id());
+        assert_eq!(unsafe { CStr::from_ptr(unit.symbol) }.to_str(), Ok("U1/U2"));
+    });
+}
 
 #[test]
 fn meta_partial_struct() {
@@ -772,6 +852,45 @@ fn meta_error_range() {
     });
 }
 
+#[test]
+fn meta_value_warning_error_range() {
+    let world = World::new();
+
+    #[derive(Component)]
+    struct Temperature {
+        value: f32,
+    }
+
+    let c = world
+        .component::<Temperature>()
+        .member::<f32>("value")
+        .range(-40.0, 60.0)
+        .warning_range(0.0, 35.0)
+        .error_range(-10.0, 45.0);
+
+    let value = c.lookup("value");
+    value.get::<&flecs::meta::MemberRanges>(|ranges| {
+        assert_eq!((ranges.value.min, ranges.value.max), (-40.0, 60.0));
+        assert_eq!((ranges.warning.min, ranges.warning.max), (0.0, 35.0));
+        assert_eq!((ranges.error.min, ranges.error.max), (-10.0, 45.0));
+    });
+
+    c.get::<&flecs::meta::EcsStruct>(|st| {
+        assert_eq!(st.members.count, 1);
+        let member = unsafe { &*(st.members.array as *const flecs_ecs::sys::ecs_member_t) };
+        assert_eq!(member.member, *value.id());
+        assert_eq!((member.range.min, member.range.max), (-40.0, 60.0));
+        assert_eq!(
+            (member.warning_range.min, member.warning_range.max),
+            (0.0, 35.0)
+        );
+        assert_eq!(
+            (member.error_range.min, member.error_range.max),
+            (-10.0, 45.0)
+        );
+    });
+}
+
 #[test]
 fn meta_struct_member_ptr() {
     let world = World::new();
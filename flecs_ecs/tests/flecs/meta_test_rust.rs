@@ -1,3 +1,4 @@
+#![allow(clippy::float_cmp)]
 use core::mem::offset_of;
 use flecs_ecs::prelude::*;
 
@@ -80,3 +81,861 @@ fn test_meta_debug_stringify() {
         r#"{"name":"bob", "components":{"flecs.meta_test_rust.test_meta_debug_stringify.Position":"Position { x: 1.0, y: 2.0 }", "flecs.meta_test_rust.test_meta_debug_stringify.Velocity":"Velocity { x: 3, y: 4 }"}}"#
     );
 }
+
+#[test]
+fn meta_enum_string_member_round_trip() {
+    // stands in for an enum of another crate, which can't derive `Component`
+    #[derive(Debug, PartialEq)]
+    enum Weather {
+        Sunny,
+        Rainy,
+    }
+
+    impl core::fmt::Display for Weather {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{:?}", self)
+        }
+    }
+
+    impl core::str::FromStr for Weather {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, ()> {
+            match s {
+                "Sunny" => Ok(Weather::Sunny),
+                "Rainy" => Ok(Weather::Rainy),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[derive(Component)]
+    struct Forecast {
+        temperature: f32,
+        weather: Weather,
+    }
+
+    let world = World::new();
+
+    let weather = register_enum_string::<Weather>(&world);
+    world
+        .component::<Forecast>()
+        .member::<f32>(("temperature", Count(1), offset_of!(Forecast, temperature)))
+        .member_id(
+            weather,
+            ("weather", Count(1), offset_of!(Forecast, weather)),
+        );
+
+    let e = world.entity().set(Forecast {
+        temperature: 21.0,
+        weather: Weather::Rainy,
+    });
+
+    let json = e.get::<&Forecast>(|forecast| world.to_json::<Forecast>(forecast));
+    assert_eq!(json, r#"{"temperature":21, "weather":"Rainy"}"#);
+    assert!(e.to_json(None).contains(r#""weather":"Rainy""#));
+
+    let other = world
+        .entity()
+        .set(Forecast {
+            temperature: 0.0,
+            weather: Weather::Sunny,
+        })
+        .set_json::<Forecast>(&json, None);
+
+    other.get::<&Forecast>(|forecast| {
+        assert!((forecast.temperature - 21.0).abs() < f32::EPSILON);
+        assert_eq!(forecast.weather, Weather::Rainy);
+    });
+
+    // strings that don't parse leave the value unchanged
+    other.set_json::<Forecast>(r#"{"weather":"Snowy"}"#, None);
+    other.get::<&Forecast>(|forecast| {
+        assert_eq!(forecast.weather, Weather::Rainy);
+    });
+}
+
+#[test]
+fn meta_table_layout_padding() {
+    #[derive(Component)]
+    #[repr(C)]
+    struct Padded {
+        a: u8,
+        b: u64,
+        c: u8,
+    }
+
+    #[derive(Component)]
+    struct Packed {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+
+    world
+        .component::<Padded>()
+        .member::<u8>(("a", Count(1), offset_of!(Padded, a)))
+        .member::<u64>(("b", Count(1), offset_of!(Padded, b)))
+        .member::<u8>(("c", Count(1), offset_of!(Padded, c)));
+
+    world
+        .component::<Packed>()
+        .member::<f32>(("x", Count(1), offset_of!(Packed, x)))
+        .member::<f32>(("y", Count(1), offset_of!(Packed, y)));
+
+    let e = world
+        .entity()
+        .set(Padded { a: 1, b: 2, c: 3 })
+        .set(Packed { x: 1.0, y: 2.0 });
+
+    let report = e.table().unwrap().layout_report();
+    assert_eq!(report.len(), 2);
+
+    let padded = report
+        .iter()
+        .find(|c| c.id == world.component_id::<Padded>())
+        .unwrap();
+    assert_eq!(padded.size, 24);
+    assert_eq!(padded.alignment, 8);
+    assert_eq!(padded.padding, Some(14));
+    assert_eq!(padded.padding_to_next, Some(7));
+
+    let packed = report
+        .iter()
+        .find(|c| c.id == world.component_id::<Packed>())
+        .unwrap();
+    assert_eq!(packed.padding, Some(0));
+    assert_eq!(packed.padding_to_next, Some(0));
+
+    let warnings = world.layout_warnings();
+    let warning = warnings
+        .iter()
+        .find(|w| w.id == world.component_id::<Padded>())
+        .unwrap();
+    assert!(warning.name.ends_with("Padded"));
+    assert_eq!(warning.padding, Some(14));
+    assert!(!warnings
+        .iter()
+        .any(|w| w.id == world.component_id::<Packed>()));
+}
+
+#[test]
+fn meta_layout_unknown_without_reflection() {
+    #[derive(Component)]
+    struct Unreflected {
+        x: f32,
+        y: u8,
+    }
+
+    let world = World::new();
+
+    let e = world.entity().set(Unreflected { x: 1.0, y: 2 });
+
+    let report = e.table().unwrap().layout_report();
+    assert_eq!(report[0].id, world.component_id::<Unreflected>());
+    assert_eq!(report[0].padding, None);
+    assert_eq!(report[0].padding_to_next, None);
+
+    let warnings = world.layout_warnings();
+    let warning = warnings
+        .iter()
+        .find(|w| w.id == world.component_id::<Unreflected>())
+        .unwrap();
+    assert_eq!(warning.size, 8);
+    assert_eq!(warning.padding, None);
+}
+
+#[test]
+fn meta_derive_struct() {
+    let world = World::new();
+
+    #[derive(Component)]
+    #[meta]
+    struct Test {
+        a: i32,
+        b: f32,
+    }
+
+    let c = world.component::<Test>().meta();
+
+    let a = c.lookup("a");
+    a.get::<&flecs::meta::Member>(|mem| {
+        assert_eq!(mem.type_, flecs::meta::I32);
+        assert_eq!(mem.count, 1);
+    });
+
+    let b = c.lookup("b");
+    b.get::<&flecs::meta::Member>(|mem| {
+        assert_eq!(mem.type_, flecs::meta::F32);
+    });
+
+    let e = world.entity().set(Test { a: 10, b: 0.5 });
+    e.get::<&Test>(|t| {
+        assert_eq!(world.to_expr(t), "{a: 10, b: 0.5}");
+    });
+}
+
+#[test]
+fn meta_derive_nested_struct() {
+    let world = World::new();
+
+    #[derive(Component)]
+    #[meta]
+    struct Test {
+        x: i32,
+    }
+
+    #[derive(Component)]
+    #[meta]
+    struct Nested {
+        a: Test,
+    }
+
+    let t = world.component::<Test>().meta();
+    let n = world.component::<Nested>().meta();
+
+    let a = n.lookup("a");
+    assert!(a.has::<flecs::meta::Member>());
+    a.get::<&flecs::meta::Member>(|mem| {
+        assert_eq!(mem.type_, t.id());
+    });
+
+    let e = world.entity().set(Nested { a: Test { x: 10 } });
+    e.get::<&Nested>(|n| {
+        assert_eq!(world.to_expr(n), "{a: {x: 10}}");
+    });
+}
+
+#[test]
+fn meta_derive_array_member() {
+    let world = World::new();
+
+    const LEN: usize = 2;
+
+    #[derive(Component)]
+    #[meta]
+    struct Test {
+        a: [i32; 3],
+        b: [f32; LEN],
+        c: i8,
+    }
+
+    let c = world.component::<Test>().meta();
+
+    c.lookup("a").get::<&flecs::meta::Member>(|mem| {
+        assert_eq!(mem.type_, flecs::meta::I32);
+        assert_eq!(mem.count, 3);
+        assert_eq!(mem.offset, offset_of!(Test, a) as i32);
+    });
+
+    c.lookup("b").get::<&flecs::meta::Member>(|mem| {
+        assert_eq!(mem.type_, flecs::meta::F32);
+        assert_eq!(mem.count, 2);
+        assert_eq!(mem.offset, offset_of!(Test, b) as i32);
+    });
+
+    let e = world.entity().set(Test {
+        a: [1, 2, 3],
+        b: [0.5, 1.5],
+        c: 4,
+    });
+    e.get::<&Test>(|t| {
+        assert_eq!(world.to_expr(t), "{a: [1, 2, 3], b: [0.5, 1.5], c: 4}");
+    });
+}
+
+#[test]
+fn meta_std_vec_round_trip_int() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Scores {
+        values: Vec<i32>,
+    }
+
+    world.component::<Scores>().meta();
+
+    let e = world.entity().set(Scores {
+        values: vec![1, 2, 3],
+    });
+
+    let json = e.get::<&Scores>(|scores| world.to_json::<Scores>(scores));
+    assert_eq!(json, "{\"values\":[1, 2, 3]}");
+
+    let mut scores = Scores {
+        values: vec![9, 9, 9, 9, 9],
+    };
+    world.from_json::<Scores>(&mut scores, &json, None);
+    assert_eq!(scores.values, vec![1, 2, 3]);
+
+    let e2 = world
+        .entity()
+        .set(Scores::default())
+        .set_json::<Scores>(&json, None);
+    e2.get::<&Scores>(|scores| assert_eq!(scores.values, vec![1, 2, 3]));
+}
+
+#[test]
+fn meta_std_vec_round_trip_struct() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Path {
+        name: String,
+        points: Vec<Position>,
+    }
+
+    world.component::<Position>().meta();
+    let id = id!(&world, Vec<Position>);
+    world
+        .component_ext::<Vec<Position>>(id)
+        .opaque_func_id::<_, Position>(id, std_vec_support::<Position>);
+    world.component::<Path>().meta();
+
+    let path = Path {
+        name: "patrol".to_string(),
+        points: vec![Position { x: 1.0, y: 2.0 }, Position { x: 3.0, y: 4.0 }],
+    };
+    let e = world.entity().set(path);
+
+    let json = e.get::<&Path>(|path| world.to_json::<Path>(path));
+    assert_eq!(
+        json,
+        "{\"name\":\"patrol\", \"points\":[{\"x\":1, \"y\":2}, {\"x\":3, \"y\":4}]}"
+    );
+
+    let e2 = world
+        .entity()
+        .set(Path::default())
+        .set_json::<Path>(&json, None);
+    e2.get::<&Path>(|copy| {
+        e.get::<&Path>(|path| assert_eq!(copy, path));
+    });
+}
+
+#[test]
+fn meta_option_round_trip() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Item {
+        count: Option<i32>,
+        label: Option<String>,
+    }
+
+    world.component::<Item>().meta();
+
+    let item = Item {
+        count: Some(3),
+        label: None,
+    };
+    let json = world.to_json::<Item>(&item);
+    assert_eq!(json, "{\"count\":3, \"label\":null}");
+
+    let mut parsed = Item {
+        count: None,
+        label: Some("old".to_string()),
+    };
+    world.from_json::<Item>(&mut parsed, &json, None);
+    assert_eq!(parsed, item);
+
+    let json = "{\"count\":null, \"label\":\"sword\"}";
+    world.from_json::<Item>(&mut parsed, json, None);
+    assert_eq!(
+        parsed,
+        Item {
+            count: None,
+            label: Some("sword".to_string()),
+        }
+    );
+    assert_eq!(world.to_json::<Item>(&parsed), json);
+}
+
+#[test]
+fn meta_hash_map_round_trip() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Inventory {
+        items: std::collections::HashMap<String, i32>,
+        names: std::collections::HashMap<String, String>,
+    }
+
+    world.component::<Inventory>().meta();
+
+    let mut inventory = Inventory::default();
+    assert_eq!(
+        world.to_json::<Inventory>(&inventory),
+        "{\"items\":[], \"names\":[]}"
+    );
+
+    inventory.items.insert("potion".to_string(), 2);
+    inventory.items.insert("weapons::sword".to_string(), 1);
+    inventory
+        .names
+        .insert("weapons.bow".to_string(), "Longbow".to_string());
+    let json = world.to_json::<Inventory>(&inventory);
+    assert_eq!(
+        json,
+        "{\"items\":[{\"key\":\"potion\", \"value\":2}, {\"key\":\"weapons::sword\", \"value\":1}], \"names\":[{\"key\":\"weapons.bow\", \"value\":\"Longbow\"}]}"
+    );
+
+    // existing entries are replaced
+    let mut parsed = Inventory::default();
+    parsed.items.insert("shield".to_string(), 5);
+    world.from_json::<Inventory>(&mut parsed, &json, None);
+    assert_eq!(parsed, inventory);
+
+    // an empty list clears the map
+    world.from_json::<Inventory>(&mut parsed, "{\"items\":[], \"names\":[]}", None);
+    assert_eq!(parsed, Inventory::default());
+}
+
+#[test]
+fn meta_hash_map_nested() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Stock {
+        shops: std::collections::HashMap<String, std::collections::HashMap<String, u32>>,
+    }
+
+    world.component::<Stock>().meta();
+
+    let mut stock = Stock::default();
+    stock.shops.insert(
+        "north".to_string(),
+        [("apple".to_string(), 3), ("pear".to_string(), 0)].into(),
+    );
+    stock.shops.insert("south".to_string(), Default::default());
+
+    let json = world.to_json::<Stock>(&stock);
+    assert_eq!(
+        json,
+        "{\"shops\":[{\"key\":\"north\", \"value\":[{\"key\":\"apple\", \"value\":3}, {\"key\":\"pear\", \"value\":0}]}, {\"key\":\"south\", \"value\":[]}]}"
+    );
+
+    let mut parsed = Stock::default();
+    world.from_json::<Stock>(&mut parsed, &json, None);
+    assert_eq!(parsed, stock);
+}
+
+#[test]
+fn meta_enum_explicit_discriminants() {
+    let world = World::new();
+
+    #[derive(Component, Debug, PartialEq, Clone, Copy)]
+    #[repr(i32)]
+    enum Color {
+        Red = 1,
+        Green = 5,
+        Blue = -3,
+    }
+
+    #[derive(Component, Debug, PartialEq, Clone, Copy)]
+    #[repr(u32)]
+    enum Level {
+        Low = 7,
+        High = 2_000_000_000,
+    }
+
+    #[derive(Component, Debug, PartialEq)]
+    #[meta]
+    struct Paint {
+        color: Color,
+        level: Level,
+    }
+
+    world.component::<Paint>().meta();
+
+    // as a component value inside a struct member
+    let paint = Paint {
+        color: Color::Green,
+        level: Level::High,
+    };
+    let json = world.to_json::<Paint>(&paint);
+    assert_eq!(json, "{\"color\":\"Green\", \"level\":\"High\"}");
+    assert_eq!(
+        world.to_expr(&Paint {
+            color: Color::Blue,
+            level: Level::Low,
+        }),
+        "{color: Blue, level: Low}"
+    );
+
+    let mut parsed = Paint {
+        color: Color::Red,
+        level: Level::Low,
+    };
+    world.from_json::<Paint>(&mut parsed, &json, None);
+    assert_eq!(parsed, paint);
+
+    // as a pair constant
+    let e = world.entity_named("e").add_enum(Color::Blue);
+    assert_eq!(
+        e.to_json(None),
+        "{\"name\":\"e\", \"pairs\":{\"flecs.meta_test_rust.meta_enum_explicit_discriminants.Color\":\"flecs.meta_test_rust.meta_enum_explicit_discriminants.Color.Blue\"}}"
+    );
+}
+
+#[test]
+fn meta_enum_discriminant_out_of_range() {
+    let world = World::new();
+
+    #[derive(Component, Debug, PartialEq, Clone, Copy)]
+    #[repr(u32)]
+    enum Level {
+        Low = 7,
+        High = 4_000_000_000,
+    }
+
+    let level = world.component::<Level>();
+
+    // only the constant that fits in an i32 is a constant of the reflection type
+    let low = level.lookup("Low");
+    let high = level.lookup("High");
+    assert!(low.has_first::<flecs::meta::Constant>(world.component_id::<i32>()));
+    assert!(!high.has_first::<flecs::meta::Constant>(world.component_id::<i32>()));
+    assert_eq!(world.to_json::<Level>(&Level::Low), "\"Low\"");
+
+    // the variant can still be added as a pair
+    let e = world.entity().add_enum(Level::High);
+    assert!(e.has_enum(Level::High));
+    assert!(!e.has_enum(Level::Low));
+}
+
+#[test]
+fn meta_bitmask_runtime_type() {
+    let world = World::new();
+
+    let layers = world.component_untyped_named("CollisionLayers").bitmask();
+    layers
+        .constant("Player", 1)
+        .constant("Enemy", 2)
+        .constant("Terrain", 4);
+
+    let to_json = |value: u32| world.to_json_id(layers.id(), &value as *const u32 as *const _);
+    let from_json = |json: &str| {
+        let mut value: u32 = 0xff;
+        world.from_json_id(layers.id(), &mut value as *mut u32 as *mut _, json, None);
+        value
+    };
+
+    assert_eq!(to_json(0), "0");
+    assert_eq!(to_json(2), "\"Enemy\"");
+    assert_eq!(to_json(5), "\"Terrain|Player\"");
+
+    assert_eq!(from_json("0"), 0);
+    assert_eq!(from_json("\"Enemy\""), 2);
+    assert_eq!(from_json("\"Player|Terrain\""), 5);
+
+    let mut value: u32 = 0;
+    let mut cursor = world.cursor_id(layers.id(), &mut value as *mut u32 as *mut _);
+    cursor.set_string("Enemy|Player").unwrap();
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn meta_bitmask_typed_member() {
+    let world = World::new();
+
+    #[derive(Component, Clone, Copy, Default, Debug, PartialEq)]
+    #[repr(transparent)]
+    struct Layers(u32);
+
+    impl Layers {
+        const PLAYER: u32 = 1 << 0;
+        const ENEMY: u32 = 1 << 1;
+    }
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Collider {
+        layers: Layers,
+    }
+
+    world
+        .component::<Layers>()
+        .bitmask()
+        .constant("Player", Layers::PLAYER as i32)
+        .constant("Enemy", Layers::ENEMY as i32);
+    world.component::<Collider>().meta();
+
+    let collider = Collider {
+        layers: Layers(Layers::PLAYER | Layers::ENEMY),
+    };
+    let json = world.to_json::<Collider>(&collider);
+    assert_eq!(json, "{\"layers\":\"Enemy|Player\"}");
+
+    let mut parsed = Collider::default();
+    world.from_json::<Collider>(&mut parsed, &json, None);
+    assert_eq!(parsed, collider);
+}
+
+#[test]
+fn meta_cursor_set_get_typed() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Named {
+        name: String,
+        values: [i32; 2],
+    }
+
+    world.component::<Position>().meta();
+    world.component::<Named>().meta();
+
+    let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    let mut cur = e.cursor::<Position>();
+    cur.push().unwrap();
+    cur.member("y").unwrap();
+    cur.set_float(5.0).unwrap();
+    assert_eq!(cur.get_float(), Ok(5.0));
+    assert_eq!(
+        cur.member("z"),
+        Err(CursorError::UnknownMember("z".to_string()))
+    );
+    // a failed move leaves the cursor on the previous member
+    assert_eq!(cur.get_member(), "y");
+    assert_eq!(cur.get_entity().err(), Some(CursorError::TypeMismatch));
+    assert_eq!(cur.get_string(), Err(CursorError::TypeMismatch));
+    assert_eq!(cur.set_string("abc"), Err(CursorError::TypeMismatch));
+    assert_eq!(cur.elem(0), Err(CursorError::InvalidScope));
+    assert_eq!(cur.push(), Err(CursorError::InvalidScope));
+    assert_eq!(cur.next(), Err(CursorError::OutOfBounds));
+    cur.pop().unwrap();
+    assert_eq!(cur.pop(), Err(CursorError::InvalidScope));
+    e.modified::<Position>();
+
+    e.get::<&Position>(|pos| {
+        assert_eq!(pos, &Position { x: 1.0, y: 5.0 });
+    });
+
+    let mut named = Named {
+        name: "hello".to_string(),
+        values: [1, 2],
+    };
+    let mut cur = world.cursor(&mut named);
+    cur.push().unwrap();
+    assert_eq!(cur.get_string(), Ok("hello".to_string()));
+    cur.member("values").unwrap();
+    cur.push().unwrap();
+    cur.elem(1).unwrap();
+    assert_eq!(cur.get_int(), Ok(2));
+    cur.set_int(7).unwrap();
+    assert_eq!(cur.elem(2), Err(CursorError::OutOfBounds));
+    assert_eq!(cur.get_int(), Ok(7));
+    cur.pop().unwrap();
+    cur.pop().unwrap();
+    assert_eq!(named.values, [1, 7]);
+}
+
+#[test]
+fn meta_from_expr_round_trip() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    world.component::<Position>().meta();
+
+    let pos = Position { x: 10.0, y: 20.0 };
+    let expr = world.to_expr(&pos);
+    assert_eq!(world.from_expr::<Position>(&expr), Ok(pos));
+
+    let mut value = Position { x: 1.0, y: 2.0 };
+    world
+        .from_expr_id(
+            world.component_id::<Position>(),
+            &mut value as *mut Position as *mut _,
+            "{y: 5}",
+        )
+        .unwrap();
+    assert_eq!(value, Position { x: 1.0, y: 5.0 });
+
+    let err = world.from_expr::<Position>("{x: 10, z: 20}").unwrap_err();
+    assert!(err.message().contains("z"), "{}", err);
+    assert!(world.from_expr::<Position>("{x: 10").is_err());
+}
+
+#[test]
+fn meta_runtime_component_layout() {
+    let world = World::new();
+
+    let health = world
+        .component_untyped_named("Health")
+        .layout(8, 4)
+        .member_id(flecs::meta::F32::ID, "current")
+        .member_id(flecs::meta::F32::ID, "max");
+
+    let component = health.get::<&flecs::Component>(|c| (c.size, c.alignment));
+    assert_eq!(component, (8, 4));
+
+    let bytes = [50.0f32.to_ne_bytes(), 100.0f32.to_ne_bytes()].concat();
+    let e = world.entity_named("player").set_bytes(health, &bytes);
+
+    let ptr = e.get_untyped(health) as *const [f32; 2];
+    assert_eq!(unsafe { *ptr }, [50.0, 100.0]);
+
+    assert_eq!(
+        world.to_json_id(health, ptr as *const _),
+        "{\"current\":50, \"max\":100}"
+    );
+    assert_eq!(
+        e.to_json(None),
+        "{\"name\":\"player\", \"components\":{\"Health\":{\"current\":50, \"max\":100}}}"
+    );
+
+    // add the component and write it in place
+    let set_count = std::rc::Rc::new(std::cell::Cell::new(0));
+    let observer_count = set_count.clone();
+    world
+        .observer::<flecs::OnSet, ()>()
+        .with_id(health)
+        .each_entity(move |_, _| observer_count.set(observer_count.get() + 1));
+
+    let e2 = world.entity_named("enemy").add_id(health);
+    assert_eq!(
+        unsafe { *(e2.get_untyped(health) as *const [f32; 2]) },
+        [0.0, 0.0]
+    );
+    unsafe { *(e2.get_untyped_mut(health) as *mut [f32; 2]) = [10.0, 20.0] };
+    e2.modified_id(health);
+    assert_eq!(set_count.get(), 1);
+
+    let query = world.query::<()>().expr("Health").build();
+    let mut found = Vec::new();
+    query.each_iter(|it, i, _| {
+        let value = unsafe { *(it.field_at_untyped(0, i as i32) as *const [f32; 2]) };
+        found.push((it.entity(i).name(), value));
+    });
+    assert_eq!(
+        found,
+        vec![
+            ("player".to_string(), [50.0, 100.0]),
+            ("enemy".to_string(), [10.0, 20.0])
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "can't set 4 bytes for component Health with size 8")]
+fn meta_runtime_component_set_bytes_wrong_size() {
+    let world = World::new();
+
+    let health = world.component_untyped_named("Health").layout(8, 4);
+    world.entity().set_bytes(health, &1.0f32.to_ne_bytes());
+}
+
+#[test]
+fn meta_untyped_nested_struct() {
+    let world = World::new();
+
+    world.component_untyped_named("Test").layout(4, 4);
+    world.component_untyped_named("Nested").layout(4, 4);
+
+    let t = world
+        .component_from(world.lookup("Test"))
+        .member_id(flecs::meta::I32::ID, "x");
+    let n = world
+        .component_from(world.lookup("Nested"))
+        .member_id(t, "a");
+
+    assert_eq!(n.size(), 4);
+    assert_eq!(n.alignment(), 4);
+
+    let a = n.lookup("a");
+    assert!(a.has::<flecs::meta::Member>());
+    a.get::<&flecs::meta::Member>(|mem| {
+        assert_eq!(mem.type_, t.id());
+    });
+
+    let e = world.entity().set_bytes(n, &10i32.to_ne_bytes());
+    assert_eq!(world.to_json_id(n, e.get_untyped(n)), "{\"a\":{\"x\":10}}");
+}
+
+#[test]
+fn meta_untyped_enum_bitmask_array() {
+    let world = World::new();
+
+    world.component_untyped_named("Color").layout(4, 4);
+    world.component_untyped_named("Layers").layout(4, 4);
+    world.component_untyped_named("Vec3").layout(12, 4);
+
+    let color = world.component_from(world.lookup("Color"));
+    color.constant("Red", 0).constant("Blue", 1);
+    let layers = world.component_from(world.lookup("Layers")).bitmask();
+    layers.constant("Player", 1).constant("Enemy", 2);
+    let vec3 = world
+        .component_from(world.lookup("Vec3"))
+        .array_id(flecs::meta::F32::ID, 3);
+
+    assert!(color.has::<flecs::meta::EcsEnum>());
+    assert!(layers.has::<flecs::meta::Bitmask>());
+    assert_eq!(vec3.size(), 12);
+
+    let to_json = |id: Entity, bytes: &[u8]| world.to_json_id(id, bytes.as_ptr() as *const _);
+    assert_eq!(to_json(color.id(), &1i32.to_ne_bytes()), "\"Blue\"");
+    assert_eq!(
+        to_json(layers.id(), &3u32.to_ne_bytes()),
+        "\"Enemy|Player\""
+    );
+
+    let value = [1.0f32, 2.0, 3.0];
+    assert_eq!(
+        world.to_json_id(vec3, value.as_ptr() as *const _),
+        "[1, 2, 3]"
+    );
+}
+
+#[test]
+fn meta_untyped_traits() {
+    let world = World::new();
+
+    world.component_untyped_named("Health").layout(8, 4);
+    let health = world
+        .component_from(world.lookup("Health"))
+        .add_trait_id(flecs::CanToggle::ID)
+        .on_delete(flecs::Delete);
+
+    assert!(health.has::<flecs::CanToggle>());
+    assert!(health.has_id((flecs::OnDelete::ID, flecs::Delete::ID)));
+
+    let e = world.entity().add_id(health);
+    health.destruct();
+    assert!(!e.is_alive());
+}
+
+#[test]
+#[should_panic(expected = "is not a component")]
+fn meta_component_from_not_a_component() {
+    let world = World::new();
+
+    let e = world.entity_named("Health");
+    world.component_from(e);
+}
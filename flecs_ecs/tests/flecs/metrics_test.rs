@@ -0,0 +1,79 @@
+#![cfg(feature = "flecs_metrics")]
+#![allow(clippy::float_cmp)]
+
+use flecs_ecs::addons::metrics::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn metrics_gauge_member() {
+    let world = World::new();
+    world.import::<MetricsModule>();
+    world.component::<Position>().meta();
+
+    let metric = world
+        .metric(world.entity_named("metrics::position_x"))
+        .member::<Position>("x")
+        .kind::<Gauge>()
+        .brief("x of position")
+        .build();
+
+    let e1 = world.entity_named("e1").set(Position { x: 10.0, y: 20.0 });
+    let e2 = world.entity_named("e2").set(Position { x: 30.0, y: 40.0 });
+
+    // metrics are updated every second of world time
+    world.progress_time(1.0);
+
+    let mut values = Vec::new();
+    metric.each_metric_instance(|instance, source, value| {
+        assert!(instance.has_id(*MetricInstance));
+        values.push((source.id(), value));
+    });
+    values.sort_by_key(|(source, _)| *source);
+    assert_eq!(values, vec![(e1.id(), 10.0), (e2.id(), 30.0)]);
+
+    let mut count = 0;
+    world
+        .query::<()>()
+        .with_id(*MetricInstance)
+        .build()
+        .each(|_| count += 1);
+    assert_eq!(count, 2);
+
+    e1.set(Position { x: 15.0, y: 20.0 });
+    world.progress_time(1.0);
+
+    metric.each_metric_instance(|_, source, value| {
+        if source == e1 {
+            assert_eq!(value, 15.0);
+        }
+    });
+}
+
+#[test]
+fn metrics_counter_increment_member() {
+    let world = World::new();
+    world.import::<MetricsModule>();
+    world.component::<Position>().meta();
+
+    let metric = world
+        .metric(world.entity_named("metrics::distance"))
+        .member::<Position>("x")
+        .kind::<CounterIncrement>()
+        .build();
+
+    world.entity().set(Position { x: 10.0, y: 20.0 });
+
+    world.progress_time(1.0);
+    world.progress_time(1.0);
+
+    let mut values = Vec::new();
+    metric.each_metric_instance(|_, _, value| values.push(value));
+    assert_eq!(values, vec![20.0]);
+}
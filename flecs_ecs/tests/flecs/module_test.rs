@@ -0,0 +1,122 @@
+#![cfg(feature = "flecs_module")]
+
+use flecs_ecs::prelude::*;
+
+mod physics {
+    use flecs_ecs::prelude::*;
+
+    #[derive(Component)]
+    pub struct Physics;
+
+    #[derive(Component)]
+    pub struct Collider {
+        pub radius: f32,
+    }
+
+    #[derive(Component)]
+    pub struct Gravity;
+
+    impl Module for Physics {
+        fn module(world: &World) {
+            world.import::<super::math::Math>();
+            world.component::<Collider>();
+            world.component::<Gravity>();
+        }
+    }
+}
+
+mod math {
+    use flecs_ecs::prelude::*;
+
+    #[derive(Component)]
+    pub struct Math;
+
+    #[derive(Component)]
+    pub struct Vec2 {
+        pub x: f32,
+        pub y: f32,
+    }
+
+    impl Module for Math {
+        fn module(world: &World) {
+            world.component::<Vec2>();
+        }
+    }
+}
+
+const PHYSICS: &str = "flecs::module_test::physics::Physics";
+const MATH: &str = "flecs::module_test::math::Math";
+
+#[test]
+fn module_components_in_scope() {
+    let world = World::new();
+    let module = world.import::<physics::Physics>();
+
+    assert!(module.has::<flecs::Module>());
+    assert_eq!(module.path().unwrap(), format!("::{PHYSICS}"));
+
+    let collider = world.component::<physics::Collider>();
+    assert_eq!(collider.path().unwrap(), format!("::{PHYSICS}::Collider"));
+    assert_eq!(collider.parent().unwrap(), module);
+    assert_eq!(
+        world.lookup(&format!("{PHYSICS}::Collider")),
+        collider.entity()
+    );
+
+    // the symbol keeps the full Rust type path
+    assert_eq!(collider.symbol(), "flecs::module_test::physics::Collider");
+}
+
+#[test]
+fn module_nested_import() {
+    let world = World::new();
+    world.import::<physics::Physics>();
+
+    // modules imported by a module are not created in its scope
+    let math = world.lookup(MATH);
+    assert!(math.has::<flecs::Module>());
+    assert_eq!(
+        world.component::<math::Vec2>().path().unwrap(),
+        format!("::{MATH}::Vec2")
+    );
+}
+
+#[test]
+fn module_import_twice() {
+    let world = World::new();
+    let module = world.import::<physics::Physics>();
+    let collider = world.component_id::<physics::Collider>();
+
+    let count_children = || {
+        let mut count = 0;
+        module.each_child(|_| count += 1);
+        count
+    };
+    let children = count_children();
+    assert_eq!(children, 2);
+
+    assert_eq!(world.import::<physics::Physics>(), module);
+    assert_eq!(world.component_id::<physics::Collider>(), collider);
+    assert_eq!(count_children(), children);
+}
+
+#[test]
+fn module_component_outside_namespace() {
+    #[derive(Component)]
+    struct Local;
+
+    impl Module for Local {
+        fn module(world: &World) {
+            world.component::<math::Vec2>();
+        }
+    }
+
+    let world = World::new();
+    let module = world.import::<Local>();
+
+    // only the type name is kept for components of another namespace
+    assert_eq!(
+        world.component::<math::Vec2>().path().unwrap(),
+        format!("{}::Vec2", module.path().unwrap())
+    );
+}
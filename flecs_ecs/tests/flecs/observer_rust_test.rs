@@ -1,6 +1,10 @@
 #![allow(dead_code)]
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
 use flecs_ecs::core::*;
+use flecs_ecs::macros::Component;
 
 use crate::common_test::*;
 
@@ -53,3 +57,497 @@ fn observer_panic_on_add_4() {
 
     world.entity().set(Position { x: 10, y: 20 });
 }
+
+/// Counts how often it is dropped, to check that captured state of callbacks is dropped once.
+struct DropCounter(Arc<AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Component)]
+struct Clicked;
+
+#[derive(Component)]
+struct Resized {
+    width: u32,
+}
+
+#[test]
+fn observer_entity_observe_drops_captures_on_world_fini() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let shared = Arc::new(0);
+
+    {
+        let world = World::new();
+        let widget = world.entity();
+
+        let counter = DropCounter(drops.clone());
+        let captured = shared.clone();
+        widget.observe::<Clicked>(move || {
+            let _ = (&counter, &captured);
+        });
+
+        let counter = DropCounter(drops.clone());
+        widget.observe_entity::<Clicked>(move |_| {
+            let _ = &counter;
+        });
+
+        let counter = DropCounter(drops.clone());
+        let captured = shared.clone();
+        widget.observe_payload::<Resized>(move |r| {
+            let _ = (&counter, &captured, r.width);
+        });
+
+        let counter = DropCounter(drops.clone());
+        widget.observe_payload_entity::<Resized>(move |_, r| {
+            let _ = (&counter, r.width);
+        });
+
+        widget.emit(&Clicked);
+        widget.emit(&Resized { width: 10 });
+
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+        assert_eq!(Arc::strong_count(&shared), 3);
+    }
+
+    assert_eq!(drops.load(Ordering::SeqCst), 4);
+    assert_eq!(Arc::strong_count(&shared), 1);
+}
+
+#[test]
+fn observer_entity_observe_drops_captures_on_entity_destruct() {
+    let world = World::new();
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    let widget = world.entity();
+    let counter = DropCounter(drops.clone());
+    widget.observe_payload::<Resized>(move |_| {
+        let _ = &counter;
+    });
+
+    widget.destruct();
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+    drop(world);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn observer_each_drops_captures_on_destruct() {
+    let world = World::new();
+    let drops = Arc::new(AtomicUsize::new(0));
+    let shared = Arc::new(0);
+
+    let counter = DropCounter(drops.clone());
+    let captured = shared.clone();
+    let observer = world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(move |_, _| {
+            let _ = (&counter, &captured);
+        });
+
+    world.entity().set(Position { x: 10, y: 20 });
+    assert_eq!(Arc::strong_count(&shared), 2);
+
+    observer.destruct();
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+    assert_eq!(Arc::strong_count(&shared), 1);
+
+    drop(world);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn observer_disable_enable() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    let observer = world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+
+    let e = world.entity().set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    observer.disable();
+    assert!(observer.has::<flecs::Disabled>());
+    e.set(Position { x: 20, y: 30 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    observer.enable();
+    assert!(!observer.has::<flecs::Disabled>());
+    e.set(Position { x: 30, y: 40 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+}
+
+#[test]
+fn observer_entity_observe_disable_and_destruct() {
+    let world = World::new();
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    world.set(Count(0));
+
+    let widget = world.entity_named("widget");
+    let counter = DropCounter(drops.clone());
+    let observer = widget.observe_entity::<Clicked>(move |e| {
+        let _ = &counter;
+        e.world().get::<&mut Count>(|count| count.0 += 1);
+    });
+
+    widget.emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    observer.disable();
+    widget.emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    observer.enable();
+    widget.emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+
+    observer.destruct();
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+    assert!(widget.is_alive());
+
+    widget.emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+
+    drop(world);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn observer_component_hooks_drop_captures_on_world_fini() {
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    {
+        let world = World::new();
+
+        let counter = DropCounter(drops.clone());
+        let counter_set = DropCounter(drops.clone());
+        world
+            .component::<Position>()
+            .on_add(move |_, _| {
+                let _ = &counter;
+            })
+            .on_set(move |_, _| {
+                let _ = &counter_set;
+            });
+
+        world.entity().set(Position { x: 10, y: 20 });
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+    }
+
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+}
+
+#[derive(Component)]
+struct Bubble {
+    stop_at: u64,
+}
+
+fn propagation_tree(world: &World) -> (EntityView<'_>, EntityView<'_>, EntityView<'_>) {
+    let root = world.entity_named("root");
+    let mid = world.entity_named("mid").child_of_id(root);
+    let leaf = world.entity_named("leaf").child_of_id(mid);
+
+    world.set(Count(0));
+    for e in [root, mid, leaf] {
+        e.observe_payload_entity::<Bubble>(|e, bubble| {
+            e.world().get::<&mut Count>(|count| {
+                // record the visit order as digits: root 1, mid 2, leaf 3
+                let digit = match e.name().as_str() {
+                    "root" => 1,
+                    "mid" => 2,
+                    _ => 3,
+                };
+                count.0 = count.0 * 10 + digit;
+            });
+            if *e.id() == bubble.stop_at {
+                e.world().stop_propagation();
+            }
+        });
+    }
+
+    (root, mid, leaf)
+}
+
+#[test]
+fn observer_emit_to_ancestors() {
+    let world = World::new();
+    let (_, mid, leaf) = propagation_tree(&world);
+
+    leaf.emit_to_ancestors(&Bubble { stop_at: 0 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 321));
+
+    world.set(Count(0));
+    leaf.emit_to_ancestors(&Bubble { stop_at: *mid.id() });
+    world.get::<&Count>(|count| assert_eq!(count.0, 32));
+}
+
+#[test]
+fn observer_emit_to_descendants() {
+    let world = World::new();
+    let (root, mid, _) = propagation_tree(&world);
+
+    root.emit_to_descendants(&Bubble { stop_at: 0 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 123));
+
+    world.set(Count(0));
+    root.emit_to_descendants(&Bubble { stop_at: *mid.id() });
+    world.get::<&Count>(|count| assert_eq!(count.0, 12));
+}
+
+#[test]
+fn observer_emit_to_descendants_depth_first() {
+    let world = World::new();
+
+    // entities without components can't be observed
+    let root = world.entity_named("root");
+    let a = world.entity_named("a").child_of_id(root);
+    let a_child = world.entity_named("a_child").child_of_id(a);
+    let b = world.entity_named("b").child_of_id(root);
+
+    let visited = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    for e in [root, a, a_child, b] {
+        let visited = visited.clone();
+        e.observe_payload_entity::<Bubble>(move |e, _| visited.borrow_mut().push(e.id()));
+    }
+
+    root.emit_to_descendants(&Bubble { stop_at: 0 });
+
+    let visited = visited.borrow();
+    assert_eq!(visited.len(), 4);
+    assert_eq!(visited[0], root.id());
+    // a child is visited before the next child of its parent
+    let a_index = visited.iter().position(|&e| e == a.id()).unwrap();
+    assert_eq!(visited[a_index + 1], a_child.id());
+}
+
+#[test]
+fn observer_propagate_to_grandparent() {
+    let world = World::new();
+    let (root, _, leaf) = propagation_tree(&world);
+
+    let leaf_id = leaf.id();
+    root.observe_entity::<Clicked>(move |e| {
+        assert_eq!(e.world().event_origin().unwrap(), leaf_id);
+        e.world().get::<&mut Count>(|count| count.0 += 1);
+    });
+
+    world
+        .event()
+        .entity(leaf)
+        .propagate::<flecs::ChildOf>()
+        .emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+    assert!(world.event_origin().is_none());
+
+    // without propagation only the observers of the entity itself are invoked
+    world.event().entity(leaf).emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+}
+
+#[test]
+fn observer_propagate_custom_relationship() {
+    #[derive(Component)]
+    struct OwnedBy;
+
+    let world = World::new();
+    let (root, mid, leaf) = propagation_tree(&world);
+
+    // follows OwnedBy instead of ChildOf: leaf -> root
+    leaf.add_first::<OwnedBy>(root);
+
+    world
+        .event()
+        .entity(leaf)
+        .propagate::<OwnedBy>()
+        .emit(&Bubble { stop_at: 0 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 31));
+
+    world.set(Count(0));
+    mid.add_first::<OwnedBy>(root);
+    world
+        .event()
+        .entity(mid)
+        .propagate::<OwnedBy>()
+        .emit(&Bubble { stop_at: *mid.id() });
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+}
+
+#[test]
+fn observer_event_for_component_id() {
+    let world = World::new();
+
+    world.set(Count(0));
+    world
+        .observer::<Clicked, ()>()
+        .with::<Position>()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+    world
+        .observer::<Clicked, ()>()
+        .with::<Velocity>()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|count| count.0 += 10);
+        });
+
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 1, y: 2 });
+
+    world.event().add::<Velocity>().entity(e).emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 10));
+
+    world.event().add::<Position>().entity(e).emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 11));
+
+    world
+        .event()
+        .add::<Position>()
+        .add_id(world.component_id::<Velocity>())
+        .entity(e)
+        .emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 22));
+}
+
+#[test]
+fn observer_event_for_pair_and_table() {
+    #[derive(Component)]
+    struct Likes;
+
+    let world = World::new();
+    let apples = world.entity_named("apples");
+    let pears = world.entity_named("pears");
+
+    world.set(Count(0));
+    world
+        .observer::<Clicked, ()>()
+        .with_first::<Likes>(apples)
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+
+    let e1 = world
+        .entity()
+        .add_first::<Likes>(apples)
+        .add_first::<Likes>(pears);
+    world
+        .entity()
+        .add_first::<Likes>(apples)
+        .add_first::<Likes>(pears);
+
+    world
+        .event()
+        .add_first::<Likes>(pears)
+        .entity(e1)
+        .emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 0));
+
+    world
+        .event()
+        .add_first::<Likes>(apples)
+        .entity(e1)
+        .emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    // emit for both entities in the table
+    world
+        .event()
+        .add_first::<Likes>(apples)
+        .table(e1.table().unwrap(), 0, 2)
+        .emit(&Clicked);
+    world.get::<&Count>(|count| assert_eq!(count.0, 3));
+}
+
+#[derive(Component)]
+struct Message {
+    text: String,
+    counter: DropCounter,
+}
+
+#[test]
+fn observer_enqueue_heap_payload() {
+    let world = World::new();
+    let drops = Arc::new(AtomicUsize::new(0));
+    let received = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let widget = world.entity_named("widget");
+    let received_ref = received.clone();
+    widget.observe_payload::<Message>(move |m| {
+        received_ref.borrow_mut().push(m.text.clone());
+    });
+
+    world.defer_begin();
+    widget.enqueue(Message {
+        text: "deferred".to_string(),
+        counter: DropCounter(drops.clone()),
+    });
+    assert!(received.borrow().is_empty());
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    world.defer_end();
+
+    assert_eq!(*received.borrow(), ["deferred"]);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+    // without defer the event is emitted right away
+    widget.enqueue(Message {
+        text: "immediate".to_string(),
+        counter: DropCounter(drops.clone()),
+    });
+    assert_eq!(*received.borrow(), ["deferred", "immediate"]);
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn observer_enqueue_discarded_payload_is_dropped() {
+    let world = World::new();
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    let widget = world.entity_named("widget");
+    widget.observe_payload::<Message>(|_| {});
+
+    world.defer_begin();
+    widget.enqueue(Message {
+        text: "never delivered".to_string(),
+        counter: DropCounter(drops.clone()),
+    });
+    widget.destruct();
+    world.defer_end();
+
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn observer_field_untyped_runtime_id() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    let runtime_id = world.component_id::<Position>();
+    world
+        .observer_id::<()>(flecs::OnSet::ID)
+        .with_id(runtime_id)
+        .run(|mut it| {
+            while it.next() {
+                let field = it.field_untyped(0);
+                assert_eq!(field.size(), std::mem::size_of::<Position>());
+                let pos = field.at(0) as *const Position;
+                let x = unsafe { (*pos).x };
+                it.world().get::<&mut Count>(|count| count.0 += x);
+            }
+        });
+
+    world.entity().set(Position { x: 5, y: 6 });
+
+    world.get::<&Count>(|count| assert_eq!(count.0, 5));
+}
@@ -275,7 +275,7 @@ fn observer_2_entities_iter() {
     e2.set(Position { x: 30, y: 40 });
     world.get::<&mut Count>(|count| {
         world.get::<&mut LastEntity>(|last| {
-            assert_eq!(count, 2);
+            assert_eq!(count.0, 2);
             assert!(last.0 == e2.id());
         });
     });
@@ -334,7 +334,7 @@ fn observer_2_entities_table_column() {
     e2.set(Position { x: 30, y: 40 });
     world.get::<&mut Count>(|count| {
         world.get::<&mut LastEntity>(|last| {
-            assert_eq!(count, 2);
+            assert_eq!(count.0, 2);
             assert!(last.0 == e2.id());
         });
     });
@@ -385,7 +385,7 @@ fn observer_2_entities_each() {
     e2.set(Position { x: 30, y: 40 });
     world.get::<&mut Count>(|count| {
         world.get::<&mut LastEntity>(|last| {
-            assert_eq!(count, 2);
+            assert_eq!(count.0, 2);
             assert!(last.0 == e2);
         });
     });
@@ -497,6 +497,246 @@ fn observer_yield_existing_2_terms() {
     });
 }
 
+#[test]
+fn observer_yield_existing_on_set_each() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 10, y: 20 });
+    let e2 = world.entity().set(Position { x: 30, y: 40 });
+    world.entity().set(Velocity { x: 1, y: 2 });
+
+    let e1_id = e1.id();
+    let e2_id = e2.id();
+
+    world.set(Count(0));
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .yield_existing()
+        .each_iter(move |it, i, p| {
+            assert_eq!(it.event(), flecs::OnSet::ID);
+            let e = it.entity(i);
+            if e == e1_id {
+                assert_eq!((p.x, p.y), (10, 20));
+            } else {
+                assert_eq!(e, e2_id);
+                assert_eq!((p.x, p.y), (30, 40));
+            }
+            it.world().get::<&mut Count>(|count| {
+                count.0 += 1;
+            });
+        });
+
+    world.get::<&Count>(|count| {
+        assert_eq!(count.0, 2);
+    });
+}
+
+#[test]
+fn observer_yield_existing_on_add_on_set() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 10, y: 20 });
+    world.entity().set(Position { x: 30, y: 40 });
+
+    world.set(Count(0));
+    world
+        .observer_id::<()>(flecs::OnAdd::ID)
+        .add_event::<flecs::OnSet>()
+        .with::<Position>()
+        .yield_existing()
+        .run(|mut it| {
+            let world = it.world();
+            while it.next() {
+                // existing entities are yielded once for each event of the observer
+                let event = it.event();
+                world.get::<&mut Count>(|count| {
+                    if event == flecs::OnAdd::ID {
+                        count.0 += it.count() as i32;
+                    } else {
+                        assert_eq!(event, flecs::OnSet::ID);
+                        count.0 += 10 * it.count() as i32;
+                    }
+                });
+            }
+        });
+
+    world.get::<&Count>(|count| {
+        assert_eq!(count.0, 22);
+    });
+}
+
+#[test]
+fn observer_each_entity_multi_component_events() {
+    let world = World::new();
+
+    world.set(LastEntity(Entity::null()));
+    world
+        .observer::<flecs::OnAdd, ()>()
+        .with::<Position>()
+        .with::<Velocity>()
+        .each_entity(|e, _| {
+            e.world().set(LastEntity(e.id()));
+        });
+
+    let e1 = world.entity().add::<Position>();
+    world.entity().add::<Velocity>();
+    world.get::<&LastEntity>(|last| assert_eq!(last.0, Entity::null()));
+
+    e1.add::<Velocity>();
+    world.get::<&LastEntity>(|last| assert_eq!(last.0, e1.id()));
+
+    let e1_id = e1.id();
+    world.set(Count(0));
+    world
+        .observer::<flecs::OnSet, (&Position, &Velocity)>()
+        .each_entity(move |e, _| {
+            assert_eq!(e, e1_id);
+            e.world().get::<&mut Count>(|count| count.0 += 1);
+        });
+    world
+        .observer::<flecs::OnRemove, (&Position, &Velocity)>()
+        .each_entity(move |e, (p, v)| {
+            assert_eq!(e, e1_id);
+            assert_eq!((p.x, v.x), (10, 1));
+            e.world().get::<&mut Count>(|count| count.0 += 10);
+        });
+
+    e1.set(Velocity { x: 1, y: 2 })
+        .set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+
+    e1.remove::<Velocity>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 12));
+}
+
+#[test]
+fn observer_each_iter_multi_component_entity() {
+    let world = World::new();
+
+    let e2 = world.entity().set(Velocity { x: 1, y: 2 });
+    world.entity().set(Position { x: 0, y: 0 });
+
+    world.set(LastEntity(Entity::null()));
+    world
+        .observer::<flecs::OnSet, (&Position, &Velocity)>()
+        .each_iter(|it, i, (p, _v)| {
+            let e = it.entity(i);
+            assert_eq!(p.x, 30);
+            assert_eq!(it.event(), flecs::OnSet::ID);
+            e.world().set(LastEntity(e.id()));
+        });
+
+    e2.set(Position { x: 30, y: 40 });
+    world.get::<&LastEntity>(|last| assert_eq!(last.0, e2.id()));
+}
+
+#[test]
+fn observer_monitor() {
+    let world = World::new();
+
+    world.set(Count(0));
+    world
+        .observer::<flecs::Monitor, ()>()
+        .with::<Position>()
+        .with::<Velocity>()
+        .each_iter(|it, _, _| {
+            let event = it.event();
+            it.world().get::<&mut Count>(|count| {
+                if event == flecs::OnAdd::ID {
+                    count.0 += 1;
+                } else {
+                    assert_eq!(event, flecs::OnRemove::ID);
+                    count.0 += 10;
+                }
+            });
+        });
+
+    let e1 = world.entity().set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 0));
+
+    e1.set(Velocity { x: 1, y: 2 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    // already matching, so setting a component again doesn't start a match
+    e1.set(Position { x: 20, y: 30 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    e1.remove::<Position>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 11));
+
+    let e2 = world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Velocity { x: 1, y: 2 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 12));
+
+    e2.remove::<Velocity>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 22));
+}
+
+#[test]
+#[should_panic]
+fn observer_monitor_typed_panics() {
+    let world = World::new();
+
+    world
+        .observer::<flecs::Monitor, (&Position, &Velocity)>()
+        .each(|_| {});
+}
+
+#[test]
+fn observer_on_table_create_delete() {
+    let world = World::new();
+
+    world.set(Count(0));
+    world
+        .observer_id::<()>(flecs::OnTableCreate::ID)
+        .add_event::<flecs::OnTableDelete>()
+        .with::<Position>()
+        .run(|mut it| {
+            let world = it.world();
+            while it.next() {
+                let table = it.table().unwrap();
+                assert!(table.has_type::<Position>());
+                let event = it.event();
+                world.get::<&mut Count>(|count| {
+                    if event == flecs::OnTableCreate::ID {
+                        count.0 += 1;
+                    } else {
+                        assert_eq!(event, flecs::OnTableDelete::ID);
+                        count.0 += 10;
+                    }
+                });
+            }
+        });
+
+    let e = world.entity().add::<Position>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    // the table for (Position) already exists
+    world.entity().add::<Position>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    e.add::<Velocity>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+
+    // tables without Position don't match
+    world.entity().add::<Velocity>();
+    world.get::<&Count>(|count| assert_eq!(count.0, 2));
+
+    e.destruct();
+    for _ in 0..2 {
+        world.delete_empty_tables(flecs_ecs::sys::ecs_delete_empty_tables_desc_t {
+            id: 0,
+            clear_generation: 0,
+            delete_generation: 1,
+            min_id_count: 0,
+            time_budget_seconds: 0.0,
+        });
+    }
+    world.get::<&Count>(|count| assert_eq!(count.0, 12));
+}
+
 #[test]
 fn observer_on_add() {
     let world = World::new();
@@ -721,6 +961,65 @@ fn observer_run_callback() {
     });
 }
 
+#[test]
+fn observer_run_callback_skip_tables() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    let mut tables = 0;
+    world.observer::<flecs::OnSet, &Position>().run_each_entity(
+        move |mut it| {
+            while it.next() {
+                tables += 1;
+                if tables % 2 == 0 {
+                    continue;
+                }
+                it.each();
+            }
+        },
+        |e, _p| {
+            e.world().get::<&mut Count>(|count| {
+                count.0 += 1;
+            });
+        },
+    );
+
+    world.entity().set(Position { x: 1, y: 0 });
+    world.entity().add::<TagA>().set(Position { x: 2, y: 0 });
+    world.entity().add::<TagB>().set(Position { x: 3, y: 0 });
+    world.entity().add::<TagC>().set(Position { x: 4, y: 0 });
+
+    world.get::<&Count>(|count| {
+        assert_eq!(count.0, 2);
+    });
+}
+
+#[test]
+fn observer_run_callback_manual_fields() {
+    let world = World::new();
+
+    world.set(Count(0));
+    world.observer::<flecs::OnSet, &Position>().run(|mut it| {
+        let world = it.world();
+        while it.next() {
+            let p = it.field::<Position>(0).unwrap();
+            for i in it.iter() {
+                world.get::<&mut Count>(|count| {
+                    count.0 += p[i].x;
+                });
+            }
+        }
+    });
+
+    world.entity().set(Position { x: 10, y: 0 });
+    world.entity().set(Position { x: 20, y: 0 });
+
+    world.get::<&Count>(|count| {
+        assert_eq!(count.0, 30);
+    });
+}
+
 #[test]
 fn observer_get_query() {
     let world = World::new();
@@ -916,7 +1215,7 @@ fn observer_on_add_pair_wildcard_singleton() {
     world.set_first::<Position>(tgt_2, Position { x: 10, y: 20 });
 
     world.get::<&mut Count>(|count| {
-        assert_eq!(count, 2);
+        assert_eq!(count.0, 2);
     });
 }
 
@@ -1000,6 +1299,49 @@ fn observer_name_from_root() {
     assert!(ns == o.parent().unwrap());
 }
 
+#[test]
+fn observer_named_lookup() {
+    let world = World::new();
+
+    let o = world
+        .observer_named::<flecs::OnSet, &Position>("MyObserver")
+        .each(|_| {});
+
+    assert_eq!(o.name(), "MyObserver");
+    assert_eq!(world.lookup("MyObserver"), *o);
+    assert!(o.has::<flecs::Observer>());
+}
+
+#[test]
+fn observer_named_terms_match_events() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    world
+        .observer_named::<flecs::OnSet, (&Position, &Velocity)>("PosVel")
+        .each_entity(|e, (pos, vel)| {
+            assert_eq!(pos.x, 10);
+            assert_eq!(vel.x, 1);
+            e.world().get::<&mut Count>(|count| {
+                count.0 += 1;
+            });
+        });
+
+    let observer = world.lookup("PosVel");
+    assert!(observer.has::<flecs::Observer>());
+
+    let e = world.entity().set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 0));
+
+    e.set(Velocity { x: 1, y: 2 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+
+    // an entity with only one of the terms doesn't match
+    world.entity().set(Velocity { x: 1, y: 2 });
+    world.get::<&Count>(|count| assert_eq!(count.0, 1));
+}
+
 // #[test]
 // #[should_panic]
 // fn observer_panic_inside() {
@@ -0,0 +1,145 @@
+use crate::common_test::*;
+
+#[derive(Component)]
+struct MemberOf;
+
+fn sorted(mut members: Vec<Entity>) -> Vec<Entity> {
+    members.sort();
+    members
+}
+
+fn members(index: &PairIndex, target: impl Into<Entity>) -> Vec<Entity> {
+    sorted(index.members_of(target, |members| members.to_vec()))
+}
+
+#[test]
+fn pair_index_existing_members() {
+    let world = World::new();
+
+    let red = world.entity();
+    let blue = world.entity();
+    let alice = world.entity().add_first::<MemberOf>(red);
+    let bob = world.entity().add_first::<MemberOf>(red);
+    let carol = world.entity().add_first::<MemberOf>(blue);
+
+    let index = world.pair_index::<MemberOf>();
+
+    assert_eq!(index.relationship(), world.component::<MemberOf>().entity);
+    assert_eq!(members(&index, red), sorted(vec![alice.id(), bob.id()]));
+    assert_eq!(members(&index, blue), [carol.id()]);
+    assert_eq!(index.target_count(), 2);
+
+    let dave = world.entity().add_first::<MemberOf>(blue);
+    assert_eq!(
+        members(&world.pair_index::<MemberOf>(), blue),
+        sorted(vec![carol.id(), dave.id()])
+    );
+}
+
+#[test]
+fn pair_index_move_member_deferred() {
+    let world = World::new();
+    world
+        .component::<MemberOf>()
+        .add_trait::<flecs::Exclusive>();
+
+    let red = world.entity();
+    let blue = world.entity();
+    let alice = world.entity().add_first::<MemberOf>(red);
+    let bob = world.entity().add_first::<MemberOf>(red);
+
+    let index = world.pair_index::<MemberOf>();
+
+    world.defer_begin();
+    alice.add_first::<MemberOf>(blue);
+    assert_eq!(members(&index, red), sorted(vec![alice.id(), bob.id()]));
+    assert!(members(&index, blue).is_empty());
+    world.defer_end();
+
+    let index = world.pair_index::<MemberOf>();
+    assert_eq!(members(&index, red), [bob.id()]);
+    assert_eq!(members(&index, blue), [alice.id()]);
+}
+
+#[test]
+fn pair_index_delete_target() {
+    let world = World::new();
+
+    let red = world.entity();
+    let blue = world.entity();
+    let alice = world.entity().add_first::<MemberOf>(red);
+    world.entity().add_first::<MemberOf>(red);
+    world.entity().add_first::<MemberOf>(blue);
+
+    world.pair_index::<MemberOf>();
+
+    red.destruct();
+
+    let index = world.pair_index::<MemberOf>();
+    assert!(!index.contains(red));
+    assert!(members(&index, red).is_empty());
+    assert!(index.contains(blue));
+    assert_eq!(index.target_count(), 1);
+    assert!(alice.is_alive());
+}
+
+#[test]
+fn pair_index_delete_member() {
+    let world = World::new();
+
+    let red = world.entity();
+    let alice = world.entity().add_first::<MemberOf>(red);
+    let bob = world.entity().add_first::<MemberOf>(red);
+
+    world.pair_index::<MemberOf>();
+
+    alice.destruct();
+    assert_eq!(members(&world.pair_index::<MemberOf>(), red), [bob.id()]);
+
+    bob.destruct();
+    assert!(!world.pair_index::<MemberOf>().contains(red));
+}
+
+#[test]
+fn pair_index_members_of_defers_changes() {
+    let world = World::new();
+
+    let red = world.entity();
+    let alice = world.entity().add_first::<MemberOf>(red);
+
+    let index = world.pair_index::<MemberOf>();
+    index.members_of(red, |members| {
+        // would grow the member list of red past its capacity
+        for _ in 0..16 {
+            world.entity().add_first::<MemberOf>(red);
+        }
+        alice.destruct();
+
+        assert_eq!(members, &[alice.id()]);
+    });
+
+    assert_eq!(index.members_of(red, <[Entity]>::len), 16);
+}
+
+#[test]
+fn pair_index_remove_many_members() {
+    let world = World::new();
+
+    let red = world.entity();
+    let entities: Vec<_> = (0..1000)
+        .map(|_| world.entity().add_first::<MemberOf>(red))
+        .collect();
+
+    let index = world.pair_index::<MemberOf>();
+
+    for e in entities.iter().step_by(2) {
+        e.remove_first::<MemberOf>(red);
+    }
+    let expected = entities.iter().skip(1).step_by(2).map(|e| e.id()).collect();
+    assert_eq!(members(&index, red), sorted(expected));
+
+    for e in entities.iter().skip(1).step_by(2) {
+        e.destruct();
+    }
+    assert!(!index.contains(red));
+}
@@ -1628,7 +1628,7 @@ fn query_builder_explicit_subject_w_type() {
         assert_eq!(p.x, 10);
         assert_eq!(p.y, 20);
         count += 1;
-        assert_eq!(e, world.singleton::<Position>());
+        assert_eq!(e, world.singleton_entity::<Position>());
     });
 
     assert_eq!(count, 1);
@@ -5045,3 +5045,112 @@ fn query_builder_scope() {
 
     assert_eq!(count, 3);
 }
+
+#[test]
+fn query_builder_without_any() {
+    let world = World::new();
+
+    // one entity for each combination of the tags
+    let mut entities = Vec::new();
+    for mask in 0..8 {
+        let e = world.entity().set(Position { x: mask, y: 0 });
+        if mask & 1 != 0 {
+            e.add::<TagA>();
+        }
+        if mask & 2 != 0 {
+            e.add::<TagB>();
+        }
+        if mask & 4 != 0 {
+            e.add::<TagC>();
+        }
+        entities.push(e.id());
+    }
+    world.entity().add::<TagD>();
+
+    let q = world
+        .query::<()>()
+        .with::<Position>()
+        .without_any::<(TagA, TagB, TagC)>()
+        .build();
+
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    assert_eq!(matched, [entities[0]]);
+
+    let q = world
+        .query::<()>()
+        .with::<Position>()
+        .without_any::<(TagA, TagC)>()
+        .build();
+
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    matched.sort();
+    assert_eq!(matched, [entities[0], entities[2]]);
+
+    // pairs
+    let apples = world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .add::<(Likes, Apples)>();
+    let pears = world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .add::<(Likes, Pears)>();
+
+    let q = world
+        .query::<()>()
+        .with::<Position>()
+        .without_any::<(TagA, (Likes, Apples))>()
+        .build();
+
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    assert!(!matched.contains(&apples.id()));
+    assert!(matched.contains(&pears.id()));
+    assert!(!matched.contains(&entities[1]));
+    assert!(matched.contains(&entities[2]));
+}
+
+#[test]
+fn query_builder_without_children() {
+    let world = World::new();
+
+    let leaf = world.entity().add::<Tag>();
+    let parent = world.entity().add::<Tag>();
+    let child_leaf = world.entity().add::<Tag>().child_of_id(parent);
+    let grandparent = world.entity().add::<Tag>();
+    let child_parent = world.entity().add::<Tag>().child_of_id(grandparent);
+    let grandchild = world.entity().add::<Tag>().child_of_id(child_parent);
+
+    // a child without the tag still makes its parent not match
+    let untagged_parent = world.entity().add::<Tag>();
+    world.entity().child_of_id(untagged_parent);
+
+    let q = world.query::<()>().with::<Tag>().without_children().build();
+
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    matched.sort();
+
+    let mut expected = vec![leaf.id(), child_leaf.id(), grandchild.id()];
+    expected.sort();
+    assert_eq!(matched, expected);
+
+    // the parent matches again once the empty table of its children is deleted
+    child_leaf.destruct();
+    for _ in 0..2 {
+        world.delete_empty_tables(sys::ecs_delete_empty_tables_desc_t {
+            id: 0,
+            clear_generation: 0,
+            delete_generation: 1,
+            min_id_count: 0,
+            time_budget_seconds: 0.0,
+        });
+    }
+
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    assert!(matched.contains(&parent.id()));
+    assert!(!matched.contains(&untagged_parent.id()));
+}
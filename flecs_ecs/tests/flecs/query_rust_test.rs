@@ -118,3 +118,1219 @@ fn test_trait_query() {
 
     assert_eq!(count, 6);
 }
+
+#[test]
+fn query_rust_pair_first_data() {
+    let world = World::new();
+
+    world
+        .entity()
+        .set_pair::<Position, Apples>(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .set_pair::<Position, Pears>(Position { x: 3, y: 4 });
+
+    let mut count = 0;
+    world
+        .new_query::<&mut (Position, Apples)>()
+        .each(|pos: &mut Position| {
+            pos.x += 10;
+            count += 1;
+        });
+    assert_eq!(count, 1);
+
+    world
+        .new_query::<&(Position, Apples)>()
+        .each(|pos| assert_eq!(pos.x, 11));
+}
+
+#[test]
+fn query_rust_pair_second_data() {
+    let world = World::new();
+
+    world
+        .entity()
+        .set_pair::<Likes, Position>(Position { x: 1, y: 2 });
+    world.entity().set(Velocity { x: 1, y: 2 });
+
+    let mut count = 0;
+    world
+        .new_query::<(&(Likes, Position), Option<&mut Velocity>)>()
+        .each(|(pos, vel)| {
+            assert_eq!(pos.y, 2);
+            assert!(vel.is_none());
+            count += 1;
+        });
+    assert_eq!(count, 1);
+
+    let e = world
+        .entity()
+        .set(Velocity { x: 3, y: 4 })
+        .set_pair::<Likes, Position>(Position { x: 5, y: 6 });
+
+    let mut count = 0;
+    world
+        .new_query::<(&Velocity, Option<&mut (Likes, Position)>)>()
+        .each(|(vel, pos)| {
+            if let Some(pos) = pos {
+                pos.x += vel.x;
+            }
+            count += 1;
+        });
+    assert_eq!(count, 2);
+
+    e.get::<&(Likes, Position)>(|pos| assert_eq!(pos.x, 8));
+}
+
+#[test]
+fn query_rust_wildcard_pair_elements() {
+    let world = World::new();
+
+    let apples = world.entity_named("apples");
+    let pears = world.entity_named("pears");
+    let bob = world
+        .entity_named("bob")
+        .add_first::<Likes>(apples)
+        .add_first::<Likes>(pears);
+
+    let query = world
+        .query::<()>()
+        .with_first::<Likes>(flecs::Wildcard::ID)
+        .build();
+
+    let mut targets = vec![];
+    query.each_iter(|it, index, _| {
+        assert_eq!(it.entity(index), bob);
+        assert_eq!(
+            it.field_id(0),
+            ecs_pair(*world.component_id::<Likes>(), flecs::Wildcard::ID)
+        );
+        let (first, second) = it.pair_elements(0).unwrap();
+        assert_eq!(first, world.component_id::<Likes>());
+        assert_eq!(it.id(0), ecs_pair(*first.id(), *second.id()));
+        assert!(it.pair(0).is_some());
+        targets.push(second.id());
+    });
+
+    // the entity is iterated once for each matched pair
+    assert_eq!(targets.len(), 2);
+    assert!(targets.contains(&apples.id()));
+    assert!(targets.contains(&pears.id()));
+}
+
+#[test]
+fn query_rust_pair_elements_not_a_pair() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+
+    world.new_query::<&Position>().each_iter(|it, _, _| {
+        assert!(it.pair_elements(0).is_none());
+        assert_eq!(it.field_id(0), world.component_id::<Position>());
+    });
+}
+
+#[test]
+fn query_rust_field_untyped_runtime_id() {
+    let world = World::new();
+
+    let runtime_id = world.component_id::<Position>();
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+
+    let query = world.query::<()>().with_id(runtime_id).build();
+
+    query.run(|mut it| {
+        while it.next() {
+            assert!(it.is_self(0));
+            assert!(it.is_set(0));
+            assert_eq!(it.src(0), 0);
+
+            let mut field = it.field_untyped(0);
+            assert_eq!(field.size(), std::mem::size_of::<Position>());
+            assert_eq!(field.count(), it.count());
+            assert!(!field.is_shared());
+            for row in it.iter() {
+                let pos = field.at_mut(row) as *mut Position;
+                unsafe { (*pos).x *= 10 };
+            }
+        }
+    });
+
+    query.each_iter(|it, row, _| {
+        let pos = it.field_at_untyped(0, row as i32) as *mut Position;
+        unsafe { (*pos).y += 1 };
+    });
+
+    e1.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (10, 3)));
+    e2.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (30, 5)));
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn query_rust_field_at_untyped_out_of_range() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+
+    world
+        .query::<()>()
+        .with::<Position>()
+        .build()
+        .run(|mut it| {
+            while it.next() {
+                it.field_at_untyped(0, it.count() as i32);
+            }
+        });
+}
+
+#[test]
+fn query_rust_table_column() {
+    let world = World::new();
+
+    for i in 0..5 {
+        world.entity().set(Position { x: i, y: i * 2 });
+    }
+    for i in 5..8 {
+        world.entity().set(Position { x: i, y: i * 2 }).add::<Tag>();
+    }
+
+    let query = world.new_query::<&Position>();
+
+    let mut from_query = vec![];
+    query.each_entity(|e, pos| from_query.push((e.id(), pos.x, pos.y)));
+
+    let mut from_tables = vec![];
+    query.run(|mut it| {
+        while it.next() {
+            let table = it.table().unwrap();
+            assert!(table.has_type::<Position>());
+            assert!(table.column::<Velocity>().is_none());
+
+            let entities = table.entities();
+            let column = table.column::<Position>().unwrap();
+            assert_eq!(entities.len(), table.count() as usize);
+            assert_eq!(column.len(), entities.len());
+            for (e, pos) in entities.iter().zip(column.iter()) {
+                from_tables.push((*e, pos.x, pos.y));
+            }
+
+            let index = table.find_column_index::<Position>().unwrap();
+            let field = table.column_field_untyped(index).unwrap();
+            assert_eq!(field.size(), std::mem::size_of::<Position>());
+            assert_eq!(field.count(), entities.len());
+        }
+    });
+
+    assert_eq!(from_query.len(), 8);
+    assert_eq!(from_query, from_tables);
+}
+
+#[test]
+fn query_rust_table_range_column() {
+    let world = World::new();
+
+    let entities: Vec<Entity> = (0..4)
+        .map(|i| world.entity().set(Position { x: i, y: 0 }).id())
+        .collect();
+
+    let table = world.entity_from_id(entities[0]).table().unwrap();
+    let range = TableRange::new(table, 1, 2);
+
+    assert_eq!(range.entities(), &entities[1..3]);
+    let column = range.column::<Position>().unwrap();
+    assert_eq!(column.len(), 2);
+    assert_eq!(column[0].x, 1);
+    column[1].y = 10;
+
+    world
+        .entity_from_id(entities[2])
+        .get::<&Position>(|pos| assert_eq!(pos.y, 10));
+}
+
+#[test]
+fn query_rust_find_stops_at_match() {
+    let world = World::new();
+
+    for i in 0..100 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+    for i in 100..200 {
+        world.entity().set(Position { x: i, y: 0 }).add::<TagA>();
+    }
+
+    let query = world.new_query::<&Position>();
+
+    let mut invoked = 0;
+    let found = query.find(|pos| {
+        invoked += 1;
+        pos.x == 5
+    });
+    let found = found.unwrap();
+    assert_eq!(invoked, 6);
+    assert_eq!(found.get::<&Position>(|pos| pos.x), 5);
+
+    let mut invoked = 0;
+    let found = query
+        .find_entity(|_, pos| {
+            invoked += 1;
+            pos.x == 5 || pos.x == 150
+        })
+        .unwrap();
+    assert_eq!(invoked, 6);
+    assert_eq!(found.get::<&Position>(|pos| pos.x), 5);
+
+    let mut invoked = 0;
+    assert!(query
+        .find(|_| {
+            invoked += 1;
+            false
+        })
+        .is_none());
+    assert_eq!(invoked, 200);
+
+    // the table locks were released, so the matched entity can change tables
+    found.add::<TagB>();
+    assert!(found.has::<TagB>());
+
+    assert!(query.is_true());
+    assert_eq!(query.count(), 200);
+    assert!(query.try_first_entity().is_some());
+}
+
+#[test]
+fn query_rust_each_while_break() {
+    let world = World::new();
+
+    for i in 0..10 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+    for i in 10..20 {
+        world.entity().set(Position { x: i, y: 0 }).add::<TagA>();
+    }
+
+    let query = world.new_query::<&mut Position>();
+
+    let mut visited = Vec::new();
+    query.each_while(|pos| {
+        visited.push(pos.x);
+        pos.y = 1;
+        if pos.x == 3 {
+            std::ops::ControlFlow::Break(())
+        } else {
+            std::ops::ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(visited, vec![0, 1, 2, 3]);
+
+    let mut visited = Vec::new();
+    let mut last = None;
+    query.each_entity_while(|e, pos| {
+        visited.push(pos.x);
+        last = Some(e.id());
+        pos.x < 12
+    });
+    assert_eq!(visited, (0..=12).collect::<Vec<_>>());
+
+    // the table lock is released when the iteration stops
+    world.entity_from_id(last.unwrap()).add::<TagB>();
+
+    // a later iteration of the same query still visits everything
+    let mut count = 0;
+    let mut updated = 0;
+    query.each(|pos| {
+        count += 1;
+        if pos.y == 1 {
+            updated += 1;
+        }
+    });
+    assert_eq!(count, 20);
+    assert_eq!(updated, 4);
+
+    let mut count = 0;
+    query.each_while(|_| {
+        count += 1;
+        true
+    });
+    assert_eq!(count, 20);
+}
+
+#[test]
+fn query_rust_rows_mut() {
+    let world = World::new();
+
+    for i in 0..5 {
+        world
+            .entity()
+            .set(Position { x: i, y: 0 })
+            .set(Velocity { x: 1, y: 2 });
+    }
+    for i in 5..10 {
+        world
+            .entity()
+            .set(Position { x: i, y: 0 })
+            .set(Velocity { x: 1, y: 2 })
+            .add::<TagA>();
+    }
+
+    let query = world.new_query::<(&mut Position, &Velocity)>();
+
+    let mut count = 0;
+    let mut rows = query.rows();
+    while let Some((_, (pos, vel))) = rows.next_row() {
+        pos.x += vel.x;
+        pos.y += vel.y;
+        count += 1;
+    }
+    drop(rows);
+    assert_eq!(count, 10);
+
+    let mut xs = Vec::new();
+    query.each(|(pos, _)| {
+        assert_eq!(pos.y, 2);
+        xs.push(pos.x);
+    });
+    assert_eq!(xs, (1..11).collect::<Vec<_>>());
+}
+
+#[test]
+fn query_rust_rows_break_and_continue() {
+    let world = World::new();
+
+    for i in 0..5 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+    for i in 5..10 {
+        world.entity().set(Position { x: i, y: 0 }).add::<TagA>();
+    }
+
+    let query = world.new_query::<&Position>();
+
+    let mut visited = Vec::new();
+    let mut rows = query.rows();
+    while let Some((_, pos)) = rows.next_row() {
+        if pos.x % 2 == 0 {
+            continue;
+        }
+        visited.push(pos.x);
+        if pos.x == 7 {
+            break;
+        }
+    }
+    assert_eq!(visited, vec![1, 3, 5, 7]);
+    drop(rows);
+
+    // the iterator released its table lock and the world is no longer deferred
+    assert!(!world.is_deferred());
+    world.entity().set(Position { x: 10, y: 0 });
+    let mut rows = query.rows();
+    let mut count = 0;
+    while rows.next_row().is_some() {
+        count += 1;
+    }
+    assert_eq!(count, 11);
+}
+
+#[test]
+fn query_rust_rows_deferred() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 0 });
+    let e2 = world.entity().set(Position { x: -1, y: 0 });
+
+    let query = world.new_query::<&Position>();
+
+    let mut rows = query.rows();
+    while let Some((e, pos)) = rows.next_row() {
+        assert!(e.world().is_deferred());
+        if pos.x < 0 {
+            e.destruct();
+        } else {
+            e.add::<TagA>();
+        }
+        // changes are queued until the loop ends
+        assert!(!e.has::<TagA>());
+    }
+    drop(rows);
+
+    assert!(!world.is_deferred());
+    assert!(e1.has::<TagA>());
+    assert!(!e2.is_alive());
+}
+
+#[test]
+fn query_rust_rows_query_iter() {
+    let world = World::new();
+
+    let parent = world.entity();
+    let child = world
+        .entity()
+        .child_of_id(parent)
+        .set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 3, y: 4 });
+
+    let query = world
+        .query::<&mut Position>()
+        .with::<(flecs::ChildOf, flecs::Wildcard)>()
+        .build();
+
+    let mut count = 0;
+    let mut rows = query.iterable().rows();
+    while let Some((e, pos)) = rows.next_row() {
+        assert_eq!(e, child);
+        pos.x = 10;
+        count += 1;
+    }
+    assert_eq!(count, 1);
+    assert_eq!(child.get::<&Position>(|pos| pos.x), 10);
+}
+
+#[test]
+fn query_rust_rows_for_loop() {
+    let world = World::new();
+
+    for i in 0..5 {
+        world
+            .entity()
+            .set(Position { x: i, y: 0 })
+            .set(Velocity { x: 1, y: 2 });
+    }
+    for i in 5..10 {
+        world
+            .entity()
+            .set(Position { x: i, y: 0 })
+            .set(Velocity { x: 1, y: 2 })
+            .add::<TagA>();
+    }
+
+    let query = world.new_query::<(&mut Position, &Velocity)>();
+
+    let mut count = 0;
+    for mut row in &query {
+        let (e, (pos, vel)) = row.get();
+        assert!(e.world().is_deferred());
+        pos.x += vel.x;
+        pos.y += vel.y;
+        count += 1;
+    }
+    assert_eq!(count, 10);
+    assert!(!world.is_deferred());
+
+    let mut xs = Vec::new();
+    for mut row in query.rows().filter(|row| !row.entity().has::<TagA>()) {
+        let (_, (pos, _)) = row.get();
+        assert_eq!(pos.y, 2);
+        xs.push(pos.x);
+    }
+    assert_eq!(xs, (1..6).collect::<Vec<_>>());
+}
+
+#[test]
+fn query_rust_rows_for_loop_query_iter() {
+    let world = World::new();
+
+    for i in 0..10 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let query = world.new_query::<&mut Position>();
+
+    let find = |x: i32| -> Option<Entity> {
+        for mut row in query.iterable().page(2, 6) {
+            let (e, pos) = row.get();
+            pos.y += 1;
+            if pos.x == x {
+                return Some(e.id());
+            }
+        }
+        None
+    };
+    assert!(find(5).is_some());
+    assert!(find(9).is_none());
+    assert!(!world.is_deferred());
+
+    let mut ys = Vec::new();
+    query.each(|pos| ys.push(pos.y));
+    assert_eq!(ys, vec![0, 0, 2, 2, 2, 2, 1, 1, 0, 0]);
+}
+
+#[test]
+fn query_rust_rows_row_outlives_iterator() {
+    let world = World::new();
+
+    let e = world.entity().set(Position { x: 1, y: 0 });
+
+    let query = world.new_query::<&mut Position>();
+
+    let mut rows = query.rows();
+    let mut row = rows.next().unwrap();
+    drop(rows);
+
+    // the row keeps the world deferred
+    assert!(world.is_deferred());
+    let (_, pos) = row.get();
+    pos.x = 2;
+    e.add::<TagA>();
+    assert!(!e.has::<TagA>());
+    drop(row);
+
+    assert!(!world.is_deferred());
+    assert!(e.has::<TagA>());
+    assert_eq!(e.get::<&Position>(|pos| pos.x), 2);
+}
+
+#[test]
+#[should_panic(expected = "the previous row has to be dropped before the next one is requested")]
+fn query_rust_rows_two_rows_alive() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 0 });
+    world.entity().set(Position { x: 2, y: 0 });
+
+    let query = world.new_query::<&mut Position>();
+
+    let _rows = query.rows().collect::<Vec<_>>();
+}
+
+#[test]
+fn query_rust_world_each_mut_persists() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 1, y: 1 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+    let e3 = world
+        .entity()
+        .set_pair::<Position, Apples>(Position { x: 5, y: 6 });
+
+    let mut count = 0;
+    world.each::<(&mut Position, Option<&Velocity>)>(|(pos, vel)| {
+        if let Some(vel) = vel {
+            pos.x += vel.x;
+            pos.y += vel.y;
+        } else {
+            pos.x = 0;
+        }
+        count += 1;
+    });
+    assert_eq!(count, 2);
+
+    world.each_entity::<&mut (Position, Apples)>(|e, pos| {
+        assert_eq!(e, e3);
+        pos.x += 10;
+    });
+
+    e1.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (2, 3)));
+    e2.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (0, 4)));
+    e3.get::<&(Position, Apples)>(|pos| assert_eq!((pos.x, pos.y), (15, 6)));
+}
+
+#[test]
+fn query_rust_uncached_matches_new_entities() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 0 });
+
+    let query = world.query::<&Position>().set_uncached().build();
+    assert_eq!(query.cache_kind(), QueryCacheKind::None);
+    assert_eq!(query.count(), 1);
+
+    // entities in new tables are found without rematching the query
+    world.entity().set(Position { x: 2, y: 0 }).add::<TagA>();
+    world.entity().set(Position { x: 3, y: 0 }).add::<TagB>();
+
+    let mut sum = 0;
+    query.each(|pos| sum += pos.x);
+    assert_eq!(sum, 6);
+
+    let mut count = 0;
+    query.run(|mut it| {
+        while it.next() {
+            count += it.count();
+        }
+    });
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn query_rust_cache_kind() {
+    let world = World::new();
+
+    let query = world.new_query::<&Position>();
+    assert_eq!(query.cache_kind(), QueryCacheKind::None);
+
+    let query = world.query::<&Position>().set_cached().build();
+    assert_eq!(query.cache_kind(), QueryCacheKind::All);
+
+    let query = world.query::<&Position>().group_by::<Position>().build();
+    assert_ne!(query.cache_kind(), QueryCacheKind::None);
+}
+
+#[test]
+#[should_panic(expected = "group_by and order_by require a cached query")]
+fn query_rust_uncached_group_by() {
+    let world = World::new();
+
+    world
+        .query::<&Position>()
+        .set_uncached()
+        .group_by::<Position>()
+        .build();
+}
+
+#[test]
+fn query_rust_query_expr() {
+    let world = World::new();
+
+    let scene = world.entity_named("scene");
+    let e1 = world
+        .entity()
+        .child_of_id(scene)
+        .set(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .child_of_id(scene)
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 1, y: 1 });
+    world.entity().set(Position { x: 5, y: 6 });
+
+    let query = world
+        .query_expr("flecs.common_test.Position, !flecs.common_test.Velocity, (ChildOf, scene)")
+        .unwrap();
+    assert_eq!(query.field_count(), 3);
+
+    let mut count = 0;
+    query.run(|mut it| {
+        while it.next() {
+            let field = it.field_untyped(0);
+            for row in it.iter() {
+                assert_eq!(it.entity(row), e1);
+                let pos = unsafe { &*(field.at(row) as *const Position) };
+                assert_eq!((pos.x, pos.y), (1, 2));
+                count += 1;
+            }
+        }
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn query_rust_expr_with_typed_terms() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 1, y: 1 });
+
+    let query = world
+        .query::<&mut Position>()
+        .expr("!flecs.common_test.Velocity")
+        .try_build()
+        .unwrap();
+    assert_eq!(query.field_count(), 2);
+
+    let mut count = 0;
+    query.each(|pos| {
+        assert_eq!(pos.x, 1);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn query_rust_query_expr_error() {
+    let world = World::new();
+
+    let err = world
+        .query_expr("flecs.common_test.Position, (ChildOf,")
+        .err()
+        .unwrap();
+    assert!(!err.message().is_empty());
+
+    let err = world.query_expr("DoesNotExist").err().unwrap();
+    assert!(err.message().contains("DoesNotExist"));
+
+    let result = world.query::<&Position>().expr("DoesNotExist").try_build();
+    assert!(result.is_err());
+
+    // the world is still usable after a failed query
+    world.entity().set(Position { x: 1, y: 2 });
+    assert_eq!(
+        world
+            .query_expr("flecs.common_test.Position")
+            .unwrap()
+            .count(),
+        1
+    );
+}
+
+fn create_named_query(world: &World) {
+    world
+        .query_named::<&Position>("shared_query")
+        .with::<TagA>()
+        .set_cached()
+        .build();
+}
+
+#[test]
+fn query_rust_lookup_named_query() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 }).add::<TagA>();
+    world.entity().set(Position { x: 3, y: 4 });
+
+    create_named_query(&world);
+
+    let query = world.try_lookup_query("shared_query").unwrap();
+    assert_eq!(query.entity().name(), "shared_query");
+    assert_eq!(query.count(), 1);
+
+    world.entity().set(Position { x: 5, y: 6 }).add::<TagA>();
+
+    let mut count = 0;
+    query.run(|mut it| {
+        while it.next() {
+            let field = it.field_untyped(0);
+            for row in it.iter() {
+                let pos = unsafe { &*(field.at(row) as *const Position) };
+                assert!(pos.x == 1 || pos.x == 5);
+                count += 1;
+            }
+        }
+    });
+    assert_eq!(count, 2);
+
+    assert!(world.try_lookup_query("not_a_query").is_none());
+    world.entity_named("not_a_query");
+    assert!(world.try_lookup_query("not_a_query").is_none());
+}
+
+#[test]
+fn query_rust_named_query_entity_deleted() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 }).add::<TagA>();
+    create_named_query(&world);
+
+    let query = world.try_lookup_query("shared_query").unwrap();
+    let copy = query.clone();
+    assert!(query.is_alive());
+
+    query.entity().destruct();
+    assert!(!query.is_alive());
+    assert!(!copy.is_alive());
+    assert!(world.try_lookup_query("shared_query").is_none());
+
+    // dropping handles of a deleted query is fine
+    drop(copy);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| query.count()));
+    let err = result.unwrap_err();
+    let msg = err.downcast_ref::<String>().unwrap();
+    assert!(msg.contains("query was used after its entity was deleted"));
+}
+
+#[test]
+fn query_rust_term_at_src_id() {
+    let world = World::new();
+
+    let source = world.entity().set(Velocity { x: 10, y: 20 });
+    world.entity().set(Position { x: 1, y: 0 });
+    world.entity().set(Position { x: 2, y: 0 }).add::<TagA>();
+    world
+        .entity()
+        .set(Position { x: 3, y: 0 })
+        .set(Velocity { x: 1, y: 1 });
+
+    let query = world
+        .query::<(&Position, &Velocity)>()
+        .term_at(1)
+        .set_src_id(source)
+        .with::<TagB>()
+        .set_oper(OperKind::Not)
+        .build();
+
+    assert_eq!(query.term(1).src_id(), source.id());
+    assert_eq!(query.term(2).oper(), OperKind::Not);
+
+    let mut xs = Vec::new();
+    query.each(|(pos, vel)| {
+        assert_eq!((vel.x, vel.y), (10, 20));
+        xs.push(pos.x);
+    });
+    xs.sort();
+    assert_eq!(xs, vec![1, 2, 3]);
+
+    let mut count = 0;
+    query.run(|mut it| {
+        while it.next() {
+            assert!(!it.is_self(1));
+            assert_eq!(it.src(1), source);
+            count += it.count();
+        }
+    });
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn query_rust_term_at_first_second() {
+    let world = World::new();
+
+    let apples = world.entity();
+    let pears = world.entity();
+    let e1 = world
+        .entity()
+        .add_id((world.component_id::<Likes>(), apples));
+    world
+        .entity()
+        .add_id((world.component_id::<Likes>(), pears));
+
+    // only the first element is replaced, the second is kept from the id
+    let query = world
+        .query::<()>()
+        .with_id((world.component_id::<Eats>(), apples))
+        .term_at(0)
+        .set_first_id(world.component_id::<Likes>())
+        .build();
+
+    assert_eq!(query.term(0).first_id(), world.component_id::<Likes>());
+    assert_eq!(query.term(0).second_id(), apples.id());
+
+    let mut count = 0;
+    query.each_entity(|e, _| {
+        assert_eq!(e, e1);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn query_rust_filter_term_no_change() {
+    let world = World::new();
+
+    world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Velocity { x: 1, y: 2 });
+
+    let q_read = world.query::<&Position>().set_cached().build();
+    q_read.is_changed();
+    q_read.run(|mut it| while it.next() {});
+    assert!(!q_read.is_changed());
+
+    let q_filter = world
+        .query::<&Velocity>()
+        .with::<Position>()
+        .filter()
+        .set_cached()
+        .build();
+    assert_eq!(q_filter.term(1).inout(), InOutKind::None);
+
+    let mut count = 0;
+    q_filter.each(|_| count += 1);
+    assert_eq!(count, 1);
+    assert!(!q_read.is_changed());
+
+    let q_write = world
+        .query::<&Velocity>()
+        .with::<Position>()
+        .set_inout()
+        .set_cached()
+        .build();
+    q_write.each(|_| {});
+    assert!(q_read.is_changed());
+}
+
+#[test]
+fn query_rust_enum_terms() {
+    #[repr(C)]
+    #[derive(Component)]
+    enum Movement {
+        Idle,
+        Walking,
+        Running,
+    }
+
+    let world = World::new();
+
+    let idle = world.entity().add_enum(Movement::Idle);
+    let walking = world.entity().add_enum(Movement::Walking);
+    let running = world.entity().add_enum(Movement::Running);
+
+    let query = world.query::<()>().with_enum(Movement::Running).build();
+    let mut entities = Vec::new();
+    query.each_entity(|e, _| entities.push(e.id()));
+    assert_eq!(entities, vec![running.id()]);
+
+    let query = world.query::<()>().with_enum_wildcard::<Movement>().build();
+    let mut entities = Vec::new();
+    query.each_entity(|e, _| entities.push(e.id()));
+    entities.sort();
+    let mut expected = vec![idle.id(), walking.id(), running.id()];
+    expected.sort();
+    assert_eq!(entities, expected);
+
+    let query = world
+        .query::<()>()
+        .with_enum_wildcard::<Movement>()
+        .without_enum(Movement::Idle)
+        .build();
+    let mut entities = Vec::new();
+    query.each_entity(|e, _| entities.push(e.id()));
+    entities.sort();
+    let mut expected = vec![walking.id(), running.id()];
+    expected.sort();
+    assert_eq!(entities, expected);
+
+    world.set(Count(0));
+    world
+        .system::<()>()
+        .with_enum(Movement::Walking)
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|c| c.0 += 1);
+        });
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+
+    world.set(Count(0));
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .with_enum(Movement::Running)
+        .filter()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|c| c.0 += 1);
+        });
+    idle.set(Position { x: 1, y: 2 });
+    running.set(Position { x: 1, y: 2 });
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+}
+
+#[test]
+fn query_rust_to_string_and_plan() {
+    let world = World::new();
+
+    let query = world.query::<&Position>().without::<Velocity>().build();
+
+    let expr = query.to_string();
+    assert!(expr.contains("Position"));
+    assert!(expr.contains("!flecs.common_test.Velocity"));
+
+    let plan = query.plan();
+    assert!(!plan.is_empty());
+    assert!(plan.contains("Position"));
+}
+
+#[test]
+fn query_rust_try_build_term_index() {
+    let world = World::new();
+
+    let error = world
+        .query::<&Position>()
+        .with::<Velocity>()
+        .with_name("DoesNotExist")
+        .try_build()
+        .err()
+        .unwrap();
+    assert!(error.message().contains("DoesNotExist"));
+    assert_eq!(error.term_index(), Some(2));
+
+    let error = world
+        .query::<()>()
+        .expr("flecs.common_test.Position, (")
+        .try_build()
+        .err()
+        .unwrap();
+    assert!(!error.message().is_empty());
+}
+
+#[test]
+fn query_rust_try_build_cascade_group_by() {
+    let world = World::new();
+
+    let error = world
+        .query::<&Position>()
+        .with::<Velocity>()
+        .cascade()
+        .group_by::<Velocity>()
+        .try_build()
+        .err()
+        .unwrap();
+    assert_eq!(error.term_index(), Some(1));
+
+    // group_by without cascade is still valid
+    let query = world
+        .query::<&Position>()
+        .with::<Velocity>()
+        .group_by::<Velocity>()
+        .try_build();
+    assert!(query.is_ok());
+}
+
+#[test]
+fn query_rust_validate_system_observer() {
+    let world = World::new();
+
+    let mut system = world.system::<&Position>();
+    system.with_name("DoesNotExist");
+    assert_eq!(system.validate().unwrap_err().term_index(), Some(1));
+
+    let mut observer = world.observer::<flecs::OnSet, &Position>();
+    assert!(observer.validate().is_ok());
+    observer.with_name("DoesNotExist");
+    assert_eq!(observer.validate().unwrap_err().term_index(), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "failed to create system")]
+fn query_rust_invalid_system_panics() {
+    let world = World::new();
+
+    world
+        .system::<&Position>()
+        .with_name("DoesNotExist")
+        .each(|_| {});
+}
+
+#[test]
+#[should_panic(expected = "failed to create observer")]
+fn query_rust_invalid_observer_panics() {
+    let world = World::new();
+
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .with_name("DoesNotExist")
+        .each(|_| {});
+}
+
+#[test]
+fn query_rust_worker_iter() {
+    let world = World::new();
+
+    for i in 0..1000 {
+        let e = world.entity().set(Position { x: i, y: 0 });
+        // spread the entities over a few tables
+        if i % 3 == 0 {
+            e.set(Velocity { x: 1, y: 1 });
+        }
+        if i % 7 == 0 {
+            e.add::<Tag>();
+        }
+    }
+
+    let query = world.new_query::<&mut Position>();
+
+    let mut visits = vec![0; 1000];
+    let mut per_worker = Vec::new();
+    for index in 0..4 {
+        let mut count = 0;
+        query.iterable().worker(index, 4).each(|pos| {
+            visits[pos.x as usize] += 1;
+            pos.y += 1;
+            count += 1;
+        });
+        per_worker.push(count);
+    }
+
+    assert!(visits.iter().all(|&v| v == 1));
+    assert!(per_worker.iter().all(|&c| c > 0));
+    query.each(|pos| assert_eq!(pos.y, 1));
+
+    // the chained iterator also works with row iteration
+    let mut count = 0;
+    let mut rows = query.iterable().worker(1, 4).rows();
+    while let Some((_, pos)) = rows.next_row() {
+        assert_eq!(pos.y, 1);
+        count += 1;
+    }
+    assert_eq!(count, per_worker[1]);
+}
+
+#[test]
+fn query_rust_page_iter() {
+    let world = World::new();
+
+    for i in 0..20 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let query = world.new_query::<&Position>();
+
+    let mut all = Vec::new();
+    query.each(|pos| all.push(pos.x));
+
+    let mut page = Vec::new();
+    query
+        .iterable()
+        .page(5, 10)
+        .each_entity(|_, pos| page.push(pos.x));
+    assert_eq!(page, all[5..15]);
+
+    let mut count = 0;
+    query.iterable().page(15, 10).each(|_| count += 1);
+    assert_eq!(count, 5);
+}
+
+#[test]
+fn query_rust_page_worker_rows() {
+    let world = World::new();
+
+    for i in 0..20 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let query = world.new_query::<&Position>();
+
+    let mut all = Vec::new();
+    query.each(|pos| all.push(pos.x));
+
+    let mut each = Vec::new();
+    query
+        .iterable()
+        .page(2, 12)
+        .worker(0, 2)
+        .each(|pos| each.push(pos.x));
+    assert_eq!(each, all[2..8]);
+
+    let mut rows = query.iterable().page(2, 12).worker(0, 2).rows();
+    let mut xs = Vec::new();
+    while let Some((_, pos)) = rows.next_row() {
+        xs.push(pos.x);
+    }
+    assert_eq!(xs, each);
+}
+
+#[test]
+fn query_rust_run_early_return() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 1, y: 1 });
+
+    let query = world.new_query::<&Position>();
+
+    query.run(|_| {});
+    query.run(|mut it| {
+        assert!(it.next());
+    });
+    query.run(|mut it| {
+        while it.next() {}
+        assert!(!it.next());
+    });
+    query.run(|mut it| {
+        it.next();
+        it.fini();
+    });
+
+    // the table isn't left locked
+    world.entity().set(Position { x: 5, y: 6 });
+    let mut count = 0;
+    query.each(|_| count += 1);
+    assert_eq!(count, 3);
+}
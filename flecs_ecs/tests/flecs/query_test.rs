@@ -308,3 +308,91 @@ fn query_iter_targets_field_not_set() {
         it.targets(1, |_| {});
     });
 }
+
+#[test]
+fn query_destruct_entities() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+    let e3 = world.entity().set(Velocity { x: 1, y: 2 });
+
+    let q = world.new_query::<&Position>();
+    assert_eq!(q.count(), 2);
+
+    q.destruct_entities();
+
+    assert_eq!(q.count(), 0);
+    assert!(!e1.is_alive());
+    assert!(!e2.is_alive());
+    assert!(e3.is_alive());
+}
+
+#[test]
+fn query_destruct_entities_while_deferred() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+
+    let q = world.new_query::<&Position>();
+
+    world.defer_begin();
+    q.destruct_entities();
+    assert!(e1.is_alive());
+    assert!(e2.is_alive());
+    world.defer_end();
+
+    assert_eq!(q.count(), 0);
+    assert!(!e1.is_alive());
+    assert!(!e2.is_alive());
+}
+
+#[test]
+fn query_each_entity_cmd_destruct() {
+    let world = World::new();
+
+    let entities: Vec<Entity> = (0..10)
+        .map(|i| world.entity().set(Position { x: i, y: i }).id())
+        .collect();
+
+    let q = world.new_query::<&Position>();
+    q.each_entity_cmd(|cmd, e, _| {
+        cmd.destruct(e);
+    });
+
+    assert_eq!(q.count(), 0);
+    for e in entities {
+        assert!(!world.is_alive(e));
+    }
+}
+
+#[test]
+fn query_each_entity_cmd_in_order() {
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+
+    world.new_query::<&Position>().each_entity_cmd(|cmd, e, p| {
+        // later commands override earlier commands for the same entity
+        cmd.add::<Tag>(e)
+            .set(e, Velocity { x: p.x, y: p.y })
+            .remove::<Tag>(e)
+            .set(e, Velocity { x: p.y, y: p.x });
+
+        if e == e2 {
+            cmd.add::<Tag>(e).destruct(e).add::<Tag>(e);
+        }
+    });
+
+    assert!(!e1.has::<Tag>());
+    e1.get::<&Velocity>(|v| {
+        assert_eq!(v.x, 2);
+        assert_eq!(v.y, 1);
+    });
+    assert!(!e2.is_alive());
+}
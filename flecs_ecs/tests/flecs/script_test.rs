@@ -0,0 +1,220 @@
+use flecs_ecs::prelude::*;
+
+const SHIP_SCRIPT: &str = "
+prefab SpaceShip {
+  Engine {}
+  Cockpit {
+    Pilot {}
+  }
+}
+
+Enterprise : SpaceShip {}
+";
+
+#[test]
+fn script_run_prefab_hierarchy() {
+    let world = World::new();
+
+    world.script().code(SHIP_SCRIPT).run().unwrap();
+
+    let ship = world.lookup("SpaceShip");
+    assert!(ship.has::<flecs::Prefab>());
+
+    let engine = world.lookup("SpaceShip::Engine");
+    assert!(engine.has_first::<flecs::ChildOf>(ship));
+    assert!(engine.has::<flecs::Prefab>());
+
+    let pilot = world.lookup("SpaceShip::Cockpit::Pilot");
+    assert_eq!(pilot.parent().unwrap(), world.lookup("SpaceShip::Cockpit"));
+
+    let enterprise = world.lookup("Enterprise");
+    assert!(enterprise.has_first::<flecs::IsA>(ship));
+    assert!(!enterprise.has::<flecs::Prefab>());
+}
+
+#[test]
+fn script_run_error_line() {
+    let world = World::new();
+
+    let err = world
+        .script()
+        .filename("ships.flecs")
+        .code("Ship {\n  Engine {}\n  Cockpit : {}\n}\n")
+        .run()
+        .unwrap_err();
+
+    assert_eq!(err.line(), Some(3), "{}", err);
+}
+
+#[test]
+fn script_run_missing_file() {
+    let world = World::new();
+
+    assert!(world
+        .script()
+        .filename("does_not_exist.flecs")
+        .run()
+        .is_err());
+}
+
+#[test]
+fn script_load_managed_update() {
+    let world = World::new();
+
+    let e = world.entity_named("ships");
+    let script = world.script().code(SHIP_SCRIPT).entity(e).load().unwrap();
+    assert_eq!(script.id(), e.id());
+    assert!(e.has::<flecs::Script>());
+    assert!(world.try_lookup("Enterprise").is_some());
+
+    script
+        .update(&world, None::<Entity>, "Voyager : SpaceShip {}")
+        .unwrap_err();
+
+    script
+        .update(
+            &world,
+            None::<Entity>,
+            "prefab SpaceShip {}\nVoyager : SpaceShip {}",
+        )
+        .unwrap();
+    assert!(world.try_lookup("Voyager").is_some());
+    assert!(world.try_lookup("Enterprise").is_none());
+
+    let err = script
+        .update(&world, None::<Entity>, "prefab SpaceShip {}\nVoyager : {}")
+        .unwrap_err();
+    assert_eq!(err.line(), Some(2), "{}", err);
+}
+
+#[test]
+fn script_template_instantiate() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Height {
+        value: f32,
+    }
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct TreeProps {
+        height: f32,
+    }
+
+    world.component_named::<Height>("Height").meta();
+    world.component::<TreeProps>().meta();
+
+    world
+        .script()
+        .code(
+            "
+template Tree {
+  prop height = f32: 10
+
+  Trunk {
+    Height: {height}
+  }
+
+  if height > 5 {
+    Crown {}
+  }
+}
+",
+        )
+        .run()
+        .unwrap();
+
+    let tree = world.lookup("Tree");
+
+    let small = world
+        .entity_named("small")
+        .set_id(TreeProps { height: 3.0 }, tree);
+
+    let tall = world.entity_named("tall");
+    let mut cur = tall.cursor_id(tree);
+    cur.push().unwrap();
+    cur.member("height").unwrap();
+    cur.set_float(8.0).unwrap();
+    cur.pop().unwrap();
+    tall.modified_id(tree);
+
+    world.lookup("small::Trunk").get::<&Height>(|h| {
+        assert!((h.value - 3.0).abs() < f32::EPSILON);
+    });
+    world.lookup("tall::Trunk").get::<&Height>(|h| {
+        assert!((h.value - 8.0).abs() < f32::EPSILON);
+    });
+    assert!(world.try_lookup("small::Crown").is_none());
+    assert!(world.try_lookup("tall::Crown").is_some());
+
+    assert!(small.has_id(tree));
+    assert!(!small.has::<TreeProps>());
+}
+
+#[test]
+fn script_vars_expr() {
+    let world = World::new();
+
+    let mut vars = ScriptVars::new(&world);
+    vars.var("speed", 5.0f32);
+
+    let result = world.from_expr_w_vars::<f32>("$speed * 2", &vars).unwrap();
+    assert!((result - 10.0).abs() < f32::EPSILON);
+
+    assert!(world
+        .from_expr_w_vars::<f32>("$missing * 2", &vars)
+        .is_err());
+}
+
+#[test]
+fn script_vars_run() {
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Speed {
+        value: f32,
+    }
+
+    world.component_named::<Speed>("Speed").meta();
+    let fleet = world.entity_named("Fleet");
+
+    let mut vars = ScriptVars::new(&world);
+    vars.var("speed", 5.0f32).var_id("parent", fleet);
+
+    world
+        .script()
+        .code("Ship {\n  (ChildOf, $parent)\n  Speed: {$speed * 2}\n}")
+        .run_w_vars(&vars)
+        .unwrap();
+
+    let ship = world.lookup("Fleet::Ship");
+    ship.get::<&Speed>(|speed| {
+        assert!((speed.value - 10.0).abs() < f32::EPSILON);
+    });
+}
+
+#[test]
+fn script_vars_drop_values() {
+    let world = World::new();
+
+    #[derive(Component)]
+    struct Holder {
+        count: std::sync::Arc<()>,
+    }
+
+    let count = std::sync::Arc::new(());
+    {
+        let mut vars = ScriptVars::new(&world);
+        vars.var(
+            "holder",
+            Holder {
+                count: count.clone(),
+            },
+        );
+        assert_eq!(std::sync::Arc::strong_count(&count), 2);
+    }
+    assert_eq!(std::sync::Arc::strong_count(&count), 1);
+}
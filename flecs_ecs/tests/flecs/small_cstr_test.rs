@@ -0,0 +1,79 @@
+use flecs_ecs::prelude::*;
+use flecs_ecs::sys;
+
+#[test]
+fn small_cstr_boundary_lengths() {
+    for (len, inline) in [(0, true), (127, true), (128, false), (129, false)] {
+        let s = "a".repeat(len);
+        let c = SmallCStr::<128>::new(&s).unwrap();
+
+        assert_eq!(c.is_inline(), inline, "length {}", len);
+        assert_eq!(c.len(), len);
+        assert_eq!(c.is_empty(), len == 0);
+        assert_eq!(c.as_str(), s);
+        assert_eq!(c.as_c_str().to_bytes(), s.as_bytes());
+        assert_eq!(
+            unsafe { std::ffi::CStr::from_ptr(c.as_ptr()) }.to_bytes_with_nul(),
+            format!("{}\0", s).as_bytes()
+        );
+    }
+}
+
+#[test]
+fn small_cstr_multibyte() {
+    // 42 three byte characters: 126 bytes, the nul terminator still fits inline
+    let s = "€".repeat(42);
+    let c = SmallCStr::<128>::new(&s).unwrap();
+    assert!(c.is_inline());
+    assert_eq!(c.as_str(), s);
+    assert_eq!(c.to_string(), s);
+}
+
+#[test]
+fn small_cstr_embedded_nul() {
+    let err = SmallCStr::<128>::new("Bo\0b").unwrap_err();
+    assert_eq!(err.nul_position(), 2);
+
+    let long = format!("{}\0", "a".repeat(200));
+    let err = SmallCStr::<128>::new(&long).unwrap_err();
+    assert_eq!(err.nul_position(), 200);
+
+    assert_eq!(SmallCStr::<128>::new_truncated("Bo\0b").as_str(), "Bo");
+    assert_eq!(SmallCStr::<128>::new_truncated("Bob").as_str(), "Bob");
+}
+
+#[test]
+fn small_cstr_name_wrappers() {
+    let world = World::new();
+
+    for len in [1, 127, 128, 129, 300] {
+        let name = "n".repeat(len);
+        let e = world.entity_named(&name);
+
+        assert_eq!(e.name(), name);
+        assert_eq!(world.lookup(&name), e);
+
+        let renamed = format!("{}x", name);
+        e.set_name(&renamed);
+        assert_eq!(world.try_lookup(&renamed), Some(e));
+        assert!(world.try_lookup(&name).is_none());
+    }
+}
+
+#[test]
+fn small_cstr_sys_call() {
+    let world = World::new();
+    let e = world.entity_named("Bob");
+
+    let name = SmallCStr::<128>::new("Bob").unwrap();
+    let found = unsafe { sys::ecs_lookup(world.ptr_mut(), name.as_ptr()) };
+    assert_eq!(found, *e.id());
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "flecs_force_enable_ecs_asserts"))]
+#[should_panic]
+fn small_cstr_wrapper_rejects_embedded_nul() {
+    let world = World::new();
+    world.entity_named("Bo\0b");
+}
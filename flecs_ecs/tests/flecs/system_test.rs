@@ -1526,73 +1526,106 @@ fn system_test_let_defer_iter() {
     });
 }
 
-#[ignore = "pre,on,post frame is internal only, replace or delete this test"]
+#[derive(Component)]
+struct PreFrame;
+#[derive(Component)]
+struct OnFrame;
+#[derive(Component)]
+struct PostFrame;
+#[derive(Component)]
+struct FrameTag;
+
 #[test]
 fn system_custom_pipeline() {
-    // let world = World::new();
+    let world = World::new();
 
-    // let mut preFrame = world.entity().add::<flecs::pipeline::Phase>();
-    // let OnFrame = world.entity().add::<flecs::pipeline::Phase>().depends_on(PreFrame);
-    // let mut postFrame = world.entity().add::<flecs::pipeline::Phase>().depends_on(OnFrame);
-    // let tag = world.entity();
+    world
+        .component::<PreFrame>()
+        .add::<flecs::pipeline::Phase>();
+    world
+        .component::<OnFrame>()
+        .add::<flecs::pipeline::Phase>()
+        .depends_on::<PreFrame>();
+    world
+        .component::<PostFrame>()
+        .add::<flecs::pipeline::Phase>()
+        .depends_on::<OnFrame>();
 
-    // let pip = world.pipeline()
-    //     .with::<flecs::system::System>()
-    //     .with(flecs::pipeline::Phase).cascade(flecs::DependsOn)
-    //     .with(Tag)
-    //     .build();
+    let pip = world
+        .pipeline()
+        .with::<flecs::system::System>()
+        .with::<flecs::pipeline::Phase>()
+        .cascade_type::<flecs::DependsOn>()
+        .with::<FrameTag>()
+        .build()
+        .id();
 
-    // int count = 0;
+    world.set(Count(0));
 
-    // world.system::<()>()
-    //     .kind(PostFrame)
-    //     .run(|mut it| {
-    //         while it.next() {
-    //                 world.get::<&Count>(|c| {
-    //                 assert_eq!(c.0,2);
-    //             });
-    //             world.get::<&mut Count>(|c| {
-    //                 c.0 += 1;
-    //             });
-    //         }
-    //     })
-    //     .add(Tag);
+    // systems are declared in the reverse order of their phases
+    world
+        .system::<()>()
+        .kind::<PostFrame>()
+        .run(|mut it| {
+            while it.next() {
+                it.world().get::<&mut Count>(|c| {
+                    assert_eq!(c.0, 2);
+                    c.0 += 1;
+                });
+            }
+        })
+        .add::<FrameTag>();
 
-    // world.system::<()>()
-    //     .kind(OnFrame)
-    //     .run(|mut it| {
-    //         while it.next() {
-    //             world.get::<&Count>(|c| {
-    //                 assert_eq!(c.0,1);
-    //             });
-    //             world.get::<&mut Count>(|c| {
-    //                 c.0 += 1;
-    //             });
-    //         }
-    //     })
-    //     .add(Tag);
+    world
+        .system::<()>()
+        .kind::<OnFrame>()
+        .run(|mut it| {
+            while it.next() {
+                it.world().get::<&mut Count>(|c| {
+                    assert_eq!(c.0, 1);
+                    c.0 += 1;
+                });
+            }
+        })
+        .add::<FrameTag>();
 
-    // world.system::<()>()
-    //     .kind(PreFrame)
-    //     .run(|mut it| {
-    //         while it.next() {
-    //             assert_eq!(count, 0);
-    //             world.get::<&mut Count>(|c| {
-    //                 c.0 += 1;
-    //             });
-    //         }
-    //     })
-    //     .add(Tag);
+    world
+        .system::<()>()
+        .kind::<PreFrame>()
+        .run(|mut it| {
+            while it.next() {
+                it.world().get::<&mut Count>(|c| {
+                    assert_eq!(c.0, 0);
+                    c.0 += 1;
+                });
+            }
+        })
+        .add::<FrameTag>();
+
+    // not part of the pipeline
+    world.system::<()>().kind::<OnFrame>().run(|_| {
+        panic!("system without FrameTag should not run");
+    });
 
-    // assert_eq!(count, 0);
+    world.get::<&Count>(|c| {
+        assert_eq!(c.0, 0);
+    });
 
-    // world.set_pipeline_id(pip);
+    world.set_pipeline_id(pip);
+    assert_eq!(world.get_pipeline(), pip);
 
-    // world.progress();
+    world.progress();
 
-    //     world.get::<&Count>(|c| {
-    //                 assert_eq!(c.0,3);
-    //             });
+    world.get::<&Count>(|c| {
+        assert_eq!(c.0, 3);
+    });
+
+    world.set(Count(0));
+    world.run_pipeline_id_time(pip, 1.0);
+
+    world.get::<&Count>(|c| {
+        assert_eq!(c.0, 3);
+    });
 }
 
 #[test]
@@ -2435,3 +2468,620 @@ fn system_register_twice_w_each_run() {
         assert_eq!(count.b, 1);
     });
 }
+
+#[test]
+fn system_pipeline_plan() {
+    let world = World::new();
+
+    let store = world
+        .system_named::<()>("Store")
+        .kind_id(flecs::pipeline::OnStore)
+        .run(|_| {});
+    let update = world.system_named::<()>("Update").run(|_| {});
+    let disabled = world.system_named::<()>("Disabled").run(|_| {});
+    let pre_update = world
+        .system_named::<()>("PreUpdate")
+        .kind_id(flecs::pipeline::PreUpdate)
+        .multi_threaded()
+        .run(|_| {});
+    disabled.disable_self();
+
+    let ids = [store.id(), update.id(), disabled.id(), pre_update.id()];
+
+    // builtin systems of the world are also part of the plan
+    let plan = world
+        .pipeline_plan()
+        .into_iter()
+        .filter_map(|report| {
+            let systems = report
+                .systems
+                .into_iter()
+                .filter(|(system, _, _)| ids.contains(&system.id()))
+                .collect::<Vec<_>>();
+            (!systems.is_empty()).then(|| (report.phase.unwrap().id(), systems))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        plan,
+        [
+            (
+                flecs::pipeline::PreUpdate::ID.into(),
+                vec![(*pre_update, true, true)]
+            ),
+            (
+                flecs::pipeline::OnUpdate::ID.into(),
+                vec![(*update, true, false), (*disabled, false, false)]
+            ),
+            (
+                flecs::pipeline::OnStore::ID.into(),
+                vec![(*store, true, false)]
+            ),
+        ]
+    );
+
+    // planning doesn't run systems
+    assert_eq!(world.info().systems_ran_frame, 0);
+
+    // the query of the plan is created once, and matches systems created later
+    let queries = world.count_id(ecs_pair(flecs::Poly::ID, flecs::Query::ID));
+    let late = world.system_named::<()>("Late").run(|_| {});
+    late.disable_self();
+    let plan = world.pipeline_plan();
+    assert!(plan
+        .iter()
+        .any(|report| report.systems.contains(&(*late, false, false))));
+    // the only new query is the one of the new system
+    assert_eq!(
+        world.count_id(ecs_pair(flecs::Poly::ID, flecs::Query::ID)),
+        queries + 1
+    );
+}
+
+#[test]
+#[cfg(feature = "flecs_pipeline_log")]
+fn system_last_run_log_skip_reasons() {
+    use flecs_ecs::addons::pipeline::{SkipReason, SystemRunStatus};
+
+    let world = World::new();
+
+    let ran = world.system_named::<()>("Ran").run(|_| {});
+    let disabled = world.system_named::<()>("Disabled").run(|_| {});
+    let interval = world
+        .system_named::<()>("Interval")
+        .set_interval(10.0)
+        .run(|_| {});
+    disabled.disable_self();
+
+    let ids = [ran.id(), disabled.id(), interval.id()];
+    let log = |world: &World| {
+        world
+            .last_run_log()
+            .iter()
+            .filter(|report| ids.contains(&report.system.id()))
+            .map(|report| report.status)
+            .collect::<Vec<_>>()
+    };
+
+    world.progress_time(1.0);
+
+    assert_eq!(
+        log(&world),
+        [
+            SystemRunStatus::Ran,
+            SystemRunStatus::Skipped(SkipReason::Disabled),
+            SystemRunStatus::Skipped(SkipReason::IntervalNotElapsed),
+        ]
+    );
+
+    world.progress_time(10.0);
+
+    assert_eq!(
+        log(&world),
+        [
+            SystemRunStatus::Ran,
+            SystemRunStatus::Skipped(SkipReason::Disabled),
+            SystemRunStatus::Ran,
+        ]
+    );
+}
+
+#[test]
+fn system_kind_phase_order() {
+    let world = World::new();
+
+    world.set(LastVal(0));
+    world.set(Count(0));
+
+    let e = world.entity().set(Position { x: 0, y: 0 });
+
+    // declared before the system it depends on, runs after it because of its phase
+    world
+        .system::<(&Position, &mut LastVal)>()
+        .term_at(1)
+        .singleton()
+        .kind::<flecs::pipeline::OnValidate>()
+        .each(|(p, last)| {
+            last.0 = p.x;
+        });
+
+    world
+        .system::<&mut Position>()
+        .kind::<flecs::pipeline::OnUpdate>()
+        .each(|p| {
+            p.x += 1;
+        });
+
+    let manual = world
+        .system::<&mut Count>()
+        .term_at(0)
+        .singleton()
+        .no_auto_add_phase()
+        .each(|c| {
+            c.0 += 1;
+        });
+
+    world.progress();
+
+    world.get::<&LastVal>(|last| assert_eq!(last.0, 1));
+    e.get::<&Position>(|p| assert_eq!(p.x, 1));
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    manual.run();
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+}
+
+#[test]
+fn system_enumerate_and_disable_by_name() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    let a = world
+        .system_named::<&mut Count>("SystemA")
+        .term_at(0)
+        .singleton()
+        .each(|c| c.0 += 1);
+    let b = world
+        .system_named::<&mut Count>("SystemB")
+        .term_at(0)
+        .singleton()
+        .each(|c| c.0 += 10);
+    let observer = world
+        .observer_named::<flecs::OnSet, &Position>("Observer")
+        .each(|_| {});
+
+    let mut ids = Vec::new();
+    world
+        .query::<()>()
+        .with::<flecs::system::System>()
+        .build()
+        .each_entity(|e, _| ids.push(e.id()));
+
+    let systems = ids
+        .iter()
+        .map(|&id| world.entity_from_id(id).try_as_system().unwrap())
+        .collect::<Vec<_>>();
+
+    let names = systems.iter().map(|s| s.name()).collect::<Vec<_>>();
+    assert!(names.contains(&"SystemA".to_string()));
+    assert!(names.contains(&"SystemB".to_string()));
+    assert!(!names.contains(&"Observer".to_string()));
+
+    let system_b = systems.iter().find(|s| s.name() == "SystemB").unwrap();
+    assert_eq!(system_b.entity(), *b);
+    assert_eq!(EntityView::from(*system_b), *b);
+    assert_eq!(format!("{}", system_b), format!("{}", b.entity()));
+    system_b.disable_self();
+
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+
+    system_b.run();
+    world.get::<&Count>(|c| assert_eq!(c.0, 11));
+
+    assert!(a.entity().try_as_observer().is_none());
+    assert!(observer.entity().try_as_system().is_none());
+    let observer = observer.entity().try_as_observer().unwrap();
+    assert_eq!(observer.name(), "Observer");
+    assert_eq!(EntityView::from(observer), observer.entity());
+}
+
+fn query_entity_count(world: &World) -> i32 {
+    let mut count = 0;
+    world
+        .query::<()>()
+        .with_id((flecs::Poly::ID, flecs::Query::ID))
+        .without::<flecs::system::System>()
+        .build()
+        .each(|_| count += 1);
+    count
+}
+
+#[test]
+fn system_shared_query() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 10, y: 20 });
+    world.entity().set(Position { x: 30, y: 40 });
+    world.entity().set(Velocity { x: 1, y: 2 });
+
+    let count = query_entity_count(&world);
+    let query = world.query::<&mut Position>().set_cached().build();
+    assert_eq!(query_entity_count(&world), count + 1);
+
+    world
+        .system::<&mut Position>()
+        .set_query(&query)
+        .each(|p| p.x += 1);
+
+    world
+        .system::<&mut Position>()
+        .set_query(&query)
+        .run(|mut it| {
+            while it.next() {
+                let mut p = it.field::<Position>(0).unwrap();
+                for i in it.iter() {
+                    p[i].y += 1;
+                }
+            }
+        });
+
+    assert_eq!(query_entity_count(&world), count + 1);
+
+    world.progress();
+    world.progress();
+
+    let mut positions = Vec::new();
+    query.each(|p| positions.push((p.x, p.y)));
+    assert_eq!(positions, vec![(12, 22), (32, 42)]);
+}
+
+#[test]
+fn system_shared_query_match_new_table() {
+    let world = World::new();
+
+    let query = world.query::<&Position>().set_cached().build();
+
+    world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Mass { value: 1 });
+
+    let system = world
+        .system::<&Position>()
+        .set_query(&query)
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|c| c.0 += 1);
+        });
+
+    world.set(Count(0));
+    system.run();
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+
+    world.entity().set(Position { x: 30, y: 40 });
+    system.run();
+    world.get::<&Count>(|c| assert_eq!(c.0, 3));
+}
+
+#[test]
+fn system_write_term_inserts_sync_point() {
+    let world = World::new();
+
+    world.entity().set(Velocity { x: 10, y: 20 });
+    world.set(Count(0));
+
+    // Position isn't matched, the write term only tells the scheduler it's written
+    world
+        .system::<&Velocity>()
+        .write::<Position>()
+        .each_entity(|e, v| {
+            e.set(Position { x: v.x, y: v.y });
+        });
+
+    world.system::<&Position>().each_entity(|e, p| {
+        e.world().get::<&mut Count>(|c| c.0 += p.x);
+    });
+
+    // the deferred set is merged before the second system runs
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 10));
+}
+
+#[test]
+fn system_run_manual_next() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 3, y: 4 });
+    world
+        .entity()
+        .set(Position { x: 5, y: 6 })
+        .set(Velocity { x: 1, y: 1 });
+    world.set(Count(0));
+
+    let system = world.system::<&mut Position>().run(|mut it| {
+        assert_eq!(it.delta_time(), 2.0);
+        assert_eq!(it.delta_system_time(), 2.0);
+        let system = it.system();
+        assert!(system.is_valid());
+
+        let mut tables = 0;
+        while it.next() {
+            tables += 1;
+            let mut pos = it.field::<Position>(0).unwrap();
+            for i in it.iter() {
+                pos[i].x += 10;
+            }
+        }
+        it.world().get::<&mut Count>(|c| c.0 = tables);
+    });
+
+    world.progress_time(2.0);
+
+    world.get::<&Count>(|c| assert_eq!(c.0, 2));
+    let mut xs = Vec::new();
+    world.new_query::<&Position>().each(|p| xs.push(p.x));
+    xs.sort();
+    assert_eq!(xs, vec![11, 13, 15]);
+
+    // running the system outside of the pipeline uses the same callback
+    system.run_dt(2.0);
+    world.get::<&Count>(|c| assert_eq!(c.0, 2));
+}
+
+#[test]
+fn system_run_early_return() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 1, y: 1 });
+    world.set(Count(0));
+
+    // skips iterating entirely while the counter is odd
+    world.system::<&Position>().run(|mut it| {
+        let skip = it.world().get::<&mut Count>(|c| {
+            c.0 += 1;
+            c.0 % 2 == 1
+        });
+        if skip {
+            return;
+        }
+        while it.next() {}
+    });
+
+    // stops after the first table
+    world.system::<&Position>().run(|mut it| {
+        if it.next() {
+            assert!(it.count() > 0);
+        }
+    });
+
+    for _ in 0..4 {
+        world.progress();
+    }
+
+    world.get::<&Count>(|c| assert_eq!(c.0, 4));
+}
+
+#[test]
+fn system_run_with_param() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 3, y: 4 });
+    world.set(Count(0));
+
+    let system = world.system::<&Position>().run(|mut it| {
+        while it.next() {
+            let pos = it.field::<Position>(0).unwrap();
+            for i in it.iter() {
+                let pushed = it.run_param(|xs: &mut Vec<i32>| xs.push(pos[i].x));
+                if pushed.is_none() {
+                    it.world().get::<&mut Count>(|c| c.0 += 1);
+                }
+            }
+        }
+    });
+
+    let mut xs: Vec<i32> = Vec::new();
+    system.run_with(0.0, &mut xs);
+    xs.sort();
+    assert_eq!(xs, vec![1, 3]);
+
+    // the pipeline doesn't pass a param
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 2));
+
+    let system = world.system::<&Position>().each_iter(|it, _, pos| {
+        it.run_param(|sum: &mut i32| *sum += pos.y);
+    });
+
+    let mut sum = 0;
+    system.run_with(0.0, &mut sum);
+    assert_eq!(sum, 6);
+}
+
+#[test]
+#[should_panic(expected = "wasn't passed with `System::run_with`")]
+fn system_run_with_param_wrong_type() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let system = world.system::<&Position>().run(|mut it| {
+        it.run_param(|_: &mut String| {});
+        while it.next() {}
+    });
+
+    let mut xs: Vec<i32> = Vec::new();
+    system.run_with(0.0, &mut xs);
+}
+
+#[derive(Component, Default)]
+struct RunLog(Vec<&'static str>);
+
+#[test]
+fn system_before_after() {
+    let world = World::new();
+    world.set(RunLog::default());
+
+    let render = world
+        .system_named::<()>("Render")
+        .run(|it| it.world().get::<&mut RunLog>(|log| log.0.push("render")));
+    let movement = world
+        .system_named::<()>("Movement")
+        .before(render)
+        .run(|it| it.world().get::<&mut RunLog>(|log| log.0.push("movement")));
+    world
+        .system_named::<()>("Input")
+        .before_named("Movement")
+        .run(|it| it.world().get::<&mut RunLog>(|log| log.0.push("input")));
+    world
+        .system_named::<()>("Load")
+        .kind::<flecs::pipeline::PreUpdate>()
+        .run(|it| it.world().get::<&mut RunLog>(|log| log.0.push("load")));
+
+    world.progress();
+    world.get::<&mut RunLog>(|log| {
+        assert_eq!(log.0, ["load", "input", "movement", "render"]);
+        log.0.clear();
+    });
+
+    // constraints added later reorder the existing systems
+    world
+        .system_named::<()>("Physics")
+        .after(movement)
+        .before_named("Render")
+        .run(|it| it.world().get::<&mut RunLog>(|log| log.0.push("physics")));
+    world
+        .system_named::<()>("Audio")
+        .run(|it| it.world().get::<&mut RunLog>(|log| log.0.push("audio")));
+
+    world.progress();
+    world.get::<&RunLog>(|log| {
+        assert_eq!(
+            log.0,
+            ["load", "input", "movement", "physics", "render", "audio"]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "ordering cycle between systems")]
+fn system_before_after_cycle() {
+    let world = World::new();
+
+    let a = world.system_named::<()>("A").run(|_| {});
+    let b = world.system_named::<()>("B").after(a).run(|_| {});
+    world.system_named::<()>("C").after(b).before(a).run(|_| {});
+}
+
+#[test]
+#[should_panic(expected = "don't apply to the custom pipeline")]
+fn system_before_custom_pipeline() {
+    let world = World::new();
+
+    let pipeline = world
+        .pipeline_named("Custom")
+        .with::<flecs::system::System>()
+        .build();
+    world.set_pipeline_id(pipeline);
+
+    let a = world.system_named::<()>("A").run(|_| {});
+    world.system_named::<()>("B").before(a).run(|_| {});
+}
+
+#[test]
+fn system_disable_systems_with_tag() {
+    #[derive(Component)]
+    struct RenderSystem;
+
+    let world = World::new();
+    world.set(Count(0));
+
+    for _ in 0..3 {
+        world
+            .system::<()>()
+            .add::<RenderSystem>()
+            .run(|it| it.world().get::<&mut Count>(|c| c.0 += 1));
+    }
+    let other = world
+        .system::<()>()
+        .run(|it| it.world().get::<&mut Count>(|c| c.0 += 10));
+    assert!(!other.has::<RenderSystem>());
+
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 13));
+
+    world.disable_systems_with::<RenderSystem>();
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 23));
+
+    world.enable_systems_with::<RenderSystem>();
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 36));
+}
+
+#[test]
+fn system_builder_set_child_of() {
+    let world = World::new();
+    world.set(Count(0));
+
+    let group = world.entity_named("Group");
+    let system = world
+        .system_named::<()>("Move")
+        .set(Position { x: 1, y: 2 })
+        .child_of_id(group)
+        .run(|it| it.world().get::<&mut Count>(|c| c.0 += 1));
+
+    assert!(system.has_id((flecs::ChildOf::ID, group)));
+    assert_eq!(system.path().unwrap(), "::Group::Move");
+    system.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (1, 2)));
+
+    world.progress();
+    group.disable_self();
+    world.progress();
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+}
+
+#[test]
+fn system_stats_invoke_count() {
+    let world = World::new();
+    world.measure_system_time(true);
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 1, y: 1 });
+
+    let run = world.system::<()>().count_invocations().run(|_| {});
+    let each = world.system::<&Position>().count_invocations().each(|_| {});
+    let disabled = world.system::<()>().count_invocations().run(|_| {});
+    disabled.disable_self();
+    let uncounted = world.system::<()>().run(|_| {});
+
+    for _ in 0..3 {
+        world.progress();
+    }
+
+    assert_eq!(run.stats().invoke_count, 3);
+    // the system is invoked once per frame, not once per table
+    assert_eq!(each.stats().invoke_count, 3);
+    assert_eq!(disabled.stats().invoke_count, 0);
+    assert_eq!(uncounted.stats().invoke_count, 0);
+
+    each.run();
+    let stats = each.stats();
+    assert_eq!(stats.invoke_count, 4);
+    assert!(stats.time_spent >= stats.last_frame_time);
+
+    let systems = world.systems();
+    for system in [run, each, disabled] {
+        assert!(systems.iter().any(|s| s.id() == system.id()));
+    }
+}
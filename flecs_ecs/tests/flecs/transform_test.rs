@@ -0,0 +1,165 @@
+#![cfg(feature = "flecs_transform")]
+
+use flecs_ecs::addons::transform::*;
+use flecs_ecs::prelude::*;
+
+fn assert_near(actual: Option<[f32; 3]>, expected: [f32; 3]) {
+    let actual = actual.expect("entity has no world transform");
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+    }
+}
+
+fn recomputed(world: &World) -> u64 {
+    world.get::<&TransformStats>(|stats| stats.recomputed)
+}
+
+#[test]
+fn transform_propagate_hierarchy() {
+    let world = World::new();
+    world.import::<TransformModule>();
+
+    let root = world
+        .entity()
+        .set_local(Transform::from_translation([1.0, 0.0, 0.0]));
+    let child = world
+        .entity()
+        .child_of_id(root)
+        .set_local(Transform::from_translation([0.0, 1.0, 0.0]));
+    let grandchild = world
+        .entity()
+        .child_of_id(child)
+        .set_local(Transform::from_translation([0.0, 0.0, 1.0]));
+
+    world.progress();
+
+    assert_near(root.world_position(), [1.0, 0.0, 0.0]);
+    assert_near(child.world_position(), [1.0, 1.0, 0.0]);
+    assert_near(grandchild.world_position(), [1.0, 1.0, 1.0]);
+}
+
+#[test]
+fn transform_propagate_rotation_scale() {
+    let world = World::new();
+    world.import::<TransformModule>();
+
+    let root = world.entity().set_local(
+        Transform::from_rotation_z(std::f32::consts::FRAC_PI_2)
+            .with_translation([5.0, 0.0, 0.0])
+            .with_scale([2.0; 3]),
+    );
+    let child = world
+        .entity()
+        .child_of_id(root)
+        .set_local(Transform::from_translation([1.0, 0.0, 0.0]));
+
+    world.progress();
+
+    assert_near(child.world_position(), [5.0, 2.0, 0.0]);
+    assert_near(child.world_transform().map(|t| t.scale), [2.0; 3]);
+}
+
+#[test]
+fn transform_local_mutation() {
+    let world = World::new();
+    world.import::<TransformModule>();
+
+    let root = world
+        .entity()
+        .set_local(Transform::from_translation([1.0, 0.0, 0.0]));
+    let child = world
+        .entity()
+        .child_of_id(root)
+        .set_local(Transform::from_translation([1.0, 0.0, 0.0]));
+
+    world.progress();
+    assert_near(child.world_position(), [2.0, 0.0, 0.0]);
+
+    root.set_local(Transform::from_translation([10.0, 0.0, 0.0]));
+    world.progress();
+    assert_near(child.world_position(), [11.0, 0.0, 0.0]);
+
+    child.set_local(Transform::from_translation([0.0, 3.0, 0.0]));
+    world.progress();
+    assert_near(child.world_position(), [10.0, 3.0, 0.0]);
+}
+
+#[test]
+fn transform_reparent() {
+    let world = World::new();
+    world.import::<TransformModule>();
+
+    let a = world
+        .entity()
+        .set_local(Transform::from_translation([1.0, 0.0, 0.0]));
+    let b = world
+        .entity()
+        .set_local(Transform::from_translation([0.0, 5.0, 0.0]));
+    let child = world
+        .entity()
+        .child_of_id(a)
+        .set_local(Transform::from_translation([0.0, 0.0, 1.0]));
+
+    world.progress();
+    assert_near(child.world_position(), [1.0, 0.0, 1.0]);
+
+    child.child_of_id(b);
+    world.progress();
+    assert_near(child.world_position(), [0.0, 5.0, 1.0]);
+
+    child.remove_first::<flecs::ChildOf>(b);
+    world.progress();
+    assert_near(child.world_position(), [0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn transform_unchanged_subtree_skipped() {
+    let world = World::new();
+    world.import::<TransformModule>();
+
+    let a = world
+        .entity()
+        .set_local(Transform::from_translation([1.0, 0.0, 0.0]));
+    let a_child = world
+        .entity()
+        .child_of_id(a)
+        .set_local(Transform::from_translation([1.0, 0.0, 0.0]));
+    let a_leaf = world
+        .entity()
+        .child_of_id(a_child)
+        .set_local(Transform::IDENTITY);
+
+    let b = world
+        .entity()
+        .set_local(Transform::from_translation([0.0, 1.0, 0.0]));
+    let b_child = world
+        .entity()
+        .child_of_id(b)
+        .set_local(Transform::from_translation([0.0, 1.0, 0.0]));
+    let b_leaf = world
+        .entity()
+        .child_of_id(b_child)
+        .set_local(Transform::IDENTITY);
+
+    world.progress();
+    assert_eq!(recomputed(&world), 6);
+
+    // nothing changed
+    world.progress();
+    world.progress();
+    assert_eq!(recomputed(&world), 6);
+
+    // only the leaf is recomputed
+    a_leaf.set_local(Transform::from_translation([0.0, 0.0, 1.0]));
+    world.progress();
+    assert_eq!(recomputed(&world), 7);
+    assert_near(a_leaf.world_position(), [2.0, 0.0, 1.0]);
+
+    // the roots share a table, so both roots and their children are recomputed. The world
+    // transform of b_child stays the same, so the leaf of b is skipped.
+    a.set_local(Transform::from_translation([2.0, 0.0, 0.0]));
+    world.progress();
+    assert_eq!(recomputed(&world), 7 + 2 + 2 + 1);
+    assert_near(a_leaf.world_position(), [3.0, 0.0, 1.0]);
+    assert_near(b_leaf.world_position(), [0.0, 2.0, 0.0]);
+}
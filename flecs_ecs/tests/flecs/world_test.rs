@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+#![allow(clippy::float_cmp)]
 use flecs_ecs::prelude::*;
 use flecs_ecs::sys;
 
@@ -65,3 +66,1031 @@ fn world_finis_reentrancy() {
 
     // world will be destroyed here, and hook above will be called.
 }
+
+#[test]
+fn world_scope_nested_path() {
+    let world = World::new();
+
+    let earth = world.entity_named("Earth");
+    world.scope_id(earth, |world| {
+        let europe = world.entity_named("Europe");
+        world.scope_id(europe, |world| {
+            world.entity_named("Netherlands");
+        });
+        assert_eq!(world.get_scope().unwrap(), earth);
+    });
+
+    assert!(world.get_scope().is_none());
+
+    let netherlands = world.lookup("Earth::Europe::Netherlands");
+    assert_eq!(netherlands.path().unwrap(), "::Earth::Europe::Netherlands");
+    assert_eq!(netherlands.parent().unwrap(), world.lookup("Earth::Europe"));
+}
+
+#[test]
+fn world_scope_restore_on_panic() {
+    let world = World::new();
+
+    let parent = world.entity_named("parent");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.scope_id(parent, |_| panic!("panic in scope"));
+    }));
+
+    assert!(result.is_err());
+    assert!(world.get_scope().is_none());
+}
+
+#[test]
+fn world_with_id_restore_on_panic() {
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    world.with::<Tag>(|| {
+        assert!(world.entity().has::<Tag>());
+    });
+    assert!(!world.entity().has::<Tag>());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.with::<Tag>(|| panic!("panic in with"));
+    }));
+
+    assert!(result.is_err());
+    assert!(!world.entity().has::<Tag>());
+}
+
+#[test]
+fn world_delete_entities_with_childof() {
+    let world = World::new();
+
+    let parent = world.entity();
+    let child_1 = world.entity().child_of_id(parent);
+    let child_2 = world.entity().child_of_id(parent);
+    let other = world.entity();
+
+    let q = world
+        .query::<()>()
+        .with_first::<flecs::ChildOf>(parent)
+        .build();
+    assert_eq!(q.count(), 2);
+
+    world.delete_entities_with_first::<flecs::ChildOf>(parent);
+
+    assert_eq!(q.count(), 0);
+    assert!(!child_1.is_alive());
+    assert!(!child_2.is_alive());
+    assert!(parent.is_alive());
+    assert!(other.is_alive());
+}
+
+#[test]
+fn world_delete_entities_with_deferred() {
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    let e1 = world.entity().add::<Tag>();
+    let e2 = world.entity().add::<Tag>();
+
+    world.defer_begin();
+    world.delete_entities_with::<Tag>();
+    assert!(e1.is_alive());
+    assert!(e2.is_alive());
+    world.defer_end();
+
+    assert!(!e1.is_alive());
+    assert!(!e2.is_alive());
+    assert_eq!(world.count::<Tag>(), 0);
+}
+
+#[test]
+fn world_remove_all() {
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1.0, y: 2.0 });
+    let e2 = world.entity().set(Position { x: 3.0, y: 4.0 });
+
+    let q = world.new_query::<&Position>();
+    assert_eq!(q.count(), 2);
+
+    world.defer(|| {
+        world.remove_all::<Position>();
+    });
+
+    assert_eq!(q.count(), 0);
+    assert!(e1.is_alive());
+    assert!(e2.is_alive());
+    assert!(!e1.has::<Position>());
+    assert!(!e2.has::<Position>());
+}
+
+#[test]
+fn world_remove_all_wildcard_pair() {
+    #[derive(Component)]
+    struct Likes;
+
+    let world = World::new();
+
+    let apples = world.entity();
+    let pears = world.entity();
+    let e1 = world.entity().add_first::<Likes>(apples);
+    let e2 = world.entity().add_first::<Likes>(pears);
+
+    world.remove_all_first::<Likes>(flecs::Wildcard::ID);
+
+    assert!(!e1.has_first::<Likes>(apples));
+    assert!(!e2.has_first::<Likes>(pears));
+    assert_eq!(world.count_first::<Likes>(flecs::Wildcard::ID), 0);
+}
+
+#[test]
+fn world_on_merge_reports_set_commands() {
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+
+    for _ in 0..1000 {
+        world.entity().set(Position { x: 1.0, y: 2.0 });
+    }
+
+    world.system::<&Position>().each_entity(|e, p| {
+        e.set(Velocity { x: p.x, y: p.y });
+    });
+
+    let infos = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let infos_hook = infos.clone();
+    let handle = world.on_merge(move |info| infos_hook.borrow_mut().push(info));
+
+    world.progress();
+
+    assert_eq!(world.count::<Velocity>(), 1000);
+    assert_eq!(infos.borrow().len(), 1);
+    let info = infos.borrow()[0];
+    assert!(info.merge_count >= 1);
+    assert!(info.set_count >= 1000);
+    assert!(info.merge_time > 0.0);
+
+    assert!(world.remove_on_merge(handle));
+    assert!(!world.remove_on_merge(handle));
+
+    world.progress();
+    assert_eq!(infos.borrow().len(), 1);
+}
+
+#[test]
+fn world_on_merge_remove_in_hook() {
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+    let e = world.entity();
+
+    let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+    let handle = std::rc::Rc::new(std::cell::Cell::new(None));
+
+    let (calls_hook, handle_hook) = (calls.clone(), handle.clone());
+    let world_ptr = world.ptr_mut();
+    handle.set(Some(world.on_merge(move |_| {
+        calls_hook.set(calls_hook.get() + 1);
+        let world = unsafe { WorldRef::from_ptr(world_ptr) }.world();
+        assert!(world.remove_on_merge(handle_hook.get().unwrap()));
+    })));
+
+    for x in 0..2 {
+        let stage = world.create_async_stage();
+        e.mut_current_stage(&stage).set(Position {
+            x: x as f32,
+            y: 0.0,
+        });
+        stage.merge();
+    }
+
+    assert_eq!(calls.get(), 1);
+    assert!(!world.remove_on_merge(handle.get().unwrap()));
+}
+
+#[test]
+fn world_on_merge_panicking_hook_keeps_hooks() {
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+    let e = world.entity();
+
+    let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+    let calls_hook = calls.clone();
+    let counter = world.on_merge(move |_| calls_hook.set(calls_hook.get() + 1));
+    let panicking = world.on_merge(|_| panic!("merge hook failed"));
+
+    let stage = world.create_async_stage();
+    e.mut_current_stage(&stage).set(Position { x: 1.0, y: 0.0 });
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| stage.merge()));
+    assert!(result.is_err());
+    assert_eq!(calls.get(), 1);
+
+    assert!(world.remove_on_merge(panicking));
+
+    let stage = world.create_async_stage();
+    e.mut_current_stage(&stage).set(Position { x: 2.0, y: 0.0 });
+    stage.merge();
+    assert_eq!(calls.get(), 2);
+
+    assert!(world.remove_on_merge(counter));
+}
+
+#[test]
+fn world_count() {
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component)]
+    struct Tag;
+
+    #[derive(Component)]
+    struct Likes;
+
+    let world = World::new();
+
+    let apples = world.entity();
+    let pears = world.entity();
+
+    world.entity().set(Position { x: 0.0, y: 0.0 }).add::<Tag>();
+    world
+        .entity()
+        .set(Position { x: 0.0, y: 0.0 })
+        .add_first::<Likes>(apples);
+    world.entity().add::<Tag>().add_first::<Likes>(pears);
+    world.entity().add_first::<Likes>(pears);
+
+    assert_eq!(world.count::<Position>(), 2);
+    assert_eq!(world.count::<Tag>(), 2);
+    assert_eq!(world.count_id(world.component_id::<Tag>()), 2);
+    assert_eq!(world.count_first::<Likes>(apples), 1);
+    assert_eq!(world.count_first::<Likes>(pears), 2);
+    assert_eq!(world.count_first::<Likes>(flecs::Wildcard::ID), 3);
+    assert_eq!(world.count_id((flecs::Wildcard::ID, pears)), 2);
+}
+
+#[test]
+fn world_count_enum_constant() {
+    #[repr(C)]
+    #[derive(Component)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[derive(Component)]
+    struct Paint;
+
+    let world = World::new();
+
+    world.entity().add_enum(Color::Red);
+    world.entity().add_enum(Color::Red);
+    world.entity().add_enum(Color::Blue);
+    world.entity().add_pair_enum::<Paint, _>(Color::Green);
+
+    assert_eq!(world.count_enum(Color::Red), 2);
+    assert_eq!(world.count_enum(Color::Green), 0);
+    assert_eq!(world.count_enum(Color::Blue), 1);
+    assert_eq!(world.count_enum_tag_pair::<Paint, _>(Color::Green), 1);
+    assert_eq!(world.count_first::<Color>(flecs::Wildcard::ID), 3);
+
+    world.remove_all_enum(Color::Red);
+    assert_eq!(world.count_enum(Color::Red), 0);
+    assert_eq!(world.count_enum(Color::Blue), 1);
+
+    world.with_enum(Color::Green, || {
+        world.entity();
+    });
+    assert_eq!(world.count_enum(Color::Green), 1);
+
+    world.delete_with_enum(Color::Green);
+    assert_eq!(world.count_enum(Color::Green), 0);
+}
+
+#[test]
+fn world_count_includes_disabled() {
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+
+    let e1 = world.entity().add::<Tag>();
+    world.entity().add::<Tag>();
+
+    assert_eq!(world.count::<Tag>(), 2);
+
+    e1.disable_self();
+
+    // count matches the C semantics, which don't filter disabled entities
+    assert_eq!(world.count::<Tag>(), 2);
+}
+
+#[test]
+fn world_async_stages_on_threads() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    const THREADS: usize = 4;
+    const PER_THREAD: usize = 100;
+
+    let world = World::new();
+    world.component::<Position>();
+
+    // creating entity ids is not thread safe, so the ids are created upfront.
+    let entities: Vec<Entity> = (0..THREADS * PER_THREAD)
+        .map(|_| world.entity().id())
+        .collect();
+
+    let stages: Vec<Stage> = (0..THREADS).map(|_| world.create_async_stage()).collect();
+    for stage in &stages {
+        assert!(stage.world().is_stage());
+    }
+
+    let stages: Vec<Stage> = std::thread::scope(|s| {
+        let handles: Vec<_> = stages
+            .into_iter()
+            .zip(entities.chunks(PER_THREAD))
+            .enumerate()
+            .map(|(i, (stage, chunk))| {
+                s.spawn(move || {
+                    for (j, e) in chunk.iter().enumerate() {
+                        EntityView::new_from(&stage, *e).set(Position {
+                            x: i as i32,
+                            y: j as i32,
+                        });
+                    }
+                    stage
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    // commands of async stages are only applied after merging
+    assert_eq!(world.count::<Position>(), 0);
+
+    for stage in &stages {
+        stage.merge();
+    }
+    drop(stages);
+
+    assert_eq!(world.count::<Position>(), (THREADS * PER_THREAD) as i32);
+    for (i, chunk) in entities.chunks(PER_THREAD).enumerate() {
+        for (j, e) in chunk.iter().enumerate() {
+            world.entity_from_id(*e).get::<&Position>(|p| {
+                assert_eq!(p.x, i as i32);
+                assert_eq!(p.y, j as i32);
+            });
+        }
+    }
+}
+
+#[test]
+fn world_readonly_scope() {
+    #[derive(Component)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    let world = World::new();
+    world.set_stage_count(2);
+    assert!(!world.is_readonly());
+
+    // components can't be registered while readonly
+    world.component::<Position>();
+
+    let e = world.entity();
+    {
+        let _readonly = world.readonly_scope(true);
+        assert!(world.is_readonly());
+
+        let stage = world.stage(1);
+        assert!(!stage.is_readonly());
+        e.mut_current_stage(stage).set(Position { x: 1, y: 2 });
+        assert!(!e.has::<Position>());
+    }
+
+    assert!(!world.is_readonly());
+    assert!(e.has::<Position>());
+}
+
+#[test]
+fn world_readonly_scope_restore_on_panic() {
+    let world = World::new();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _readonly = world.readonly_scope(false);
+        panic!("panic while readonly");
+    }));
+
+    assert!(result.is_err());
+    assert!(!world.is_readonly());
+}
+
+#[test]
+#[should_panic(expected = "cannot use readonly world/stage to create mutable handle")]
+fn world_readonly_mut_current_stage_world() {
+    let world = World::new();
+    let e = world.entity();
+
+    let _readonly = world.readonly_scope(false);
+    e.mut_current_stage(&world);
+}
+
+#[test]
+fn world_readonly_add_to_world_is_deferred() {
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+    world.component::<Tag>();
+    let e = world.entity();
+
+    {
+        let _readonly = world.readonly_scope(false);
+        e.add::<Tag>();
+        assert!(!e.has::<Tag>());
+    }
+
+    assert!(e.has::<Tag>());
+}
+
+#[test]
+#[should_panic(expected = "readonly mode")]
+fn world_readonly_progress() {
+    let _guard = flecs_ecs::log::panic_on_abort_scope(true);
+
+    let world = World::new();
+
+    let _readonly = world.readonly_scope(false);
+    world.progress();
+}
+
+#[derive(Component, Clone, Default, Debug, PartialEq)]
+struct RollbackPosition {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Clone, Default, Debug, PartialEq)]
+struct RollbackVelocity {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Clone, Default, Debug, PartialEq)]
+struct RollbackTrail {
+    points: Vec<f32>,
+}
+
+#[derive(Component, Clone, Default, Debug, PartialEq)]
+struct RollbackFrame(u32);
+
+fn rollback_world() -> World {
+    let world = World::new();
+
+    world
+        .system::<(&mut RollbackPosition, &RollbackVelocity, &mut RollbackTrail)>()
+        .each(|(pos, vel, trail)| {
+            pos.x += vel.x;
+            pos.y += vel.y;
+            trail.points.push(pos.x);
+        });
+
+    world
+        .system::<&mut RollbackFrame>()
+        .term_at(0)
+        .singleton()
+        .each(|frame| frame.0 += 1);
+
+    world
+}
+
+type RollbackState = (
+    Vec<(u64, RollbackPosition, RollbackTrail)>,
+    u32,
+    Option<String>,
+);
+
+fn rollback_state(world: &World) -> RollbackState {
+    let mut entities = Vec::new();
+    world
+        .query::<(&RollbackPosition, &RollbackTrail)>()
+        .build()
+        .each_entity(|e, (pos, trail)| {
+            entities.push((*e.id(), pos.clone(), trail.clone()));
+        });
+    entities.sort_by_key(|(e, _, _)| *e);
+
+    let frame = world.get::<&RollbackFrame>(|frame| frame.0);
+    let child = world
+        .try_lookup_recursive("parent::child")
+        .map(|e| e.path().unwrap());
+
+    (entities, frame, child)
+}
+
+#[test]
+fn world_copy_from_rollback() {
+    let mut world = rollback_world();
+    world.set(RollbackFrame(0));
+
+    let parent = world.entity_named("parent").id();
+    for i in 0..10 {
+        let e = world
+            .entity()
+            .set(RollbackPosition::default())
+            .set(RollbackVelocity {
+                x: i as f32,
+                y: 1.0,
+            })
+            .set(RollbackTrail::default());
+        if i == 0 {
+            e.set_name("child").child_of_id(parent);
+        }
+    }
+    // recycle an id, so the generation of the entity is not 0
+    world.entity().destruct();
+    let generation = world.entity().set(RollbackPosition::default()).id();
+    assert_ne!(*generation >> 32, 0);
+
+    for _ in 0..10 {
+        world.progress();
+    }
+
+    let mut snapshot = World::new();
+    snapshot.copy_from(&world);
+    let at_snapshot = rollback_state(&world);
+    assert_eq!(rollback_state(&snapshot), at_snapshot);
+    assert_eq!(at_snapshot.1, 10);
+    assert!(snapshot.is_alive(generation));
+
+    for _ in 0..10 {
+        world.progress();
+    }
+    let first_run = rollback_state(&world);
+    assert_eq!(first_run.1, 20);
+    assert_eq!(first_run.0[1].2.points.len(), 20);
+
+    // the snapshot is not affected by the simulation
+    assert_eq!(rollback_state(&snapshot), at_snapshot);
+
+    // entities created after the snapshot are removed by the rollback
+    let late = world.entity().set(RollbackPosition::default()).id();
+
+    world.copy_from(&snapshot);
+    assert_eq!(rollback_state(&world), at_snapshot);
+    assert!(!world.is_alive(late));
+    assert!(world.is_alive(generation));
+    assert_eq!(world.entity_from_id(generation).parent(), None);
+    assert_eq!(world.lookup("parent::child").parent().unwrap().id(), parent);
+
+    for _ in 0..10 {
+        world.progress();
+    }
+    assert_eq!(rollback_state(&world), first_run);
+
+    // deleting the restored parent deletes the restored child
+    world.entity_from_id(parent).destruct();
+    assert!(world.try_lookup_recursive("parent::child").is_none());
+}
+
+#[test]
+#[should_panic(expected = "is registered with a different id in the destination world")]
+fn world_copy_from_component_id_mismatch() {
+    let world = World::new();
+    world.component::<RollbackPosition>();
+    world.component::<RollbackVelocity>();
+    world.entity().set(RollbackPosition::default());
+
+    let mut other = World::new();
+    other.component::<RollbackVelocity>();
+    other.component::<RollbackPosition>();
+
+    other.copy_from(&world);
+}
+
+#[test]
+fn world_app_run_frames() {
+    #[derive(Component)]
+    struct AppFrames(u32);
+
+    let world = World::new();
+    world.set(AppFrames(0));
+
+    world
+        .system::<&mut AppFrames>()
+        .term_at(0)
+        .singleton()
+        .each(|frames| frames.0 += 1);
+
+    let result = world.app().set_target_fps(0.0).set_frames(5).run();
+
+    assert_eq!(result, 0);
+    assert!(world.should_quit());
+    world.get::<&AppFrames>(|frames| assert_eq!(frames.0, 5));
+}
+
+#[test]
+fn world_app_run_until_quit() {
+    #[derive(Component)]
+    struct AppFrames(u32);
+
+    let world = World::new();
+    world.set(AppFrames(0));
+
+    world
+        .system::<&mut AppFrames>()
+        .term_at(0)
+        .singleton()
+        .each_iter(|it, _, frames| {
+            frames.0 += 1;
+            if frames.0 == 3 {
+                it.world().quit();
+            }
+        });
+
+    let result = world.app().set_target_fps(0.0).run();
+
+    assert_eq!(result, 0);
+    world.get::<&AppFrames>(|frames| assert_eq!(frames.0, 3));
+}
+
+#[test]
+#[cfg(feature = "flecs_rest")]
+fn world_enable_rest_twice() {
+    let world = World::new();
+
+    let rest = world.enable_rest(Some(27761));
+    let server = world.get::<&flecs::rest::Rest>(|r| (r.port, r.impl_));
+    assert_eq!(server.0, 27761);
+
+    #[cfg(feature = "flecs_stats")]
+    assert!(world.try_lookup("flecs::stats").is_some());
+
+    // the second call keeps the running server
+    let rest_again = world.enable_rest(Some(27762));
+    assert_eq!(rest, rest_again);
+    assert_eq!(
+        world.get::<&flecs::rest::Rest>(|r| (r.port, r.impl_)),
+        server
+    );
+
+    world.progress();
+
+    rest.remove::<flecs::rest::Rest>();
+    assert!(!world.has::<flecs::rest::Rest>());
+}
+
+#[test]
+#[cfg(feature = "flecs_stats")]
+fn world_stats_sample() {
+    use crate::common_test::Position;
+
+    let world = World::new();
+    world.import::<flecs_ecs::addons::stats::Stats>();
+
+    let system = world.system::<&Position>().each(|_| {});
+    world.progress();
+
+    let before = world.world_stats();
+    let system_before = world.system_stats(system).unwrap();
+    assert!(system_before.matched_entity_count < 1.0);
+
+    for _ in 0..10 {
+        world.entity().set(Position { x: 1, y: 2 });
+    }
+    world.progress();
+    world.progress();
+
+    let after = world.world_stats();
+    assert!(after.entity_count >= before.entity_count + 10.0);
+    assert!(after.frame_count >= before.frame_count + 2.0);
+    assert!(after.systems_ran > before.systems_ran);
+    assert!(after.system_count >= 1.0);
+
+    let system_after = world.system_stats(system).unwrap();
+    assert!((system_after.matched_entity_count - 10.0).abs() < f32::EPSILON);
+    assert!(system_after.time_spent >= system_before.time_spent);
+    assert!(!system_after.task);
+
+    assert!(world.system_stats(world.entity()).is_none());
+}
+
+#[test]
+#[cfg(feature = "flecs_stats")]
+fn world_import_stats_in_multiple_worlds() {
+    for _ in 0..2 {
+        let world = World::new();
+        world.import::<flecs_ecs::addons::stats::Stats>();
+        assert!(world.try_lookup("flecs::stats").is_some());
+    }
+}
+
+// asserts of flecs are compiled out in release builds
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "flecs aborted")]
+fn world_flecs_assert_panics() {
+    let _guard = flecs_ecs::log::panic_on_abort_scope(true);
+
+    let world = World::new();
+    let e = world.entity();
+
+    unsafe { sys::ecs_add_id(world.ptr_mut(), *e.id(), 0) };
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn world_flecs_assert_panic_message() {
+    let _guard = flecs_ecs::log::panic_on_abort_scope(true);
+
+    let world = World::new();
+    let e = world.entity();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+        sys::ecs_add_id(world.ptr_mut(), *e.id(), 0);
+    }));
+
+    let message = result.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.contains("INVALID_PARAMETER"));
+
+    // the world is still usable after the panic was caught
+    assert!(e.is_alive());
+}
+
+#[test]
+fn world_time_scale_delta_time() {
+    #[derive(Component)]
+    struct LastDelta(f32);
+
+    let world = World::new();
+    world.set(LastDelta(0.0));
+
+    world
+        .system::<&mut LastDelta>()
+        .term_at(0)
+        .singleton()
+        .each_iter(|it, _, last| last.0 = it.delta_time());
+
+    world.progress_time(2.0);
+    world.get::<&LastDelta>(|last| assert_eq!(last.0, 2.0));
+
+    world.set_time_scale(0.5);
+    assert_eq!(world.get_time_scale(), 0.5);
+    world.progress_time(2.0);
+    world.get::<&LastDelta>(|last| assert_eq!(last.0, 1.0));
+    assert_eq!(world.delta_time(), 1.0);
+    assert_eq!(world.world_time(), 3.0);
+
+    // frames of an external loop are scaled as well
+    world.frame_begin(4.0);
+    world.frame_end();
+    assert_eq!(world.delta_time(), 2.0);
+    assert_eq!(world.world_time(), 5.0);
+}
+
+#[test]
+fn world_progress_returns_false_after_quit() {
+    let world = World::new();
+    world.set_target_fps(0.0);
+
+    let mut frames = 0;
+    while world.progress() {
+        frames += 1;
+        if frames == 3 {
+            world.quit();
+        }
+    }
+
+    assert_eq!(frames, 3);
+    assert!(!world.progress());
+}
+
+#[test]
+fn world_make_alive_with_generation() {
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+
+    // an id assigned by a server, with generation 3
+    let id = Entity((3 << 32) | 1000);
+    assert!(!world.exists(1000));
+    assert!(world.try_get_alive(1000).is_none());
+
+    let e = world.make_alive(id).set(Position { x: 1.0, y: 2.0 });
+    assert_eq!(e.id(), id);
+    assert!(world.is_alive(id));
+    assert!(world.exists(1000));
+    // the same id with another generation is not alive
+    assert!(!world.is_alive((2 << 32) | 1000));
+
+    let alive = world.try_get_alive(1000).unwrap();
+    assert_eq!(alive.id(), id);
+    assert_eq!(world.get_alive(1000).id(), id);
+    alive.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (1.0, 2.0)));
+
+    // resurrect the id with a newer generation after it was deleted
+    e.destruct();
+    assert!(world.try_get_alive(1000).is_none());
+
+    let id = Entity((5 << 32) | 1000);
+    world.make_alive(id).set(Position { x: 3.0, y: 4.0 });
+    assert_eq!(world.get_alive(1000).id(), id);
+    assert!(!world.is_alive((3 << 32) | 1000));
+}
+
+#[test]
+fn world_entity_range() {
+    let world = World::new();
+    let outside = world.entity();
+
+    world.set_entity_range(5000, 6000);
+    world.enable_range_check(true);
+
+    let e = world.entity();
+    assert_eq!(e.id(), 5000);
+    e.add_id(world.entity());
+
+    world.enable_range_check(false);
+    outside.add_id(e);
+    assert!(outside.has_id(e));
+}
+
+// asserts of flecs are compiled out in release builds
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "OUT_OF_RANGE")]
+fn world_entity_range_check_panics() {
+    let _guard = flecs_ecs::log::panic_on_abort_scope(true);
+
+    let world = World::new();
+    let outside = world.entity();
+
+    world.set_entity_range(5000, 6000);
+    world.enable_range_check(true);
+
+    outside.add_id(world.entity());
+}
+
+#[test]
+fn world_ctx_in_system() {
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    struct Config {
+        speed: f32,
+    }
+
+    let world = World::new();
+    world.set_ctx(Config { speed: 2.0 });
+
+    world.system::<&mut Position>().each_iter(|it, _, p| {
+        p.x += it.world().ctx::<Config>().unwrap().speed;
+    });
+
+    let e = world.entity().set(Position { x: 1.0, y: 0.0 });
+
+    world.progress();
+    e.get::<&Position>(|p| assert_eq!(p.x, 3.0));
+
+    world.ctx_mut(|config: &mut Config| config.speed = 5.0);
+
+    world.progress();
+    e.get::<&Position>(|p| assert_eq!(p.x, 8.0));
+}
+
+#[test]
+fn world_ctx_type_mismatch() {
+    let world = World::new();
+    assert!(world.ctx::<i32>().is_none());
+
+    world.set_ctx(10_i32);
+    assert_eq!(world.ctx::<i32>(), Some(&10));
+    assert!(world.ctx::<u32>().is_none());
+    assert!(world.ctx_mut(|_: &mut u32| unreachable!()).is_none());
+
+    // a context set without a type is not returned
+    let mut raw = 0_i32;
+    world.set_context(&mut raw as *mut i32 as *mut std::ffi::c_void, None);
+    assert!(world.ctx::<i32>().is_none());
+}
+
+#[test]
+fn world_ctx_dropped() {
+    use std::rc::Rc;
+
+    let first = Rc::new(());
+    let second = Rc::new(());
+
+    let world = World::new();
+    world.set_ctx(first.clone());
+    world.set_ctx(second.clone());
+    assert_eq!(Rc::strong_count(&first), 1);
+    assert_eq!(Rc::strong_count(&second), 2);
+
+    drop(world);
+    assert_eq!(Rc::strong_count(&second), 1);
+}
+
+#[test]
+fn world_singleton() {
+    #[derive(Component)]
+    struct Gravity {
+        value: f32,
+    }
+
+    let world = World::new();
+    assert!(!world.try_singleton::<Gravity>(|_| unreachable!()));
+    assert!(!world.try_singleton_mut::<Gravity>(|_| unreachable!()));
+
+    world.set_singleton(Gravity { value: 9.81 });
+
+    assert_eq!(world.singleton(|g: &Gravity| g.value), 9.81);
+    assert!(world.try_singleton::<Gravity>(|g| assert_eq!(g.value, 9.81)));
+
+    let entity = world.singleton_entity::<Gravity>();
+    assert_eq!(entity, world.component_id::<Gravity>());
+    entity.get::<&Gravity>(|g| assert_eq!(g.value, 9.81));
+}
+
+#[test]
+fn world_singleton_mut_invokes_on_set() {
+    #[derive(Component)]
+    struct Gravity {
+        value: f32,
+    }
+
+    let world = World::new();
+    world.set_singleton(Gravity { value: 9.81 });
+
+    let count = std::rc::Rc::new(std::cell::Cell::new(0));
+    let observed = count.clone();
+    world
+        .observer::<flecs::OnSet, &Gravity>()
+        .singleton()
+        .each(move |_| observed.set(observed.get() + 1));
+
+    world.singleton_mut(|g: &mut Gravity| g.value = 1.62);
+    assert_eq!(count.get(), 1);
+    assert_eq!(world.singleton(|g: &Gravity| g.value), 1.62);
+
+    assert!(world.try_singleton_mut::<Gravity>(|g| g.value = 3.72));
+    assert_eq!(count.get(), 2);
+    assert_eq!(world.singleton(|g: &Gravity| g.value), 3.72);
+}
+
+#[test]
+#[should_panic(expected = "is not set")]
+fn world_singleton_not_set_panics() {
+    #[derive(Component)]
+    struct Gravity {
+        #[allow(dead_code)]
+        value: f32,
+    }
+
+    let world = World::new();
+    world.singleton(|_: &Gravity| ());
+}
@@ -9,6 +9,7 @@ use syn::{
     bracketed, parenthesized,
     parse::{Parse, ParseStream},
     parse_macro_input,
+    spanned::Spanned,
     token::{Bracket, Comma},
     Data, DeriveInput, Expr, Fields, Ident, LitInt, LitStr, Path, Result, Token, Type,
 };
@@ -28,10 +29,32 @@ use syn::{
 ///   In that case, the user has to manually register the hooks for each variant of T of the generic component
 ///   by using `T::register_ctor_hook` and `T::register_clone_hook`.
 ///
+/// # Registration name
+/// - With `#[flecs(name = "...")]` the component is registered with the given name instead of the path of the
+///   Rust type, and `#[flecs(symbol = "...")]` sets the symbol. Both also apply to components that are
+///   registered implicitly, e.g. by `EntityView::set`.
+///
+/// # Collection
+/// - With the `auto_register` feature, non-generic components are collected at start up so
+///   `World::register_collected` can register them. `#[flecs(no_collect)]` leaves a component out, e.g.
+///   because it's registered by a module.
+///
+/// # Reflection
+/// - With `#[meta]` the `Meta` trait is implemented, `world.component::<T>().meta()` then registers every named field
+///   as a member with its type, offset and, for arrays, element count. Fields marked `#[skip]` are left out.
+///   `Option<T>` and `HashMap<String, T>` fields are registered as the opaque types of
+///   `flecs_ecs::addons::meta::rust_types`. Fields such as references, pointers, slices and tuples produce a
+///   compile error naming the field.
+///
 /// # Enums:
 ///
 /// Ensure that enums annotated with `Component` have at least one variant; otherwise, a compile-time error will be triggered.
 ///
+/// `#[repr(C)]`, `#[repr(i32)]` and `#[repr(u32)]` enums are registered as a reflection enum type, each variant is a
+/// constant with its discriminant as value, so explicit and negative discriminants are serialized by name. Flecs
+/// stores the value of a constant as `i32`, variants with a discriminant outside of its range are reported as an
+/// error when the enum is registered and are not constants of the reflection type.
+///
 /// ## Example:
 ///
 /// ```ignore
@@ -56,10 +79,15 @@ use syn::{
 ///     Jumping,
 /// }
 /// ```
-#[proc_macro_derive(Component, attributes(meta, skip, on_registration))]
+#[proc_macro_derive(Component, attributes(meta, skip, on_registration, flecs))]
 pub fn component_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
     let mut input = parse_macro_input!(input as DeriveInput);
 
+    let (registration_names, no_collect) = match flecs_attributes(&input) {
+        Ok(attributes) => attributes,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let has_repr_c = check_repr_c(&input);
     let has_on_registration = input
         .attrs
@@ -81,6 +109,7 @@ pub fn component_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
                 has_fields,
                 &is_tag,
                 has_on_registration,
+                &registration_names,
             ));
         }
         Data::Enum(_) => {
@@ -91,11 +120,13 @@ pub fn component_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
                     true,
                     &is_tag,
                     has_on_registration,
+                    &registration_names,
                 ));
             } else {
                 generated_impls.push(impl_cached_component_data_enum(
                     &mut input,
                     has_on_registration,
+                    &registration_names,
                 ));
             }
         }
@@ -105,11 +136,17 @@ pub fn component_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
     input.generics.make_where_clause();
 
     let meta_impl = impl_meta(&input, has_repr_c, input.ident.clone());
+    let collect_impl = if no_collect {
+        quote! {}
+    } else {
+        impl_collect(&input)
+    };
 
     // Combine the generated code with the original struct definition
     let output = quote! {
         #( #generated_impls )*
         #meta_impl
+        #collect_impl
     };
 
     output.into()
@@ -123,10 +160,12 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
     }
 
     let mut meta_fields_impl = Vec::new();
+    // reported regardless of the `flecs_meta` feature, so a type doesn't only fail to compile with it
+    let mut meta_errors = Vec::new();
 
     match input.data.clone() {
-        Data::Struct(data_struct) => {
-            if let Fields::Named(fields_named) = &data_struct.fields {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => {
                 for field in &fields_named.named {
                     let is_ignored = field.attrs.iter().any(|attr| attr.path().is_ident("skip"));
 
@@ -134,22 +173,33 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
                         continue;
                     }
 
-                    let field_name = &field.ident;
-                    let field_type = &field.ty;
+                    let Some(field_name) = &field.ident else {
+                        continue;
+                    };
 
-                    if let Some(field_name) = field_name {
-                        meta_fields_impl.push(quote! {
-                            .member_id(id!(world, #field_type), (stringify!(#field_name), flecs_ecs::addons::meta::Count(1), core::mem::offset_of!(#struct_name, #field_name)))
-                        });
-                    } else {
-                        meta_fields_impl.push( quote! {
-                            compile_error!("Meta expects named fields, unnamed fields are not supported");
+                    match meta_member_type(&field.ty) {
+                        Ok((member_type, count)) => meta_fields_impl.push(quote! {
+                            .member_id(#member_type, (stringify!(#field_name), flecs_ecs::addons::meta::Count(#count), core::mem::offset_of!(#struct_name, #field_name)))
+                        }),
+                        Err(type_kind) => {
+                            let message = format!(
+                                "Meta does not support field `{}`: {} can't be described as a member",
+                                field_name, type_kind
+                            );
+                            meta_errors.push(quote_spanned! { field.ty.span() =>
+                                compile_error!(#message);
+                            });
                         }
-                        );
                     }
                 }
             }
-        }
+            Fields::Unnamed(_) => {
+                meta_errors.push(quote! {
+                    compile_error!("Meta expects named fields, unnamed fields are not supported");
+                });
+            }
+            Fields::Unit => {}
+        },
         Data::Enum(data_enum) => {
             if !has_repr_c {
                 meta_fields_impl.push( quote! {
@@ -187,7 +237,81 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
         #( #meta_fields_impl )*;
     };
 
-    meta_impl_return(meta_fn_impl, struct_name)
+    let meta_impl = meta_impl_return(meta_fn_impl, struct_name);
+
+    quote! {
+        #meta_impl
+        #( #meta_errors )*
+    }
+}
+
+/// Splits a field type into the id of the type registered for the member and its element count.
+/// Arrays become a member of the element type, types that have no layout flecs can describe
+/// (references, pointers, slices, tuples, ...) return what kind of type they are.
+fn meta_member_type(ty: &Type) -> std::result::Result<(TokenStream, TokenStream), &'static str> {
+    match ty {
+        Type::Path(_) => Ok((meta_type_id(ty), quote! { 1 })),
+        Type::Paren(paren) => meta_member_type(&paren.elem),
+        Type::Group(group) => meta_member_type(&group.elem),
+        Type::Array(array) => match &*array.elem {
+            Type::Path(_) => {
+                let len = &array.len;
+                Ok((meta_type_id(&array.elem), quote! { (#len) as i32 }))
+            }
+            Type::Array(_) => Err("a nested array"),
+            _ => Err("an array of this element type"),
+        },
+        Type::Reference(_) => Err("a reference"),
+        Type::Ptr(_) => Err("a raw pointer"),
+        Type::Slice(_) => Err("a slice"),
+        Type::Tuple(_) => Err("a tuple"),
+        Type::BareFn(_) => Err("a function pointer"),
+        Type::TraitObject(_) | Type::ImplTrait(_) => Err("a trait object"),
+        _ => Err("this type"),
+    }
+}
+
+/// Id of the type of a member, `Option<T>` and `HashMap<String, T>` are registered with the
+/// reflection support of `flecs_ecs::addons::meta::rust_types`, after their value type so that
+/// nested options and maps are registered too.
+fn meta_type_id(ty: &Type) -> TokenStream {
+    let Type::Path(type_path) = ty else {
+        return quote! { id!(world, #ty) };
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return quote! { id!(world, #ty) };
+    };
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return quote! { id!(world, #ty) };
+    };
+    let types = arguments
+        .args
+        .iter()
+        .filter_map(|argument| match argument {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let is_string = |ty: &Type| matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "String"));
+
+    match (segment.ident.to_string().as_str(), types.as_slice()) {
+        ("Option", [value]) => {
+            let value_id = meta_type_id(value);
+            quote! { {
+                let _ = #value_id;
+                flecs_ecs::addons::meta::rust_types::option_id::<#value>(world)
+            } }
+        }
+        ("HashMap", [key, value]) if is_string(key) => {
+            let value_id = meta_type_id(value);
+            quote! { {
+                let _ = #value_id;
+                flecs_ecs::addons::meta::rust_types::hash_map_id::<#value>(world)
+            } }
+        }
+        _ => quote! { id!(world, #ty) },
+    }
 }
 
 #[cfg(feature = "flecs_meta")]
@@ -213,6 +337,29 @@ fn meta_impl_return(_meta_fn_impl: TokenStream, struct_name: Ident) -> TokenStre
     }
 }
 
+/// Collect the component for `World::register_collected`, generic components can't be collected.
+#[cfg(feature = "auto_register")]
+fn impl_collect(input: &DeriveInput) -> TokenStream {
+    if !input.generics.params.is_empty() {
+        return quote! {};
+    }
+
+    let name = &input.ident;
+    quote! {
+        const _: () = {
+            #[flecs_ecs::core::component_registration::ctor]
+            fn collect() {
+                flecs_ecs::core::component_registration::collect_component::<#name>();
+            }
+        };
+    }
+}
+
+#[cfg(not(feature = "auto_register"))]
+fn impl_collect(_input: &DeriveInput) -> TokenStream {
+    quote! {}
+}
+
 fn generate_tag_trait(has_fields: bool) -> proc_macro2::TokenStream {
     if has_fields {
         quote! {
@@ -260,6 +407,7 @@ fn impl_cached_component_data_struct(
     has_fields: bool,
     is_tag: &TokenStream,
     has_on_registration: bool,
+    registration_names: &TokenStream,
 ) -> proc_macro2::TokenStream {
     let is_generic = !ast.generics.params.is_empty();
 
@@ -481,6 +629,8 @@ fn impl_cached_component_data_struct(
     };
 
     let component_info_impl = quote! {
+        #registration_names
+
         #[inline(always)]
         fn index() -> u32 {
             static INDEX: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(u32::MAX);
@@ -680,6 +830,7 @@ fn generate_variant_match_arm(
 fn impl_cached_component_data_enum(
     ast: &mut syn::DeriveInput,
     has_on_registration: bool,
+    registration_names: &TokenStream,
 ) -> proc_macro2::TokenStream {
     let is_generic = !ast.generics.params.is_empty();
 
@@ -711,6 +862,23 @@ fn impl_cached_component_data_enum(
         .map(|(index, variant)| generate_variant_match_arm(variant, name, true, index))
         .collect();
 
+    // fieldless enums are cast to their discriminant, which is the value of the constant
+    let discriminant_fn = if variants
+        .iter()
+        .all(|variant| matches!(variant.fields, syn::Fields::Unit))
+    {
+        let variant_idents = variants.iter().map(|variant| &variant.ident);
+        quote! {
+            fn discriminant(&self) -> i64 {
+                match self {
+                    #(#name::#variant_idents => #name::#variant_idents as i64),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let has_variants = !variants.is_empty();
     let size_variants = variants.len() as u32;
     let not_empty_trait_or_error = if has_variants {
@@ -736,6 +904,8 @@ fn impl_cached_component_data_enum(
             }
         }
 
+        #discriminant_fn
+
         fn __enum_data_mut() -> *mut u64 {
             static mut ENUM_FIELD_ENTITY_ID: [u64; #size_variants as usize] = [0; #size_variants as usize];
             unsafe { ENUM_FIELD_ENTITY_ID.as_mut_ptr() }
@@ -754,6 +924,8 @@ fn impl_cached_component_data_enum(
     };
 
     let component_info_impl = quote! {
+            #registration_names
+
             #[inline(always)]
             fn index() -> u32 {
                 static INDEX: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(u32::MAX);
@@ -873,6 +1045,46 @@ fn impl_cached_component_data_enum(
     }
 }
 
+/// Parses `#[flecs(name = "...", symbol = "...", no_collect)]` into the `CUSTOM_NAME` and
+/// `CUSTOM_SYMBOL` constants of `ComponentId`, and whether the component is left out of
+/// `World::register_collected`.
+fn flecs_attributes(input: &syn::DeriveInput) -> Result<(TokenStream, bool)> {
+    let mut name = None;
+    let mut symbol = None;
+    let mut no_collect = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("flecs"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else if meta.path.is_ident("symbol") {
+                symbol = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else if meta.path.is_ident("no_collect") {
+                no_collect = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `name`, `symbol` or `no_collect`"))
+            }
+        })?;
+    }
+
+    let name = name.map(|name| quote! { const CUSTOM_NAME: Option<&'static str> = Some(#name); });
+    let symbol =
+        symbol.map(|symbol| quote! { const CUSTOM_SYMBOL: Option<&'static str> = Some(#symbol); });
+    Ok((
+        quote! {
+            #name
+            #symbol
+        },
+        no_collect,
+    ))
+}
+
 fn check_repr_c(input: &syn::DeriveInput) -> bool {
     for attr in &input.attrs {
         if attr.path().is_ident("repr") {
@@ -900,6 +1112,83 @@ fn check_repr_c(input: &syn::DeriveInput) -> bool {
     false // Return false if no `#[repr(C)]` is found
 }
 
+/// `FlagSet` macro for enums whose variants are used as bits of a `Flags<T>` component.
+///
+/// Each variant maps to one bit, in declaration order. The enum must be fieldless, have at
+/// least one and at most 64 variants and implement `Copy`.
+///
+/// ## Example:
+///
+/// ```ignore
+/// #[derive(FlagSet, Clone, Copy)]
+/// enum UnitFlags {
+///     Burning,
+///     Stunned,
+///     Invisible,
+/// }
+/// ```
+#[proc_macro_derive(FlagSet)]
+pub fn flag_set_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let Data::Enum(data_enum) = &input.data else {
+        return quote_spanned! {name.span()=>
+            compile_error!("FlagSet can only be derived for enums");
+        }
+        .into();
+    };
+
+    if !input.generics.params.is_empty() {
+        return quote_spanned! {name.span()=>
+            compile_error!("FlagSet can not be derived for generic enums");
+        }
+        .into();
+    }
+
+    if data_enum.variants.is_empty() || data_enum.variants.len() > 64 {
+        return quote_spanned! {name.span()=>
+            compile_error!("FlagSet enums must have between 1 and 64 variants");
+        }
+        .into();
+    }
+
+    if let Some(variant) = data_enum
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return quote_spanned! {variant.ident.span()=>
+            compile_error!("FlagSet enum variants can not have fields");
+        }
+        .into();
+    }
+
+    let variants = data_enum
+        .variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+    let names = variants.iter().map(|variant| variant.to_string());
+    let indices = (0..variants.len() as u32).collect::<Vec<_>>();
+    let count = variants.len() as u32;
+
+    quote! {
+        impl flecs_ecs::core::FlagSet for #name {
+            const COUNT: u32 = #count;
+            const NAMES: &'static [&'static str] = &[#( #names ),*];
+
+            #[inline(always)]
+            fn index(self) -> u32 {
+                match self {
+                    #( Self::#variants => #indices, )*
+                }
+            }
+        }
+    }
+    .into()
+}
+
 struct Tuples {
     macro_ident: Ident,
     start: usize,
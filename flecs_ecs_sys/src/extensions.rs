@@ -346,6 +346,28 @@ impl Default for crate::ecs_iter_to_json_desc_t {
     }
 }
 
+#[allow(clippy::derivable_impls)] // this is generated by bindgen
+impl Default for crate::ecs_bitmask_constant_t {
+    fn default() -> Self {
+        Self {
+            name: core::ptr::null(),
+            value: Default::default(),
+            _unused: Default::default(),
+            constant: Default::default(),
+        }
+    }
+}
+
+#[allow(clippy::derivable_impls)] // this is generated by bindgen
+impl Default for crate::ecs_bitmask_desc_t {
+    fn default() -> Self {
+        Self {
+            entity: Default::default(),
+            constants: Default::default(),
+        }
+    }
+}
+
 unsafe impl Sync for crate::EcsIdentifier {}
 unsafe impl Send for crate::EcsIdentifier {}
 unsafe impl Send for crate::EcsPoly {}
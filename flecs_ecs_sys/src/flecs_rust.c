@@ -117,3 +117,86 @@ error:
     return -1;
 }
 
+/* Append the entities of a table of another world to the table with the same
+ * type in dst. Entities keep their ids and must be alive and empty in dst.
+ * Component values are copied with the copy hook, or memcpy if the component
+ * has no copy hook. */
+void ecs_rust_table_copy(
+    ecs_world_t *dst,
+    const ecs_table_t *src_table)
+{
+    ecs_check(dst != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(src_table != NULL, ECS_INVALID_PARAMETER, NULL);
+
+    int32_t i, count = ecs_table_count(src_table);
+    ecs_type_t type = src_table->type;
+    if (!count || !type.count) {
+        return;
+    }
+
+    ecs_table_t *table = ecs_table_find(dst, type.array, type.count);
+    if (!table) {
+        ecs_abort(ECS_INTERNAL_ERROR, NULL);
+    }
+
+    const ecs_entity_t *entities = ecs_table_entities(src_table);
+    uint32_t *flags = ecs_os_malloc_n(uint32_t, count);
+    for (i = 0; i < count; i ++) {
+        ecs_record_t *r = flecs_entities_get(dst, entities[i]);
+        /* Checked in every build, the copy would corrupt the table otherwise */
+        if (!r) {
+            ecs_abort(ECS_INVALID_PARAMETER, "entity is not alive");
+        }
+        if (r->table) {
+            ecs_abort(ECS_INVALID_PARAMETER, "entity is not empty");
+        }
+        flags[i] = ECS_RECORD_TO_ROW_FLAGS(r->row);
+    }
+
+    void **data = ecs_os_malloc_n(void*, type.count);
+    for (i = 0; i < type.count; i ++) {
+        int32_t column = ecs_table_type_to_column_index(src_table, i);
+        data[i] = column == -1 ? NULL : src_table->data.columns[column].data;
+    }
+
+    ecs_table_diff_t diff = {
+        .added.array = table->type.array,
+        .added.count = table->type.count
+    };
+
+    flecs_bulk_new(dst, table, entities, NULL, count, data, false, NULL, 
+        &diff);
+
+    /* Creating the table may have flagged entities as relationship targets
+     * before they were inserted, restore the flags cleared by flecs_bulk_new */
+    for (i = 0; i < count; i ++) {
+        ecs_record_t *r = flecs_entities_get(dst, entities[i]);
+        if (flags[i] & EcsEntityIsTraversable) {
+            flecs_record_add_flag(r, EcsEntityIsTraversable);
+        }
+        r->row |= flags[i];
+    }
+
+    ecs_os_free(data);
+    ecs_os_free(flags);
+error:
+    return;
+}
+
+
+#ifdef FLECS_PIPELINE
+const ecs_query_t* ecs_rust_pipeline_query(
+    const ecs_world_t *world,
+    ecs_entity_t pipeline)
+{
+    ecs_check(world != NULL, ECS_INVALID_PARAMETER, NULL);
+
+    const EcsPipeline *p = ecs_get(world, pipeline, EcsPipeline);
+    if (!p || !p->state) {
+        return NULL;
+    }
+    return p->state->query;
+error:
+    return NULL;
+}
+#endif
@@ -22,3 +22,15 @@ FLECS_API
     ecs_id_t id,
     ecs_table_t* table);
 
+FLECS_API
+void ecs_rust_table_copy(
+    ecs_world_t *dst,
+    const ecs_table_t *src_table);
+
+
+#ifdef FLECS_PIPELINE
+FLECS_API
+const ecs_query_t* ecs_rust_pipeline_query(
+    const ecs_world_t *world,
+    ecs_entity_t pipeline);
+#endif
@@ -30,6 +30,16 @@ extern "C-unwind" {
         table: *mut ecs_table_t,
     ) -> i32;
 }
+extern "C-unwind" {
+    pub fn ecs_rust_table_copy(dst: *mut ecs_world_t, src_table: *const ecs_table_t);
+}
+#[cfg(feature = "flecs_pipeline")]
+extern "C-unwind" {
+    pub fn ecs_rust_pipeline_query(
+        world: *const ecs_world_t,
+        pipeline: ecs_entity_t,
+    ) -> *const ecs_query_t;
+}
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -146,16 +156,18 @@ pub struct WorldInfo {
     pub emit_time_total: f32,
     /// Total time spent in merges.
     pub merge_time_total: f32,
-    /// Time elapsed in simulation.
-    pub world_time_total: f32,
-    /// Time elapsed in simulation (no scaling).
-    pub world_time_total_raw: f32,
     /// Time spent on query rematching.
     pub rematch_time_total: f32,
+    /// Time elapsed in simulation.
+    pub world_time_total: f64,
+    /// Time elapsed in simulation (no scaling).
+    pub world_time_total_raw: f64,
     /// Total number of frames.
     pub frame_count_total: i64,
     /// Total number of merges.
     pub merge_count_total: i64,
+    /// Total number of monitor evaluations.
+    pub eval_comp_monitors_total: i64,
     /// Total number of rematches.
     pub rematch_count_total: i64,
     /// Total number of times a new id was created.
@@ -180,8 +192,7 @@ pub struct WorldInfo {
     pub pair_id_count: i32,
     /// Number of tables.
     pub table_count: i32,
-    /// Number of tables without entities.
-    pub empty_table_count: i32,
+    /// Command statistics.
     pub cmd: WorldInfoCmd,
     /// Value set by `ecs_set_name_prefix()`. Used
     /// to remove library prefixes of symbol names (such as `Ecs`, `ecs_`) when
@@ -272,6 +283,15 @@ fn compile_test_check_if_any_ecs_world_info_fields_changed() {
         },
         name_prefix: core::ptr::null(),
     };
+
+    assert_eq!(
+        core::mem::size_of::<WorldInfo>(),
+        core::mem::size_of::<ecs_world_info_t>()
+    );
+    assert_eq!(
+        core::mem::offset_of!(WorldInfo, cmd),
+        core::mem::offset_of!(ecs_world_info_t, cmd)
+    );
 }
 
 unsafe impl Send for EcsWorldStats {}